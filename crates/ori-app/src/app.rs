@@ -1,24 +1,36 @@
-use std::{any::Any, collections::HashMap};
+use std::{
+    any::Any,
+    collections::{HashMap, HashSet},
+    sync::Weak,
+    time::Duration,
+};
 
 use instant::Instant;
 use ori_core::{
-    canvas::{Canvas, Color},
+    canvas::{Canvas, Color, Primitive},
     command::{CommandProxy, CommandReceiver},
     context::{BaseCx, BuildCx, Contexts, DrawCx, EventCx, LayoutCx, RebuildCx},
     event::{
-        Code, Event, FocusTarget, Ime, Key, KeyPressed, KeyReleased, Modifiers, PointerButton,
-        PointerId, PointerLeft, PointerMoved, PointerPressed, PointerReleased, PointerScrolled,
-        RequestFocus, WindowCloseRequested, WindowMaximized, WindowResized, WindowScaled,
+        Code, Event, FocusTarget, Ime, Key, KeyPressed, KeyReleased, Modifiers, OpenUrl,
+        PointerButton, PointerId, PointerLeft, PointerMoved, PointerPressed, PointerRelativeMoved,
+        PointerReleased, PointerScrolled, RequestFocus, Shortcut, TimerId, TimerRequest, Timers,
+        WindowCloseRequested, WindowMaximized, WindowResized, WindowScaled,
     },
     layout::{Point, Size, Space, Vector},
-    log::trace,
-    style::{Styles, Theme},
+    log::{field, trace, trace_span},
+    style::{ColorScheme, Styles, Theme, Viewport},
+    transition::{ReducedMotion, Transition},
     view::{any, AnyState, BoxedView, View, ViewState},
     views::opaque,
-    window::{Cursor, Window, WindowId, WindowSizing, WindowSnapshot, WindowUpdate},
+    window::{
+        Cursor, Monitor, Monitors, RedrawReason, Window, WindowId, WindowSizing, WindowSnapshot,
+        WindowUpdate,
+    },
 };
 
-use crate::{AppBuilder, AppCommand, AppDelegate, AppRequest, DelegateCx, UiBuilder};
+use crate::{
+    AppBuilder, AppCommand, AppDelegate, AppRequest, DelegateCx, FramePacing, Shortcuts, UiBuilder,
+};
 
 /// Information needed to render a window.
 pub struct WindowRenderState<'a> {
@@ -30,6 +42,12 @@ pub struct WindowRenderState<'a> {
 
     /// The clear color of the window.
     pub clear_color: Color,
+
+    /// Whether anything changed since the last frame of the window.
+    ///
+    /// When nothing changed, the platform can skip rendering and presenting the frame, as long
+    /// as the last frame is still on screen, i.e. the surface hasn't been resized or damaged.
+    pub changed: bool,
 }
 
 pub(crate) struct WindowState<T> {
@@ -43,14 +61,30 @@ pub(crate) struct WindowState<T> {
     window: Window,
     snapshot: WindowSnapshot,
     animate: Option<Instant>,
+    last_frame: Option<Instant>,
+    // the time of the next animation frame, when it's delayed by the frame pacing
+    paced_frame: Option<Instant>,
+    // the size, scale and clear color of the last frame, to tell whether the next one changed
+    last_present: Option<(Size, f32, Color)>,
+    // whether the last frame drew nothing, and therefore probably wasn't presented
+    unpresented: bool,
+    // whether the window is being drawn, animation frames requested while drawing are
+    // requested at the end of the frame, once it's known whether it was presented
+    drawing: bool,
+    // whether the window was resized since it was last rebuilt, the window is rebuilt at
+    // most once per frame, so the viewport styles are up to date
+    resized: bool,
 }
 
 impl<T> WindowState<T> {
     fn rebuild(&mut self, data: &mut T, base: &mut BaseCx) {
+        let _span = trace_span!("rebuild", window = ?self.window.id()).entered();
         let t = Instant::now();
 
         self.view_state.prepare();
+        self.resized = false;
 
+        set_viewport(base, &self.window);
         let mut cx = RebuildCx::new(base, &mut self.view_state);
 
         let mut new_view = (self.ui)(data);
@@ -76,6 +110,7 @@ impl<T> WindowState<T> {
         rebuild: &mut bool,
         event: &Event,
     ) -> bool {
+        let _span = trace_span!("event", window = ?self.window.id()).entered();
         let t = Instant::now();
 
         let hovered = self.window.is_hovered(self.view_state.id());
@@ -98,6 +133,7 @@ impl<T> WindowState<T> {
     }
 
     fn layout(&mut self, data: &mut T, base: &mut BaseCx) {
+        let _span = trace_span!("layout", window = ?self.window.id()).entered();
         let t = Instant::now();
 
         self.view_state.mark_layed_out();
@@ -107,6 +143,12 @@ impl<T> WindowState<T> {
         let max_size = match self.window.sizing {
             WindowSizing::Fixed => self.window.size,
             WindowSizing::Content => Size::INFINITY,
+
+            // a window fitting its content shouldn't be larger than the monitor
+            WindowSizing::FitContent => match base.monitors().primary() {
+                Some(monitor) => self.window.size.min(monitor.work_area.size()),
+                None => self.window.size,
+            },
         };
 
         let space = Space::new(Size::ZERO, max_size);
@@ -128,6 +170,12 @@ impl<T> WindowState<T> {
             self.window.size = size;
         }
 
+        // a window fitting its content is only resized once, after which it's fixed
+        if let WindowSizing::FitContent = self.window.sizing {
+            self.window.size = size;
+            self.window.sizing = WindowSizing::Fixed;
+        }
+
         trace!(
             window = ?self.window.id(),
             elapsed = ?t.elapsed(),
@@ -136,6 +184,12 @@ impl<T> WindowState<T> {
     }
 
     fn draw(&mut self, data: &mut T, base: &mut BaseCx) {
+        let span = trace_span!(
+            "draw",
+            window = ?self.window.id(),
+            primitives = field::Empty,
+        );
+        let _span = span.enter();
         let t = Instant::now();
 
         self.view_state.mark_drawn();
@@ -144,10 +198,37 @@ impl<T> WindowState<T> {
 
         let mut cx = DrawCx::new(base, &mut self.view_state, &mut self.canvas);
 
+        #[cfg(feature = "debug")]
+        if let Some(inspector) = cx.get_context_mut::<ori_core::debug::Inspector>() {
+            inspector.clear();
+        }
+
         cx.insert_context(self.window.clone());
         self.view.draw(&mut self.state, &mut cx, data);
+
+        #[cfg(feature = "debug")]
+        if let Some(inspector) = cx.remove_context::<ori_core::debug::Inspector>() {
+            if inspector.enabled {
+                inspector.draw(&mut cx);
+            }
+
+            cx.insert_context(inspector);
+        }
+
         self.window = cx.remove_context().expect("Window context missing");
 
+        #[cfg(feature = "debug")]
+        if let Some(stats) = base.get_context::<ori_core::debug::ViewStats>() {
+            if stats.heatmap {
+                stats.draw_heatmap(&mut self.canvas);
+            }
+        }
+
+        if !span.is_disabled() {
+            let primitives: usize = self.canvas.primitives().map(Primitive::count).sum();
+            span.record("primitives", primitives);
+        }
+
         trace!(
             window = ?self.window.id(),
             elapsed = ?t.elapsed(),
@@ -155,8 +236,27 @@ impl<T> WindowState<T> {
         );
     }
 
-    fn animate(&mut self, animate: Instant) -> Vec<AppRequest<T>> {
-        if self.view_state.needs_animate() && self.animate.is_none() {
+    fn animate(&mut self, animate: Instant, interval: Option<Duration>) -> Vec<AppRequest<T>> {
+        // frames that aren't presented aren't throttled by the vsync of the platform, so
+        // they're paced to the refresh rate of a typical monitor instead
+        let interval = match self.unpresented {
+            true => interval.or(Some(UNPRESENTED_FRAME_INTERVAL)),
+            false => interval,
+        };
+
+        if self.view_state.needs_animate() && self.animate.is_none() && !self.drawing {
+            // delay the frame until the interval since the last frame has passed, the
+            // redraw is then requested by App::handle_timers
+            if let (Some(interval), Some(last_frame)) = (interval, self.last_frame) {
+                let deadline = last_frame + interval;
+
+                if deadline > animate {
+                    self.paced_frame = Some(deadline);
+                    return Vec::new();
+                }
+            }
+
+            self.paced_frame = None;
             self.animate = Some(animate);
             return vec![AppRequest::RequestRedraw(self.window.id())];
         }
@@ -165,6 +265,18 @@ impl<T> WindowState<T> {
     }
 }
 
+// the minimum time between animation frames that draw nothing, about 60 fps
+const UNPRESENTED_FRAME_INTERVAL: Duration = Duration::from_micros(16_667);
+
+// set the viewport styles to the size of the window, so that viewport relative lengths can be
+// computed while building the window
+fn set_viewport(base: &mut BaseCx, window: &Window) {
+    if let Some(styles) = base.get_context_mut::<Styles>() {
+        styles.insert_value(Viewport::WIDTH, window.size.width);
+        styles.insert_value(Viewport::HEIGHT, window.size.height);
+    }
+}
+
 /// The main application state.
 pub struct App<T> {
     pub(crate) windows: HashMap<WindowId, WindowState<T>>,
@@ -174,6 +286,51 @@ pub struct App<T> {
     pub(crate) receiver: CommandReceiver,
     pub(crate) requests: Vec<AppRequest<T>>,
     pub(crate) contexts: Contexts,
+    pub(crate) theme: Theme,
+    pub(crate) theme_transition: Transition,
+    pub(crate) theme_change: Option<ThemeChange>,
+    pub(crate) color_scheme: Option<ColorScheme>,
+    pub(crate) follow_system_theme: bool,
+    pub(crate) shortcuts: Shortcuts<T>,
+    pub(crate) timers: Vec<ActiveTimer>,
+    pub(crate) frame_pacing: FramePacing,
+    pub(crate) animation_speed: f32,
+    pub(crate) reduced_motion: bool,
+    pub(crate) last_input: Instant,
+    // the keys currently held, a press of a held key is a key repeat
+    pub(crate) held_keys: HashSet<Code>,
+    // the styles without the stylesheets, which are reapplied when one is reloaded
+    #[cfg(feature = "reload")]
+    pub(crate) base_styles: Styles,
+    #[cfg(feature = "reload")]
+    pub(crate) stylesheets: Vec<(std::path::PathBuf, Styles)>,
+    // kept alive to keep watching the stylesheets
+    #[cfg(feature = "reload")]
+    pub(crate) _stylesheet_watcher: Option<notify::RecommendedWatcher>,
+}
+
+// a running timer
+pub(crate) struct ActiveTimer {
+    id: TimerId,
+    deadline: Instant,
+    interval: Option<Duration>,
+    owner: Option<Weak<()>>,
+}
+
+impl ActiveTimer {
+    // timers started by views are stopped when the view is dropped
+    fn is_alive(&self) -> bool {
+        self.owner
+            .as_ref()
+            .is_none_or(|owner| owner.strong_count() > 0)
+    }
+}
+
+// an in-progress change of the theme
+pub(crate) struct ThemeChange {
+    from: Theme,
+    to: Theme,
+    start: Instant,
 }
 
 impl<T> App<T> {
@@ -184,24 +341,48 @@ impl<T> App<T> {
 
     /// A window was requested to be closed.
     ///
+    /// The [`Event::WindowCloseRequested`] is sent to the delegates, and then to the views
+    /// of the window, even if a delegate handled it, so views tracking unsaved state can
+    /// respond. If either handles the event, the close is vetoed, and the window can be
+    /// closed later with [`AppCommand::CloseWindow`], e.g. after the user confirmed it in
+    /// a dialog.
+    ///
     /// Returns `true` if the window was closed, i.e. the event was not handled.
     pub fn close_requested(&mut self, data: &mut T, window_id: WindowId) -> bool {
         let event = Event::WindowCloseRequested(WindowCloseRequested { window: window_id });
 
-        let handled = self.window_event(data, window_id, &event);
+        let mut handled = self.delegate_event(data, &event);
+        let mut rebuild = false;
 
-        if !handled {
-            self.remove_window(window_id);
-            self.requests.push(AppRequest::CloseWindow(window_id));
+        if let Some(window_state) = self.windows.get_mut(&window_id) {
+            let mut base = BaseCx::new(&mut self.contexts, &mut self.proxy);
+            handled |= window_state.event(data, &mut base, &mut rebuild, &event);
+        }
 
-            if self.windows.is_empty() {
-                self.requests.push(AppRequest::Quit);
-            }
+        if rebuild {
+            self.rebuild(data);
+        }
+
+        self.handle_commands(data);
+        self.handle_window_requests();
+
+        if !handled {
+            self.close_window(window_id);
         }
 
         !handled
     }
 
+    // close a window without a close request, quitting when it was the last one
+    fn close_window(&mut self, window_id: WindowId) {
+        self.remove_window(window_id);
+        self.requests.push(AppRequest::CloseWindow(window_id));
+
+        if self.windows.is_empty() {
+            self.requests.push(AppRequest::Quit);
+        }
+    }
+
     /// A window was resized.
     pub fn window_resized(
         &mut self,
@@ -211,9 +392,17 @@ impl<T> App<T> {
         height: u32,
     ) -> bool {
         if let Some(window_state) = self.windows.get_mut(&window_id) {
+            let size = Size::new(width as f32, height as f32);
+
+            // styles can depend on the size of the viewport, see `Viewport`, the window is
+            // rebuilt before the next frame
+            if window_state.window.size != size {
+                window_state.resized = true;
+            }
+
             window_state.view_state.request_layout();
-            window_state.window.size = Size::new(width as f32, height as f32);
-            window_state.snapshot.size = Size::new(width as f32, height as f32);
+            window_state.window.size = size;
+            window_state.snapshot.size = size;
         }
 
         let event = Event::WindowResized(WindowResized {
@@ -265,11 +454,22 @@ impl<T> App<T> {
         pointer_id: PointerId,
         position: Point,
     ) -> bool {
+        self.last_input = Instant::now();
+
         let Some(window_state) = self.windows.get_mut(&window_id) else {
             return false;
         };
 
         let delta = window_state.window.move_pointer(pointer_id, position);
+
+        // the inspector shows the hovered view, so it needs to be redrawn
+        #[cfg(feature = "debug")]
+        if let Some(inspector) = self.contexts.get::<ori_core::debug::Inspector>() {
+            if inspector.enabled {
+                window_state.view_state.request_draw();
+            }
+        }
+
         self.update_hovered(window_id);
 
         let event = Event::PointerMoved(PointerMoved {
@@ -282,6 +482,23 @@ impl<T> App<T> {
         self.window_event(data, window_id, &event)
     }
 
+    /// A pointer reported relative motion, see [`PointerRelativeMoved`].
+    pub fn pointer_relative_moved(
+        &mut self,
+        data: &mut T,
+        window_id: WindowId,
+        pointer_id: PointerId,
+        delta: Vector,
+    ) -> bool {
+        let event = Event::PointerRelativeMoved(PointerRelativeMoved {
+            id: pointer_id,
+            modifiers: self.modifiers,
+            delta,
+        });
+
+        self.window_event(data, window_id, &event)
+    }
+
     /// A pointer left the window.
     pub fn pointer_left(
         &mut self,
@@ -314,6 +531,8 @@ impl<T> App<T> {
         pointer_id: PointerId,
         delta: Vector,
     ) -> bool {
+        self.last_input = Instant::now();
+
         let position = self
             .pointer_position(window_id, pointer_id)
             .unwrap_or(Point::ZERO);
@@ -337,6 +556,8 @@ impl<T> App<T> {
         button: PointerButton,
         pressed: bool,
     ) -> bool {
+        self.last_input = Instant::now();
+
         let position = self
             .pointer_position(window_id, pointer_id)
             .unwrap_or(Point::ZERO);
@@ -346,6 +567,11 @@ impl<T> App<T> {
 
             if let Some(window_state) = self.windows.get_mut(&window_id) {
                 window_state.window.press_pointer(pointer_id, button);
+
+                // focus rings are hidden when focus is moved with a pointer
+                if window_state.window.set_keyboard_navigation(false) {
+                    window_state.view_state.request_draw();
+                }
             }
 
             let event = Event::PointerPressed(PointerPressed {
@@ -373,6 +599,37 @@ impl<T> App<T> {
         }
     }
 
+    // toggle the inspector if its shortcut was pressed
+    #[cfg(feature = "debug")]
+    fn toggle_inspector(&mut self, code: Option<Code>) -> bool {
+        let Some(inspector) = self.contexts.get_mut::<ori_core::debug::Inspector>() else {
+            return false;
+        };
+
+        let Some(code) = code else {
+            return false;
+        };
+
+        let shortcut = Shortcut {
+            code,
+            modifiers: self.modifiers,
+        };
+
+        if inspector.shortcut != shortcut {
+            return false;
+        }
+
+        inspector.toggle();
+
+        for window_state in self.windows.values_mut() {
+            window_state.view_state.request_draw();
+        }
+
+        self.handle_window_requests();
+
+        true
+    }
+
     /// A keyboard key was pressed or released.
     pub fn keyboard_key(
         &mut self,
@@ -383,7 +640,16 @@ impl<T> App<T> {
         text: Option<String>,
         pressed: bool,
     ) -> bool {
+        self.last_input = Instant::now();
+
+        #[cfg(feature = "debug")]
+        if pressed && self.toggle_inspector(code) {
+            return true;
+        }
+
         if pressed {
+            let repeat = code.is_some_and(|code| !self.held_keys.insert(code));
+
             let event = Event::KeyPressed(KeyPressed {
                 key,
                 code,
@@ -393,7 +659,24 @@ impl<T> App<T> {
 
             let mut handled = self.window_event(data, window_id, &event);
 
-            if let (Some(window), Key::Tab) = (self.windows.get(&window_id), key) {
+            // shortcuts are only triggered if the key wasn't handled by a view, and stop
+            // the key from being used for focus navigation
+            if let (false, Some(code)) = (handled, code) {
+                let shortcut = Shortcut {
+                    code,
+                    modifiers: self.modifiers,
+                };
+
+                if self.shortcut(data, shortcut, repeat) {
+                    return true;
+                }
+            }
+
+            if let (Some(window), Key::Tab) = (self.windows.get_mut(&window_id), key) {
+                if window.window.set_keyboard_navigation(true) {
+                    window.view_state.request_draw();
+                }
+
                 let event = match window.view_state.has_focused() {
                     true if self.modifiers.shift => Event::FocusPrev,
                     false if self.modifiers.shift => Event::FocusGiven(FocusTarget::Prev),
@@ -407,6 +690,10 @@ impl<T> App<T> {
 
             handled
         } else {
+            if let Some(code) = code {
+                self.held_keys.remove(&code);
+            }
+
             let event = Event::KeyReleased(KeyReleased {
                 key,
                 code,
@@ -417,6 +704,39 @@ impl<T> App<T> {
         }
     }
 
+    /// Get the keyboard shortcuts of the application.
+    pub fn shortcuts(&self) -> &Shortcuts<T> {
+        &self.shortcuts
+    }
+
+    /// Get the keyboard shortcuts of the application mutably.
+    pub fn shortcuts_mut(&mut self) -> &mut Shortcuts<T> {
+        &mut self.shortcuts
+    }
+
+    // call the callback of a shortcut, returns true if the shortcut is registered, and
+    // triggered by key repeats if `repeat` is set
+    fn shortcut(&mut self, data: &mut T, shortcut: Shortcut, repeat: bool) -> bool {
+        let Some(callback) = self.shortcuts.get_mut(shortcut, repeat) else {
+            return false;
+        };
+
+        let mut rebuild = false;
+        let mut base = BaseCx::new(&mut self.contexts, &mut self.proxy);
+        let mut cx = DelegateCx::new(&mut base, &mut self.requests, &mut rebuild);
+
+        callback(&mut cx, data);
+
+        if rebuild {
+            self.rebuild(data);
+        }
+
+        self.handle_commands(data);
+        self.handle_window_requests();
+
+        true
+    }
+
     /// The modifiers changed.
     pub fn modifiers_changed(&mut self, modifiers: Modifiers) {
         self.modifiers = modifiers;
@@ -433,6 +753,7 @@ impl<T> App<T> {
 
         let snapshot = window.snapshot();
 
+        set_viewport(&mut base, &window);
         let mut cx = BuildCx::new(&mut base, &mut view_state);
 
         cx.insert_context(window.clone());
@@ -451,6 +772,12 @@ impl<T> App<T> {
             window,
             snapshot,
             animate: None,
+            last_frame: None,
+            paced_frame: None,
+            last_present: None,
+            unpresented: false,
+            drawing: false,
+            resized: false,
         };
 
         self.windows.insert(window_id, window_state);
@@ -471,6 +798,11 @@ impl<T> App<T> {
         self.windows.get_mut(&window_id).map(|w| &mut w.window)
     }
 
+    /// Get a proxy for sending commands to the application.
+    pub fn proxy(&self) -> CommandProxy {
+        self.proxy.clone()
+    }
+
     /// Add a context.
     pub fn add_context(&mut self, context: impl Any) {
         self.contexts.insert(context);
@@ -488,17 +820,270 @@ impl<T> App<T> {
                 self.add_window(data, builder, window);
             }
             AppCommand::CloseWindow(window_id) => {
-                self.requests.push(AppRequest::CloseWindow(window_id));
+                self.close_window(window_id);
             }
             AppCommand::DragWindow(window_id) => {
                 self.requests.push(AppRequest::DragWindow(window_id));
             }
+            AppCommand::SetTheme(theme) => {
+                self.set_theme(data, theme);
+            }
+            AppCommand::SetFramePacing(pacing) => {
+                self.set_frame_pacing(pacing);
+            }
+            AppCommand::SetAnimationSpeed(speed) => {
+                self.set_animation_speed(data, speed);
+            }
             AppCommand::Quit => {
                 self.requests.push(AppRequest::Quit);
             }
         }
     }
 
+    /// Get the active theme.
+    ///
+    /// While the theme is transitioning, this is the interpolated theme, and the windows are
+    /// rebuilt with it on every frame of the transition.
+    pub fn theme(&self) -> Theme {
+        self.theme
+    }
+
+    /// Change the active theme, transitioning from the current one.
+    pub fn set_theme(&mut self, data: &mut T, theme: Theme) {
+        self.theme_change = Some(ThemeChange {
+            from: self.theme,
+            to: theme,
+            start: Instant::now(),
+        });
+
+        self.step_theme(data, None);
+    }
+
+    /// Get the [`FramePacing`] of the application.
+    pub fn frame_pacing(&self) -> FramePacing {
+        self.frame_pacing
+    }
+
+    /// Set the [`FramePacing`] of the application.
+    pub fn set_frame_pacing(&mut self, pacing: FramePacing) {
+        self.frame_pacing = pacing;
+    }
+
+    /// Get the animation speed of the application, see [`App::set_animation_speed`].
+    pub fn animation_speed(&self) -> f32 {
+        self.animation_speed
+    }
+
+    /// Set the animation speed of the application.
+    ///
+    /// This multiplies the time delivered with [`Event::Animate`] and the time of theme
+    /// transitions, e.g. `0.25` slows animations down for inspection. A speed of `0` disables
+    /// animations, the time is then delivered unscaled, but [`ReducedMotion`] is enabled, so
+    /// transitions complete instantly and indeterminate animations stop. Negative speeds are
+    /// treated as `0`.
+    pub fn set_animation_speed(&mut self, data: &mut T, speed: f32) {
+        self.animation_speed = speed.max(0.0);
+        self.update_reduced_motion(data);
+    }
+
+    // scale the time since the last frame by the animation speed, with animations disabled
+    // the time is left as is, since views like text inputs still need it for e.g. blinking
+    fn animation_delta(&self, delta_time: f32) -> f32 {
+        match self.animation_speed > 0.0 {
+            true => delta_time * self.animation_speed,
+            false => delta_time,
+        }
+    }
+
+    // the minimum time between animation frames, depending on whether the user is idle
+    fn frame_interval(&self) -> Option<Duration> {
+        let idle = self.last_input.elapsed() >= self.frame_pacing.idle_after;
+        self.frame_pacing.frame_interval(idle)
+    }
+
+    /// Get the color scheme preferred by the system, if known.
+    pub fn color_scheme(&self) -> Option<ColorScheme> {
+        self.color_scheme
+    }
+
+    /// The color scheme preferred by the system changed.
+    ///
+    /// This should be called by the platform at startup, and whenever the preference
+    /// changes. If the application follows the system theme, the theme is switched
+    /// accordingly, either way [`Event::ColorSchemeChanged`] is sent to delegates and windows.
+    pub fn color_scheme_changed(&mut self, data: &mut T, scheme: ColorScheme) {
+        if self.color_scheme == Some(scheme) {
+            return;
+        }
+
+        let initial = self.color_scheme.is_none();
+        self.color_scheme = Some(scheme);
+
+        if self.follow_system_theme {
+            let theme = Theme::from_scheme(scheme);
+
+            // the initial theme shouldn't fade in from the default one
+            if initial {
+                self.theme = theme;
+                self.theme_change = None;
+                self.apply_theme(data);
+            } else {
+                self.set_theme(data, theme);
+            }
+        }
+
+        self.event(data, &Event::ColorSchemeChanged(scheme));
+    }
+
+    /// Get whether the user prefers reduced motion, as reported by the platform.
+    pub fn reduced_motion(&self) -> bool {
+        self.reduced_motion
+    }
+
+    /// Whether the user prefers reduced motion changed.
+    ///
+    /// This should be called by the platform at startup, and whenever the preference
+    /// changes. While enabled, transitions complete instantly, and
+    /// [`Event::ReducedMotionChanged`] is sent to delegates and windows.
+    pub fn reduced_motion_changed(&mut self, data: &mut T, reduced: bool) {
+        self.reduced_motion = reduced;
+        self.update_reduced_motion(data);
+    }
+
+    // motion is reduced when the user prefers it, or when animations are disabled
+    fn motion_reduced(&self) -> bool {
+        self.reduced_motion || self.animation_speed == 0.0
+    }
+
+    fn update_reduced_motion(&mut self, data: &mut T) {
+        let reduced = self.motion_reduced();
+
+        if self.contexts.get::<ReducedMotion>() == Some(&ReducedMotion(reduced)) {
+            return;
+        }
+
+        self.contexts.insert(ReducedMotion(reduced));
+
+        if reduced && self.theme_change.is_some() {
+            self.step_theme(data, None);
+        }
+
+        self.event(data, &Event::ReducedMotionChanged(reduced));
+    }
+
+    /// Get the monitors connected to the system, as reported by the platform.
+    pub fn monitors(&self) -> Option<&Monitors> {
+        self.contexts.get::<Monitors>()
+    }
+
+    /// The monitors connected to the system changed.
+    ///
+    /// This should be called by the platform at startup, and whenever a monitor is
+    /// connected, disconnected or changed. [`Event::MonitorsChanged`] is sent to delegates
+    /// and windows.
+    pub fn monitors_changed(&mut self, data: &mut T, monitors: Vec<Monitor>) {
+        let monitors = Monitors::new(monitors);
+
+        if self.monitors() == Some(&monitors) {
+            return;
+        }
+
+        self.contexts.insert(monitors);
+        self.event(data, &Event::MonitorsChanged);
+    }
+
+    // step the theme transition, rebuilding the windows with the interpolated styles
+    fn step_theme(&mut self, data: &mut T, window: Option<WindowId>) {
+        let Some(ref change) = self.theme_change else {
+            return;
+        };
+
+        let duration = self.theme_transition.duration;
+        let elapsed = match self.motion_reduced() {
+            true => f32::INFINITY,
+            false => self.animation_delta(change.start.elapsed().as_secs_f32()),
+        };
+
+        let t = match duration > 0.0 {
+            true => f32::min(elapsed / duration, 1.0),
+            false => 1.0,
+        };
+
+        if t >= 1.0 {
+            self.theme = change.to;
+            self.theme_change = None;
+            self.apply_theme(data);
+            return;
+        }
+
+        self.theme = change.from.mix(change.to, self.theme_transition.get(t));
+        self.extend_theme_styles();
+
+        // views resolve their styles when rebuilt, so they're rebuilt on every step, when
+        // drawing only the drawn window is rebuilt, since every window steps the transition
+        let mut base = BaseCx::new(&mut self.contexts, &mut self.proxy);
+
+        for (id, window_state) in self.windows.iter_mut() {
+            window_state.view_state.request_animate();
+
+            if window.is_none_or(|window| window == *id) {
+                window_state.rebuild(data, &mut base);
+            }
+        }
+    }
+
+    // apply the current theme, rebuilding the windows with the new styles
+    fn apply_theme(&mut self, data: &mut T) {
+        self.extend_theme_styles();
+        self.rebuild(data);
+    }
+
+    // extend the styles with the current theme, and redraw every window since the clear color
+    // might have changed
+    fn extend_theme_styles(&mut self) {
+        if let Some(styles) = self.contexts.get_mut::<Styles>() {
+            styles.extend(Styles::from(self.theme));
+        }
+
+        for window_state in self.windows.values_mut() {
+            window_state.view_state.request_draw();
+        }
+    }
+
+    // reload a stylesheet, and rebuild the windows with the new styles
+    //
+    // the styles are rebuilt from the base styles and the current theme, so that styles
+    // removed from the stylesheet are reset
+    #[cfg(feature = "reload")]
+    fn reload_stylesheet(&mut self, data: &mut T, path: &std::path::Path) {
+        let Some(sheet) = crate::builder::load_stylesheet(path) else {
+            return;
+        };
+
+        ori_core::log::info!("reloaded stylesheet `{}`", path.display());
+
+        for (stylesheet_path, stylesheet) in &mut self.stylesheets {
+            if stylesheet_path == path {
+                *stylesheet = sheet.clone();
+            }
+        }
+
+        let mut styles = self.base_styles.clone();
+        styles.extend(Styles::from(self.theme));
+
+        for (path, sheet) in &self.stylesheets {
+            crate::builder::apply_stylesheet(&mut styles, path, sheet.clone());
+        }
+
+        self.contexts.insert(styles);
+
+        self.rebuild(data);
+
+        for window_state in self.windows.values_mut() {
+            window_state.view_state.request_layout();
+        }
+    }
+
     /// Handle all pending commands.
     pub fn handle_commands(&mut self, data: &mut T) {
         while let Some(command) = self.receiver.try_recv() {
@@ -510,6 +1095,29 @@ impl<T> App<T> {
                 continue;
             }
 
+            // platforms can report color scheme and reduced motion changes from other threads
+            // as commands
+            if let Some(&scheme) = command.get::<ColorScheme>() {
+                self.color_scheme_changed(data, scheme);
+                continue;
+            }
+
+            if let Some(&ReducedMotion(reduced)) = command.get::<ReducedMotion>() {
+                self.reduced_motion_changed(data, reduced);
+                continue;
+            }
+
+            #[cfg(feature = "reload")]
+            if let Some(crate::reload::ReloadStylesheet(path)) = command.get() {
+                self.reload_stylesheet(data, path);
+                continue;
+            }
+
+            if let Some(OpenUrl(url)) = command.get() {
+                self.requests.push(AppRequest::OpenUrl(url.clone()));
+                continue;
+            }
+
             if let Some(RequestFocus(window, view)) = command.get() {
                 self.window_event(data, *window, &Event::FocusWanted);
                 self.window_event(data, *window, &Event::FocusGiven(FocusTarget::View(*view)));
@@ -519,6 +1127,94 @@ impl<T> App<T> {
         }
     }
 
+    // start and stop the timers requested by views and delegates
+    fn collect_timers(&mut self) {
+        let Some(timers) = self.contexts.get_mut::<Timers>() else {
+            return;
+        };
+
+        for request in timers.take_requests() {
+            match request {
+                TimerRequest::Start {
+                    id,
+                    duration,
+                    repeat,
+                    owner,
+                } => {
+                    // a zero interval would fire on every iteration of the event loop
+                    let interval = duration.max(Duration::from_millis(1));
+
+                    self.timers.push(ActiveTimer {
+                        id,
+                        deadline: Instant::now() + duration,
+                        interval: repeat.then_some(interval),
+                        owner,
+                    });
+                }
+                TimerRequest::Stop(id) => self.timers.retain(|timer| timer.id != id),
+            }
+        }
+
+        self.timers.retain(ActiveTimer::is_alive);
+    }
+
+    /// Get the deadline of the next timer, or animation frame delayed by the
+    /// [`FramePacing`], if any.
+    ///
+    /// The event loop should wake up at this time and call [`App::handle_timers`].
+    pub fn next_timer(&mut self) -> Option<Instant> {
+        self.collect_timers();
+
+        let timers = self.timers.iter().map(|timer| timer.deadline);
+        let frames = self.windows.values().filter_map(|w| w.paced_frame);
+        timers.chain(frames).min()
+    }
+
+    /// Fire all the timers that are due, sending [`Event::Timer`] for each.
+    pub fn handle_timers(&mut self, data: &mut T) {
+        self.collect_timers();
+
+        let now = Instant::now();
+        let mut fired = Vec::new();
+
+        self.timers.retain_mut(|timer| {
+            if timer.deadline > now {
+                return true;
+            }
+
+            fired.push(timer.id);
+
+            match timer.interval {
+                // skip the missed intervals instead of firing them all at once
+                Some(interval) => {
+                    timer.deadline += interval;
+
+                    if timer.deadline <= now {
+                        timer.deadline = now + interval;
+                    }
+
+                    true
+                }
+                None => false,
+            }
+        });
+
+        for id in fired {
+            self.event(data, &Event::Timer(id));
+        }
+
+        // request the animation frames delayed by the frame pacing
+        let interval = self.frame_interval();
+        for window_state in self.windows.values_mut() {
+            if window_state.paced_frame.is_some_and(|frame| frame <= now) {
+                window_state.paced_frame = None;
+
+                let requests = window_state.animate(now, interval);
+                self.requests.extend(requests);
+            }
+        }
+    }
+
     /// Update the hovered state of a window.
     pub fn update_hovered(&mut self, window_id: WindowId) -> bool {
         let mut changed = false;
@@ -573,8 +1269,11 @@ impl<T> App<T> {
         }
     }
 
+    // send an event to the delegates, rebuilding if requested, returns whether a delegate
+    // handled the event, which doesn't stop it from being sent to the windows
     fn delegate_event(&mut self, data: &mut T, event: &Event) -> bool {
         let mut rebuild = false;
+        let mut handled = false;
         let mut base = BaseCx::new(&mut self.contexts, &mut self.proxy);
 
         for delegate in &mut self.delegates {
@@ -582,18 +1281,23 @@ impl<T> App<T> {
 
             if delegate.event(&mut cx, data, event) {
                 rebuild = true;
+                handled = true;
                 break;
             }
         }
 
+        // the views are rebuilt before the event is sent to the windows, so they see the
+        // data changed by the delegates
         if rebuild {
             self.rebuild(data);
         }
 
-        false
+        handled
     }
 
     fn handle_window_requests(&mut self) {
+        let interval = self.frame_interval();
+
         for window_state in self.windows.values_mut() {
             let id = window_state.window.id();
 
@@ -604,11 +1308,12 @@ impl<T> App<T> {
                 self.requests.push(AppRequest::UpdateWindow(id, update));
             }
 
-            if window_state.view_state.needs_draw()
-                || window_state.view_state.needs_layout()
-                || window_state.view_state.needs_animate()
-            {
+            if window_state.view_state.needs_draw() || window_state.view_state.needs_layout() {
                 self.requests.push(AppRequest::RequestRedraw(id));
+            } else {
+                // animation frames are paced, see FramePacing
+                let requests = window_state.animate(Instant::now(), interval);
+                self.requests.extend(requests);
             }
 
             let cursor = window_state.view_state.cursor().unwrap_or_default();
@@ -639,7 +1344,7 @@ impl<T> App<T> {
 
     /// Handle an event for the entire application.
     ///
-    /// Returns true if the event was handled by a delegate.
+    /// Returns true if the event was handled by either a delegate or a view.
     pub fn event(&mut self, data: &mut T, event: &Event) -> bool {
         trace!(event = ?event, "Event");
 
@@ -648,26 +1353,24 @@ impl<T> App<T> {
 
         // we first send the event to the delegates
         let mut handled = self.delegate_event(data, event);
-
         let mut rebuild = false;
 
-        // if the event was handled by a delegate we don't send it to the windows
-        if !handled {
-            for window_state in self.windows.values_mut() {
-                let mut base = BaseCx::new(&mut self.contexts, &mut self.proxy);
+        for window_state in self.windows.values_mut() {
+            let mut base = BaseCx::new(&mut self.contexts, &mut self.proxy);
 
-                handled |= window_state.event(data, &mut base, &mut rebuild, event);
-            }
+            handled |= window_state.event(data, &mut base, &mut rebuild, event);
         }
 
-        // rebuild the view tree if requested
+        // rebuild the view tree if requested, this is done once for all the rebuilds
+        // requested by the views while handling the event
         if rebuild {
             self.rebuild(data);
         }
 
         // update the window state after handling the event
+        let interval = self.frame_interval();
         for window_state in self.windows.values_mut() {
-            let requests = window_state.animate(animate);
+            let requests = window_state.animate(animate, interval);
             self.requests.extend(requests);
         }
 
@@ -680,7 +1383,7 @@ impl<T> App<T> {
 
     /// Handle an event for a single window.
     ///
-    /// Returns true if the event was handled by a delegate.
+    /// Returns true if the event was handled by either a delegate or a view.
     pub fn window_event(&mut self, data: &mut T, window_id: WindowId, event: &Event) -> bool {
         trace!(event = ?event, window = ?window_id, "Window event");
 
@@ -689,27 +1392,25 @@ impl<T> App<T> {
 
         // we first send the event to the delegates
         let mut handled = self.delegate_event(data, event);
-
         let mut rebuild = false;
 
-        // if the event was handled by a delegate we don't send it to the window
-        if !handled {
-            if let Some(window_state) = self.windows.get_mut(&window_id) {
-                let mut base = BaseCx::new(&mut self.contexts, &mut self.proxy);
+        if let Some(window_state) = self.windows.get_mut(&window_id) {
+            let mut base = BaseCx::new(&mut self.contexts, &mut self.proxy);
 
-                // we send the event to the window, remembering to set the style context
-                handled |= window_state.event(data, &mut base, &mut rebuild, event);
-            }
+            // we send the event to the window, remembering to set the style context
+            handled |= window_state.event(data, &mut base, &mut rebuild, event);
         }
 
-        // rebuild the view tree if requested
+        // rebuild the view tree if requested, this is done once for all the rebuilds
+        // requested by the views while handling the event
         if rebuild {
             self.rebuild(data);
         }
 
         // update the window state after handling the event
+        let interval = self.frame_interval();
         if let Some(window_state) = self.windows.get_mut(&window_id) {
-            let requests = window_state.animate(animate);
+            let requests = window_state.animate(animate, interval);
             self.requests.extend(requests);
         }
 
@@ -734,6 +1435,8 @@ impl<T> App<T> {
                     None => 0.0,
                 };
 
+                let delta_time = self.animation_delta(delta_time);
+
                 // we send an Animate event to the window, this uses the time since the last frame
                 // set in either the event, window_event, or draw_window functions
                 let event = Event::Animate(delta_time);
@@ -742,14 +1445,44 @@ impl<T> App<T> {
         }
     }
 
+    // find the reason a window is about to be drawn
+    fn redraw_reason(&self, window_id: WindowId) -> RedrawReason {
+        let Some(window_state) = self.windows.get(&window_id) else {
+            return RedrawReason::Requested;
+        };
+
+        let view_state = &window_state.view_state;
+
+        if view_state.needs_animate() {
+            RedrawReason::Animate
+        } else if view_state.needs_layout() {
+            RedrawReason::Layout
+        } else if view_state.needs_draw() {
+            RedrawReason::Draw
+        } else {
+            RedrawReason::Requested
+        }
+    }
+
     /// Draw a single window, returning the scene if it needs to be rendered.
     pub fn draw_window(
         &mut self,
         data: &mut T,
         window_id: WindowId,
     ) -> Option<WindowRenderState<'_>> {
+        let _span = trace_span!("frame", window = ?window_id).entered();
         trace!(window = ?window_id, "Draw window");
 
+        let start = Instant::now();
+        let reason = self.redraw_reason(window_id);
+
+        // step the theme transition if the theme is being changed
+        self.step_theme(data, Some(window_id));
+
+        if let Some(window_state) = self.windows.get_mut(&window_id) {
+            window_state.drawing = true;
+        }
+
         // animate the window before drawing it
         //
         // this will send an Animate event if needed
@@ -764,13 +1497,20 @@ impl<T> App<T> {
 
         let mut base = BaseCx::new(&mut self.contexts, &mut self.proxy);
 
+        // rebuild if the window was resized, since the viewport styles changed
+        if window_state.resized {
+            window_state.rebuild(data, &mut base);
+        }
+
         // layout if needed
         if window_state.view_state.needs_layout() {
             window_state.layout(data, &mut base);
         }
 
         // draw if needed
-        if window_state.view_state.needs_draw() {
+        let drawn = window_state.view_state.needs_draw();
+
+        if drawn {
             window_state.draw(data, &mut base);
 
             // since hover state is determined by the scene, and since draw modifies the scene,
@@ -780,19 +1520,50 @@ impl<T> App<T> {
             }
         }
 
+        // the frame is done, so the debug statistics are reset for the next one
+        #[cfg(feature = "debug")]
+        if let Some(stats) = self.contexts.get_mut::<ori_core::debug::ViewStats>() {
+            stats.log();
+            stats.clear();
+        }
+
+        #[cfg(feature = "debug")]
+        if let Some(log) = self.contexts.get_mut::<ori_core::debug::RedrawLog>() {
+            log.log();
+            log.clear();
+        }
+
+        let interval = self.frame_interval();
         let window_state = self.windows.get_mut(&window_id)?;
 
+        let frame_interval = match window_state.last_frame.replace(start) {
+            Some(last_frame) => start.duration_since(last_frame),
+            None => Duration::ZERO,
+        };
+
+        let primitives = window_state.canvas.primitives().map(Primitive::count).sum();
+
+        let stats = window_state.window.frame_stats_mut();
+        stats.frames += 1;
+        stats.frame_time = start.elapsed();
+        stats.frame_interval = frame_interval;
+        stats.primitives = primitives;
+        stats.reason = reason;
+
+        window_state.unpresented = !drawn;
+        window_state.drawing = false;
+
         // we need to update the window state after layout and draw
         //
         // if somehow the a layout or draw has been requested we must tell the window to redraw
-        let requests = window_state.animate(animate);
+        let requests = window_state.animate(animate, interval);
         self.requests.extend(requests);
 
         // handle any pending commands
         self.handle_commands(data);
         self.handle_window_requests();
 
-        let window_state = self.windows.get(&window_id)?;
+        let window_state = self.windows.get_mut(&window_id)?;
 
         // the clear color is the palette background color, but can be overridden by the window
         let clear_color = match window_state.window.color {
@@ -803,10 +1574,447 @@ impl<T> App<T> {
             }
         };
 
+        let window = &window_state.window;
+        let present = (window.size, window.scale, clear_color);
+        let changed = drawn || window_state.last_present != Some(present);
+        window_state.last_present = Some(present);
+
         Some(WindowRenderState {
             canvas: &window_state.canvas,
             logical_size: window_state.window.size,
             clear_color,
+            changed,
         })
     }
+
+    /// Report the time the platform spent rendering the last frame of a window.
+    ///
+    /// This is stored in [`FrameStats::render_time`] of the window.
+    ///
+    /// [`FrameStats::render_time`]: ori_core::window::FrameStats::render_time
+    pub fn frame_rendered(&mut self, window_id: WindowId, render_time: Duration) {
+        if let Some(window_state) = self.windows.get_mut(&window_id) {
+            window_state.window.frame_stats_mut().render_time = render_time;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use ori_core::{command::CommandWaker, context::BaseCx, view::Pod};
+
+    use super::*;
+
+    // the time and reduced motion of every animation frame
+    type Frames = Vec<(f32, bool)>;
+
+    // a view that animates every frame, drawing only if `draw` is set
+    struct Animating {
+        draw: bool,
+    }
+
+    impl View<Frames> for Animating {
+        type State = ();
+
+        fn build(&mut self, cx: &mut BuildCx, _data: &mut Frames) -> Self::State {
+            cx.animate();
+        }
+
+        fn rebuild(&mut self, _: &mut Self::State, _: &mut RebuildCx, _: &mut Frames, _: &Self) {}
+
+        fn event(
+            &mut self,
+            _state: &mut Self::State,
+            cx: &mut EventCx,
+            data: &mut Frames,
+            event: &Event,
+        ) -> bool {
+            if let Event::Animate(dt) = event {
+                data.push((*dt, BaseCx::reduced_motion(cx)));
+                cx.animate();
+
+                if self.draw {
+                    cx.draw();
+                }
+            }
+
+            false
+        }
+
+        fn layout(
+            &mut self,
+            _state: &mut Self::State,
+            _cx: &mut LayoutCx,
+            _data: &mut Frames,
+            space: Space,
+        ) -> Size {
+            space.min
+        }
+
+        fn draw(&mut self, _state: &mut Self::State, _cx: &mut DrawCx, _data: &mut Frames) {}
+    }
+
+    // a view that starts a timeout when built
+    struct Timeout;
+
+    impl View<bool> for Timeout {
+        type State = ();
+
+        fn build(&mut self, cx: &mut BuildCx, _data: &mut bool) -> Self::State {
+            cx.set_timeout(Duration::from_secs(1));
+        }
+
+        fn rebuild(&mut self, _: &mut Self::State, _: &mut RebuildCx, _: &mut bool, _: &Self) {}
+
+        fn event(&mut self, _: &mut Self::State, _: &mut EventCx, _: &mut bool, _: &Event) -> bool {
+            false
+        }
+
+        fn layout(
+            &mut self,
+            _: &mut Self::State,
+            _: &mut LayoutCx,
+            _: &mut bool,
+            space: Space,
+        ) -> Size {
+            space.min
+        }
+
+        fn draw(&mut self, _state: &mut Self::State, _cx: &mut DrawCx, _data: &mut bool) {}
+    }
+
+    // a view that records the primary color of the theme every time it's built or rebuilt
+    struct Primary;
+
+    impl View<Vec<Color>> for Primary {
+        type State = ();
+
+        fn build(&mut self, cx: &mut BuildCx, data: &mut Vec<Color>) -> Self::State {
+            data.extend(cx.styles().get(Theme::PRIMARY));
+        }
+
+        fn rebuild(&mut self, _: &mut (), cx: &mut RebuildCx, data: &mut Vec<Color>, _: &Self) {
+            data.extend(cx.styles().get(Theme::PRIMARY));
+        }
+
+        fn event(&mut self, _: &mut (), _: &mut EventCx, _: &mut Vec<Color>, _: &Event) -> bool {
+            false
+        }
+
+        fn layout(
+            &mut self,
+            _: &mut (),
+            _: &mut LayoutCx,
+            _: &mut Vec<Color>,
+            space: Space,
+        ) -> Size {
+            space.min
+        }
+
+        fn draw(&mut self, _: &mut (), _: &mut DrawCx, _: &mut Vec<Color>) {}
+    }
+
+    // a view that records the width of the viewport every time it's built or rebuilt
+    struct ViewportWidth;
+
+    impl View<Vec<f32>> for ViewportWidth {
+        type State = ();
+
+        fn build(&mut self, cx: &mut BuildCx, data: &mut Vec<f32>) -> Self::State {
+            data.extend(cx.styles().get(Viewport::WIDTH));
+        }
+
+        fn rebuild(&mut self, _: &mut (), cx: &mut RebuildCx, data: &mut Vec<f32>, _: &Self) {
+            data.extend(cx.styles().get(Viewport::WIDTH));
+        }
+
+        fn event(&mut self, _: &mut (), _: &mut EventCx, _: &mut Vec<f32>, _: &Event) -> bool {
+            false
+        }
+
+        fn layout(&mut self, _: &mut (), _: &mut LayoutCx, _: &mut Vec<f32>, space: Space) -> Size {
+            space.min
+        }
+
+        fn draw(&mut self, _: &mut (), _: &mut DrawCx, _: &mut Vec<f32>) {}
+    }
+
+    fn app(builder: AppBuilder<Frames>, data: &mut Frames, draw: bool) -> (App<Frames>, WindowId) {
+        let mut app = builder.build(CommandWaker::new(|| {}));
+
+        let window = Window::new();
+        let window_id = window.id();
+        app.add_window(
+            data,
+            Box::new(move |_| Box::new(Animating { draw })),
+            window,
+        );
+
+        (app, window_id)
+    }
+
+    fn redraw_requested(app: &mut App<Frames>, window_id: WindowId) -> bool {
+        (app.take_requests())
+            .any(|request| matches!(request, AppRequest::RequestRedraw(id) if id == window_id))
+    }
+
+    #[test]
+    fn unpresented_frames_are_paced() {
+        let mut data = Frames::new();
+        let (mut app, window_id) = app(AppBuilder::new(), &mut data, false);
+
+        // the first frame is drawn, so the next one is requested immediately
+        let state = app.draw_window(&mut data, window_id).unwrap();
+        assert!(state.changed);
+        assert!(redraw_requested(&mut app, window_id));
+
+        // the second frame only animates, so the next one is paced
+        let state = app.draw_window(&mut data, window_id).unwrap();
+        assert!(!state.changed);
+        assert!(!redraw_requested(&mut app, window_id));
+
+        let deadline = app.next_timer().unwrap();
+        assert!(deadline > Instant::now());
+    }
+
+    #[test]
+    fn animation_speed_scales_delta() {
+        let mut data = Frames::new();
+        let builder = AppBuilder::new().animation_speed(0.5);
+        let (mut app, window_id) = app(builder, &mut data, true);
+
+        let start = Instant::now();
+        app.draw_window(&mut data, window_id);
+        thread::sleep(Duration::from_millis(20));
+        app.draw_window(&mut data, window_id);
+
+        let (dt, reduced) = data[1];
+        assert!((0.01..=start.elapsed().as_secs_f32() * 0.5).contains(&dt));
+        assert!(!reduced);
+
+        // disabling animations keeps the time real, but reduces motion
+        app.set_animation_speed(&mut data, 0.0);
+
+        thread::sleep(Duration::from_millis(20));
+        app.draw_window(&mut data, window_id);
+
+        let (dt, reduced) = data[2];
+        assert!((0.02..1.0).contains(&dt));
+        assert!(reduced);
+    }
+
+    #[test]
+    fn timers_stop_with_view() {
+        let mut app = AppBuilder::new().build(CommandWaker::new(|| {}));
+        let mut data = true;
+
+        app.add_window(
+            &mut data,
+            Box::new(|data: &mut bool| Box::new(data.then(|| Pod::new(Timeout)))),
+            Window::new(),
+        );
+
+        assert!(app.next_timer().is_some());
+
+        data = false;
+        app.rebuild(&mut data);
+
+        assert!(app.next_timer().is_none());
+    }
+
+    // a view that records the value it was built with, whenever it gets an event
+    struct Seen(u32);
+
+    impl View<(u32, Vec<u32>)> for Seen {
+        type State = ();
+
+        fn build(&mut self, _: &mut BuildCx, _: &mut (u32, Vec<u32>)) -> Self::State {}
+
+        fn rebuild(&mut self, _: &mut (), _: &mut RebuildCx, _: &mut (u32, Vec<u32>), _: &Self) {}
+
+        fn event(
+            &mut self,
+            _: &mut (),
+            _: &mut EventCx,
+            data: &mut (u32, Vec<u32>),
+            _: &Event,
+        ) -> bool {
+            data.1.push(self.0);
+            false
+        }
+
+        fn layout(
+            &mut self,
+            _: &mut (),
+            _: &mut LayoutCx,
+            _: &mut (u32, Vec<u32>),
+            space: Space,
+        ) -> Size {
+            space.min
+        }
+
+        fn draw(&mut self, _: &mut (), _: &mut DrawCx, _: &mut (u32, Vec<u32>)) {}
+    }
+
+    // a delegate that increments the data on every event
+    struct Increment;
+
+    impl AppDelegate<(u32, Vec<u32>)> for Increment {
+        fn event(
+            &mut self,
+            cx: &mut DelegateCx<(u32, Vec<u32>)>,
+            data: &mut (u32, Vec<u32>),
+            _: &Event,
+        ) -> bool {
+            data.0 += 1;
+            cx.rebuild();
+            false
+        }
+    }
+
+    #[test]
+    fn delegates_rebuild_before_windows() {
+        let mut app = AppBuilder::new()
+            .delegate(Increment)
+            .build(CommandWaker::new(|| {}));
+        let mut data = (0, Vec::new());
+
+        let window = Window::new();
+        let window_id = window.id();
+        app.add_window(&mut data, Box::new(|data| Box::new(Seen(data.0))), window);
+
+        app.event(&mut data, &Event::Update);
+        app.window_event(&mut data, window_id, &Event::Update);
+        assert_eq!(data.1, [1, 2]);
+    }
+
+    #[test]
+    fn theme_transition_rebuilds_views() {
+        let builder = AppBuilder::new().theme_transition(Transition::linear(1.0));
+        let mut app = builder.build(CommandWaker::new(|| {}));
+        let mut data = Vec::new();
+
+        let window = Window::new();
+        let window_id = window.id();
+        app.add_window(&mut data, Box::new(|_| Box::new(Primary)), window);
+
+        let from = app.theme();
+        let to = Theme {
+            primary: Color::BLACK,
+            ..from
+        };
+
+        app.set_theme(&mut data, to);
+
+        // halfway through the transition, the views see an interpolated color
+        let change = app.theme_change.as_mut().unwrap();
+        change.start -= Duration::from_millis(500);
+        app.draw_window(&mut data, window_id);
+
+        let color = *data.last().unwrap();
+        assert_ne!(color, from.primary);
+        assert_ne!(color, Color::BLACK);
+    }
+
+    #[test]
+    fn resizing_rebuilds_once_per_frame() {
+        let mut app = AppBuilder::new().build(CommandWaker::new(|| {}));
+        let mut data = Vec::new();
+
+        let window = Window::new().size(800, 600);
+        let window_id = window.id();
+        app.add_window(&mut data, Box::new(|_| Box::new(ViewportWidth)), window);
+        assert_eq!(data, [800.0]);
+
+        // small resizes still update the viewport, but only once per frame
+        app.window_resized(&mut data, window_id, 801, 600);
+        app.window_resized(&mut data, window_id, 802, 600);
+        app.draw_window(&mut data, window_id);
+        assert_eq!(data, [800.0, 802.0]);
+
+        app.draw_window(&mut data, window_id);
+        assert_eq!(data, [800.0, 802.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "reload")]
+    fn reloading_stylesheet_resets_removed_styles() {
+        use ori_core::style::Style;
+
+        const A: Style<f32> = Style::new("test.a");
+        const B: Style<f32> = Style::new("test.b");
+
+        let path = std::env::temp_dir().join(format!("ori-{}.style", std::process::id()));
+        std::fs::write(&path, "test.a: 1\ntest.b: 2\ntheme.primary: 3").unwrap();
+
+        let builder = AppBuilder::new().stylesheet(&path);
+        let mut app = builder.build(CommandWaker::new(|| {}));
+        let mut data = Frames::new();
+
+        // the primary color is of the wrong type, so it's ignored
+        let styles = app.contexts.get::<Styles>().unwrap();
+        assert_eq!(styles.get(A), Some(1.0));
+        assert_eq!(styles.get(B), Some(2.0));
+        assert_eq!(styles.get(Theme::PRIMARY), Some(app.theme().primary));
+
+        std::fs::write(&path, "test.a: 3").unwrap();
+        app.reload_stylesheet(&mut data, &path);
+        std::fs::remove_file(&path).unwrap();
+
+        let styles = app.contexts.get::<Styles>().unwrap();
+        assert_eq!(styles.get(A), Some(3.0));
+        assert_eq!(styles.get(B), None);
+    }
+
+    #[test]
+    fn shortcuts_stop_focus_navigation_and_repeats() {
+        let push = |_: &mut DelegateCx<Frames>, data: &mut Frames| data.push((0.0, false));
+        let builder = AppBuilder::new()
+            .shortcut(Shortcut::new(Code::Tab), push)
+            .repeating_shortcut(Shortcut::new(Code::A), push);
+
+        let mut data = Frames::new();
+        let (mut app, window_id) = app(builder, &mut data, false);
+        let key = |app: &mut App<Frames>, data: &mut Frames, key, code, pressed| {
+            app.keyboard_key(data, window_id, key, Some(code), None, pressed)
+        };
+
+        // the shortcut handles tab, so focus isn't moved
+        assert!(key(&mut app, &mut data, Key::Tab, Code::Tab, true));
+        assert_eq!(data.len(), 1);
+
+        let window = app.get_window_mut(window_id).unwrap();
+        assert!(!window.set_keyboard_navigation(false));
+
+        // repeats are ignored, unless the shortcut repeats
+        key(&mut app, &mut data, Key::Tab, Code::Tab, true);
+        assert_eq!(data.len(), 1);
+
+        key(&mut app, &mut data, Key::Tab, Code::Tab, false);
+        key(&mut app, &mut data, Key::Tab, Code::Tab, true);
+        assert_eq!(data.len(), 2);
+
+        key(&mut app, &mut data, Key::Character('a'), Code::A, true);
+        key(&mut app, &mut data, Key::Character('a'), Code::A, true);
+        assert_eq!(data.len(), 4);
+    }
+
+    #[test]
+    fn closing_last_window_quits() {
+        let mut data = Frames::new();
+        let (mut app, window_id) = app(AppBuilder::new(), &mut data, false);
+        app.take_requests().for_each(drop);
+
+        app.proxy().cmd(AppCommand::CloseWindow(window_id));
+        app.handle_commands(&mut data);
+
+        let requests: Vec<_> = app.take_requests().collect();
+        assert!(app.get_window(window_id).is_none());
+        assert!(matches!(requests[..], [
+            AppRequest::CloseWindow(id),
+            AppRequest::Quit
+        ] if id == window_id));
+    }
 }
@@ -1,19 +1,48 @@
+use std::{
+    any::Any,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use instant::Instant;
+
 use ori_core::{
     command::{CommandProxy, CommandWaker},
     context::Contexts,
+    event::Shortcut,
+    log::warn,
     style::{Styles, Theme},
-    text::{FontSource, Fonts},
+    text::{FontFamily, FontSource, Fonts},
+    transition::{ReducedMotion, Transition},
+    view::PixelSnap,
     window::Window,
 };
 
-use crate::{App, AppDelegate, AppRequest, IntoUiBuilder};
+use crate::{
+    App, AppDelegate, AppRequest, DelegateCx, FramePacing, IntoUiBuilder, MessageDelegate,
+    Shortcuts,
+};
 
 /// A builder for an [`App`].
 pub struct AppBuilder<T> {
     delegates: Vec<Box<dyn AppDelegate<T>>>,
     requests: Vec<AppRequest<T>>,
     styles: Styles,
+    theme: Theme,
+    theme_transition: Transition,
+    follow_system_theme: bool,
     fonts: Fonts,
+    shortcuts: Shortcuts<T>,
+    frame_pacing: FramePacing,
+    animation_speed: f32,
+    pixel_snap: bool,
+    stylesheets: Vec<(PathBuf, Styles)>,
+    #[cfg(feature = "debug")]
+    view_stats: Option<ori_core::debug::ViewStats>,
+    #[cfg(feature = "debug")]
+    inspector: Option<ori_core::debug::Inspector>,
+    #[cfg(feature = "debug")]
+    redraw_log: Option<ori_core::debug::RedrawLog>,
 }
 
 impl<T> Default for AppBuilder<T> {
@@ -29,7 +58,21 @@ impl<T> AppBuilder<T> {
             delegates: Vec::new(),
             requests: Vec::new(),
             styles: Styles::from(Theme::dark()),
+            theme: Theme::dark(),
+            theme_transition: Transition::ease(0.2),
+            follow_system_theme: false,
             fonts: Fonts::new(),
+            shortcuts: Shortcuts::new(),
+            frame_pacing: FramePacing::new(),
+            animation_speed: 1.0,
+            pixel_snap: false,
+            stylesheets: Vec::new(),
+            #[cfg(feature = "debug")]
+            view_stats: None,
+            #[cfg(feature = "debug")]
+            inspector: None,
+            #[cfg(feature = "debug")]
+            redraw_log: None,
         }
     }
 
@@ -39,15 +82,163 @@ impl<T> AppBuilder<T> {
         self
     }
 
+    /// Add a handler for messages of type `M`, sent with an
+    /// [`EventProxy`](ori_core::command::EventProxy).
+    ///
+    /// The handler is called on the UI thread, after which the view tree is rebuilt.
+    ///
+    /// # Example
+    /// ```ignore
+    /// struct Downloaded(Vec<u8>);
+    ///
+    /// fn ui(_data: &mut Data) -> impl View<Data> {
+    ///     on_click(button(text("Download")), |cx, _| {
+    ///         let proxy = cx.event_proxy::<Downloaded>();
+    ///         std::thread::spawn(move || proxy.send(Downloaded(download())));
+    ///     })
+    /// }
+    ///
+    /// App::build()
+    ///     .message(|_, data: &mut Data, Downloaded(bytes)| data.bytes = bytes.clone())
+    ///     .window(Window::new(), ui)
+    /// ```
+    pub fn message<M: Any>(
+        self,
+        handler: impl FnMut(&mut DelegateCx<T>, &mut T, &M) + 'static,
+    ) -> Self {
+        self.delegate(MessageDelegate::new(handler))
+    }
+
     /// Add a style to the application.
     pub fn style(mut self, styles: impl Into<Styles>) -> Self {
         self.styles.extend(styles);
         self
     }
 
+    /// Add a stylesheet to the application, see [`Styles::parse`].
+    ///
+    /// Stylesheets are applied in order, on top of the styles and theme of the application.
+    /// If the stylesheet cannot be read or parsed, a warning is logged and it is ignored.
+    /// With the `reload` feature enabled, the file is watched, and reloaded whenever it
+    /// changes, which is useful when iterating on the look of an application.
+    pub fn stylesheet(mut self, path: impl AsRef<Path>) -> Self {
+        let path = path.as_ref();
+        let styles = load_stylesheet(path).unwrap_or_default();
+
+        self.stylesheets.push((path.to_path_buf(), styles));
+        self
+    }
+
     /// Add a theme to the application.
     pub fn theme(mut self, theme: Theme) -> Self {
         self.styles.extend(Styles::from(theme));
+        self.theme = theme;
+        self
+    }
+
+    /// Set the transition used when the theme is changed with [`AppCommand::SetTheme`].
+    ///
+    /// [`AppCommand::SetTheme`]: crate::AppCommand::SetTheme
+    pub fn theme_transition(mut self, transition: Transition) -> Self {
+        self.theme_transition = transition;
+        self
+    }
+
+    /// Follow the color scheme preferred by the system.
+    ///
+    /// When enabled, the theme is switched between [`Theme::light`] and [`Theme::dark`]
+    /// whenever the system reports a [`ColorScheme`], including once at startup.
+    /// Delegates still receive [`Event::ColorSchemeChanged`] either way.
+    ///
+    /// [`ColorScheme`]: ori_core::style::ColorScheme
+    /// [`Event::ColorSchemeChanged`]: ori_core::event::Event::ColorSchemeChanged
+    pub fn follow_system_theme(mut self, follow: bool) -> Self {
+        self.follow_system_theme = follow;
+        self
+    }
+
+    /// Add a keyboard shortcut to the application, see [`Shortcuts`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// App::build().shortcut(Shortcut::new(Code::Q).ctrl(), |cx, _| cx.quit())
+    /// ```
+    pub fn shortcut(
+        mut self,
+        shortcut: Shortcut,
+        callback: impl FnMut(&mut DelegateCx<T>, &mut T) + 'static,
+    ) -> Self {
+        self.shortcuts.insert(shortcut, callback);
+        self
+    }
+
+    /// Add a keyboard shortcut that's also triggered by key repeats, see
+    /// [`Shortcuts::insert_repeating`].
+    pub fn repeating_shortcut(
+        mut self,
+        shortcut: Shortcut,
+        callback: impl FnMut(&mut DelegateCx<T>, &mut T) + 'static,
+    ) -> Self {
+        self.shortcuts.insert_repeating(shortcut, callback);
+        self
+    }
+
+    /// Set the [`FramePacing`] of the application, limiting how often windows are redrawn
+    /// while animating.
+    pub fn frame_pacing(mut self, pacing: FramePacing) -> Self {
+        self.frame_pacing = pacing;
+        self
+    }
+
+    /// Set the animation speed of the application, see [`App::set_animation_speed`].
+    pub fn animation_speed(mut self, speed: f32) -> Self {
+        self.animation_speed = speed;
+        self
+    }
+
+    /// Set whether views are snapped to device pixels, for crisp text and edges.
+    ///
+    /// Parts of the view tree can override this with [`pixel_snap`](ori_core::views::pixel_snap),
+    /// see [`PixelSnap`].
+    pub fn pixel_snap(mut self, snap: bool) -> Self {
+        self.pixel_snap = snap;
+        self
+    }
+
+    /// Record how many times each view is rebuilt, laid out and drawn every frame.
+    ///
+    /// Requires the `debug` feature, see [`ViewStats`](ori_core::debug::ViewStats).
+    ///
+    /// # Example
+    /// ```ignore
+    /// App::build().view_stats(ViewStats::new().heatmap(true).log_top(10))
+    /// ```
+    #[cfg(feature = "debug")]
+    pub fn view_stats(mut self, stats: ori_core::debug::ViewStats) -> Self {
+        self.view_stats = Some(stats);
+        self
+    }
+
+    /// Add an inspector, outlining the bounds of every view when toggled.
+    ///
+    /// Requires the `debug` feature, see [`Inspector`](ori_core::debug::Inspector).
+    ///
+    /// # Example
+    /// ```ignore
+    /// App::build().inspector(Inspector::new().shortcut(Shortcut::new(Code::I).ctrl()))
+    /// ```
+    #[cfg(feature = "debug")]
+    pub fn inspector(mut self, inspector: ori_core::debug::Inspector) -> Self {
+        self.inspector = Some(inspector);
+        self
+    }
+
+    /// Log where layouts, draws and animation frames were requested from every frame.
+    ///
+    /// Requires the `debug` feature, see [`RedrawLog`](ori_core::debug::RedrawLog).
+    #[cfg(feature = "debug")]
+    pub fn redraw_log(mut self, log: ori_core::debug::RedrawLog) -> Self {
+        self.redraw_log = Some(log);
         self
     }
 
@@ -60,6 +251,32 @@ impl<T> AppBuilder<T> {
         self
     }
 
+    /// Add a font to the application, registered under the family `name`, see
+    /// [`Fonts::load_font_named`].
+    pub fn font_named<'a>(mut self, name: &str, font: impl Into<FontSource<'a>>) -> Self {
+        if let Err(err) = self.fonts.load_font_named(name, font) {
+            eprintln!("Failed to load font: {}", err);
+        }
+
+        self
+    }
+
+    /// Set the font families used for characters missing from the font of some text, see
+    /// [`Fonts::set_fallback_families`].
+    ///
+    /// # Example
+    /// ```ignore
+    /// App::build().fallback_families(["Noto Color Emoji", "Noto Sans CJK JP"])
+    /// ```
+    pub fn fallback_families(
+        mut self,
+        families: impl IntoIterator<Item = impl Into<FontFamily>>,
+    ) -> Self {
+        let families: Vec<_> = families.into_iter().map(Into::into).collect();
+        self.fonts.set_fallback_families(&families);
+        self
+    }
+
     /// Add a window to the application.
     pub fn window<V, P>(
         mut self,
@@ -75,9 +292,46 @@ impl<T> AppBuilder<T> {
     pub fn build(self, waker: CommandWaker) -> App<T> {
         let (proxy, receiver) = CommandProxy::new(waker);
 
+        #[cfg(feature = "reload")]
+        let stylesheet_watcher = {
+            let paths: Vec<_> = self
+                .stylesheets
+                .iter()
+                .map(|(path, _)| path.clone())
+                .collect();
+            crate::reload::watch_stylesheets(&paths, proxy.clone())
+        };
+
+        let mut styles = self.styles.clone();
+
+        for (path, sheet) in &self.stylesheets {
+            apply_stylesheet(&mut styles, path, sheet.clone());
+        }
+
         let mut contexts = Contexts::new();
-        contexts.insert(self.styles);
+        contexts.insert(styles);
         contexts.insert(self.fonts);
+        contexts.insert(PixelSnap(self.pixel_snap));
+
+        // animations are disabled by reducing motion, see App::set_animation_speed
+        if self.animation_speed <= 0.0 {
+            contexts.insert(ReducedMotion(true));
+        }
+
+        #[cfg(feature = "debug")]
+        if let Some(stats) = self.view_stats {
+            contexts.insert(stats);
+        }
+
+        #[cfg(feature = "debug")]
+        if let Some(inspector) = self.inspector {
+            contexts.insert(inspector);
+        }
+
+        #[cfg(feature = "debug")]
+        if let Some(log) = self.redraw_log {
+            contexts.insert(log);
+        }
 
         App {
             windows: Default::default(),
@@ -87,6 +341,56 @@ impl<T> AppBuilder<T> {
             receiver,
             requests: self.requests,
             contexts,
+            theme: self.theme,
+            theme_transition: self.theme_transition,
+            theme_change: None,
+            color_scheme: None,
+            follow_system_theme: self.follow_system_theme,
+            shortcuts: self.shortcuts,
+            timers: Vec::new(),
+            frame_pacing: self.frame_pacing,
+            animation_speed: self.animation_speed.max(0.0),
+            reduced_motion: false,
+            last_input: Instant::now(),
+            held_keys: Default::default(),
+            #[cfg(feature = "reload")]
+            base_styles: self.styles,
+            #[cfg(feature = "reload")]
+            stylesheets: self.stylesheets,
+            #[cfg(feature = "reload")]
+            _stylesheet_watcher: stylesheet_watcher,
         }
     }
 }
+
+/// Read and parse a stylesheet, logging any errors.
+pub(crate) fn load_stylesheet(path: &Path) -> Option<Styles> {
+    let source = match fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(err) => {
+            warn!("failed to read stylesheet `{}`: {}", path.display(), err);
+            return None;
+        }
+    };
+
+    match Styles::parse(&source) {
+        Ok(styles) => Some(styles),
+        Err(err) => {
+            warn!("failed to parse stylesheet `{}`: {}", path.display(), err);
+            None
+        }
+    }
+}
+
+/// Extend `styles` with a stylesheet, ignoring the values of the wrong type with a warning.
+pub(crate) fn apply_stylesheet(styles: &mut Styles, path: &Path, mut sheet: Styles) {
+    for key in sheet.remove_type_mismatches(styles) {
+        warn!(
+            "stylesheet `{}` sets style {:#x} to a value of the wrong type, ignoring it",
+            path.display(),
+            key,
+        );
+    }
+
+    styles.extend(sheet);
+}
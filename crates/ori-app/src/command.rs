@@ -1,8 +1,11 @@
 use ori_core::{
+    style::Theme,
     view::{BoxedView, View},
     window::{Window, WindowId},
 };
 
+use crate::FramePacing;
+
 /// Commands that can be sent to the application.
 ///
 /// # Example
@@ -22,11 +25,26 @@ pub enum AppCommand {
     OpenWindow(Window, Box<dyn FnMut() -> BoxedView<()> + Send>),
 
     /// Close a window.
+    ///
+    /// This closes the window without sending a close request, and quits the
+    /// application when it was the last window.
     CloseWindow(WindowId),
 
     /// Drag a window.
     DragWindow(WindowId),
 
+    /// Change the active theme.
+    ///
+    /// The colors of the theme are transitioned from the old theme to the new one,
+    /// see [`AppBuilder::theme_transition`](crate::AppBuilder::theme_transition).
+    SetTheme(Theme),
+
+    /// Change the frame pacing, e.g. when entering a battery saving mode.
+    SetFramePacing(FramePacing),
+
+    /// Change the animation speed, see [`App::set_animation_speed`](crate::App::set_animation_speed).
+    SetAnimationSpeed(f32),
+
     /// Quit the application.
     Quit,
 }
@@ -1,13 +1,18 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    any::Any,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
 
 use ori_core::{
     context::BaseCx,
     event::Event,
+    style::Theme,
     view::{any, AnyView},
     window::{Window, WindowId},
 };
 
-use crate::{AppRequest, UiBuilder};
+use crate::{AppCommand, AppRequest, FramePacing, UiBuilder};
 
 /// The context passed to the [`Delegate`] trait.
 pub struct DelegateCx<'a, 'b, T> {
@@ -44,10 +49,35 @@ impl<'a, 'b, T> DelegateCx<'a, 'b, T> {
     }
 
     /// Request a rebuild of the view tree.
+    ///
+    /// When handling an event, the view tree is rebuilt once all the delegates have handled
+    /// it, before it's sent to the windows.
     pub fn rebuild(&mut self) {
         *self.rebuild = true;
     }
 
+    /// Set `target` to `value`, requesting a rebuild only if the value changed.
+    ///
+    /// Returns `true` if the value changed.
+    pub fn set_if_changed<U: PartialEq>(&mut self, target: &mut U, value: U) -> bool {
+        if *target == value {
+            return false;
+        }
+
+        *target = value;
+        self.rebuild();
+
+        true
+    }
+
+    /// Open a URL with the default handler of the platform, e.g. a browser.
+    ///
+    /// On Linux this requires the `open-url` feature, and only `http`, `https` and `mailto`
+    /// URLs are opened.
+    pub fn open_url(&mut self, url: impl Into<String>) {
+        self.requests.push(AppRequest::OpenUrl(url.into()));
+    }
+
     /// Quit the application.
     pub fn quit(&mut self) {
         self.requests.push(AppRequest::Quit);
@@ -63,13 +93,56 @@ impl<'a, 'b, T> DelegateCx<'a, 'b, T> {
         (self.requests).push(AppRequest::OpenWindow(window, builder));
     }
 
-    /// Close a window.
+    /// Close a window, see [`AppCommand::CloseWindow`].
+    ///
+    /// Unlike closing the window from the platform, this doesn't send
+    /// [`Event::WindowCloseRequested`], and can't be vetoed.
     pub fn close_window(&mut self, window_id: WindowId) {
-        self.requests.push(AppRequest::CloseWindow(window_id));
+        self.cmd(AppCommand::CloseWindow(window_id));
+    }
+
+    /// Change the active theme, see [`AppCommand::SetTheme`].
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.cmd(AppCommand::SetTheme(theme));
+    }
+
+    /// Change the frame pacing, see [`AppCommand::SetFramePacing`].
+    pub fn set_frame_pacing(&mut self, pacing: FramePacing) {
+        self.cmd(AppCommand::SetFramePacing(pacing));
+    }
+
+    /// Change the animation speed, see [`AppCommand::SetAnimationSpeed`].
+    pub fn set_animation_speed(&mut self, speed: f32) {
+        self.cmd(AppCommand::SetAnimationSpeed(speed));
     }
 }
 
 /// A delegate for handling events in an application.
+///
+/// # Confirming window closes
+/// When the user tries to close a window, [`Event::WindowCloseRequested`] is sent to the
+/// delegates, and then to the views of the window. Returning `true` vetoes the close, which
+/// can be used to ask the user to confirm it, e.g. when there are unsaved changes.
+/// Once confirmed, the window is closed with [`DelegateCx::close_window`].
+///
+/// ```ignore
+/// fn event(&mut self, cx: &mut DelegateCx<Data>, data: &mut Data, event: &Event) -> bool {
+///     if let Event::WindowCloseRequested(e) = event {
+///         if data.unsaved {
+///             data.confirm_close = Some(e.window);
+///             cx.rebuild();
+///             return true;
+///         }
+///     }
+///
+///     if let Some(&ConfirmClose(window)) = event.cmd() {
+///         cx.close_window(window);
+///         return true;
+///     }
+///
+///     false
+/// }
+/// ```
 pub trait AppDelegate<T> {
     /// Called when the application is initialized.
     fn init(&mut self, cx: &mut DelegateCx<T>, data: &mut T) {
@@ -84,3 +157,40 @@ pub trait AppDelegate<T> {
     /// Handle an event.
     fn event(&mut self, cx: &mut DelegateCx<T>, data: &mut T, event: &Event) -> bool;
 }
+
+/// An [`AppDelegate`] calling a handler for messages of type `M`.
+///
+/// Messages are sent with an [`EventProxy`](ori_core::command::EventProxy), and a rebuild
+/// is requested after the handler is called, see [`AppBuilder::message`](crate::AppBuilder::message).
+pub struct MessageDelegate<M, F> {
+    handler: F,
+    marker: PhantomData<fn(M)>,
+}
+
+impl<M, F> MessageDelegate<M, F> {
+    /// Create a new [`MessageDelegate`].
+    pub fn new(handler: F) -> Self {
+        Self {
+            handler,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, M, F> AppDelegate<T> for MessageDelegate<M, F>
+where
+    M: Any,
+    F: FnMut(&mut DelegateCx<T>, &mut T, &M),
+{
+    fn event(&mut self, cx: &mut DelegateCx<T>, data: &mut T, event: &Event) -> bool {
+        match event.cmd::<M>() {
+            Some(message) => {
+                (self.handler)(cx, data, message);
+                cx.rebuild();
+
+                true
+            }
+            None => false,
+        }
+    }
+}
@@ -7,13 +7,19 @@ mod app;
 mod builder;
 mod command;
 mod delegate;
+mod pacing;
+#[cfg(feature = "reload")]
+mod reload;
 mod request;
+mod shortcut;
 
 pub use app::*;
 pub use builder::*;
 pub use command::*;
 pub use delegate::*;
+pub use pacing::*;
 pub use request::*;
+pub use shortcut::*;
 
 use ori_core::view::{AnyView, BoxedView};
 
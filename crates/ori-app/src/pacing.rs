@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+/// Limits on how often windows are redrawn while animating.
+///
+/// Only redraws requested by animations are paced, redraws caused by input, layout or
+/// explicit draw requests are always done immediately, so the application stays
+/// responsive regardless of the limits.
+///
+/// # Example
+/// ```ignore
+/// // animate at 60 fps, and drop to 10 fps after a second without input
+/// App::build().frame_pacing(FramePacing::new().max_fps(60.0).idle_fps(10.0))
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FramePacing {
+    /// The maximum number of animation frames per second, `None` means no limit.
+    pub max_fps: Option<f32>,
+
+    /// The maximum number of animation frames per second while idle, `None` means the
+    /// same as [`FramePacing::max_fps`].
+    pub idle_fps: Option<f32>,
+
+    /// The time without input after which the application is idle.
+    pub idle_after: Duration,
+}
+
+impl Default for FramePacing {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FramePacing {
+    /// Create a new [`FramePacing`] without any limits.
+    pub fn new() -> Self {
+        Self {
+            max_fps: None,
+            idle_fps: None,
+            idle_after: Duration::from_secs(1),
+        }
+    }
+
+    /// Create a new [`FramePacing`] for saving battery, animating at 30 fps, and at 10 fps
+    /// while idle.
+    pub fn battery_saver() -> Self {
+        Self::new().max_fps(30.0).idle_fps(10.0)
+    }
+
+    /// Set the maximum number of animation frames per second.
+    pub fn max_fps(mut self, fps: f32) -> Self {
+        self.max_fps = Some(fps);
+        self
+    }
+
+    /// Set the maximum number of animation frames per second while idle.
+    pub fn idle_fps(mut self, fps: f32) -> Self {
+        self.idle_fps = Some(fps);
+        self
+    }
+
+    /// Set the time without input after which the application is idle.
+    pub fn idle_after(mut self, duration: Duration) -> Self {
+        self.idle_after = duration;
+        self
+    }
+
+    /// Get the minimum time between animation frames, `None` means no limit.
+    pub fn frame_interval(&self, idle: bool) -> Option<Duration> {
+        let fps = match idle {
+            true => self.idle_fps.or(self.max_fps),
+            false => self.max_fps,
+        };
+
+        (fps.filter(|fps| *fps > 0.0)).map(|fps| Duration::from_secs_f32(1.0 / fps))
+    }
+}
@@ -0,0 +1,63 @@
+use std::path::{Path, PathBuf};
+
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use ori_core::{command::CommandProxy, log::warn};
+
+/// A command sent when a watched stylesheet has changed.
+pub(crate) struct ReloadStylesheet(pub PathBuf);
+
+/// Watch `paths` for changes, sending [`ReloadStylesheet`] commands to `proxy`.
+///
+/// The parent directories are watched instead of the files themselves, since many editors
+/// save files by replacing them.
+pub(crate) fn watch_stylesheets(
+    paths: &[PathBuf],
+    proxy: CommandProxy,
+) -> Option<RecommendedWatcher> {
+    if paths.is_empty() {
+        return None;
+    }
+
+    let watched = paths.to_vec();
+    let result = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let Ok(event) = event else { return };
+
+        if !(event.kind.is_create() || event.kind.is_modify()) {
+            return;
+        }
+
+        for path in &watched {
+            if event.paths.iter().any(|changed| same_file(changed, path)) {
+                proxy.cmd(ReloadStylesheet(path.clone()));
+            }
+        }
+    });
+
+    let mut watcher = match result {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            warn!("failed to watch stylesheets: {}", err);
+            return None;
+        }
+    };
+
+    for path in paths {
+        let dir = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+
+        if let Err(err) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+            warn!("failed to watch stylesheet `{}`: {}", path.display(), err);
+        }
+    }
+
+    Some(watcher)
+}
+
+fn same_file(a: &Path, b: &Path) -> bool {
+    match (a.canonicalize(), b.canonicalize()) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => a.file_name() == b.file_name(),
+    }
+}
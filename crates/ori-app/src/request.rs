@@ -19,6 +19,12 @@ pub enum AppRequest<T> {
     /// Update a window.
     UpdateWindow(WindowId, WindowUpdate),
 
+    /// Open a URL with the default handler of the platform, e.g. a browser.
+    ///
+    /// On Linux this requires the `open-url` feature, and only `http`, `https` and `mailto`
+    /// URLs are opened.
+    OpenUrl(String),
+
     /// Quit the application.
     Quit,
 }
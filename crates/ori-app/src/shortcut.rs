@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+
+use ori_core::event::Shortcut;
+
+use crate::DelegateCx;
+
+/// A callback called when a [`Shortcut`] is pressed.
+pub type ShortcutCallback<T> = Box<dyn FnMut(&mut DelegateCx<T>, &mut T)>;
+
+/// A registry of application-wide keyboard shortcuts.
+///
+/// Key presses are first sent to the delegates and the window, and shortcuts are only
+/// consulted when the key press wasn't handled. This means focused views can override
+/// shortcuts, e.g. a focused text input consuming `Ctrl+A`. A key press triggering a
+/// shortcut isn't used for focus navigation.
+///
+/// Shortcuts aren't triggered by key repeats while the key is held, unless inserted with
+/// [`Shortcuts::insert_repeating`].
+pub struct Shortcuts<T> {
+    callbacks: HashMap<Shortcut, (ShortcutCallback<T>, bool)>,
+}
+
+impl<T> Default for Shortcuts<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Shortcuts<T> {
+    /// Create a new empty [`Shortcuts`] registry.
+    pub fn new() -> Self {
+        Self {
+            callbacks: HashMap::new(),
+        }
+    }
+
+    /// Insert a shortcut, replacing any existing callback for it.
+    pub fn insert(
+        &mut self,
+        shortcut: Shortcut,
+        callback: impl FnMut(&mut DelegateCx<T>, &mut T) + 'static,
+    ) {
+        self.callbacks.insert(shortcut, (Box::new(callback), false));
+    }
+
+    /// Insert a shortcut that's also triggered by key repeats, replacing any existing
+    /// callback for it.
+    pub fn insert_repeating(
+        &mut self,
+        shortcut: Shortcut,
+        callback: impl FnMut(&mut DelegateCx<T>, &mut T) + 'static,
+    ) {
+        self.callbacks.insert(shortcut, (Box::new(callback), true));
+    }
+
+    /// Remove a shortcut.
+    ///
+    /// Returns `true` if the shortcut was registered.
+    pub fn remove(&mut self, shortcut: Shortcut) -> bool {
+        self.callbacks.remove(&shortcut).is_some()
+    }
+
+    /// Check if a shortcut is registered.
+    pub fn contains(&self, shortcut: Shortcut) -> bool {
+        self.callbacks.contains_key(&shortcut)
+    }
+
+    /// Iterate over the registered shortcuts.
+    pub fn iter(&self) -> impl Iterator<Item = Shortcut> + '_ {
+        self.callbacks.keys().copied()
+    }
+
+    // get the callback of a shortcut, unless it's a repeat and the shortcut doesn't repeat
+    pub(crate) fn get_mut(
+        &mut self,
+        shortcut: Shortcut,
+        repeat: bool,
+    ) -> Option<&mut ShortcutCallback<T>> {
+        match self.callbacks.get_mut(&shortcut)? {
+            (callback, repeats) if *repeats || !repeat => Some(callback),
+            _ => None,
+        }
+    }
+}
@@ -169,6 +169,28 @@ impl BorderWidth {
             left: self.left + width,
         }
     }
+
+    /// Snap the width of the borders to whole device pixels at `scale`, see [`snap_width`].
+    pub fn snap(&self, scale: f32) -> Self {
+        Self {
+            top: snap_width(self.top, scale),
+            right: snap_width(self.right, scale),
+            bottom: snap_width(self.bottom, scale),
+            left: snap_width(self.left, scale),
+        }
+    }
+}
+
+/// Snap a line `width` to whole device pixels at `scale`, so it is drawn crisply.
+///
+/// Lines that aren't empty are at least one device pixel wide, so a `1.0` wide border stays
+/// visible at any scale, e.g. `0.8` at a scale of `1.25`.
+pub fn snap_width(width: f32, scale: f32) -> f32 {
+    if width <= 0.0 || scale <= 0.0 {
+        return width;
+    }
+
+    f32::max((width * scale).round(), 1.0) / scale
 }
 
 impl From<(f32, f32, f32, f32)> for BorderWidth {
@@ -236,3 +258,24 @@ impl From<f32> for Styled<BorderWidth> {
         Self::Value(BorderWidth::from(x))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_fractional_scales() {
+        // a 1px border is one device pixel at 1.25, and two at 1.5
+        assert_eq!(snap_width(1.0, 1.25) * 1.25, 1.0);
+        assert_eq!(snap_width(1.0, 1.5) * 1.5, 2.0);
+        assert_eq!(snap_width(2.0, 1.25) * 1.25, 3.0);
+
+        // hairlines stay visible, and missing borders stay missing
+        assert_eq!(snap_width(0.25, 1.5) * 1.5, 1.0);
+        assert_eq!(snap_width(0.0, 1.5), 0.0);
+
+        let width = BorderWidth::new(1.0, 0.0, 2.0, 1.0).snap(1.5);
+        assert_eq!(width.right, 0.0);
+        assert_eq!(width.bottom * 1.5, 3.0);
+    }
+}
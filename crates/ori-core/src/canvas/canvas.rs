@@ -1,7 +1,6 @@
 use std::{
-    collections::BTreeMap,
+    collections::{btree_map::Entry, BTreeMap},
     hash::{Hash, Hasher},
-    mem,
     sync::Arc,
 };
 
@@ -214,6 +213,15 @@ pub enum Primitive {
         /// The view of the layer.
         view: Option<ViewId>,
     },
+
+    /// A blur of what was drawn before it, within a curve, see [`Canvas::backdrop_blur`].
+    BackdropBlur {
+        /// The curve to blur within.
+        curve: Arc<Curve>,
+
+        /// The radius of the blur, the standard deviation of a gaussian blur.
+        radius: f32,
+    },
 }
 
 impl Primitive {
@@ -222,6 +230,7 @@ impl Primitive {
         match self {
             Primitive::Fill { .. } => 1,
             Primitive::Stroke { .. } => 1,
+            Primitive::BackdropBlur { .. } => 1,
             Primitive::Layer { primitives, .. } => primitives.iter().map(Self::count).sum(),
         }
     }
@@ -258,7 +267,12 @@ impl Canvas {
     /// Clear the canvas.
     pub fn clear(&mut self) {
         self.overlays.clear();
-        Arc::make_mut(&mut self.primitives).clear();
+
+        // the primitives might be shared with a cached canvas, which shouldn't be copied
+        match Arc::get_mut(&mut self.primitives) {
+            Some(primitives) => primitives.clear(),
+            None => self.primitives = Arc::new(Vec::new()),
+        }
     }
 
     /// Draw a rectangle.
@@ -308,6 +322,19 @@ impl Canvas {
         });
     }
 
+    /// Blur what was drawn before, within a curve, e.g. behind a translucent overlay.
+    ///
+    /// This requires the renderer to sample what it has already rendered, which the glow
+    /// and skia renderers do. Renderers that can't ignore it, so what is drawn over the blur
+    /// should be translucent, to fall back to plainly dimming the content behind it.
+    pub fn backdrop_blur(&mut self, curve: impl Into<Arc<Curve>>, radius: f32) {
+        let primitives = Arc::make_mut(&mut self.primitives);
+        primitives.push(Primitive::BackdropBlur {
+            curve: curve.into(),
+            radius,
+        });
+    }
+
     /// Draw a canvas.
     pub fn draw_canvas(&mut self, canvas: Canvas) {
         self.layer(Affine::IDENTITY, None, None, |ca| *ca = canvas);
@@ -319,15 +346,11 @@ impl Canvas {
 
         let result = f(&mut overlay);
 
-        for (i, mut others) in overlay.overlays {
-            let others = mem::take(Arc::make_mut(&mut others));
-            let primitives = Arc::make_mut(self.overlays.entry(i).or_default());
-            primitives.extend(others);
+        for (i, others) in overlay.overlays {
+            self.extend_overlay(i, others);
         }
 
-        let other = mem::take(Arc::make_mut(&mut overlay.primitives));
-        let primitives = Arc::make_mut(self.overlays.entry(index).or_default());
-        primitives.extend(other);
+        self.extend_overlay(index, overlay.primitives);
 
         result
     }
@@ -344,10 +367,8 @@ impl Canvas {
 
         let result = f(&mut layer);
 
-        for (i, mut other) in layer.overlays {
-            let other = mem::take(Arc::make_mut(&mut other));
-            let primitives = Arc::make_mut(self.overlays.entry(i).or_default());
-            primitives.extend(other);
+        for (i, other) in layer.overlays {
+            self.extend_overlay(i, other);
         }
 
         let primitives = Arc::make_mut(&mut self.primitives);
@@ -361,6 +382,28 @@ impl Canvas {
         result
     }
 
+    // add primitives to the overlay at `index`, the overlays of cached canvases are shared
+    // rather than copied, when nothing else is drawn at the same index
+    fn extend_overlay(&mut self, index: i32, other: Arc<Vec<Primitive>>) {
+        if other.is_empty() {
+            return;
+        }
+
+        match self.overlays.entry(index) {
+            Entry::Vacant(entry) => {
+                entry.insert(other);
+            }
+            Entry::Occupied(mut entry) => {
+                let primitives = Arc::make_mut(entry.get_mut());
+
+                match Arc::try_unwrap(other) {
+                    Ok(other) => primitives.extend(other),
+                    Err(other) => primitives.extend(other.iter().cloned()),
+                }
+            }
+        }
+    }
+
     /// Draw a layer with a transformation.
     pub fn transformed<T>(&mut self, transform: Affine, f: impl FnOnce(&mut Self) -> T) -> T {
         self.layer(transform, None, None, f)
@@ -401,7 +444,7 @@ impl Canvas {
                             return view;
                         }
                     }
-                    Primitive::Stroke { .. } => {}
+                    Primitive::Stroke { .. } | Primitive::BackdropBlur { .. } => {}
                     Primitive::Layer {
                         primitives,
                         transform,
@@ -440,3 +483,28 @@ impl Canvas {
         recurse(&self.primitives, None, point)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_overlays_are_shared() {
+        let mut cached = Canvas::new();
+        cached.overlay(1, |canvas| {
+            canvas.rect(Rect::new(Point::ZERO, Point::all(10.0)), Color::RED);
+        });
+
+        // drawing a cached canvas again doesn't copy its overlays
+        let mut canvas = Canvas::new();
+        canvas.draw_canvas(cached.clone());
+        assert!(Arc::ptr_eq(&canvas.overlays[&1], &cached.overlays[&1]));
+
+        // but they're copied when they have to be merged
+        canvas.overlay(1, |canvas| {
+            canvas.rect(Rect::new(Point::ZERO, Point::all(5.0)), Color::BLUE);
+        });
+        assert_eq!(canvas.overlays[&1].len(), 2);
+        assert_eq!(cached.overlays[&1].len(), 1);
+    }
+}
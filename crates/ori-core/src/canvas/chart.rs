@@ -0,0 +1,286 @@
+use crate::layout::{Point, Rect, Vector};
+
+use super::{Canvas, Curve, FillRule, Paint, Stroke};
+
+/// A mapping from data space to a rect on the screen, used to plot charts.
+///
+/// Data space has the y-axis pointing up, so `min` is mapped to the bottom left of
+/// `rect`, and `max` to the top right.
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// let values = vec![Point::new(0.0, 1.0), Point::new(1.0, 3.0), Point::new(2.0, 2.0)];
+///
+/// canvas_view(move |canvas, size| {
+///     let plot = Plot::fit(Rect::min_size(Point::ZERO, size).shrink(20.0), &values);
+///
+///     plot.draw_area(canvas, &values, Color::BLUE.fade(0.2));
+///     plot.draw_line(canvas, &values, 2.0, Color::BLUE);
+///     plot.draw_axes(canvas, 5, 1.0, Color::BLACK);
+/// })
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plot {
+    /// The rect on the screen.
+    pub rect: Rect,
+
+    /// The minimum of the data space.
+    pub min: Point,
+
+    /// The maximum of the data space.
+    pub max: Point,
+}
+
+impl Plot {
+    /// The length of the tick marks drawn by [`Plot::draw_axes`].
+    pub const TICK_LENGTH: f32 = 4.0;
+
+    /// Create a new [`Plot`], mapping `min..max` to `rect`.
+    pub fn new(rect: Rect, min: Point, max: Point) -> Self {
+        Self { rect, min, max }
+    }
+
+    /// Create a new [`Plot`], with a data space fitting `points`.
+    ///
+    /// The y-axis always includes zero, so that areas and bars have a baseline.
+    pub fn fit(rect: Rect, points: &[Point]) -> Self {
+        let mut min = Point::new(f32::INFINITY, 0.0);
+        let mut max = Point::new(f32::NEG_INFINITY, 0.0);
+
+        for point in points {
+            min = min.min(*point);
+            max = max.max(*point);
+        }
+
+        if points.is_empty() {
+            min.x = 0.0;
+            max.x = 1.0;
+        }
+
+        Self::new(rect, min, max)
+    }
+
+    /// Map a point from data space to the screen.
+    pub fn map(&self, point: Point) -> Point {
+        Point::new(self.map_x(point.x), self.map_y(point.y))
+    }
+
+    /// Map an x-coordinate from data space to the screen.
+    pub fn map_x(&self, x: f32) -> f32 {
+        let t = normalize(x, self.min.x, self.max.x);
+        self.rect.min.x + self.rect.width() * t
+    }
+
+    /// Map a y-coordinate from data space to the screen.
+    pub fn map_y(&self, y: f32) -> f32 {
+        let t = normalize(y, self.min.y, self.max.y);
+        self.rect.max.y - self.rect.height() * t
+    }
+
+    /// Map a point from the screen to data space.
+    pub fn unmap(&self, point: Point) -> Point {
+        let tx = normalize(point.x, self.rect.min.x, self.rect.max.x);
+        let ty = normalize(point.y, self.rect.max.y, self.rect.min.y);
+
+        Point::new(
+            self.min.x + (self.max.x - self.min.x) * tx,
+            self.min.y + (self.max.y - self.min.y) * ty,
+        )
+    }
+
+    // the y-coordinate on the screen areas and bars grow from
+    fn baseline(&self) -> f32 {
+        self.map_y(f32::clamp(0.0, self.min.y, self.max.y))
+    }
+
+    /// Create a curve connecting `points` with lines.
+    pub fn line(&self, points: &[Point]) -> Curve {
+        let mut curve = Curve::new();
+
+        for (i, point) in points.iter().enumerate() {
+            match i {
+                0 => curve.move_to(self.map(*point)),
+                _ => curve.line_to(self.map(*point)),
+            }
+        }
+
+        curve
+    }
+
+    /// Create a curve of the area between `points` and the baseline.
+    pub fn area(&self, points: &[Point]) -> Curve {
+        let mut curve = Curve::new();
+
+        let (Some(first), Some(last)) = (points.first(), points.last()) else {
+            return curve;
+        };
+
+        let baseline = self.baseline();
+        curve.move_to(Point::new(self.map_x(first.x), baseline));
+
+        for point in points {
+            curve.line_to(self.map(*point));
+        }
+
+        curve.line_to(Point::new(self.map_x(last.x), baseline));
+        curve.close();
+
+        curve
+    }
+
+    /// Create a curve with a bar for each of `points`, centered on the x-coordinate of the
+    /// point, and `width` wide in data space.
+    pub fn bars(&self, points: &[Point], width: f32) -> Curve {
+        let mut curve = Curve::new();
+        let baseline = self.baseline();
+
+        for point in points {
+            let left = self.map_x(point.x - width / 2.0);
+            let right = self.map_x(point.x + width / 2.0);
+            let top = self.map_y(point.y);
+
+            let min = Point::new(left.min(right), top.min(baseline));
+            let max = Point::new(left.max(right), top.max(baseline));
+            curve.push_rect(Rect::new(min, max));
+        }
+
+        curve
+    }
+
+    /// Get the ticks of the x-axis, see [`ticks`].
+    pub fn x_ticks(&self, count: usize) -> Vec<f32> {
+        ticks(self.min.x, self.max.x, count)
+    }
+
+    /// Get the ticks of the y-axis, see [`ticks`].
+    pub fn y_ticks(&self, count: usize) -> Vec<f32> {
+        ticks(self.min.y, self.max.y, count)
+    }
+
+    /// Draw a line through `points`.
+    pub fn draw_line(
+        &self,
+        canvas: &mut Canvas,
+        points: &[Point],
+        stroke: impl Into<Stroke>,
+        paint: impl Into<Paint>,
+    ) {
+        canvas.stroke(self.line(points), stroke, paint);
+    }
+
+    /// Draw the area between `points` and the baseline.
+    pub fn draw_area(&self, canvas: &mut Canvas, points: &[Point], paint: impl Into<Paint>) {
+        canvas.fill(self.area(points), FillRule::NonZero, paint);
+    }
+
+    /// Draw a bar for each of `points`, see [`Plot::bars`].
+    pub fn draw_bars(
+        &self,
+        canvas: &mut Canvas,
+        points: &[Point],
+        width: f32,
+        paint: impl Into<Paint>,
+    ) {
+        canvas.fill(self.bars(points, width), FillRule::NonZero, paint);
+    }
+
+    /// Draw the x and y axes, with about `ticks` tick marks each.
+    ///
+    /// Tick labels aren't drawn, use [`Plot::x_ticks`] and [`Plot::y_ticks`] to place them.
+    pub fn draw_axes(
+        &self,
+        canvas: &mut Canvas,
+        ticks: usize,
+        stroke: impl Into<Stroke>,
+        paint: impl Into<Paint>,
+    ) {
+        let mut curve = Curve::new();
+
+        curve.move_to(self.rect.top_left());
+        curve.line_to(self.rect.bottom_left());
+        curve.line_to(self.rect.bottom_right());
+
+        for x in self.x_ticks(ticks) {
+            let point = Point::new(self.map_x(x), self.rect.max.y);
+            curve.move_to(point);
+            curve.line_to(point + Vector::new(0.0, Self::TICK_LENGTH));
+        }
+
+        for y in self.y_ticks(ticks) {
+            let point = Point::new(self.rect.min.x, self.map_y(y));
+            curve.move_to(point);
+            curve.line_to(point - Vector::new(Self::TICK_LENGTH, 0.0));
+        }
+
+        canvas.stroke(curve, stroke, paint);
+    }
+}
+
+fn normalize(value: f32, min: f32, max: f32) -> f32 {
+    match max == min {
+        true => 0.5,
+        false => (value - min) / (max - min),
+    }
+}
+
+/// Get about `count` evenly spaced ticks between `min` and `max`.
+///
+/// The spacing of the ticks is a power of ten times one, two or five, so that the ticks
+/// are round numbers.
+pub fn ticks(min: f32, max: f32, count: usize) -> Vec<f32> {
+    if count == 0 || !min.is_finite() || !max.is_finite() || min >= max {
+        return Vec::new();
+    }
+
+    let rough = (max - min) / count as f32;
+    let magnitude = 10f32.powf(rough.log10().floor());
+
+    let step = match rough / magnitude {
+        r if r < 1.5 => magnitude,
+        r if r < 3.0 => magnitude * 2.0,
+        r if r < 7.0 => magnitude * 5.0,
+        _ => magnitude * 10.0,
+    };
+
+    let first = (min / step).ceil() as i64;
+    let last = (max / step).floor() as i64;
+
+    (first..=last).map(|i| i as f32 * step).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plot_map() {
+        let rect = Rect::new(Point::new(0.0, 0.0), Point::new(100.0, 50.0));
+        let plot = Plot::new(rect, Point::new(0.0, 0.0), Point::new(10.0, 5.0));
+
+        assert_eq!(plot.map(Point::new(0.0, 0.0)), Point::new(0.0, 50.0));
+        assert_eq!(plot.map(Point::new(10.0, 5.0)), Point::new(100.0, 0.0));
+        assert_eq!(plot.unmap(Point::new(50.0, 25.0)), Point::new(5.0, 2.5));
+    }
+
+    #[test]
+    fn plot_fit() {
+        let points = [Point::new(1.0, 2.0), Point::new(3.0, 8.0)];
+        let plot = Plot::fit(Rect::ZERO, &points);
+
+        assert_eq!(plot.min, Point::new(1.0, 0.0));
+        assert_eq!(plot.max, Point::new(3.0, 8.0));
+    }
+
+    #[test]
+    fn nice_ticks() {
+        assert_eq!(ticks(0.0, 10.0, 5), vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(
+            ticks(0.0, 100.0, 4),
+            vec![0.0, 20.0, 40.0, 60.0, 80.0, 100.0]
+        );
+        assert_eq!(ticks(-1.0, 1.0, 4), vec![-1.0, -0.5, 0.0, 0.5, 1.0]);
+        assert!(ticks(1.0, 1.0, 4).is_empty());
+    }
+}
@@ -80,6 +80,10 @@ pub fn hex(hex: &str) -> Color {
 }
 
 /// A color with red, green, blue and alpha components.
+///
+/// The components are in the sRGB color space, gamma encoded like colors in CSS, and the alpha
+/// is not premultiplied. Renderers write them to the window as they are, without converting
+/// them, so the same color looks the same with every renderer.
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Default, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -135,24 +139,29 @@ impl Color {
         )
     }
 
+    // convert a component to an 8 bit integer, rounding to the nearest value
+    fn to_u8(x: f32) -> u8 {
+        (x.clamp(0.0, 1.0) * 255.0).round() as u8
+    }
+
     /// Get the red component as an 8 bit integer.
     pub fn r8(&self) -> u8 {
-        (self.r * 255.0) as u8
+        Self::to_u8(self.r)
     }
 
     /// Get the green component as an 8 bit integer.
     pub fn g8(&self) -> u8 {
-        (self.g * 255.0) as u8
+        Self::to_u8(self.g)
     }
 
     /// Get the blue component as an 8 bit integer.
     pub fn b8(&self) -> u8 {
-        (self.b * 255.0) as u8
+        Self::to_u8(self.b)
     }
 
     /// Get the alpha component as an 8 bit integer.
     pub fn a8(&self) -> u8 {
-        (self.a * 255.0) as u8
+        Self::to_u8(self.a)
     }
 
     /// Try to parse a color from a hex string.
@@ -850,6 +859,16 @@ impl Color {
     /// Note that this is a linear interpolation in the oklab color space.
     /// If rgb interpolation is required use `mix_rgb`.
     pub fn mix(self, other: Self, t: f32) -> Self {
+        self.mix_oklab(other, t)
+    }
+
+    /// Linearly interpolate between two colors in the oklab color space.
+    ///
+    /// This uses a fractor `t` between `0.0` and `1.0`.
+    /// Where `0.0` is `self` and `1.0` is `other`.
+    ///
+    /// See <https://bottosson.github.io/posts/oklab/>.
+    pub fn mix_oklab(self, other: Self, t: f32) -> Self {
         let (al, aa, ab, aalpha) = self.to_oklaba();
         let (bl, ba, bb, balpha) = other.to_oklaba();
 
@@ -861,6 +880,40 @@ impl Color {
         Self::oklaba(l, a, b, alpha)
     }
 
+    /// Interpolate between two colors in the oklch color space.
+    ///
+    /// This uses a fractor `t` between `0.0` and `1.0`.
+    /// Where `0.0` is `self` and `1.0` is `other`.
+    ///
+    /// The hue is interpolated along the shortest path around the color wheel, which keeps
+    /// the chroma of the colors, unlike [`Color::mix_oklab`] which can pass through gray.
+    pub fn mix_oklch(self, other: Self, t: f32) -> Self {
+        let (al, ac, mut ah, aalpha) = self.to_oklcha();
+        let (bl, bc, mut bh, balpha) = other.to_oklcha();
+
+        // achromatic colors have no meaningful hue, so we use the hue of the other color
+        if ac < 1e-4 {
+            ah = bh;
+        } else if bc < 1e-4 {
+            bh = ah;
+        }
+
+        let mut dh = bh - ah;
+
+        if dh > 180.0 {
+            dh -= 360.0;
+        } else if dh < -180.0 {
+            dh += 360.0;
+        }
+
+        let l = al * (1.0 - t) + bl * t;
+        let c = ac * (1.0 - t) + bc * t;
+        let h = (ah + dh * t).rem_euclid(360.0);
+        let alpha = aalpha * (1.0 - t) + balpha * t;
+
+        Self::oklcha(l, c, h, alpha)
+    }
+
     /// Linearly interpolate between two colors.
     ///
     /// This uses a fractor `t` between `0.0` and `1.0`.
@@ -924,14 +977,9 @@ impl Color {
         ]
     }
 
-    /// Convert the color to linear sRGB.
+    /// Convert the color to 8 bit sRGB components, the inverse of [`Color::rgba8`].
     pub fn to_rgba8(self) -> [u8; 4] {
-        [
-            (self.r * 255.0) as u8,
-            (self.g * 255.0) as u8,
-            (self.b * 255.0) as u8,
-            (self.a * 255.0) as u8,
-        ]
+        [self.r8(), self.g8(), self.b8(), self.a8()]
     }
 }
 
@@ -1117,6 +1165,14 @@ mod tests {
         assert_eq!(color, Color::MAGENTA);
     }
 
+    #[test]
+    fn rgba8_roundtrip() {
+        for x in 0..=255 {
+            let color = Color::rgba8(x, x, x, x);
+            assert_eq!(color.to_rgba8(), [x; 4]);
+        }
+    }
+
     #[test]
     fn hex_display() {
         let display = DisplayHex::new(0xa0, 0xb2, 0xcb, 0xd6);
@@ -1133,6 +1189,31 @@ mod tests {
         assert!(f32::abs(color.b - color2.b) < 0.0001);
     }
 
+    #[test]
+    fn mix_endpoints() {
+        let a = Color::rgb(0.9, 0.2, 0.1);
+        let b = Color::rgb(0.1, 0.4, 0.8);
+
+        for mix in [Color::mix_oklab, Color::mix_oklch] {
+            let start = mix(a, b, 0.0);
+            let end = mix(a, b, 1.0);
+
+            assert!(f32::abs(start.r - a.r) < 0.001);
+            assert!(f32::abs(start.b - a.b) < 0.001);
+            assert!(f32::abs(end.r - b.r) < 0.001);
+            assert!(f32::abs(end.b - b.b) < 0.001);
+        }
+    }
+
+    #[test]
+    fn mix_oklch_shortest_hue() {
+        let a = Color::oklch(0.7, 0.1, 350.0);
+        let b = Color::oklch(0.7, 0.1, 30.0);
+
+        let (_, _, h) = a.mix_oklch(b, 0.5).to_oklch();
+        assert!(f32::abs(h - 10.0) < 0.5);
+    }
+
     #[test]
     fn okhsv_inverse() {
         let color = Color::rgb(0.05, 0.15, 0.20);
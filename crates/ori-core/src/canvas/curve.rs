@@ -1,4 +1,4 @@
-use std::f32::consts::{PI, SQRT_2};
+use std::f32::consts::SQRT_2;
 
 use crate::layout::{Affine, Point, Rect, Size, Vector};
 
@@ -51,6 +51,12 @@ impl Default for Curve {
 }
 
 impl Curve {
+    /// The default tolerance used when approximating curves, this is a tenth of a pixel
+    /// when the curve is drawn without scaling.
+    pub const TOLERANCE: f32 = 0.1;
+
+    const CONIC_TOLERANCE: f32 = 0.25;
+
     /// Create a new curve.
     pub fn new() -> Self {
         Self {
@@ -185,6 +191,20 @@ impl Curve {
     ///
     /// Conic curves are approximated by quadratic bezier curves.
     pub fn conic_to(&mut self, control: Point, point: Point, weight: f32) {
+        self.conic_to_with_tolerance(control, point, weight, Self::CONIC_TOLERANCE);
+    }
+
+    /// Draw a conic curve to a `point`, with a control point `control` and a `weight`.
+    ///
+    /// Conic curves are approximated by quadratic bezier curves, that deviate at most
+    /// `tolerance` from the conic.
+    pub fn conic_to_with_tolerance(
+        &mut self,
+        control: Point,
+        point: Point,
+        weight: f32,
+        tolerance: f32,
+    ) {
         let last_point = self.points.last().copied().unwrap_or(Point::ZERO);
 
         let conic = Conic {
@@ -194,7 +214,7 @@ impl Curve {
             weight,
         };
 
-        if let Some(pow2) = conic.compute_quad_pow2(0.25) {
+        if let Some(pow2) = conic.compute_quad_pow2(tolerance) {
             let mut points = [Point::ZERO; 64];
             let len = conic.chop_into_quads_pow2(pow2, &mut points);
 
@@ -234,20 +254,53 @@ impl Curve {
     }
 
     /// Check if the curve contains a `point` using the given `rule`.
+    ///
+    /// Open sub-paths are implicitly closed, like when the curve is filled.
     pub fn contains(&self, point: Point, rule: FillRule) -> bool {
         if !self.bounds.contains(point) || self.is_empty() {
             return false;
         }
 
+        let winding = self.winding(point);
+
         match rule {
-            FillRule::NonZero => self.contains_even_odd(point),
-            FillRule::EvenOdd => self.contains_even_odd(point),
+            FillRule::NonZero => winding != 0,
+            FillRule::EvenOdd => winding % 2 != 0,
+        }
+    }
+
+    /// Check if a `point` is within `width / 2.0` of the curve, as if it was stroked
+    /// with `width`.
+    ///
+    /// Curved segments are approximated by lines, and stroke caps and joins are treated
+    /// as round.
+    pub fn stroke_contains(&self, point: Point, width: f32) -> bool {
+        let radius = width / 2.0;
+
+        if !self.bounds.expand(radius).contains(point) || self.is_empty() {
+            return false;
         }
+
+        let mut contains = false;
+        self.for_each_line(|s, e| {
+            contains |= segment_distance(s, e, point) <= radius;
+        });
+
+        contains
     }
 
     /// Stroke the `curve` with the given `stroke`.
     pub fn stroke_curve(&mut self, curve: &Curve, stroke: Stroke) {
-        self.stroke_impl(curve, stroke);
+        self.stroke_impl(curve, stroke, Self::TOLERANCE);
+    }
+
+    /// Stroke the `curve` with the given `stroke`, approximating the outline of curved
+    /// segments with at most `tolerance` error.
+    ///
+    /// The tolerance is in the units of the curve, so when the curve is drawn scaled, it
+    /// should be divided by the scale, see [`Curve::TOLERANCE`].
+    pub fn stroke_curve_with_tolerance(&mut self, curve: &Curve, stroke: Stroke, tolerance: f32) {
+        self.stroke_impl(curve, stroke, tolerance);
     }
 
     pub(crate) fn append_reverse(&mut self, curve: &Curve) {
@@ -479,50 +532,6 @@ impl Curve {
         [x1, x2]
     }
 
-    pub(crate) fn cube_roots(a: f32, b: f32, c: f32, d: f32) -> [f32; 3] {
-        if a.abs() < 1e-6 {
-            let [x1, x2] = Self::square_roots(b, c, d);
-            return [x1, x2, f32::NAN];
-        }
-
-        let a_inv = 1.0 / a;
-        let a = b * a_inv;
-        let b = c * a_inv;
-        let c = d * a_inv;
-
-        let q = (a * a - b * 3.0) / 9.0;
-        let r = (2.0 * a * a * a - 9.0 * a * b + 27.0 * c) / 54.0;
-
-        let q3 = q * q * q;
-        let r2_minus_q3 = r * r - q3;
-        let adiv3 = a / 3.0;
-
-        if r2_minus_q3 < 0.0 {
-            // we have 3 real roots
-            let theta = (r / q3.sqrt()).acos();
-            let neg2_root_q = -2.0 * q.sqrt();
-
-            let x1 = neg2_root_q * (theta / 3.0).cos() - adiv3;
-            let x2 = neg2_root_q * ((theta + 2.0 * PI) / 3.0).cos() - adiv3;
-            let x3 = neg2_root_q * ((theta - 2.0 * PI) / 3.0).cos() - adiv3;
-
-            [x1, x2, x3]
-        } else {
-            // we have 1 real root
-            let mut a = (r.abs() + r2_minus_q3.sqrt()).cbrt();
-
-            if r < 0.0 {
-                a = -a;
-            }
-
-            if a != 0.0 {
-                a += q / a;
-            }
-
-            [a - adiv3, f32::NAN, f32::NAN]
-        }
-    }
-
     fn lerps(a: f32, b: f32, t: f32) -> f32 {
         a + (b - a) * t
     }
@@ -539,99 +548,218 @@ impl Curve {
         )
     }
 
-    // check if the curve contains a point using the even-odd rule
+    // compute the winding number of the curve around a point
     //
-    // this works by counting the number of times a ray starting at the `point`
-    // and extending to the right along the x-axis intersects the curve
-    fn contains_even_odd(&self, p: Point) -> bool {
-        let mut crossings = 0;
-        let mut s = *self.points.last().unwrap();
+    // this works by summing the signed crossings of a ray starting at the `point`
+    // and extending to the right along the x-axis, with the curve
+    fn winding(&self, p: Point) -> i32 {
+        let mut winding = 0;
+        let mut start = Point::ZERO;
+        let mut s = Point::ZERO;
 
         for segment in self.iter() {
             match segment {
                 CurveSegment::Move(e) => {
+                    // implicitly close the previous sub-path
+                    winding += Self::line_winding(s, start, p);
+                    start = e;
                     s = e;
                 }
                 CurveSegment::Line(e) => {
-                    crossings += Self::line_intersection_count(s, e, p);
+                    winding += Self::line_winding(s, e, p);
                     s = e;
                 }
                 CurveSegment::Quad(c0, e) => {
-                    crossings += Self::quad_intersection_count(s, c0, e, p);
+                    winding += Self::quad_winding(s, c0, e, p);
                     s = e;
                 }
                 CurveSegment::Cubic(c0, c1, e) => {
-                    crossings += Self::cubic_intersection_count(s, c0, c1, e, p);
+                    winding += Self::cubic_winding(s, c0, c1, e, p);
                     s = e;
                 }
-                CurveSegment::Close => {}
+                CurveSegment::Close => {
+                    winding += Self::line_winding(s, start, p);
+                    s = start;
+                }
             }
         }
 
-        crossings % 2 == 1
+        winding + Self::line_winding(s, start, p)
     }
 
-    fn line_intersection_count(s: Point, e: Point, p: Point) -> usize {
-        let a = e.y - s.y;
-        let b = s.y - p.y;
+    // call `f` with every segment of the curve, with curves flattened into lines
+    fn for_each_line(&self, mut f: impl FnMut(Point, Point)) {
+        const STEPS: usize = 16;
+
+        let mut start = Point::ZERO;
+        let mut s = Point::ZERO;
+
+        for segment in self.iter() {
+            match segment {
+                CurveSegment::Move(e) => {
+                    start = e;
+                    s = e;
+                }
+                CurveSegment::Line(e) => {
+                    f(s, e);
+                    s = e;
+                }
+                CurveSegment::Quad(c0, e) => {
+                    let mut prev = s;
+
+                    for i in 1..=STEPS {
+                        let t = i as f32 / STEPS as f32;
+                        let next = Point::new(
+                            Self::quadratic_bezier(s.x, c0.x, e.x, t),
+                            Self::quadratic_bezier(s.y, c0.y, e.y, t),
+                        );
 
-        let t = -b / a;
+                        f(prev, next);
+                        prev = next;
+                    }
 
-        let is_on_curve = (0.0..=1.0).contains(&t);
-        let is_right = Self::lerps(s.x, e.x, t) >= p.x;
+                    s = e;
+                }
+                CurveSegment::Cubic(c0, c1, e) => {
+                    let mut prev = s;
 
-        (is_on_curve && is_right) as usize
+                    for i in 1..=STEPS {
+                        let t = i as f32 / STEPS as f32;
+                        let next = Point::new(
+                            Self::cubic_bezier(s.x, c0.x, c1.x, e.x, t),
+                            Self::cubic_bezier(s.y, c0.y, c1.y, e.y, t),
+                        );
+
+                        f(prev, next);
+                        prev = next;
+                    }
+
+                    s = e;
+                }
+                CurveSegment::Close => {
+                    f(s, start);
+                    s = start;
+                }
+            }
+        }
     }
 
-    pub(crate) fn quad_intersections(s: Point, c0: Point, e: Point, p: Point) -> [f32; 2] {
-        let a = s.y - 2.0 * c0.y + e.y;
-        let b = 2.0 * (c0.y - s.y);
-        let c = s.y - p.y;
+    // the direction in which an edge from `y0` to `y1` crosses the ray at `y`
+    //
+    // crossings are counted on the half-open range `y0 <= y < y1`, so that a ray passing
+    // through a vertex shared by two edges is only counted once
+    fn crossing(y0: f32, y1: f32, y: f32) -> i32 {
+        if y0 <= y && y < y1 {
+            1
+        } else if y1 <= y && y < y0 {
+            -1
+        } else {
+            0
+        }
+    }
+
+    fn line_winding(s: Point, e: Point, p: Point) -> i32 {
+        let crossing = Self::crossing(s.y, e.y, p.y);
+
+        if crossing == 0 {
+            return 0;
+        }
+
+        let t = (p.y - s.y) / (e.y - s.y);
 
-        Self::square_roots(a, b, c)
+        match Self::lerps(s.x, e.x, t) < p.x {
+            true => 0,
+            false => crossing,
+        }
     }
 
-    fn quad_intersection_count(s: Point, c0: Point, e: Point, p: Point) -> usize {
-        let [t1, t2] = Self::quad_intersections(s, c0, e, p);
+    // the winding of a piece of a curve between `t0` and `t1`, that is monotonic along the
+    // y-axis, where `f` evaluates the curve
+    fn monotonic_winding(t0: f32, t1: f32, p: Point, f: impl Fn(f32) -> Point) -> i32 {
+        let (a, b) = (f(t0), f(t1));
+        let crossing = Self::crossing(a.y, b.y, p.y);
 
-        let is_valid = |t: f32| {
-            let is_on_curve = (0.0..=1.0).contains(&t);
-            let is_right = Self::quadratic_bezier(s.x, c0.x, e.x, t) >= p.x;
+        if crossing == 0 {
+            return 0;
+        }
 
-            is_on_curve && is_right
-        };
+        // find where the piece crosses the ray, which is unique since it's monotonic
+        let (mut lo, mut hi) = (t0, t1);
+
+        for _ in 0..32 {
+            let mid = (lo + hi) / 2.0;
 
-        is_valid(t1) as usize + is_valid(t2) as usize
+            match (f(mid).y > p.y) == (a.y > p.y) {
+                true => lo = mid,
+                false => hi = mid,
+            }
+        }
+
+        match f((lo + hi) / 2.0).x < p.x {
+            true => 0,
+            false => crossing,
+        }
     }
 
-    pub(crate) fn cubic_intersections(
-        s: Point,
-        c0: Point,
-        c1: Point,
-        e: Point,
-        p: Point,
-    ) -> [f32; 3] {
-        let a = -s.y + 3.0 * c0.y - 3.0 * c1.y + e.y;
-        let b = 3.0 * (s.y - 2.0 * c0.y + c1.y);
-        let c = 3.0 * (c0.y - s.y);
-        let d = s.y - p.y;
+    // sum the winding of the pieces of a curve between the `extrema` along the y-axis
+    fn split_winding(extrema: &[f32], p: Point, f: impl Fn(f32) -> Point) -> i32 {
+        let mut splits: Vec<f32> = (extrema.iter().copied())
+            .filter(|t| *t > 0.0 && *t < 1.0)
+            .collect();
+        splits.sort_by(f32::total_cmp);
+
+        let mut winding = 0;
+        let mut t0 = 0.0;
+
+        for t1 in splits.into_iter().chain([1.0]) {
+            winding += Self::monotonic_winding(t0, t1, p, &f);
+            t0 = t1;
+        }
 
-        Self::cube_roots(a, b, c, d)
+        winding
     }
 
-    fn cubic_intersection_count(s: Point, c0: Point, c1: Point, e: Point, p: Point) -> usize {
-        let is_valid = |t: f32| {
-            let is_on_curve = (0.0..=1.0).contains(&t);
-            let is_right = Self::cubic_bezier(s.x, c0.x, c1.x, e.x, t) >= p.x;
-            is_on_curve && is_right
-        };
+    fn quad_winding(s: Point, c0: Point, e: Point, p: Point) -> i32 {
+        // the derivative along the y-axis is zero at a single point
+        let extremum = (s.y - c0.y) / (s.y - 2.0 * c0.y + e.y);
 
-        let [t1, t2, t3] = Self::cubic_intersections(s, c0, c1, e, p);
+        Self::split_winding(&[extremum], p, |t| {
+            Point::new(
+                Self::quadratic_bezier(s.x, c0.x, e.x, t),
+                Self::quadratic_bezier(s.y, c0.y, e.y, t),
+            )
+        })
+    }
 
-        is_valid(t1) as usize + is_valid(t2) as usize + is_valid(t3) as usize
+    fn cubic_winding(s: Point, c0: Point, c1: Point, e: Point, p: Point) -> i32 {
+        // the derivative along the y-axis is a quadratic with up to two roots
+        let a = c0.y - s.y;
+        let b = c1.y - c0.y;
+        let c = e.y - c1.y;
+        let extrema = Self::square_roots(a - 2.0 * b + c, 2.0 * (b - a), a);
+
+        Self::split_winding(&extrema, p, |t| {
+            Point::new(
+                Self::cubic_bezier(s.x, c0.x, c1.x, e.x, t),
+                Self::cubic_bezier(s.y, c0.y, c1.y, e.y, t),
+            )
+        })
     }
 }
 
+// the distance from `p` to the line segment from `s` to `e`
+fn segment_distance(s: Point, e: Point, p: Point) -> f32 {
+    let line = e - s;
+    let length_squared = line.length_squared();
+
+    if length_squared == 0.0 {
+        return p.distance(s);
+    }
+
+    let t = ((p - s).dot(line) / length_squared).clamp(0.0, 1.0);
+    p.distance(s + line * t)
+}
+
 fn between(a: f32, b: f32, c: f32) -> bool {
     (a - b) * (c - b) <= 0.0
 }
@@ -856,3 +984,81 @@ impl<'a> Iterator for CurveIter<'a> {
         (remaining, Some(remaining))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contains_rect() {
+        let curve = Curve::rect(Rect::new(Point::new(0.0, 0.0), Point::new(10.0, 10.0)));
+
+        assert!(curve.contains(Point::new(5.0, 5.0), FillRule::NonZero));
+        assert!(curve.contains(Point::new(5.0, 0.0), FillRule::EvenOdd));
+        assert!(!curve.contains(Point::new(15.0, 5.0), FillRule::NonZero));
+    }
+
+    #[test]
+    fn contains_circle() {
+        let curve = Curve::circle(Point::new(10.0, 10.0), 10.0);
+
+        assert!(curve.contains(Point::new(10.0, 10.0), FillRule::NonZero));
+        assert!(curve.contains(Point::new(2.0, 10.0), FillRule::EvenOdd));
+        assert!(!curve.contains(Point::new(1.0, 1.0), FillRule::NonZero));
+    }
+
+    #[test]
+    fn contains_fill_rule() {
+        // two rects wound in the same direction, one inside the other
+        let mut curve = Curve::new();
+        curve.push_rect(Rect::new(Point::new(0.0, 0.0), Point::new(30.0, 30.0)));
+        curve.push_rect(Rect::new(Point::new(10.0, 10.0), Point::new(20.0, 20.0)));
+
+        let point = Point::new(15.0, 15.0);
+        assert!(curve.contains(point, FillRule::NonZero));
+        assert!(!curve.contains(point, FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn contains_on_vertex() {
+        // a ray through the vertex at (10, 0), shared by a line and a quad
+        let mut curve = Curve::new();
+        curve.move_to(Point::new(0.0, 10.0));
+        curve.line_to(Point::new(10.0, 0.0));
+        curve.quad_to(Point::new(10.0, -10.0), Point::new(0.0, -10.0));
+        curve.close();
+
+        assert!(curve.contains(Point::new(5.0, 0.0), FillRule::EvenOdd));
+        assert!(!curve.contains(Point::new(15.0, 0.0), FillRule::NonZero));
+
+        // and by two cubics
+        let mut curve = Curve::new();
+        curve.move_to(Point::new(0.0, 10.0));
+        curve.cubic_to(
+            Point::new(5.0, 10.0),
+            Point::new(10.0, 5.0),
+            Point::new(10.0, 0.0),
+        );
+        curve.cubic_to(
+            Point::new(10.0, -5.0),
+            Point::new(5.0, -10.0),
+            Point::new(0.0, -10.0),
+        );
+        curve.close();
+
+        assert!(curve.contains(Point::new(5.0, 0.0), FillRule::EvenOdd));
+    }
+
+    #[test]
+    fn stroke_contains() {
+        let mut curve = Curve::new();
+        curve.move_to(Point::new(0.0, 0.0));
+        curve.line_to(Point::new(100.0, 0.0));
+        curve.quad_to(Point::new(150.0, 50.0), Point::new(100.0, 100.0));
+
+        assert!(curve.stroke_contains(Point::new(50.0, 2.0), 6.0));
+        assert!(!curve.stroke_contains(Point::new(50.0, 4.0), 6.0));
+        assert!(curve.stroke_contains(Point::new(125.0, 50.0), 4.0));
+        assert!(!curve.stroke_contains(Point::new(50.0, 50.0), 4.0));
+    }
+}
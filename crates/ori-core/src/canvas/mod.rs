@@ -2,12 +2,16 @@
 
 mod border;
 mod canvas;
+mod chart;
 mod color;
 mod curve;
+mod shadow;
 mod stroke;
 
 pub use border::*;
 pub use canvas::*;
+pub use chart::*;
 pub use color::*;
 pub use curve::*;
+pub use shadow::*;
 pub use stroke::*;
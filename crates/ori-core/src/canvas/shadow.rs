@@ -0,0 +1,107 @@
+use crate::layout::Vector;
+
+use super::Color;
+
+/// A drop shadow, cast by a rectangle with rounded corners.
+///
+/// Shadows are usually not created directly, but through the elevation styles of the
+/// [`Theme`](crate::style::Theme), e.g. [`Theme::ELEVATION_2`](crate::style::Theme::ELEVATION_2).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Shadow {
+    /// The offset of the shadow.
+    pub offset: Vector,
+
+    /// The blur radius of the shadow.
+    pub blur: f32,
+
+    /// The distance the shadow is expanded by before blurring.
+    pub spread: f32,
+
+    /// The color of the shadow.
+    pub color: Color,
+}
+
+impl Shadow {
+    /// A shadow that isn't drawn.
+    pub const NONE: Self = Self {
+        offset: Vector::ZERO,
+        blur: 0.0,
+        spread: 0.0,
+        color: Color::TRANSPARENT,
+    };
+
+    /// The maximum number of layers used to approximate the blur.
+    pub const MAX_LAYERS: usize = 8;
+
+    /// Create a new [`Shadow`].
+    pub const fn new(offset: Vector, blur: f32, color: Color) -> Self {
+        Self {
+            offset,
+            blur,
+            spread: 0.0,
+            color,
+        }
+    }
+
+    /// Set the spread of the shadow.
+    pub const fn spread(mut self, spread: f32) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Returns true if the shadow isn't visible.
+    pub fn is_none(&self) -> bool {
+        self.color.is_transparent()
+    }
+
+    /// Linearly interpolate between two shadows.
+    ///
+    /// This uses a factor `t` between `0.0` and `1.0`.
+    /// Where `0.0` is `self` and `1.0` is `other`.
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        Self {
+            offset: self.offset * (1.0 - t) + other.offset * t,
+            blur: self.blur * (1.0 - t) + other.blur * t,
+            spread: self.spread * (1.0 - t) + other.spread * t,
+            color: self.color.mix_rgb(other.color, t),
+        }
+    }
+
+    /// Get the layers used to approximate the blur of the shadow.
+    ///
+    /// Each layer is the distance the shape is expanded by, and the color it is filled
+    /// with. Where all layers overlap, they add up to the color of the shadow.
+    pub fn layers(&self) -> impl Iterator<Item = (f32, Color)> + '_ {
+        let count = (self.blur.max(0.0).ceil() as usize).clamp(1, Self::MAX_LAYERS);
+        let step = self.blur.max(0.0) / count as f32;
+
+        // the alpha of each layer, such that `count` layers blend to `self.color.a`
+        let alpha = 1.0 - (1.0 - self.color.a).powf(1.0 / count as f32);
+        let color = Color::rgba(self.color.r, self.color.g, self.color.b, alpha);
+
+        (0..count).map(move |i| {
+            let expand = self.spread + (i as f32 + 0.5) * step - self.blur.max(0.0) / 2.0;
+            (expand, color)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layers_blend_to_color() {
+        let shadow = Shadow::new(Vector::ZERO, 6.0, Color::BLACK.fade(0.4));
+
+        let layers: Vec<_> = shadow.layers().collect();
+        assert_eq!(layers.len(), 6);
+
+        let alpha = layers.iter().fold(0.0, |a, (_, c)| a + c.a * (1.0 - a));
+        assert!((alpha - 0.4).abs() < 1e-4);
+
+        // the layers are centered around the edge of the shape
+        assert!((layers[0].0 + layers[5].0).abs() < 1e-4);
+    }
+}
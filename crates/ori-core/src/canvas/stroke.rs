@@ -60,6 +60,17 @@ impl Default for Stroke {
     }
 }
 
+impl Stroke {
+    /// Snap the width of the stroke to whole device pixels at `scale`, see
+    /// [`snap_width`](super::snap_width).
+    pub fn snap(self, scale: f32) -> Self {
+        Self {
+            width: super::snap_width(self.width, scale),
+            ..self
+        }
+    }
+}
+
 impl From<f32> for Stroke {
     fn from(value: f32) -> Self {
         Self {
@@ -79,7 +90,6 @@ impl Hash for Stroke {
 }
 
 impl Curve {
-    const MAX_ERROR: f32 = 0.1;
     const MAX_DEPTH: u8 = 6;
 
     const QUAD_SAMPLES: usize = 5;
@@ -93,7 +103,16 @@ impl Curve {
         self.line_to(p1 + offset);
     }
 
-    fn offset_quad_bezier(&mut self, p0: Point, p1: Point, p2: Point, offset: f32, depth: u8) {
+    #[allow(clippy::too_many_arguments)]
+    fn offset_quad_bezier(
+        &mut self,
+        p0: Point,
+        p1: Point,
+        p2: Point,
+        offset: f32,
+        tolerance: f32,
+        depth: u8,
+    ) {
         let n0 = quad_bezier_normal(p0, p1, p2, 0.0);
         let n1 = quad_bezier_normal(p0, p1, p2, 0.5);
         let n2 = quad_bezier_normal(p0, p1, p2, 1.0);
@@ -103,7 +122,7 @@ impl Curve {
         let op2 = p2 + n2 * offset;
 
         let error = Self::offset_quad_error(p0, p1, p2, op0, op1, op2, offset);
-        if error < Self::MAX_ERROR || depth >= Self::MAX_DEPTH {
+        if error < tolerance || depth >= Self::MAX_DEPTH {
             self.quad_to(op1, op2);
             return;
         }
@@ -118,8 +137,8 @@ impl Curve {
 
         let [p01, center, p12] = Self::divide_quad_bezier(p0, p1, p2, split);
 
-        self.offset_quad_bezier(p0, p01, center, offset, depth + 1);
-        self.offset_quad_bezier(center, p12, p2, offset, depth + 1);
+        self.offset_quad_bezier(p0, p01, center, offset, tolerance, depth + 1);
+        self.offset_quad_bezier(center, p12, p2, offset, tolerance, depth + 1);
     }
 
     fn offset_quad_error(
@@ -162,6 +181,7 @@ impl Curve {
         [p01, center, p12]
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn offset_cubic_bezier(
         &mut self,
         p0: Point,
@@ -169,6 +189,7 @@ impl Curve {
         p2: Point,
         p3: Point,
         offset: f32,
+        tolerance: f32,
         depth: u8,
     ) {
         let n0 = cubic_bezier_normal(p0, p1, p2, p3, 0.0);
@@ -182,15 +203,15 @@ impl Curve {
         let o3 = p3 + n3 * offset;
 
         let error = Self::offset_cubic_error(p0, p1, p2, p3, o0, o1, o2, o3, offset);
-        if error < Self::MAX_ERROR || depth >= Self::MAX_DEPTH {
+        if error < tolerance || depth >= Self::MAX_DEPTH {
             self.cubic_to(o1, o2, o3);
             return;
         }
 
         let [p01, p012, center, p123, p23] = Self::divide_cubic_bezier(p0, p1, p2, p3, 0.5);
 
-        self.offset_cubic_bezier(p0, p01, p012, center, offset, depth + 1);
-        self.offset_cubic_bezier(center, p123, p23, p3, offset, depth + 1);
+        self.offset_cubic_bezier(p0, p01, p012, center, offset, tolerance, depth + 1);
+        self.offset_cubic_bezier(center, p123, p23, p3, offset, tolerance, depth + 1);
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -274,6 +295,7 @@ impl Curve {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn stroke_join(
         &mut self,
         pivot: Point,
@@ -282,6 +304,7 @@ impl Curve {
         r: f32,
         join: StrokeJoin,
         miter_limit: f32,
+        tolerance: f32,
     ) {
         if n0.cross(n1) * r > 0.0 {
             self.line_to(pivot + n1 * r);
@@ -290,7 +313,7 @@ impl Curve {
 
         match join {
             StrokeJoin::Miter => self.stroke_miter(pivot, n0, n1, r, miter_limit),
-            StrokeJoin::Round => self.stroke_round(pivot, n0, n1, r),
+            StrokeJoin::Round => self.stroke_round(pivot, n0, n1, r, tolerance),
             StrokeJoin::Bevel => self.stroke_bevel(pivot, n1, r),
         }
     }
@@ -319,7 +342,7 @@ impl Curve {
         self.line_to(p1);
     }
 
-    fn stroke_round(&mut self, pivot: Point, n0: Vector, n1: Vector, r: f32) {
+    fn stroke_round(&mut self, pivot: Point, n0: Vector, n1: Vector, r: f32, tolerance: f32) {
         let p1 = pivot + n1 * r;
 
         let nmid = Vector::normalize(n0 + n1);
@@ -333,8 +356,8 @@ impl Curve {
         let c0 = pivot + Vector::normalize(n0 + nmid) * r * inv_cos_theta_over_2;
         let c1 = pivot + Vector::normalize(n1 + nmid) * r * inv_cos_theta_over_2;
 
-        self.conic_to(c0, mid, cos_theta_over_2);
-        self.conic_to(c1, p1, cos_theta_over_2);
+        self.conic_to_with_tolerance(c0, mid, cos_theta_over_2, tolerance);
+        self.conic_to_with_tolerance(c1, p1, cos_theta_over_2, tolerance);
     }
 
     fn stroke_bevel(&mut self, pivot: Point, n1: Vector, r: f32) {
@@ -352,14 +375,15 @@ impl Curve {
         n0: Option<Vector>,
         r: f32,
         stroke: Stroke,
+        tolerance: f32,
         first: &mut Option<(Point, Vector)>,
     ) -> (Point, Vector) {
         let n1 = line_normal(p0, p1);
 
         match n0 {
             Some(n0) => {
-                self.stroke_join(p0, n0, n1, r, stroke.join, stroke.miter);
-                outside.stroke_join(p0, n0, n1, -r, stroke.join, stroke.miter);
+                self.stroke_join(p0, n0, n1, r, stroke.join, stroke.miter, tolerance);
+                outside.stroke_join(p0, n0, n1, -r, stroke.join, stroke.miter, tolerance);
             }
             None => {
                 self.move_to(p0 + n1 * r);
@@ -374,7 +398,7 @@ impl Curve {
         (p1, n1)
     }
 
-    pub(super) fn stroke_impl(&mut self, curve: &Curve, stroke: Stroke) {
+    pub(super) fn stroke_impl(&mut self, curve: &Curve, stroke: Stroke, tolerance: f32) {
         if stroke.width <= 0.0 {
             return;
         }
@@ -403,7 +427,8 @@ impl Curve {
                     first = None;
                 }
                 CurveSegment::Line(p1) => {
-                    let (p1, n1) = self.push_line(&mut outside, p0, p1, n0, r, stroke, &mut first);
+                    let (p1, n1) =
+                        self.push_line(&mut outside, p0, p1, n0, r, stroke, tolerance, &mut first);
                     n0 = Some(n1);
                     p0 = p1;
                 }
@@ -412,8 +437,16 @@ impl Curve {
 
                     match n0 {
                         Some(n0) => {
-                            self.stroke_join(p0, n0, n1, r, stroke.join, stroke.miter);
-                            outside.stroke_join(p0, n0, n1, -r, stroke.join, stroke.miter);
+                            self.stroke_join(p0, n0, n1, r, stroke.join, stroke.miter, tolerance);
+                            outside.stroke_join(
+                                p0,
+                                n0,
+                                n1,
+                                -r,
+                                stroke.join,
+                                stroke.miter,
+                                tolerance,
+                            );
                         }
                         None => {
                             self.move_to(p0 + n1 * r);
@@ -422,8 +455,8 @@ impl Curve {
                         }
                     }
 
-                    self.offset_quad_bezier(p0, p1, p2, r, 0);
-                    outside.offset_quad_bezier(p0, p1, p2, -r, 0);
+                    self.offset_quad_bezier(p0, p1, p2, r, tolerance, 0);
+                    outside.offset_quad_bezier(p0, p1, p2, -r, tolerance, 0);
 
                     n0 = Some(quad_bezier_normal(p0, p1, p2, 1.0));
                     p0 = p2;
@@ -433,8 +466,16 @@ impl Curve {
 
                     match n0 {
                         Some(n0) => {
-                            self.stroke_join(p0, n0, n1, r, stroke.join, stroke.miter);
-                            outside.stroke_join(p0, n0, n1, -r, stroke.join, stroke.miter);
+                            self.stroke_join(p0, n0, n1, r, stroke.join, stroke.miter, tolerance);
+                            outside.stroke_join(
+                                p0,
+                                n0,
+                                n1,
+                                -r,
+                                stroke.join,
+                                stroke.miter,
+                                tolerance,
+                            );
                         }
                         None => {
                             self.move_to(p0 + n1 * r);
@@ -443,17 +484,18 @@ impl Curve {
                         }
                     }
 
-                    self.offset_cubic_bezier(p0, p1, p2, p3, r, 0);
-                    outside.offset_cubic_bezier(p0, p1, p2, p3, -r, 0);
+                    self.offset_cubic_bezier(p0, p1, p2, p3, r, tolerance, 0);
+                    outside.offset_cubic_bezier(p0, p1, p2, p3, -r, tolerance, 0);
 
                     n0 = Some(cubic_bezier_normal(p0, p1, p2, p3, 1.0));
                     p0 = p3;
                 }
                 CurveSegment::Close => {
                     let (pf, nf) = first.unwrap_or((p0, n0.unwrap()));
-                    let (p1, n1) = self.push_line(&mut outside, p0, pf, n0, r, stroke, &mut first);
-                    self.stroke_join(p1, n1, nf, r, stroke.join, stroke.miter);
-                    outside.stroke_join(p1, n1, nf, -r, stroke.join, stroke.miter);
+                    let (p1, n1) =
+                        self.push_line(&mut outside, p0, pf, n0, r, stroke, tolerance, &mut first);
+                    self.stroke_join(p1, n1, nf, r, stroke.join, stroke.miter, tolerance);
+                    outside.stroke_join(p1, n1, nf, -r, stroke.join, stroke.miter, tolerance);
 
                     self.close();
 
@@ -5,6 +5,7 @@ use std::{
     cell::UnsafeCell,
     fmt::Debug,
     future::Future,
+    marker::PhantomData,
     mem::ManuallyDrop,
     pin::Pin,
     sync::Arc,
@@ -128,6 +129,11 @@ impl CommandProxy {
         self.wake();
     }
 
+    /// Get a typed [`EventProxy`] for sending messages of type `M`.
+    pub fn event_proxy<M: Any + Send>(&self) -> EventProxy<M> {
+        EventProxy::new(self.clone())
+    }
+
     /// Spawn a future that is polled when commands are handled.
     pub fn spawn_async(&self, future: impl Future<Output = ()> + Send + 'static) {
         let task = Arc::new(CommandTask::new(self, future));
@@ -155,6 +161,71 @@ impl Debug for CommandProxy {
     }
 }
 
+/// A typed channel for sending messages of type `M` to the user interface.
+///
+/// This is useful for waking the user interface from another thread, e.g. when a
+/// network request completes or a watched file changes. Messages are sent as commands,
+/// and are received on the UI thread with [`Event::cmd`](crate::event::Event::cmd), or
+/// with a message handler on the application builder.
+///
+/// # Example
+/// ```
+/// # use ori_core::command::{CommandProxy, CommandWaker};
+/// struct Progress(f32);
+///
+/// let (proxy, receiver) = CommandProxy::new(CommandWaker::new(|| {}));
+/// let progress = proxy.event_proxy::<Progress>();
+///
+/// std::thread::spawn(move || progress.send(Progress(0.5)))
+///     .join()
+///     .unwrap();
+///
+/// let command = receiver.try_recv().unwrap();
+/// assert_eq!(command.get::<Progress>().map(|p| p.0), Some(0.5));
+/// ```
+pub struct EventProxy<M> {
+    proxy: CommandProxy,
+    marker: PhantomData<fn(M)>,
+}
+
+impl<M: Any + Send> EventProxy<M> {
+    /// Create a new [`EventProxy`] sending messages through `proxy`.
+    pub fn new(proxy: CommandProxy) -> Self {
+        Self {
+            proxy,
+            marker: PhantomData,
+        }
+    }
+
+    /// Send a message, waking the event loop.
+    pub fn send(&self, message: M) {
+        self.proxy.cmd(message);
+    }
+
+    /// Get the underlying [`CommandProxy`].
+    pub fn proxy(&self) -> &CommandProxy {
+        &self.proxy
+    }
+}
+
+impl<M> Clone for EventProxy<M> {
+    fn clone(&self) -> Self {
+        Self {
+            proxy: self.proxy.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<M> Debug for EventProxy<M> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = std::any::type_name::<M>();
+        f.debug_struct("EventProxy")
+            .field("message", &message)
+            .finish()
+    }
+}
+
 /// A receiver for [`Command`]s.
 pub struct CommandReceiver {
     rx: Receiver<Command>,
@@ -1,12 +1,19 @@
 use std::{
     any::{self, Any},
     future::Future,
+    sync::Weak,
+    time::Duration,
 };
 
 use crate::{
     clipboard::Clipboard,
-    command::{Command, CommandProxy},
+    command::{Command, CommandProxy, EventProxy},
+    event::{OpenUrl, TimerId, TimerRequest, Timers},
+    layout::{Affine, Vector},
     text::Fonts,
+    transition::ReducedMotion,
+    view::PixelSnap,
+    window::{Monitors, Window},
 };
 
 use super::Contexts;
@@ -33,16 +40,76 @@ impl<'a> BaseCx<'a> {
         self.context_or_default::<Clipboard>()
     }
 
+    /// Get the [`Monitors`] connected to the system.
+    ///
+    /// This is empty until the platform has reported the monitors.
+    pub fn monitors(&mut self) -> &Monitors {
+        self.context_or_default::<Monitors>()
+    }
+
+    /// Get whether the user prefers reduced motion, see [`ReducedMotion`].
+    pub fn reduced_motion(&self) -> bool {
+        self.get_context::<ReducedMotion>()
+            .is_some_and(|motion| motion.0)
+    }
+
+    /// Get the time to step transitions by, where `dt` is the time since the last frame.
+    ///
+    /// When the user prefers reduced motion, this is long enough for any transition to
+    /// complete instantly.
+    pub fn transition_delta(&self, dt: f32) -> f32 {
+        match self.reduced_motion() {
+            true => f32::INFINITY,
+            false => dt,
+        }
+    }
+
+    /// Get whether views are snapped to device pixels, see [`PixelSnap`].
+    pub fn pixel_snap(&self) -> bool {
+        self.get_context::<PixelSnap>().is_some_and(|snap| snap.0)
+    }
+
+    /// Snap the `transform` of a view inside `parent` to device pixels when enabled, see
+    /// [`Affine::pixel_snap`].
+    pub(crate) fn snap_transform(
+        &self,
+        parent: Affine,
+        offset: Vector,
+        transform: Affine,
+    ) -> (Affine, Vector) {
+        if !self.pixel_snap() {
+            return (transform, Vector::ZERO);
+        }
+
+        let scale = self
+            .get_context::<Window>()
+            .map_or(1.0, |window| window.scale);
+        transform.pixel_snap(parent, offset, scale)
+    }
+
     /// Get the [`CommandProxy`].
     pub fn proxy(&self) -> CommandProxy {
         self.proxy.clone()
     }
 
+    /// Get a typed [`EventProxy`] for sending messages of type `M`.
+    pub fn event_proxy<M: Any + Send>(&self) -> EventProxy<M> {
+        self.proxy.event_proxy()
+    }
+
     /// Emit a command.
     pub fn cmd<T: Any + Send>(&mut self, command: T) {
         self.proxy.cmd_silent(Command::new(command));
     }
 
+    /// Open a URL with the default handler of the platform, e.g. a browser.
+    ///
+    /// On Linux this requires the `open-url` feature, and only `http`, `https` and `mailto`
+    /// URLs are opened.
+    pub fn open_url(&mut self, url: impl Into<String>) {
+        self.cmd(OpenUrl(url.into()));
+    }
+
     /// Spawn a future.
     pub fn spawn_async(&mut self, future: impl Future<Output = ()> + Send + 'static) {
         self.proxy.spawn_async(future);
@@ -53,6 +120,49 @@ impl<'a> BaseCx<'a> {
         self.proxy.cmd_async(future);
     }
 
+    /// Start a timer, sending [`Event::Timer`] once after `duration`.
+    ///
+    /// Timers are driven by the event loop, which sleeps until the next timer is due.
+    /// Timers started by views are stopped when the view is dropped.
+    ///
+    /// [`Event::Timer`]: crate::event::Event::Timer
+    pub fn set_timeout(&mut self, duration: Duration) -> TimerId {
+        self.start_timer(duration, false, None)
+    }
+
+    /// Start a timer, sending [`Event::Timer`] every `duration`, until it is cleared
+    /// with [`BaseCx::clear_timer`].
+    ///
+    /// [`Event::Timer`]: crate::event::Event::Timer
+    pub fn set_interval(&mut self, duration: Duration) -> TimerId {
+        self.start_timer(duration, true, None)
+    }
+
+    /// Stop a timer, started with either [`BaseCx::set_timeout`] or [`BaseCx::set_interval`].
+    pub fn clear_timer(&mut self, id: TimerId) {
+        let timers = self.context_or_default::<Timers>();
+        timers.request(TimerRequest::Stop(id));
+    }
+
+    pub(crate) fn start_timer(
+        &mut self,
+        duration: Duration,
+        repeat: bool,
+        owner: Option<Weak<()>>,
+    ) -> TimerId {
+        let id = TimerId::new();
+
+        let timers = self.context_or_default::<Timers>();
+        timers.request(TimerRequest::Start {
+            id,
+            duration,
+            repeat,
+            owner,
+        });
+
+        id
+    }
+
     /// Get a reference to the [`Contexts`].
     pub fn contexts(&self) -> &Contexts {
         self.contexts
@@ -1,8 +1,11 @@
 use std::ops::{Deref, DerefMut};
 
 use crate::{
-    canvas::{BorderRadius, BorderWidth, Canvas, Color, Curve, FillRule, Mask, Paint, Stroke},
+    canvas::{
+        BorderRadius, BorderWidth, Canvas, Color, Curve, FillRule, Mask, Paint, Shadow, Stroke,
+    },
     layout::{Affine, Point, Rect, Size, Vector},
+    style::Theme,
     text::{Fonts, TextBuffer},
     view::ViewState,
 };
@@ -66,6 +69,25 @@ impl<'a, 'b> DrawCx<'a, 'b> {
         self.visible.intersects(rect)
     }
 
+    /// Get the fraction of a rect that is visible, from `0.0` to `1.0`.
+    ///
+    /// This is the part of the rect inside the window and every mask it's drawn in, e.g. the
+    /// viewport of a scroll view.
+    pub fn visible_fraction(&self, rect: Rect) -> f32 {
+        let window = Rect::min_size(Point::ZERO, self.window().size);
+        let visible = (self.visible)
+            .intersection(window.transform(self.transform.inverse()))
+            .intersection(rect);
+
+        if rect.area() > 0.0 {
+            visible.area() / rect.area()
+        } else if self.is_visible(rect) {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
     /// Get the transform of the view.
     pub fn transform(&self) -> Affine {
         self.transform
@@ -114,8 +136,10 @@ impl<'a, 'b> DrawCx<'a, 'b> {
     }
 
     /// Stroke a curve.
+    ///
+    /// The width of the stroke is snapped to whole device pixels, see [`Stroke::snap`].
     pub fn stroke(&mut self, curve: Curve, stroke: impl Into<Stroke>, paint: impl Into<Paint>) {
-        let stroke = stroke.into();
+        let stroke = stroke.into().snap(self.window().scale);
 
         if !self.is_visible(curve.bounds().expand(stroke.width * 2.0)) {
             return;
@@ -124,6 +148,15 @@ impl<'a, 'b> DrawCx<'a, 'b> {
         self.canvas.stroke(curve, stroke, paint.into());
     }
 
+    /// Blur what was drawn before, within a curve, see [`Canvas::backdrop_blur`].
+    pub fn backdrop_blur(&mut self, curve: Curve, radius: f32) {
+        if radius <= 0.0 || !self.is_visible(curve.bounds().expand(radius * 3.0)) {
+            return;
+        }
+
+        self.canvas.backdrop_blur(curve, radius);
+    }
+
     /// Draw a text buffer.
     pub fn text(&mut self, buffer: &TextBuffer, color: Color, offset: Vector) {
         self.text_raw(buffer.raw(), color, offset);
@@ -140,6 +173,8 @@ impl<'a, 'b> DrawCx<'a, 'b> {
     }
 
     /// Draw a rectangle with rounded corners and a border.
+    ///
+    /// The border width is snapped to whole device pixels, see [`BorderWidth::snap`].
     pub fn quad(
         &mut self,
         rect: Rect,
@@ -149,7 +184,7 @@ impl<'a, 'b> DrawCx<'a, 'b> {
         border_paint: impl Into<Paint>,
     ) {
         let radius = border_radius.into();
-        let width = border_width.into();
+        let width = border_width.into().snap(self.window().scale);
         let rect = rect.round();
 
         let mut curve = Curve::new();
@@ -163,6 +198,69 @@ impl<'a, 'b> DrawCx<'a, 'b> {
         self.fill(curve, FillRule::NonZero, border_paint);
     }
 
+    /// Draw the drop shadow of a rectangle with rounded corners.
+    ///
+    /// The blur is approximated by drawing a few translucent layers, see [`Shadow::layers`].
+    pub fn shadow(
+        &mut self,
+        rect: Rect,
+        border_radius: impl Into<BorderRadius>,
+        shadow: impl Into<Shadow>,
+    ) {
+        let radius = border_radius.into();
+        let shadow = shadow.into();
+
+        if shadow.is_none() {
+            return;
+        }
+
+        let rect = rect.round() + shadow.offset;
+
+        for (expand, color) in shadow.layers() {
+            let layer = rect.expand(expand);
+
+            if layer.width() <= 0.0 || layer.height() <= 0.0 {
+                continue;
+            }
+
+            let radius = BorderRadius::new(
+                (radius.top_left + expand).max(0.0),
+                (radius.top_right + expand).max(0.0),
+                (radius.bottom_right + expand).max(0.0),
+                (radius.bottom_left + expand).max(0.0),
+            );
+
+            let mut curve = Curve::new();
+            curve.push_rect_with_radius(layer, radius);
+
+            self.fill(curve, FillRule::NonZero, color);
+        }
+    }
+
+    /// Draw a focus ring around a rectangle with rounded corners.
+    ///
+    /// This should be called by focusable views when they are focused. The ring is styled by
+    /// [`Theme::FOCUS_RING`] and [`Theme::FOCUS_RING_WIDTH`], and isn't drawn when the
+    /// window hides focus rings, see [`Window::is_focus_visible`].
+    ///
+    /// [`Window::is_focus_visible`]: crate::window::Window::is_focus_visible
+    pub fn draw_focus_ring(&mut self, rect: Rect, border_radius: impl Into<BorderRadius>) {
+        if !self.window().is_focus_visible() {
+            return;
+        }
+
+        let width = self.styles().get_or(2.0, Theme::FOCUS_RING_WIDTH);
+        let color = self.styles().get_or(Color::BLUE, Theme::FOCUS_RING);
+
+        self.quad(
+            rect.expand(width),
+            Color::TRANSPARENT,
+            border_radius.into().expand(width),
+            BorderWidth::all(width),
+            color,
+        );
+    }
+
     /// Draw a canvas.
     pub fn draw_canvas(&mut self, canvas: Canvas) {
         self.canvas.draw_canvas(canvas);
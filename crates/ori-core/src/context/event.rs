@@ -92,10 +92,27 @@ impl<'a, 'b> EventCx<'a, 'b> {
     }
 
     /// Request a rebuild of the view tree.
+    ///
+    /// Rebuilds are batched, requesting several rebuilds while handling a single event
+    /// will only rebuild the view tree once, after the event has been handled.
     pub fn rebuild(&mut self) {
         *self.rebuild = true;
     }
 
+    /// Set `target` to `value`, requesting a rebuild only if the value changed.
+    ///
+    /// Returns `true` if the value changed.
+    pub fn set_if_changed<U: PartialEq>(&mut self, target: &mut U, value: U) -> bool {
+        if *target == value {
+            return false;
+        }
+
+        *target = value;
+        self.rebuild();
+
+        true
+    }
+
     /// Get whether the view was hovered last call.
     pub fn was_hovered(&self) -> bool {
         self.view_state.prev_flags.contains(ViewFlags::HOVERED)
@@ -16,9 +16,12 @@ pub use event::*;
 pub use layout::*;
 pub use rebuild::*;
 
+use std::time::Duration;
+
 use crate::{
-    event::{Ime, RequestFocus},
-    style::Styles,
+    event::{Ime, RequestFocus, TimerId},
+    layout::Direction,
+    style::{Styles, Theme},
     view::{ViewId, ViewState},
     window::{Cursor, Window},
 };
@@ -51,6 +54,11 @@ impl_context! {BuildCx<'_, '_>, RebuildCx<'_, '_>, EventCx<'_, '_>, LayoutCx<'_,
         self.context()
     }
 
+    /// Get the [`Direction`] of the user interface, see [`Theme::DIRECTION`].
+    pub fn direction(&self) -> Direction {
+        self.styles().get(Theme::DIRECTION).unwrap_or_default()
+    }
+
     /// Get the id of the view.
     pub fn id(&self) -> ViewId {
         self.view_state.id()
@@ -140,20 +148,53 @@ impl_context! {BuildCx<'_, '_>, RebuildCx<'_, '_>, EventCx<'_, '_> {
     }
 
     /// Request a layout of the view tree.
+    #[track_caller]
     pub fn layout(&mut self) {
+        #[cfg(feature = "debug")]
+        crate::debug::record_request(self, crate::debug::UpdateRequest::Layout);
+
         self.view_state.request_layout();
     }
 
     /// Request a draw of the view tree.
+    #[track_caller]
     pub fn draw(&mut self) {
+        #[cfg(feature = "debug")]
+        crate::debug::record_request(self, crate::debug::UpdateRequest::Draw);
+
         self.view_state.request_draw();
     }
 
     /// Request an animation frame.
+    #[track_caller]
     pub fn animate(&mut self) {
+        #[cfg(feature = "debug")]
+        crate::debug::record_request(self, crate::debug::UpdateRequest::Animate);
+
         self.view_state.request_animate();
     }
 
+    /// Start a timer, sending [`Event::Timer`] once after `duration`.
+    ///
+    /// The timer is stopped when the view is dropped, see [`BaseCx::set_timeout`].
+    ///
+    /// [`Event::Timer`]: crate::event::Event::Timer
+    pub fn set_timeout(&mut self, duration: Duration) -> TimerId {
+        let owner = self.view_state.alive();
+        self.base.start_timer(duration, false, Some(owner))
+    }
+
+    /// Start a timer, sending [`Event::Timer`] every `duration`, until it is cleared
+    /// with [`BaseCx::clear_timer`].
+    ///
+    /// The timer is stopped when the view is dropped, see [`BaseCx::set_interval`].
+    ///
+    /// [`Event::Timer`]: crate::event::Event::Timer
+    pub fn set_interval(&mut self, duration: Duration) -> TimerId {
+        let owner = self.view_state.alive();
+        self.base.start_timer(duration, true, Some(owner))
+    }
+
     /// Request focus for the view.
     pub fn focus(&mut self) {
         let cmd = RequestFocus(self.window().id(), self.id());
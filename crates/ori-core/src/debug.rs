@@ -0,0 +1,581 @@
+//! Tools for debugging views, enabled with the `debug` feature.
+//!
+//! - [`ViewStats`] records how many times every [`Pod`](crate::view::Pod) was rebuilt,
+//!   laid out and drawn during a frame.
+//! - [`Inspector`] outlines the bounds of every view, and shows the type and size of the
+//!   hovered view.
+//! - [`RedrawLog`] records where layouts, draws and animation frames were requested from.
+//! - [`EasingPreview`](crate::views::EasingPreview) plots an easing, for tuning transitions.
+//!
+//! They are enabled by inserting them into the [`Contexts`](crate::context::Contexts).
+
+use std::{any, collections::HashMap, panic::Location};
+
+use crate::{
+    canvas::{Canvas, Color, Curve},
+    context::{BaseCx, DrawCx},
+    event::{Code, Shortcut},
+    layout::{Padding, Point, Rect, Size, Vector},
+    text::{TextAttributes, TextBuffer},
+    view::ViewId,
+};
+
+/// A pass over the view tree.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ViewPass {
+    /// [`View::rebuild`](crate::view::View::rebuild).
+    Rebuild,
+
+    /// [`View::layout`](crate::view::View::layout).
+    Layout,
+
+    /// [`View::draw`](crate::view::View::draw).
+    Draw,
+}
+
+/// The number of passes a single view ran during a frame.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ViewCounts {
+    /// The type name of the view, without module paths.
+    pub name: String,
+
+    /// The number of times the view was rebuilt.
+    pub rebuilds: u32,
+
+    /// The number of times the view was laid out.
+    pub layouts: u32,
+
+    /// The number of times the view was drawn, not counting cached draws.
+    pub draws: u32,
+
+    /// The rect of the view in window space, if it was drawn.
+    pub rect: Option<Rect>,
+}
+
+impl ViewCounts {
+    /// Get the total number of passes.
+    pub fn total(&self) -> u32 {
+        self.rebuilds + self.layouts + self.draws
+    }
+}
+
+/// Per frame statistics of how many times each view ran each pass.
+///
+/// The application clears the statistics after drawing each window, optionally drawing
+/// a heatmap over the window and logging the views that did the most work.
+#[derive(Clone, Debug, Default)]
+pub struct ViewStats {
+    views: HashMap<ViewId, ViewCounts>,
+
+    /// Whether to draw a heatmap of the drawn views over the window.
+    pub heatmap: bool,
+
+    /// The number of views to log at the end of every frame.
+    pub log_top: usize,
+}
+
+impl ViewStats {
+    /// The number of passes at which the heatmap is fully red.
+    const HEATMAP_MAX: f32 = 8.0;
+
+    /// Create a new [`ViewStats`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether to draw a heatmap.
+    pub fn heatmap(mut self, heatmap: bool) -> Self {
+        self.heatmap = heatmap;
+        self
+    }
+
+    /// Set the number of views to log at the end of every frame.
+    pub fn log_top(mut self, count: usize) -> Self {
+        self.log_top = count;
+        self
+    }
+
+    /// Record a pass of a view.
+    pub fn record(&mut self, id: ViewId, name: &str, pass: ViewPass) {
+        let counts = self.views.entry(id).or_insert_with(|| ViewCounts {
+            name: short_type_name(name),
+            ..Default::default()
+        });
+
+        match pass {
+            ViewPass::Rebuild => counts.rebuilds += 1,
+            ViewPass::Layout => counts.layouts += 1,
+            ViewPass::Draw => counts.draws += 1,
+        }
+    }
+
+    /// Record the rect of a view in window space.
+    pub fn record_rect(&mut self, id: ViewId, rect: Rect) {
+        if let Some(counts) = self.views.get_mut(&id) {
+            counts.rect = Some(rect);
+        }
+    }
+
+    /// Get the counts of a view.
+    pub fn get(&self, id: ViewId) -> Option<&ViewCounts> {
+        self.views.get(&id)
+    }
+
+    /// Get an iterator over the counts of all views that ran a pass.
+    pub fn iter(&self) -> impl Iterator<Item = (ViewId, &ViewCounts)> {
+        self.views.iter().map(|(id, counts)| (*id, counts))
+    }
+
+    /// Get the `count` views with the most passes, most passes first.
+    pub fn top(&self, count: usize) -> Vec<(ViewId, &ViewCounts)> {
+        let mut views = self.iter().collect::<Vec<_>>();
+        views.sort_by_key(|(_, counts)| u32::MAX - counts.total());
+        views.truncate(count);
+        views
+    }
+
+    /// Clear the statistics, this is done at the end of every frame.
+    pub fn clear(&mut self) {
+        self.views.clear();
+    }
+
+    /// Log the views with the most passes, see [`Self::log_top`].
+    pub fn log(&self) {
+        if self.log_top == 0 || self.views.is_empty() {
+            return;
+        }
+
+        tracing::debug!(views = self.views.len(), "View passes this frame");
+
+        for (id, counts) in self.top(self.log_top) {
+            tracing::debug!(
+                view = ?id,
+                rebuilds = counts.rebuilds,
+                layouts = counts.layouts,
+                draws = counts.draws,
+                "{}",
+                counts.name,
+            );
+        }
+    }
+
+    /// Draw a heatmap of the drawn views, going from green to red as the number of
+    /// passes increases.
+    pub fn draw_heatmap(&self, canvas: &mut Canvas) {
+        canvas.overlay(i32::MAX, |canvas| {
+            for counts in self.views.values() {
+                let Some(rect) = counts.rect else {
+                    continue;
+                };
+
+                let heat = f32::min(counts.total() as f32 / Self::HEATMAP_MAX, 1.0);
+                let color = Color::rgba(heat, 1.0 - heat, 0.0, 0.1 + heat * 0.2);
+                canvas.rect(rect, color);
+            }
+        });
+    }
+}
+
+/// A view recorded by the [`Inspector`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct InspectedView {
+    /// The id of the view.
+    pub id: ViewId,
+
+    /// The type name of the view, without module paths.
+    pub name: String,
+
+    /// The rect of the view in window space.
+    pub rect: Rect,
+
+    /// The depth of the view in the tree of pods.
+    pub depth: usize,
+
+    /// The padding of the view, set by views like [`Pad`](crate::views::Pad).
+    pub padding: Padding,
+
+    /// The margin of the view, which is the padding of its parent.
+    pub margin: Padding,
+}
+
+/// An overlay outlining the bounds of every view, toggled with a [`Shortcut`].
+///
+/// The bounds are outlined in a color depending on their depth, padding in green and margins
+/// in orange.
+///
+/// While enabled, every visible [`Pod`](crate::view::Pod) records itself while drawing,
+/// in draw order, and [`Inspector::views`] can be used to walk the drawn tree. Cached
+/// drawing is disabled while the inspector is enabled, so that every view is visited.
+#[derive(Clone, Debug)]
+pub struct Inspector {
+    /// Whether the inspector is enabled.
+    pub enabled: bool,
+
+    /// The shortcut that toggles the inspector, `F12` by default.
+    pub shortcut: Shortcut,
+
+    views: Vec<InspectedView>,
+    // the indices of the views currently being drawn
+    stack: Vec<usize>,
+}
+
+impl Default for Inspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Inspector {
+    /// Create a new disabled [`Inspector`].
+    pub fn new() -> Self {
+        Self {
+            enabled: false,
+            shortcut: Shortcut::new(Code::F12),
+            views: Vec::new(),
+            stack: Vec::new(),
+        }
+    }
+
+    /// Set whether the inspector is enabled.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+
+    /// Set the shortcut that toggles the inspector.
+    pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.shortcut = shortcut;
+        self
+    }
+
+    /// Toggle the inspector.
+    pub fn toggle(&mut self) {
+        self.enabled = !self.enabled;
+        self.clear();
+    }
+
+    /// Get the views recorded during the last draw, in draw order.
+    pub fn views(&self) -> &[InspectedView] {
+        &self.views
+    }
+
+    /// Get the deepest view containing `point`, preferring views drawn later.
+    pub fn view_at(&self, point: Point) -> Option<&InspectedView> {
+        (self.views.iter())
+            .filter(|view| view.rect.contains(point))
+            .max_by_key(|view| view.depth)
+    }
+
+    /// Clear the recorded views, this is done before every draw.
+    pub fn clear(&mut self) {
+        self.views.clear();
+        self.stack.clear();
+    }
+
+    fn enter(&mut self, id: ViewId, name: &str, rect: Rect) {
+        let margin = match self.stack.last() {
+            Some(&parent) => self.views[parent].padding,
+            None => Padding::default(),
+        };
+
+        self.stack.push(self.views.len());
+        self.views.push(InspectedView {
+            id,
+            name: short_type_name(name),
+            rect,
+            depth: self.stack.len() - 1,
+            padding: Padding::default(),
+            margin,
+        });
+    }
+
+    fn exit(&mut self) {
+        self.stack.pop();
+    }
+
+    fn set_padding(&mut self, padding: Padding) {
+        if let Some(&index) = self.stack.last() {
+            self.views[index].padding = padding;
+        }
+    }
+
+    /// Draw the outlines of the recorded views, and a label for the hovered one.
+    pub fn draw(&self, cx: &mut DrawCx) {
+        let pointer = cx
+            .window()
+            .pointers()
+            .first()
+            .map(|pointer| pointer.position);
+        let hovered = pointer.and_then(|pointer| self.view_at(pointer));
+
+        cx.overlay(i32::MAX, |cx| {
+            for view in &self.views {
+                let hue = (view.depth as f32 * 40.0) % 360.0;
+                let color = Color::hsla(hue, 0.8, 0.5, 0.6);
+                cx.stroke(Curve::rect(view.rect), 1.0, color);
+
+                if view.padding != Padding::default() {
+                    let rect = inset(view.rect, view.padding, 1.0);
+                    cx.stroke(Curve::rect(rect), 1.0, Color::rgba(0.2, 0.8, 0.3, 0.8));
+                }
+
+                if view.margin != Padding::default() {
+                    let rect = inset(view.rect, view.margin, -1.0);
+                    cx.stroke(Curve::rect(rect), 1.0, Color::rgba(1.0, 0.6, 0.1, 0.8));
+                }
+            }
+
+            let Some(view) = hovered else {
+                return;
+            };
+
+            cx.fill_rect(view.rect, Color::rgba(0.2, 0.5, 1.0, 0.2));
+
+            let size = view.rect.size();
+            let label = format!("{} {}x{}", view.name, size.width, size.height);
+
+            let mut buffer = TextBuffer::new(cx.fonts(), 12.0, 1.2);
+            buffer.set_text(cx.fonts(), &label, TextAttributes::default());
+
+            // place the label above the view, or below it if there is no room
+            let label_size = buffer.size() + Size::all(8.0);
+            let window = cx.window().size;
+
+            let y = match view.rect.min.y >= label_size.height {
+                true => view.rect.min.y - label_size.height,
+                false => view.rect.max.y,
+            };
+            let x = f32::min(view.rect.min.x, window.width - label_size.width).max(0.0);
+
+            let label_rect = Rect::min_size(Point::new(x, y), label_size);
+            cx.fill_rect(label_rect, Color::rgba(0.0, 0.0, 0.0, 0.8));
+            cx.text(&buffer, Color::WHITE, Vector::new(x + 4.0, y + 4.0));
+        });
+    }
+}
+
+/// A request for the view tree to be updated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateRequest {
+    /// A layout was requested with `cx.layout()`.
+    Layout,
+
+    /// A draw was requested with `cx.draw()`.
+    Draw,
+
+    /// An animation frame was requested with `cx.animate()`.
+    Animate,
+}
+
+/// A log of where updates of the view tree were requested from during a frame.
+///
+/// Every call to `cx.layout()`, `cx.draw()` and `cx.animate()` is recorded with the
+/// location of the caller, and the application logs the requests at the end of every
+/// frame. This is useful for finding what keeps redrawing the window, for example an
+/// animation that never stops.
+#[derive(Clone, Debug, Default)]
+pub struct RedrawLog {
+    requests: HashMap<(UpdateRequest, &'static Location<'static>), u32>,
+}
+
+impl RedrawLog {
+    /// Create a new [`RedrawLog`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a request.
+    pub fn record(&mut self, request: UpdateRequest, location: &'static Location<'static>) {
+        *self.requests.entry((request, location)).or_default() += 1;
+    }
+
+    /// Get the requests, with the number of times they were made, most made first.
+    pub fn requests(&self) -> Vec<(UpdateRequest, &'static Location<'static>, u32)> {
+        let mut requests = (self.requests.iter())
+            .map(|(&(request, location), &count)| (request, location, count))
+            .collect::<Vec<_>>();
+
+        requests.sort_by_key(|&(_, location, count)| (u32::MAX - count, location));
+        requests
+    }
+
+    /// Clear the log, this is done at the end of every frame.
+    pub fn clear(&mut self) {
+        self.requests.clear();
+    }
+
+    /// Log the requests.
+    pub fn log(&self) {
+        for (request, location, count) in self.requests() {
+            tracing::debug!(?request, count, "Update requested at {}", location);
+        }
+    }
+}
+
+// shrink a rect by a padding, or grow it when `sign` is negative
+fn inset(rect: Rect, padding: Padding, sign: f32) -> Rect {
+    let min = Vector::new(padding.left, padding.top) * sign;
+    let max = Vector::new(padding.right, padding.bottom) * sign;
+    Rect::new(rect.min + min, rect.max - max)
+}
+
+// strip the module paths from a type name, `ori_core::views::Pad<ori_core::views::Text>`
+// becomes `Pad<Text>`
+fn short_type_name(name: &str) -> String {
+    let mut short = String::with_capacity(name.len());
+    let mut segment = String::new();
+
+    for c in name.chars() {
+        match c {
+            ':' => segment.clear(),
+            '<' | '>' | ',' | ' ' | '(' | ')' | '[' | ']' | '&' | ';' => {
+                short.push_str(&segment);
+                short.push(c);
+                segment.clear();
+            }
+            _ => segment.push(c),
+        }
+    }
+
+    short.push_str(&segment);
+    short
+}
+
+/// Record a pass of a view of type `V`, if a [`ViewStats`] is present.
+pub(crate) fn record<V: ?Sized>(base: &mut BaseCx, id: ViewId, pass: ViewPass) {
+    if let Some(stats) = base.get_context_mut::<ViewStats>() {
+        stats.record(id, any::type_name::<V>(), pass);
+    }
+}
+
+/// Record an update request from the caller, if a [`RedrawLog`] is present.
+#[track_caller]
+pub(crate) fn record_request(base: &mut BaseCx, request: UpdateRequest) {
+    if let Some(log) = base.get_context_mut::<RedrawLog>() {
+        log.record(request, Location::caller());
+    }
+}
+
+/// Record a visible view of type `V` in the [`Inspector`], returns true if the inspector
+/// is enabled, in which case [`inspect_exit`] must be called after drawing the view.
+pub(crate) fn inspect_enter<V: ?Sized>(cx: &mut DrawCx) -> bool {
+    let id = cx.id();
+    let rect = cx.rect().transform(cx.transform());
+
+    match cx.get_context_mut::<Inspector>() {
+        Some(inspector) if inspector.enabled => {
+            inspector.enter(id, any::type_name::<V>(), rect);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Set the padding of the view being drawn in the [`Inspector`], see [`Pad`](crate::views::Pad).
+pub(crate) fn inspect_padding(cx: &mut DrawCx, padding: Padding) {
+    if let Some(inspector) = cx.get_context_mut::<Inspector>() {
+        if inspector.enabled {
+            inspector.set_padding(padding);
+        }
+    }
+}
+
+/// Leave a view entered with [`inspect_enter`].
+pub(crate) fn inspect_exit(cx: &mut DrawCx) {
+    if let Some(inspector) = cx.get_context_mut::<Inspector>() {
+        inspector.exit();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_names() {
+        assert_eq!(short_type_name("ori_core::views::Text"), "Text");
+        assert_eq!(
+            short_type_name("ori_core::views::Pad<alloc::vec::Vec<(u32, f32)>>"),
+            "Pad<Vec<(u32, f32)>>"
+        );
+    }
+
+    #[test]
+    fn top_views() {
+        let mut stats = ViewStats::new();
+
+        let a = ViewId::new();
+        let b = ViewId::new();
+
+        stats.record(a, "a::A", ViewPass::Rebuild);
+        stats.record(b, "b::B", ViewPass::Rebuild);
+        stats.record(b, "b::B", ViewPass::Layout);
+        stats.record(b, "b::B", ViewPass::Draw);
+
+        let top = stats.top(1);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].0, b);
+        assert_eq!(top[0].1.name, "B");
+        assert_eq!(top[0].1.total(), 3);
+
+        stats.clear();
+        assert!(stats.get(a).is_none());
+    }
+
+    #[test]
+    fn inspector_view_at() {
+        let mut inspector = Inspector::new().enabled(true);
+
+        let outer = ViewId::new();
+        let inner = ViewId::new();
+
+        let rect = Rect::min_size(Point::ZERO, Size::all(100.0));
+        inspector.enter(outer, "Outer", rect);
+        inspector.enter(inner, "Inner", rect.shrink(10.0));
+        inspector.exit();
+        inspector.exit();
+
+        assert_eq!(inspector.views().len(), 2);
+        assert_eq!(inspector.view_at(Point::all(50.0)).unwrap().id, inner);
+        assert_eq!(inspector.view_at(Point::all(5.0)).unwrap().id, outer);
+        assert!(inspector.view_at(Point::all(150.0)).is_none());
+    }
+
+    #[test]
+    fn inspector_padding_is_child_margin() {
+        let mut inspector = Inspector::new().enabled(true);
+        let padding = Padding::all(10.0);
+
+        let rect = Rect::min_size(Point::ZERO, Size::all(100.0));
+        inspector.enter(ViewId::new(), "Pad", rect);
+        inspector.set_padding(padding);
+        inspector.enter(ViewId::new(), "Text", inset(rect, padding, 1.0));
+        inspector.exit();
+        inspector.exit();
+
+        let views = inspector.views();
+        assert_eq!(views[0].padding, padding);
+        assert_eq!(views[0].margin, Padding::default());
+        assert_eq!(views[1].margin, padding);
+        assert_eq!(inset(views[1].rect, views[1].margin, -1.0), rect);
+    }
+
+    #[test]
+    fn redraw_log() {
+        let mut log = RedrawLog::new();
+
+        let a = Location::caller();
+        let b = Location::caller();
+
+        log.record(UpdateRequest::Draw, a);
+        log.record(UpdateRequest::Animate, b);
+        log.record(UpdateRequest::Animate, b);
+
+        let requests = log.requests();
+        assert_eq!(requests[0], (UpdateRequest::Animate, b, 2));
+        assert_eq!(requests[1], (UpdateRequest::Draw, a, 1));
+
+        log.clear();
+        assert!(log.requests().is_empty());
+    }
+}
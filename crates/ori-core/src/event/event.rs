@@ -1,16 +1,23 @@
 use std::any::Any;
 
-use crate::{command::Command, view::ViewId, window::WindowId};
+use crate::{command::Command, style::ColorScheme, view::ViewId, window::WindowId};
 
 use super::{
-    IsKey, KeyPressed, KeyReleased, PointerLeft, PointerMoved, PointerPressed, PointerReleased,
-    PointerScrolled, WindowCloseRequested, WindowMaximized, WindowResized, WindowScaled,
+    IsKey, KeyPressed, KeyReleased, PointerLeft, PointerMoved, PointerPressed,
+    PointerRelativeMoved, PointerReleased, PointerScrolled, TimerId, WindowCloseRequested,
+    WindowMaximized, WindowResized, WindowScaled,
 };
 
 /// A request to focus a view.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct RequestFocus(pub WindowId, pub ViewId);
 
+/// A request to open a URL with the default handler of the platform, e.g. a browser.
+///
+/// See [`BaseCx::open_url`](crate::context::BaseCx::open_url).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OpenUrl(pub String);
+
 /// A target for focus.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum FocusTarget {
@@ -40,9 +47,30 @@ pub enum Event {
     /// The window requested to be close.
     WindowCloseRequested(WindowCloseRequested),
 
+    /// The color scheme preferred by the system changed.
+    ///
+    /// This is also sent once at startup, if the platform reports a preference.
+    ColorSchemeChanged(ColorScheme),
+
+    /// Whether motion should be reduced changed, either because the preference of the user
+    /// changed, or because animations were disabled, see
+    /// [`BaseCx::reduced_motion`](crate::context::BaseCx::reduced_motion).
+    ///
+    /// This is also sent once at startup, if the platform reports a preference.
+    ReducedMotionChanged(bool),
+
+    /// The monitors connected to the system changed, see
+    /// [`BaseCx::monitors`](crate::context::BaseCx::monitors).
+    ///
+    /// This is also sent once at startup, if the platform reports the monitors.
+    MonitorsChanged,
+
     /// A pointer moved.
     PointerMoved(PointerMoved),
 
+    /// A pointer reported relative motion, see [`PointerRelativeMoved`].
+    PointerRelativeMoved(PointerRelativeMoved),
+
     /// A pointer left the window.
     PointerLeft(PointerLeft),
 
@@ -78,6 +106,9 @@ pub enum Event {
     /// An animation frame has passed.
     Animate(f32),
 
+    /// A timer fired, see [`BaseCx::set_timeout`](crate::context::BaseCx::set_timeout).
+    Timer(TimerId),
+
     /// A command was sent.
     Command(Command),
 
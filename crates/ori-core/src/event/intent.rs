@@ -0,0 +1,120 @@
+/// Hover intent, a hover that only takes effect after the pointer has rested on a target.
+///
+/// This is useful for things like submenus, which would otherwise open and close while the
+/// pointer passes over other items on its way to the submenu. The intent is advanced by
+/// [`HoverIntent::step`] on every [`Event::Animate`](super::Event::Animate), and the
+/// timer is restarted whenever the pointer moves to another target, so targets that are
+/// only passed over never become intended.
+///
+/// # Example
+/// ```
+/// # use ori_core::event::HoverIntent;
+/// let mut intent = HoverIntent::new(0.2);
+///
+/// // the pointer passes over the first item on its way to the second
+/// intent.hover(Some(0));
+/// assert_eq!(intent.step(0.1), None);
+/// intent.hover(Some(1));
+/// assert_eq!(intent.step(0.1), None);
+/// assert_eq!(intent.step(0.1), Some(1));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct HoverIntent<K> {
+    /// The time in seconds the pointer has to rest on a target.
+    pub delay: f32,
+
+    intended: Option<K>,
+    pending: Option<K>,
+    timer: f32,
+}
+
+impl<K: Clone + PartialEq> HoverIntent<K> {
+    /// Create a new [`HoverIntent`], with a `delay` in seconds.
+    pub fn new(delay: f32) -> Self {
+        Self {
+            delay,
+            intended: None,
+            pending: None,
+            timer: 0.0,
+        }
+    }
+
+    /// Get the intended target.
+    pub fn intended(&self) -> Option<&K> {
+        self.intended.as_ref()
+    }
+
+    /// Get whether a target is waiting to become intended, in which case an animation
+    /// frame should be requested.
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some() && self.pending != self.intended
+    }
+
+    /// Set the target the pointer is over, returning whether it's pending.
+    pub fn hover(&mut self, target: Option<K>) -> bool {
+        if target != self.pending {
+            self.pending = target;
+            self.timer = 0.0;
+        }
+
+        self.is_pending()
+    }
+
+    /// Set the intended target immediately, e.g. when it was selected with the keyboard.
+    pub fn set(&mut self, target: Option<K>) {
+        self.intended = target.clone();
+        self.pending = target;
+        self.timer = 0.0;
+    }
+
+    /// Advance the timer by `dt` seconds, returning the target if it became intended.
+    pub fn step(&mut self, dt: f32) -> Option<K> {
+        if !self.is_pending() {
+            return None;
+        }
+
+        self.timer += dt;
+
+        if self.timer < self.delay {
+            return None;
+        }
+
+        self.intended = self.pending.clone();
+        self.intended.clone()
+    }
+}
+
+impl<K> Default for HoverIntent<K> {
+    fn default() -> Self {
+        Self {
+            delay: 0.0,
+            intended: None,
+            pending: None,
+            timer: 0.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restart_on_new_target() {
+        let mut intent = HoverIntent::new(0.2);
+
+        assert!(intent.hover(Some(0)));
+        assert_eq!(intent.step(0.15), None);
+
+        // moving away and back restarts the timer
+        intent.hover(None);
+        intent.hover(Some(0));
+        assert_eq!(intent.step(0.15), None);
+        assert_eq!(intent.step(0.1), Some(0));
+        assert_eq!(intent.intended(), Some(&0));
+
+        // hovering the intended target again isn't pending
+        assert!(!intent.hover(Some(0)));
+        assert_eq!(intent.step(1.0), None);
+    }
+}
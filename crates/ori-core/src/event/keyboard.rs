@@ -250,6 +250,13 @@ pub enum Code {
     Numpad7,
     Numpad8,
     Numpad9,
+    NumPlus,
+    NumMinus,
+    NumSlash,
+    NumPeriod,
+    NumComma,
+    NumEqual,
+    NumEnter,
 
     // Function keys
     F1,
@@ -264,6 +271,18 @@ pub enum Code {
     F10,
     F11,
     F12,
+    F13,
+    F14,
+    F15,
+    F16,
+    F17,
+    F18,
+    F19,
+    F20,
+    F21,
+    F22,
+    F23,
+    F24,
 
     // Symbol keys
     Minus,
@@ -278,6 +297,7 @@ pub enum Code {
     Period,
     Slash,
     NumStar,
+    IntlBackslash,
 
     // Arrow keys
     Left,
@@ -300,6 +320,18 @@ pub enum Code {
     CapsLock,
     NumLock,
     ScrollLock,
+    PrintScreen,
+    Pause,
+    ContextMenu,
+
+    // Media keys
+    AudioMute,
+    AudioVolumeDown,
+    AudioVolumeUp,
+    MediaPlayPause,
+    MediaStop,
+    MediaTrackNext,
+    MediaTrackPrevious,
 
     // Modifier keys
     LShift,
@@ -314,7 +346,10 @@ pub enum Code {
 
 impl Code {
     /// Convert a Linux scancode to a key code.
-    pub fn from_linux_scancode(scancode: u8) -> Option<Self> {
+    ///
+    /// Scancodes are the `KEY_*` constants from `linux/input-event-codes.h`, on X11 these are
+    /// the keycodes minus 8.
+    pub fn from_linux_scancode(scancode: u32) -> Option<Self> {
         Some(match scancode {
             0x01 => Self::Escape,
             0x02 => Self::Key1,
@@ -386,14 +421,126 @@ impl Code {
             0x44 => Self::F10,
             0x45 => Self::NumLock,
             0x46 => Self::ScrollLock,
-            0x47 => Self::Home,
-
+            0x47 => Self::Numpad7,
+            0x48 => Self::Numpad8,
+            0x49 => Self::Numpad9,
+            0x4a => Self::NumMinus,
+            0x4b => Self::Numpad4,
+            0x4c => Self::Numpad5,
+            0x4d => Self::Numpad6,
+            0x4e => Self::NumPlus,
+            0x4f => Self::Numpad1,
+            0x50 => Self::Numpad2,
+            0x51 => Self::Numpad3,
+            0x52 => Self::Numpad0,
+            0x53 => Self::NumPeriod,
+
+            0x56 => Self::IntlBackslash,
+            0x57 => Self::F11,
+            0x58 => Self::F12,
+
+            0x60 => Self::NumEnter,
+            0x61 => Self::RCtrl,
+            0x62 => Self::NumSlash,
+            0x63 => Self::PrintScreen,
+            0x64 => Self::RAlt,
+
+            0x66 => Self::Home,
             0x67 => Self::Up,
+            0x68 => Self::PageUp,
             0x69 => Self::Left,
             0x6a => Self::Right,
+            0x6b => Self::End,
             0x6c => Self::Down,
+            0x6d => Self::PageDown,
+            0x6e => Self::Insert,
+            0x6f => Self::Delete,
+
+            0x71 => Self::AudioMute,
+            0x72 => Self::AudioVolumeDown,
+            0x73 => Self::AudioVolumeUp,
+            0x75 => Self::NumEqual,
+            0x77 => Self::Pause,
+            0x79 => Self::NumComma,
+            0x7d => Self::LMeta,
+            0x7e => Self::RMeta,
+            0x7f => Self::ContextMenu,
+
+            0xa3 => Self::MediaTrackNext,
+            0xa4 => Self::MediaPlayPause,
+            0xa5 => Self::MediaTrackPrevious,
+            0xa6 => Self::MediaStop,
+
+            0xb7 => Self::F13,
+            0xb8 => Self::F14,
+            0xb9 => Self::F15,
+            0xba => Self::F16,
+            0xbb => Self::F17,
+            0xbc => Self::F18,
+            0xbd => Self::F19,
+            0xbe => Self::F20,
+            0xbf => Self::F21,
+            0xc0 => Self::F22,
+            0xc1 => Self::F23,
+            0xc2 => Self::F24,
 
             _ => return None,
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn linux_scancodes() {
+        // from linux/input-event-codes.h
+        let table = [
+            (1, Code::Escape),
+            (14, Code::Backspace),
+            (28, Code::Enter),
+            (29, Code::LCtrl),
+            (30, Code::A),
+            (56, Code::LAlt),
+            (59, Code::F1),
+            (68, Code::F10),
+            (71, Code::Numpad7),
+            (74, Code::NumMinus),
+            (78, Code::NumPlus),
+            (82, Code::Numpad0),
+            (83, Code::NumPeriod),
+            (87, Code::F11),
+            (88, Code::F12),
+            (96, Code::NumEnter),
+            (97, Code::RCtrl),
+            (98, Code::NumSlash),
+            (100, Code::RAlt),
+            (102, Code::Home),
+            (104, Code::PageUp),
+            (107, Code::End),
+            (109, Code::PageDown),
+            (110, Code::Insert),
+            (111, Code::Delete),
+            (113, Code::AudioMute),
+            (115, Code::AudioVolumeUp),
+            (125, Code::LMeta),
+            (126, Code::RMeta),
+            (164, Code::MediaPlayPause),
+            (183, Code::F13),
+            (194, Code::F24),
+        ];
+
+        for (scancode, code) in table {
+            assert_eq!(
+                Code::from_linux_scancode(scancode),
+                Some(code),
+                "{}",
+                scancode
+            );
+        }
+
+        assert_eq!(Code::from_linux_scancode(0), None);
+        assert_eq!(Code::from_linux_scancode(0x100 + 30), None);
+    }
+}
@@ -2,14 +2,22 @@
 
 mod event;
 mod ime;
+mod intent;
 mod keyboard;
 mod modifiers;
 mod pointer;
+mod shortcut;
+mod timer;
+mod visibility;
 mod window;
 
 pub use event::*;
 pub use ime::*;
+pub use intent::*;
 pub use keyboard::*;
 pub use modifiers::*;
 pub use pointer::*;
+pub use shortcut::*;
+pub use timer::*;
+pub use visibility::*;
 pub use window::*;
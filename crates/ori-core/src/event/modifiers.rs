@@ -1,5 +1,6 @@
 /// Modifiers for keyboard and pointer events.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Modifiers {
     /// Whether the shift key is held down.
     pub shift: bool,
@@ -84,6 +84,26 @@ pub struct PointerMoved {
     pub modifiers: Modifiers,
 }
 
+/// A pointer was moved, reported as raw relative motion.
+///
+/// Unlike [`PointerMoved`], the motion isn't limited by the edges of the window or the
+/// screen, which makes it useful for camera controls. It's only really meaningful while
+/// the cursor is grabbed, see [`Window::cursor_grab`](crate::window::Window::cursor_grab).
+///
+/// On X11 the motion is derived from the movement of the cursor, and therefore stops at
+/// the edges of the window while the cursor is confined.
+#[derive(Clone, Debug, PartialEq, Hash)]
+pub struct PointerRelativeMoved {
+    /// The unique id of the pointer.
+    pub id: PointerId,
+
+    /// The relative motion of the pointer, in logical pixels.
+    pub delta: Vector,
+
+    /// The modifiers of the pointer.
+    pub modifiers: Modifiers,
+}
+
 /// A pointer left the window.
 #[derive(Clone, Debug, PartialEq, Hash)]
 pub struct PointerLeft {
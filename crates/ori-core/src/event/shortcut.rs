@@ -0,0 +1,96 @@
+use std::fmt::{self, Display};
+
+use super::{Code, KeyPressed, Modifiers};
+
+/// A keyboard shortcut, a key [`Code`] combined with [`Modifiers`].
+///
+/// # Example
+/// ```
+/// # use ori_core::event::{Code, Shortcut};
+/// let save = Shortcut::new(Code::S).ctrl();
+/// assert_eq!(save.to_string(), "Ctrl+S");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Shortcut {
+    /// The code of the key.
+    pub code: Code,
+
+    /// The modifiers that must be held down, and no others.
+    pub modifiers: Modifiers,
+}
+
+impl Shortcut {
+    /// Create a new [`Shortcut`], with no modifiers.
+    pub const fn new(code: Code) -> Self {
+        Self {
+            code,
+            modifiers: Modifiers {
+                shift: false,
+                ctrl: false,
+                alt: false,
+                meta: false,
+            },
+        }
+    }
+
+    /// Require the shift key.
+    pub const fn shift(mut self) -> Self {
+        self.modifiers.shift = true;
+        self
+    }
+
+    /// Require the control key.
+    pub const fn ctrl(mut self) -> Self {
+        self.modifiers.ctrl = true;
+        self
+    }
+
+    /// Require the alt key.
+    pub const fn alt(mut self) -> Self {
+        self.modifiers.alt = true;
+        self
+    }
+
+    /// Require the meta key.
+    pub const fn meta(mut self) -> Self {
+        self.modifiers.meta = true;
+        self
+    }
+
+    /// Check if a key press matches the shortcut.
+    pub fn matches(&self, pressed: &KeyPressed) -> bool {
+        pressed.code == Some(self.code) && pressed.modifiers == self.modifiers
+    }
+}
+
+impl Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let modifiers = [
+            (self.modifiers.ctrl, "Ctrl+"),
+            (self.modifiers.alt, "Alt+"),
+            (self.modifiers.shift, "Shift+"),
+            (self.modifiers.meta, "Meta+"),
+        ];
+
+        for (held, name) in modifiers {
+            if held {
+                f.write_str(name)?;
+            }
+        }
+
+        match self.code {
+            Code::Key0 => f.write_str("0"),
+            Code::Key1 => f.write_str("1"),
+            Code::Key2 => f.write_str("2"),
+            Code::Key3 => f.write_str("3"),
+            Code::Key4 => f.write_str("4"),
+            Code::Key5 => f.write_str("5"),
+            Code::Key6 => f.write_str("6"),
+            Code::Key7 => f.write_str("7"),
+            Code::Key8 => f.write_str("8"),
+            Code::Key9 => f.write_str("9"),
+            code => write!(f, "{:?}", code),
+        }
+    }
+}
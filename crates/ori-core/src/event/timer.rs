@@ -0,0 +1,93 @@
+use std::{
+    fmt::{self, Debug},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Weak,
+    },
+    time::Duration,
+};
+
+/// An opaque unique identifier for a timer.
+///
+/// Created by [`BaseCx::set_timeout`](crate::context::BaseCx::set_timeout) and
+/// [`BaseCx::set_interval`](crate::context::BaseCx::set_interval).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TimerId {
+    id: u64,
+}
+
+impl TimerId {
+    /// Create a new [`TimerId`].
+    pub fn new() -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+
+    /// Get the underlying id as a [`u64`].
+    pub fn as_u64(&self) -> u64 {
+        self.id
+    }
+}
+
+impl Default for TimerId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Debug for TimerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TimerId({})", self.id)
+    }
+}
+
+/// A request to start or stop a timer.
+#[derive(Clone, Debug)]
+pub enum TimerRequest {
+    /// Start a timer firing after `duration`, and every `duration` after that if `repeat`.
+    Start {
+        /// The id of the timer.
+        id: TimerId,
+
+        /// The duration until the timer fires.
+        duration: Duration,
+
+        /// Whether the timer repeats.
+        repeat: bool,
+
+        /// The owner of the timer, the timer is stopped when it is dropped.
+        owner: Option<Weak<()>>,
+    },
+
+    /// Stop a timer.
+    Stop(TimerId),
+}
+
+/// The pending timer requests, stored as a context.
+///
+/// These are collected by the application, which keeps track of the deadlines, and sends
+/// [`Event::Timer`](super::Event::Timer) when a timer fires.
+#[derive(Debug, Default)]
+pub struct Timers {
+    requests: Vec<TimerRequest>,
+}
+
+impl Timers {
+    /// Create a new [`Timers`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request a timer to be started or stopped.
+    pub fn request(&mut self, request: TimerRequest) {
+        self.requests.push(request);
+    }
+
+    /// Take the pending requests.
+    pub fn take_requests(&mut self) -> Vec<TimerRequest> {
+        std::mem::take(&mut self.requests)
+    }
+}
@@ -0,0 +1,21 @@
+/// The visibility of a view, a property of views that track it, see
+/// [`VisibilityHandler`](crate::views::VisibilityHandler).
+///
+/// This is updated every time the view is drawn, or would have been drawn if it was visible.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Visibility {
+    /// The fraction of the view that is visible, from `0.0` to `1.0`, see
+    /// [`DrawCx::visible_fraction`](crate::context::DrawCx::visible_fraction).
+    pub fraction: f32,
+}
+
+/// Emitted when a view becomes visible or hidden, see
+/// [`VisibilityHandler`](crate::views::VisibilityHandler).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct VisibilityChanged {
+    /// Whether the view is visible.
+    pub visible: bool,
+
+    /// The fraction of the view that is visible, from `0.0` to `1.0`.
+    pub fraction: f32,
+}
@@ -58,6 +58,27 @@ impl Affine {
         }
     }
 
+    /// Snap the translation to whole device pixels at `scale`, where `parent` is the transform
+    /// this is applied after, and `offset` is how far `parent` was moved by snapping.
+    ///
+    /// Returns the snapped transform, and how far it was moved, which is passed on when
+    /// snapping the transforms applied after it. Snapping is relative to the exact position,
+    /// so nested transforms don't accumulate rounding errors. Transforms that rotate or scale
+    /// are not snapped.
+    pub fn pixel_snap(self, parent: Affine, offset: Vector, scale: f32) -> (Self, Vector) {
+        let is_translation = parent.matrix == Matrix::IDENTITY && self.matrix == Matrix::IDENTITY;
+
+        if !is_translation || scale <= 0.0 {
+            return (self, Vector::ZERO);
+        }
+
+        let exact = parent.translation - offset + self.translation;
+        let snapped = (exact * scale).round() / scale;
+
+        let transform = Self::translate(snapped - parent.translation);
+        (transform, snapped - exact)
+    }
+
     /// Compute the inverse transformation.
     pub fn inverse(self) -> Self {
         let matrix = self.matrix.inverse();
@@ -102,3 +123,37 @@ impl MulAssign<Affine> for Affine {
         *self = *self * rhs;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pixel_snap_nested() {
+        let scale = 1.5;
+        let local = Affine::translate(Vector::new(10.3, 4.45));
+
+        let mut exact = Affine::IDENTITY;
+        let mut snapped = Affine::IDENTITY;
+        let mut offset = Vector::ZERO;
+
+        for _ in 0..8 {
+            exact *= local;
+
+            let (transform, new_offset) = local.pixel_snap(snapped, offset, scale);
+            snapped *= transform;
+            offset = new_offset;
+
+            // every level lands on a device pixel, within half a pixel of the exact position
+            let device = snapped.translation * scale;
+            assert!((device - device.round()).length() < 1e-3);
+
+            let error = snapped.translation - exact.translation;
+            assert!(error.x.abs() <= 0.5 / scale + 1e-3);
+            assert!(error.y.abs() <= 0.5 / scale + 1e-3);
+        }
+
+        let rotated = Affine::rotate(0.5);
+        assert_eq!(local.pixel_snap(rotated, offset, scale).0, local);
+    }
+}
@@ -0,0 +1,218 @@
+use super::{Axis, Point, Rect, Size};
+
+/// A side of a rectangle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Side {
+    /// The top side.
+    Top,
+    /// The bottom side.
+    Bottom,
+    /// The left side.
+    Left,
+    /// The right side.
+    Right,
+}
+
+impl Side {
+    /// Get the axis a popover on this side is placed along.
+    pub fn axis(self) -> Axis {
+        match self {
+            Side::Top | Side::Bottom => Axis::Vertical,
+            Side::Left | Side::Right => Axis::Horizontal,
+        }
+    }
+
+    /// Get the opposite side.
+    pub fn flip(self) -> Self {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+
+    fn is_after(self) -> bool {
+        matches!(self, Side::Bottom | Side::Right)
+    }
+}
+
+/// The placement of a popover, computed by [`Anchor::place`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Placement {
+    /// The rectangle of the popover.
+    pub rect: Rect,
+
+    /// The side of the anchor the popover was placed on, which is flipped from the
+    /// preferred side if the popover didn't fit.
+    pub side: Side,
+
+    /// The offset of the arrow along the edge of the popover facing the anchor, pointing
+    /// at the center of the anchor.
+    pub arrow: f32,
+}
+
+/// Positioning of a popover, e.g. a tooltip, dropdown or menu, relative to an anchor.
+///
+/// # Example
+/// ```
+/// # use ori_core::layout::{Anchor, Point, Rect, Side, Size};
+/// let anchor = Rect::min_size(Point::new(10.0, 10.0), Size::new(40.0, 20.0));
+/// let window = Rect::min_size(Point::ZERO, Size::new(200.0, 200.0));
+///
+/// let placement = Anchor::new(Side::Bottom)
+///     .gap(4.0)
+///     .place(anchor, Size::new(100.0, 50.0), window);
+///
+/// assert_eq!(placement.rect.min, Point::new(0.0, 34.0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Anchor {
+    /// The preferred side of the anchor to place the popover on.
+    pub side: Side,
+
+    /// The alignment of the popover along the side of the anchor, where `0.0` aligns the
+    /// starts, `0.5` the centers and `1.0` the ends.
+    pub align: f32,
+
+    /// The gap between the anchor and the popover.
+    pub gap: f32,
+
+    /// Whether the popover is placed on the opposite side if it doesn't fit.
+    pub flip: bool,
+}
+
+impl Anchor {
+    /// Create a new [`Anchor`] on `side`, centered with no gap.
+    pub const fn new(side: Side) -> Self {
+        Self {
+            side,
+            align: 0.5,
+            gap: 0.0,
+            flip: true,
+        }
+    }
+
+    /// Set the alignment along the side of the anchor.
+    pub const fn align(mut self, align: f32) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Set the gap between the anchor and the popover.
+    pub const fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// Set whether the popover is placed on the opposite side if it doesn't fit.
+    pub const fn flip(mut self, flip: bool) -> Self {
+        self.flip = flip;
+        self
+    }
+
+    /// Place a popover of `size` next to `anchor`, keeping it inside `bounds`, usually the
+    /// rectangle of the window.
+    pub fn place(&self, anchor: Rect, size: Size, bounds: Rect) -> Placement {
+        let axis = self.side.axis();
+
+        let (anchor_start, anchor_cross_start) = axis.unpack(anchor.min);
+        let (anchor_end, anchor_cross_end) = axis.unpack(anchor.max);
+        let (bounds_start, bounds_cross_start) = axis.unpack(bounds.min);
+        let (bounds_end, bounds_cross_end) = axis.unpack(bounds.max);
+        let (major, minor) = axis.unpack(size);
+
+        let room_before = anchor_start - self.gap - bounds_start;
+        let room_after = bounds_end - anchor_end - self.gap;
+
+        // flip to the other side if it has more room
+        let mut side = self.side;
+
+        if self.flip {
+            let (room, other) = match side.is_after() {
+                true => (room_after, room_before),
+                false => (room_before, room_after),
+            };
+
+            if room < major && other > room {
+                side = side.flip();
+            }
+        }
+
+        let start = match side.is_after() {
+            true => anchor_end + self.gap,
+            false => anchor_start - self.gap - major,
+        };
+
+        let cross_room = anchor_cross_end - anchor_cross_start - minor;
+        let cross_start = anchor_cross_start + cross_room * self.align;
+
+        // keep the popover inside the bounds, preferring the start if it's too large
+        let start = start.min(bounds_end - major).max(bounds_start);
+        let cross_start = cross_start
+            .min(bounds_cross_end - minor)
+            .max(bounds_cross_start);
+
+        let anchor_center = (anchor_cross_start + anchor_cross_end) / 2.0;
+        let arrow = (anchor_center - cross_start).clamp(0.0, minor);
+
+        Placement {
+            rect: Rect::min_size(axis.pack::<Point>(start, cross_start), size),
+            side,
+            arrow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW: Rect = Rect::new(Point::ZERO, Point::new(200.0, 200.0));
+
+    #[test]
+    fn place_on_side() {
+        let anchor = Rect::min_size(Point::new(80.0, 80.0), Size::new(40.0, 20.0));
+        let size = Size::new(20.0, 10.0);
+
+        let placement = Anchor::new(Side::Top).gap(2.0).place(anchor, size, WINDOW);
+        assert_eq!(placement.rect.min, Point::new(90.0, 68.0));
+        assert_eq!(placement.side, Side::Top);
+        assert_eq!(placement.arrow, 10.0);
+
+        let placement = Anchor::new(Side::Right)
+            .align(0.0)
+            .place(anchor, size, WINDOW);
+        assert_eq!(placement.rect.min, Point::new(120.0, 80.0));
+        assert_eq!(placement.arrow, 10.0);
+    }
+
+    #[test]
+    fn flip_on_overflow() {
+        let anchor = Rect::min_size(Point::new(80.0, 170.0), Size::new(40.0, 20.0));
+        let size = Size::new(20.0, 30.0);
+
+        let placement = Anchor::new(Side::Bottom).place(anchor, size, WINDOW);
+        assert_eq!(placement.side, Side::Top);
+        assert_eq!(placement.rect.min, Point::new(90.0, 140.0));
+
+        let placement = Anchor::new(Side::Bottom)
+            .flip(false)
+            .place(anchor, size, WINDOW);
+        assert_eq!(placement.side, Side::Bottom);
+        assert_eq!(placement.rect.max.y, 200.0);
+    }
+
+    #[test]
+    fn clamp_to_bounds() {
+        let anchor = Rect::min_size(Point::new(190.0, 10.0), Size::new(10.0, 10.0));
+        let size = Size::new(50.0, 10.0);
+
+        let placement = Anchor::new(Side::Bottom).place(anchor, size, WINDOW);
+        assert_eq!(placement.rect.min, Point::new(150.0, 20.0));
+
+        // the arrow still points at the anchor
+        assert_eq!(placement.arrow, 45.0);
+    }
+}
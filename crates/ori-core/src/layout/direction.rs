@@ -0,0 +1,42 @@
+use crate::style::{Style, Theme};
+
+/// The direction of the user interface, i.e. whether it reads left-to-right or
+/// right-to-left.
+///
+/// The direction is the [`Theme::DIRECTION`] style, so it can be set for the whole
+/// application or for a part of the view tree with
+/// [`with_style`](crate::views::with_style). In a right-to-left interface, the main axis
+/// of horizontal stacks is mirrored, and text aligned at the start is aligned to the right.
+///
+/// The text itself is always shaped by its own direction, so mixing e.g. Arabic and English
+/// text works regardless of the direction of the interface.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Left-to-right.
+    #[default]
+    Ltr,
+
+    /// Right-to-left.
+    Rtl,
+}
+
+impl Direction {
+    /// Get whether the direction is right-to-left.
+    pub fn is_rtl(self) -> bool {
+        self == Self::Rtl
+    }
+
+    /// Mirror the `offset` of something of `size` within a `container`, if right-to-left.
+    pub fn mirror(self, offset: f32, size: f32, container: f32) -> f32 {
+        match self {
+            Self::Ltr => offset,
+            Self::Rtl => container - offset - size,
+        }
+    }
+}
+
+impl Theme {
+    /// The direction of the user interface, see [`Direction`].
+    pub const DIRECTION: Style<Direction> = Style::new("theme.direction");
+}
@@ -2,7 +2,9 @@
 
 mod affine;
 mod alignment;
+mod anchor;
 mod axis;
+mod direction;
 mod justify;
 mod matrix;
 mod padding;
@@ -14,7 +16,9 @@ mod vector;
 
 pub use affine::*;
 pub use alignment::*;
+pub use anchor::*;
 pub use axis::*;
+pub use direction::*;
 pub use justify::*;
 pub use matrix::*;
 pub use padding::*;
@@ -7,6 +7,8 @@ pub mod canvas;
 pub mod clipboard;
 pub mod command;
 pub mod context;
+#[cfg(feature = "debug")]
+pub mod debug;
 pub mod event;
 pub mod image;
 pub mod layout;
@@ -19,4 +21,5 @@ pub mod window;
 
 pub mod views;
 
+pub use raw_window_handle;
 pub use tracing as log;
@@ -1,7 +1,11 @@
 //! Styleing and theming.
 
 mod palette;
+mod sheet;
 mod style;
+mod units;
 
 pub use palette::*;
+pub use sheet::*;
 pub use style::*;
+pub use units::*;
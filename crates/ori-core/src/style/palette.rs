@@ -1,7 +1,28 @@
-use crate::canvas::Color;
+use crate::{
+    canvas::{Color, Shadow},
+    layout::Vector,
+};
 
 use super::{Style, Styles};
 
+/// A color scheme preference, usually reported by the system.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ColorScheme {
+    /// A light color scheme.
+    Light,
+
+    /// A dark color scheme.
+    Dark,
+}
+
+impl ColorScheme {
+    /// Check if the color scheme is dark.
+    pub fn is_dark(self) -> bool {
+        self == Self::Dark
+    }
+}
+
 /// A theme.
 #[derive(Clone, Copy, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -76,6 +97,41 @@ impl Theme {
     }
 }
 
+impl Theme {
+    /// Create the default theme for a [`ColorScheme`].
+    pub fn from_scheme(scheme: ColorScheme) -> Self {
+        match scheme {
+            ColorScheme::Light => Self::light(),
+            ColorScheme::Dark => Self::dark(),
+        }
+    }
+
+    /// Interpolate every color of the theme towards `other`.
+    ///
+    /// This uses a fractor `t` between `0.0` and `1.0`, see [`Color::mix`].
+    pub fn mix(self, other: Self, t: f32) -> Self {
+        Self {
+            background: self.background.mix(other.background, t),
+            surface: self.surface.mix(other.surface, t),
+            outline: self.outline.mix(other.outline, t),
+            contrast: self.contrast.mix(other.contrast, t),
+            primary: self.primary.mix(other.primary, t),
+            secondary: self.secondary.mix(other.secondary, t),
+            accent: self.accent.mix(other.accent, t),
+            danger: self.danger.mix(other.danger, t),
+            success: self.success.mix(other.success, t),
+            warning: self.warning.mix(other.warning, t),
+            info: self.info.mix(other.info, t),
+        }
+    }
+}
+
+impl From<ColorScheme> for Theme {
+    fn from(scheme: ColorScheme) -> Self {
+        Self::from_scheme(scheme)
+    }
+}
+
 impl From<Theme> for Styles {
     fn from(theme: Theme) -> Self {
         fn surf(color: Color, is_light: bool, level: i32) -> Color {
@@ -104,6 +160,13 @@ impl From<Theme> for Styles {
             }
         }
 
+        fn elevation(level: f32, is_light: bool) -> Shadow {
+            let alpha = if is_light { 0.2 } else { 0.4 };
+            let color = Color::BLACK.fade(alpha);
+
+            Shadow::new(Vector::new(0.0, level), level * 2.0, color)
+        }
+
         let is_light = theme.background.luminocity() > 0.5;
 
         Styles::new()
@@ -132,6 +195,14 @@ impl From<Theme> for Styles {
             .with(Theme::WARNING_LOW, low(theme.warning, is_light))
             .with(Theme::INFO, theme.info)
             .with(Theme::INFO_LOW, low(theme.info, is_light))
+            .with(Theme::ELEVATION_0, Shadow::NONE)
+            .with(Theme::ELEVATION_1, elevation(1.0, is_light))
+            .with(Theme::ELEVATION_2, elevation(2.0, is_light))
+            .with(Theme::ELEVATION_3, elevation(4.0, is_light))
+            .with(Theme::ELEVATION_4, elevation(6.0, is_light))
+            .with(Theme::ELEVATION_5, elevation(8.0, is_light))
+            .with(Theme::FOCUS_RING, theme.info)
+            .with(Theme::FOCUS_RING_WIDTH, 2.0)
     }
 }
 
@@ -169,3 +240,37 @@ impl Theme {
     pub const INFO: Style<Color> = Style::new("theme.info");
     pub const INFO_LOW: Style<Color> = Style::new("theme.info_low");
 }
+
+/// Elevation levels, the drop shadows of surfaces raised above the background.
+///
+/// Level `0` is flat, and every following level casts a larger and softer shadow.
+impl Theme {
+    /// No shadow.
+    pub const ELEVATION_0: Style<Shadow> = Style::new("theme.elevation_0");
+
+    /// The shadow of slightly raised surfaces, e.g. buttons.
+    pub const ELEVATION_1: Style<Shadow> = Style::new("theme.elevation_1");
+
+    /// The shadow of cards.
+    pub const ELEVATION_2: Style<Shadow> = Style::new("theme.elevation_2");
+
+    /// The shadow of menus and popovers.
+    pub const ELEVATION_3: Style<Shadow> = Style::new("theme.elevation_3");
+
+    /// The shadow of dialogs.
+    pub const ELEVATION_4: Style<Shadow> = Style::new("theme.elevation_4");
+
+    /// The shadow of surfaces above everything else, e.g. dragged items.
+    pub const ELEVATION_5: Style<Shadow> = Style::new("theme.elevation_5");
+}
+
+/// The focus ring, drawn around focused views, see [`DrawCx::draw_focus_ring`].
+///
+/// [`DrawCx::draw_focus_ring`]: crate::context::DrawCx::draw_focus_ring
+impl Theme {
+    /// The color of the focus ring.
+    pub const FOCUS_RING: Style<Color> = Style::new("theme.focus_ring");
+
+    /// The width of the focus ring.
+    pub const FOCUS_RING_WIDTH: Style<f32> = Style::new("theme.focus_ring_width");
+}
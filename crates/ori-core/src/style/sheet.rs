@@ -0,0 +1,169 @@
+use crate::canvas::Color;
+
+use super::{hash_style_key, Style, Styles};
+
+/// An error that can occur when parsing a stylesheet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StyleParseError {
+    /// The line the error occurred on, starting at 1.
+    pub line: usize,
+
+    /// The error message.
+    pub message: String,
+}
+
+impl std::fmt::Display for StyleParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for StyleParseError {}
+
+impl Styles {
+    /// Parse a stylesheet.
+    ///
+    /// A stylesheet is a list of `key: value` pairs, one per line, where the key is the
+    /// name of a [`Style`], e.g. `theme.primary` or `theme.font_size`. Lines starting
+    /// with `//` are comments, and a trailing `;` is allowed.
+    ///
+    /// Values can be
+    /// - colors, written in hex, e.g. `#1c71d8`, parsed as [`Color`],
+    /// - numbers, optionally followed by `px`, e.g. `4` or `4px`, parsed as `f32`,
+    /// - `true` or `false`, parsed as `bool`,
+    /// - the name of another style, e.g. `theme.accent`.
+    ///
+    /// Note that the type of the value must match the type of the style, otherwise the
+    /// value is ignored when the style is looked up, see [`Styles::get`]. Values of the
+    /// wrong type can be found when the stylesheet is applied, with
+    /// [`Styles::remove_type_mismatches`].
+    ///
+    /// # Example
+    /// ```
+    /// # use ori_core::{canvas::Color, style::{Styles, Theme}};
+    /// let styles = Styles::parse(
+    ///     "
+    ///     // a custom primary color
+    ///     theme.primary: #ff0000;
+    ///     theme.accent: theme.primary;
+    ///     theme.font_size: 16px;
+    ///     ",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(styles.get(Theme::ACCENT), Some(Color::hex("#ff0000")));
+    /// assert_eq!(styles.get(Theme::FONT_SIZE), Some(16.0));
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, StyleParseError> {
+        let mut styles = Styles::new();
+
+        for (index, line) in source.lines().enumerate() {
+            let error = |message: &str| StyleParseError {
+                line: index + 1,
+                message: message.to_string(),
+            };
+
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with("//") {
+                continue;
+            }
+
+            let line = line.strip_suffix(';').unwrap_or(line);
+
+            let Some((key, value)) = line.split_once(':') else {
+                return Err(error("expected `key: value`"));
+            };
+
+            let key = key.trim();
+            let value = value.trim();
+
+            if !is_key(key) {
+                return Err(error(&format!("invalid key `{}`", key)));
+            }
+
+            if value.starts_with('#') {
+                let color = Color::try_hex(value)
+                    .ok_or_else(|| error(&format!("invalid color `{}`", value)))?;
+
+                styles.insert_value(Style::new(key), color);
+                continue;
+            }
+
+            if let Ok(number) = value.strip_suffix("px").unwrap_or(value).parse::<f32>() {
+                styles.insert_value(Style::new(key), number);
+                continue;
+            }
+
+            match value {
+                "true" => styles.insert_value(Style::new(key), true),
+                "false" => styles.insert_value(Style::new(key), false),
+                _ if is_key(value) => {
+                    let key = hash_style_key(key.as_bytes());
+                    styles.insert_style_keys(key, hash_style_key(value.as_bytes()));
+                }
+                _ => return Err(error(&format!("invalid value `{}`", value))),
+            }
+        }
+
+        Ok(styles)
+    }
+}
+
+fn is_key(key: &str) -> bool {
+    let valid = |c: char| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-');
+    !key.is_empty() && key.chars().all(valid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_values() {
+        const NUMBER: Style<f32> = Style::new("a.number");
+        const PIXELS: Style<f32> = Style::new("a.pixels");
+        const FLAG: Style<bool> = Style::new("a.flag");
+        const COLOR: Style<Color> = Style::new("a.color");
+        const ALIAS: Style<Color> = Style::new("a.alias");
+
+        let styles = Styles::parse(
+            "
+            // comment
+            a.number: 1.5
+            a.pixels: 4px;
+            a.flag: true
+            a.color: #ff0000
+            a.alias: a.color
+            ",
+        )
+        .unwrap();
+
+        assert_eq!(styles.get(NUMBER), Some(1.5));
+        assert_eq!(styles.get(PIXELS), Some(4.0));
+        assert_eq!(styles.get(FLAG), Some(true));
+        assert_eq!(styles.get(COLOR), Some(Color::hex("#ff0000")));
+        assert_eq!(styles.get(ALIAS), Some(Color::hex("#ff0000")));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Styles::parse("a.b: 1\nnot a style").err().unwrap();
+        assert_eq!(err.line, 2);
+
+        assert!(Styles::parse("a.color: #zz").is_err());
+        assert!(Styles::parse("a.value: not valid").is_err());
+    }
+
+    #[test]
+    fn wrong_type_is_ignored() {
+        const COLOR: Style<Color> = Style::new("a.color");
+        const FLAG: Style<bool> = Style::new("a.flag");
+
+        let styles = Styles::parse("a.color: 4\na.flag: #ff0000").unwrap();
+
+        assert_eq!(styles.get(COLOR), None);
+        assert_eq!(styles.get_or(Color::BLUE, COLOR), Color::BLUE);
+        assert_eq!(styles.get(FLAG), None);
+    }
+}
@@ -113,12 +113,73 @@ impl Styles {
         self
     }
 
+    /// Insert a style class.
+    ///
+    /// A class is a named collection of styles, that is applied on top of the current
+    /// styles by the [`class`](crate::views::class) view, when it is active.
+    pub fn insert_class(&mut self, name: &str, styles: impl Into<Styles>) {
+        // styles are only ever used on the ui thread
+        #[allow(clippy::arc_with_non_send_sync)]
+        let entry = StyleEntry::Value(TypeId::of::<Styles>(), Arc::new(styles.into()));
+        Arc::make_mut(&mut self.styles).insert(class_key(name), entry);
+    }
+
+    /// Insert a style class.
+    pub fn with_class(mut self, name: &str, styles: impl Into<Styles>) -> Self {
+        self.insert_class(name, styles);
+        self
+    }
+
+    /// Get a style class.
+    pub fn get_class(&self, name: &str) -> Option<&Styles> {
+        self.get_class_key(class_key(name))
+    }
+
+    pub(crate) fn get_class_key(&self, key: u64) -> Option<&Styles> {
+        self.get_ref::<Styles>(key).ok()
+    }
+
     /// Extend the styles with another collection of styles.
     pub fn extend(&mut self, styles: impl Into<Styles>) {
         let styles = Arc::unwrap_or_clone(styles.into().styles);
         Arc::make_mut(&mut self.styles).extend(styles);
     }
 
+    /// Remove the values of a different type than the value of the same style in `base`,
+    /// returning their keys.
+    ///
+    /// This is used to ignore the values of a parsed stylesheet that can't be used, since
+    /// stylesheets are parsed without knowing the types of the styles.
+    pub fn remove_type_mismatches(&mut self, base: &Styles) -> Vec<u64> {
+        let mismatches: Vec<u64> = (self.styles.iter())
+            .filter(|(key, entry)| match entry {
+                StyleEntry::Value(type_id, _) => {
+                    base.type_id(**key).is_some_and(|base| base != *type_id)
+                }
+                StyleEntry::Key(_) => false,
+            })
+            .map(|(key, _)| *key)
+            .collect();
+
+        if !mismatches.is_empty() {
+            let styles = Arc::make_mut(&mut self.styles);
+
+            for key in &mismatches {
+                styles.remove(key);
+            }
+        }
+
+        mismatches
+    }
+
+    // get the type of the value of a style, following style keys
+    fn type_id(&self, key: u64) -> Option<TypeId> {
+        match self.styles.get(&key)? {
+            StyleEntry::Value(type_id, _) => Some(*type_id),
+            StyleEntry::Key(key) => self.type_id(*key),
+        }
+    }
+
     #[inline(always)]
     fn get_ref<T>(&self, key: u64) -> Result<&T, GetRefError>
     where
@@ -148,21 +209,20 @@ impl Styles {
     }
 
     /// Get a style.
+    ///
+    /// Returns `None` if the style isn't set, or if it's set to a value of a different type
+    /// than `T`, see [`Styles::remove_type_mismatches`].
     #[track_caller]
     #[inline(always)]
     pub fn get<T>(&self, style: Style<T>) -> Option<T>
     where
         T: Clone + 'static,
     {
+        // stylesheets are parsed without knowing the types of the styles, so a value of the
+        // wrong type is treated as missing, instead of crashing the app
         match self.get_ref::<T>(style.key) {
             Ok(value) => Some(value.clone()),
-            Err(GetRefError::TypeMismatch) => {
-                panic!(
-                    "style is of a different type than `{}`",
-                    std::any::type_name::<T>()
-                )
-            }
-            Err(GetRefError::KeyNotFound) => None,
+            Err(GetRefError::TypeMismatch | GetRefError::KeyNotFound) => None,
         }
     }
 
@@ -319,6 +379,21 @@ pub const fn hash_style_key(bytes: &[u8]) -> u64 {
     hash
 }
 
+/// Hash the key of a style class.
+///
+/// Classes live in the same map as styles, so the name is prefixed to avoid collisions.
+#[inline(always)]
+pub(crate) fn class_key(name: &str) -> u64 {
+    let mut hash = hash_style_key(b"class:");
+
+    for byte in name.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+
+    hash
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -364,4 +439,40 @@ mod tests {
         assert_eq!(styles.get(KEY_A), Some(42));
         assert_eq!(styles.get(KEY_B), Some(42));
     }
+
+    #[test]
+    fn style_class() {
+        let styles = Styles::new()
+            .with_value(KEY_A, 1)
+            .with_class("error", Styles::new().with_value(KEY_A, 2));
+
+        assert_eq!(styles.get(KEY_A), Some(1));
+
+        let class = styles.get_class("error").unwrap();
+        assert_eq!(class.get(KEY_A), Some(2));
+
+        assert!(styles.get_class("warning").is_none());
+        assert_eq!(class_key("a"), hash_style_key(b"class:a"));
+    }
+
+    #[test]
+    fn remove_type_mismatches() {
+        const KEY_C: Style<f32> = Style::new("c");
+
+        let base = Styles::new().with_value(KEY_A, 1).with_style(KEY_B, KEY_A);
+        let mut styles = Styles::new()
+            .with_value(Style::<f32>::new("a"), 2.0)
+            .with_value(Style::<f32>::new("b"), 3.0)
+            .with_value(KEY_C, 4.0);
+
+        let mut removed = styles.remove_type_mismatches(&base);
+        removed.sort();
+
+        let mut expected = vec![KEY_A.key, KEY_B.key];
+        expected.sort();
+
+        assert_eq!(removed, expected);
+        assert_eq!(styles.len(), 1);
+        assert_eq!(styles.get(KEY_C), Some(4.0));
+    }
 }
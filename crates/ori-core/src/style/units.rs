@@ -0,0 +1,82 @@
+use crate::views::TextStyle;
+
+use super::{comp, Style, Styled, Theme};
+
+/// The styles describing the viewport, i.e. the window being built.
+///
+/// These are set by the application before a window is built or rebuilt, and the window
+/// is rebuilt when it is resized.
+pub struct Viewport;
+
+impl Viewport {
+    /// The width of the viewport.
+    pub const WIDTH: Style<f32> = Style::new("viewport.width");
+
+    /// The height of the viewport.
+    pub const HEIGHT: Style<f32> = Style::new("viewport.height");
+}
+
+impl Theme {
+    /// The root font size, used by [`rem`].
+    pub const FONT_SIZE: Style<f32> = Style::new("theme.font_size");
+}
+
+/// The font size used when neither [`Theme::FONT_SIZE`] nor [`TextStyle::FONT_SIZE`] is set.
+pub const DEFAULT_FONT_SIZE: f32 = 16.0;
+
+/// A length relative to the current font size, [`TextStyle::FONT_SIZE`].
+///
+/// Falls back to the root font size, see [`rem`].
+pub fn em(x: f32) -> Styled<f32> {
+    comp(move |styles| {
+        let root = styles.get_or(DEFAULT_FONT_SIZE, Theme::FONT_SIZE);
+        x * styles.get_or(root, TextStyle::FONT_SIZE)
+    })
+}
+
+/// A length relative to the root font size, [`Theme::FONT_SIZE`].
+pub fn rem(x: f32) -> Styled<f32> {
+    comp(move |styles| x * styles.get_or(DEFAULT_FONT_SIZE, Theme::FONT_SIZE))
+}
+
+/// A length in percent of the width of the viewport, see [`Viewport::WIDTH`].
+pub fn vw(x: f32) -> Styled<f32> {
+    comp(move |styles| x / 100.0 * styles.get_or(0.0, Viewport::WIDTH))
+}
+
+/// A length in percent of the height of the viewport, see [`Viewport::HEIGHT`].
+pub fn vh(x: f32) -> Styled<f32> {
+    comp(move |styles| x / 100.0 * styles.get_or(0.0, Viewport::HEIGHT))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::style::Styles;
+
+    use super::*;
+
+    #[test]
+    fn font_relative() {
+        let styles = Styles::new();
+        assert_eq!(em(2.0).get(&styles), Some(32.0));
+        assert_eq!(rem(2.0).get(&styles), Some(32.0));
+
+        let styles = styles.with_value(Theme::FONT_SIZE, 10.0);
+        assert_eq!(em(2.0).get(&styles), Some(20.0));
+        assert_eq!(rem(2.0).get(&styles), Some(20.0));
+
+        let styles = styles.with_value(TextStyle::FONT_SIZE, 12.0);
+        assert_eq!(em(2.0).get(&styles), Some(24.0));
+        assert_eq!(rem(2.0).get(&styles), Some(20.0));
+    }
+
+    #[test]
+    fn viewport_relative() {
+        let styles = Styles::new()
+            .with_value(Viewport::WIDTH, 800.0)
+            .with_value(Viewport::HEIGHT, 600.0);
+
+        assert_eq!(vw(50.0).get(&styles), Some(400.0));
+        assert_eq!(vh(10.0).get(&styles), Some(60.0));
+    }
+}
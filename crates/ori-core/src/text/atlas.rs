@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use cosmic_text::{CacheKey, FontSystem, SwashCache, SwashContent};
 
 use crate::{
+    canvas::Color,
     image::Image,
     layout::{Point, Rect, Size},
 };
@@ -57,6 +58,7 @@ impl FontAtlas {
                     Size::new(glyph.width as f32, glyph.height as f32),
                 ),
                 layout: glyph.layout,
+                color: glyph.color,
                 x: rx,
                 y: ry,
                 width: glyph.width,
@@ -147,6 +149,7 @@ impl FontAtlas {
                 Point::new(image.placement.left as f32, image.placement.top as f32),
                 Size::new(image.placement.width as f32, image.placement.height as f32),
             ),
+            color: matches!(image.content, SwashContent::Color),
             x: rx,
             y: ry,
             width,
@@ -166,12 +169,28 @@ pub struct AtlasGlyph {
     /// The layout rect of the glyph.
     pub layout: Rect,
 
+    /// Whether the glyph has colors of its own, e.g. an emoji, and shouldn't be tinted by the
+    /// color of the text.
+    pub color: bool,
+
     x: u32,
     y: u32,
     width: u32,
     height: u32,
 }
 
+impl AtlasGlyph {
+    /// Get the color to tint the glyph with, when drawing text of `color`.
+    ///
+    /// Color glyphs keep their own colors, and only take the opacity of the text.
+    pub fn tint(&self, color: Color) -> Color {
+        match self.color {
+            true => Color::WHITE.fade(color.a),
+            false => color,
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Node {
     x: u32,
@@ -231,3 +250,27 @@ impl Node {
         Some(rect)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn color_glyphs_are_not_tinted() {
+        let mut glyph = AtlasGlyph {
+            uv: Rect::ZERO,
+            layout: Rect::ZERO,
+            color: false,
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+        };
+
+        let color = Color::RED.fade(0.5);
+        assert_eq!(glyph.tint(color), color);
+
+        glyph.color = true;
+        assert_eq!(glyph.tint(color), Color::WHITE.fade(0.5));
+    }
+}
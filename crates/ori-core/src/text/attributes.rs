@@ -1,7 +1,7 @@
 use cosmic_text::fontdb;
 use smol_str::SmolStr;
 
-use crate::style::Styled;
+use crate::{layout::Direction, style::Styled};
 
 /// A font family, by default [`FontFamily::SansSerif`].
 #[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
@@ -209,6 +209,16 @@ impl TextAlign {
     pub const Right: Self = Self::End;
     pub const Bottom: Self = Self::End;
 
+    /// Resolve the text align for a [`Direction`], where the start is on the right in
+    /// right-to-left interfaces.
+    pub fn resolve(self, direction: Direction) -> Self {
+        match (self, direction) {
+            (Self::Start, Direction::Rtl) => Self::End,
+            (Self::End, Direction::Rtl) => Self::Start,
+            (align, _) => align,
+        }
+    }
+
     /// Convert the text align to a [`cosmic_text::Align`].
     pub fn to_cosmic_text(self) -> cosmic_text::Align {
         match self {
@@ -241,8 +251,20 @@ impl TextWrap {
     }
 }
 
+/// How text that doesn't fit in the available width is handled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextOverflow {
+    /// The text is laid out as usual, and may overflow.
+    #[default]
+    Visible,
+
+    /// The text is kept on a single line, and truncated with an ellipsis when it doesn't fit.
+    Ellipsis,
+}
+
 /// Attributes of a section of text.
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
 pub struct TextAttributes {
     /// The font family of the text.
     pub family: FontFamily,
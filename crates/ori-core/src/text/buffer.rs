@@ -1,8 +1,8 @@
 use cosmic_text::{Buffer, Shaping};
 
-use crate::layout::{Point, Rect, Size};
+use crate::layout::{Point, Rect, Size, FILL};
 
-use super::{Fonts, TextAlign, TextAttributes, TextWrap};
+use super::{Fonts, TextAlign, TextAttributes, TextHit, TextWrap};
 
 /// A convenient wrapper around a [`cosmic_text::Buffer`].
 #[derive(Debug)]
@@ -62,6 +62,11 @@ impl TextBuffer {
         Fonts::buffer_size(&self.buffer)
     }
 
+    /// Hit-test the text buffer at a `point`, see [`TextHit`].
+    pub fn hit(&self, point: Point) -> Option<TextHit> {
+        TextHit::new(&self.buffer, point)
+    }
+
     /// Get the rect of the text buffer.
     pub fn rect(&self) -> Rect {
         Rect::min_size(Point::ZERO, self.size())
@@ -91,12 +96,93 @@ impl TextBuffer {
 
     /// Set the text of the text buffer.
     pub fn set_text(&mut self, fonts: &mut Fonts, text: &str, attrs: TextAttributes) {
-        self.buffer.set_text(
+        self.set_rich_text(fonts, [(text, attrs)]);
+    }
+
+    /// Set the text of the text buffer to `spans` of text with different attributes.
+    ///
+    /// The metadata of each glyph is the index of the span it belongs to.
+    pub fn set_rich_text<'a>(
+        &mut self,
+        fonts: &mut Fonts,
+        spans: impl IntoIterator<Item = (&'a str, TextAttributes)>,
+    ) {
+        // the alignment is stored per line, so it has to be kept across the new lines
+        let align = self.buffer.lines.first().and_then(|line| line.align());
+
+        let spans: Vec<_> = spans.into_iter().collect();
+        let default = spans.first().map(|(_, attrs)| attrs.clone());
+        let default = default.unwrap_or_default();
+
+        let mut fallback = Vec::new();
+        for (i, (text, attrs)) in spans.iter().enumerate() {
+            for (text, attrs) in fonts.fallback_spans(text, attrs) {
+                fallback.push((text, attrs, i));
+            }
+        }
+
+        self.buffer.set_rich_text(
             &mut fonts.font_system,
-            text,
-            attrs.to_cosmic_text(),
+            (fallback.iter()).map(|(text, attrs, i)| (*text, attrs.to_cosmic_text().metadata(*i))),
+            default.to_cosmic_text(),
             Shaping::Advanced,
         );
+
+        for line in &mut self.buffer.lines {
+            line.set_align(align);
+        }
+    }
+
+    /// Truncate the text so that it fits in `width`, ending it with an ellipsis.
+    ///
+    /// Only the first line is kept, and the text should be set with [`TextWrap::None`].
+    /// Returns `true` if the text was truncated, in which case the full text has to be set
+    /// again before the width can grow.
+    pub fn truncate(
+        &mut self,
+        fonts: &mut Fonts,
+        text: &str,
+        attrs: TextAttributes,
+        width: f32,
+    ) -> bool {
+        const ELLIPSIS: &str = "\u{2026}";
+
+        if self.size().width <= width && self.buffer.lines.len() <= 1 {
+            return false;
+        }
+
+        let metrics = self.buffer.metrics();
+        let line_height = metrics.line_height / metrics.font_size;
+        let ellipsis = fonts.measure(
+            ELLIPSIS,
+            &attrs,
+            metrics.font_size,
+            line_height,
+            TextWrap::None,
+            FILL,
+        );
+        let available = width - ellipsis.width;
+
+        // find the end of the last glyph of the first line, that fits before the ellipsis
+        let mut end = 0;
+
+        if let Some(run) = self.buffer.layout_runs().next() {
+            let start = run.glyphs.iter().map(|g| g.x).fold(f32::INFINITY, f32::min);
+
+            for glyph in run.glyphs {
+                if glyph.x + glyph.w - start <= available {
+                    end = end.max(glyph.end);
+                }
+            }
+        }
+
+        let line = text.lines().next().unwrap_or_default();
+        let end = end.min(line.len());
+        let truncated = format!("{}{}", line[..end].trim_end(), ELLIPSIS);
+
+        self.set_text(fonts, &truncated, attrs);
+
+        true
     }
 
     /// Set the wrapping mode of the text buffer.
@@ -116,3 +202,25 @@ impl AsMut<Buffer> for TextBuffer {
         &mut self.buffer
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hit() {
+        let mut fonts = Fonts::new();
+        let mut buffer = TextBuffer::new(&mut fonts, 16.0, 1.0);
+        buffer.set_text(&mut fonts, "Hello\nworld", TextAttributes::default());
+
+        // the left half of the first glyph of the second line
+        let hit = buffer.hit(Point::new(1.0, 24.0)).unwrap();
+        assert_eq!((hit.line, hit.index, hit.offset), (1, 0, 6));
+        assert!(hit.inside);
+
+        // past the end of the first line
+        let hit = buffer.hit(Point::new(1000.0, 8.0)).unwrap();
+        assert_eq!((hit.line, hit.index, hit.offset), (0, 5, 5));
+        assert!(!hit.inside);
+    }
+}
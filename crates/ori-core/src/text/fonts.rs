@@ -1,6 +1,12 @@
-use std::{collections::HashMap, hash::BuildHasherDefault, io, sync::Arc};
+use std::{
+    collections::HashMap,
+    fs,
+    hash::{BuildHasherDefault, Hash, Hasher},
+    io,
+    sync::Arc,
+};
 
-use cosmic_text::{Buffer, CacheKey, Command, FontSystem, SwashCache};
+use cosmic_text::{fontdb, Buffer, CacheKey, Command, Font, FontSystem, PhysicalGlyph, SwashCache};
 use ori_macro::include_font;
 use tracing::{debug, trace};
 
@@ -9,7 +15,7 @@ use crate::{
     layout::{Affine, Point, Rect, Size, Vector},
 };
 
-use super::{FontAtlas, FontSource};
+use super::{FontAtlas, FontFamily, FontSource, TextAttributes, TextBuffer, TextHit, TextWrap};
 
 /// A context for loading and rasterizing fonts.
 ///
@@ -29,6 +35,23 @@ pub struct Fonts {
 
     /// The font atlas.
     pub font_atlas: FontAtlas,
+
+    fallback_families: Vec<FontFamily>,
+    // keyed by the hash of the parameters, so that looking up doesn't allocate
+    measurements: HashMap<u64, Measurement, BuildHasherDefault<seahash::SeaHasher>>,
+}
+
+// measurements are cleared when there are more than this many
+const MAX_MEASUREMENTS: usize = 4096;
+
+// the parameters are kept to tell apart measurements with colliding hashes
+#[derive(Clone, Debug)]
+struct Measurement {
+    text: Box<str>,
+    attrs: TextAttributes,
+    metrics: [u32; 3],
+    wrap: TextWrap,
+    size: Size,
 }
 
 impl Default for Fonts {
@@ -45,6 +68,8 @@ impl Fonts {
             font_system: FontSystem::new(),
             curve_cache: HashMap::default(),
             font_atlas: FontAtlas::new(1024),
+            fallback_families: Vec::new(),
+            measurements: HashMap::default(),
         };
 
         for font in fonts.font_system.db().faces() {
@@ -70,31 +95,221 @@ impl Fonts {
     /// This will usually either be a path to a font file or the font data itself, but can also
     /// be a [`Vec<FontSource>`] to load multiple fonts at once.
     pub fn load_font<'a>(&mut self, source: impl Into<FontSource<'a>>) -> Result<(), io::Error> {
-        match source.into() {
-            FontSource::Data(data) => {
-                self.font_system.db_mut().load_font_data(data.to_vec());
-            }
-            FontSource::Path(path) => {
-                self.font_system.db_mut().load_font_file(path)?;
+        self.load_font_faces(source.into())?;
+        Ok(())
+    }
+
+    /// Loads a font from a [`FontSource`], and registers it under the family `name`.
+    ///
+    /// The font can then be referenced with [`FontFamily::Name`], regardless of the family
+    /// name stored in the font itself, which is useful for fonts embedded with
+    /// [`include_font!`](super::include_font).
+    ///
+    /// # Example
+    /// ```ignore
+    /// fonts.load_font_named("Brand", include_font!("assets/brand.ttf"))?;
+    /// ```
+    pub fn load_font_named<'a>(
+        &mut self,
+        name: &str,
+        source: impl Into<FontSource<'a>>,
+    ) -> Result<(), io::Error> {
+        for id in self.load_font_faces(source.into())? {
+            let db = self.font_system.db_mut();
+
+            let Some(mut face) = db.face(id).cloned() else {
+                continue;
+            };
+
+            // the name is put first, as that is the name fontdb reports the face by
+            face.families
+                .insert(0, (name.into(), fontdb::Language::English_UnitedStates));
+
+            db.remove_face(id);
+            db.push_face_info(face);
+        }
+
+        Ok(())
+    }
+
+    fn load_font_faces(&mut self, source: FontSource) -> Result<Vec<fontdb::ID>, io::Error> {
+        let sources = match source {
+            FontSource::Data(data) => vec![fontdb::Source::Binary(Arc::new(data.to_vec()))],
+            FontSource::Path(path) => vec![fontdb::Source::Binary(Arc::new(fs::read(path)?))],
+            FontSource::Bundle(data) => decompress_font_bundle(data.as_ref()),
+        };
+
+        let mut ids = Vec::new();
+        self.clear_measurements();
+
+        for source in sources {
+            for id in self.font_system.db_mut().load_font_source(source) {
+                let face = self.font_system.db().face(id).unwrap();
+
+                for (family, _) in &face.families {
+                    debug!("Loaded font family: {}", family);
+                }
+
+                ids.push(id);
             }
-            FontSource::Bundle(data) => {
-                let fonts = decompress_font_bundle(data.as_ref());
+        }
+
+        Ok(ids)
+    }
+
+    /// Set the families used for characters missing from the font of some text, tried in
+    /// order, e.g. an emoji font followed by a CJK font.
+    ///
+    /// Characters that none of the families contain are looked up in the fonts of the system.
+    pub fn set_fallback_families(&mut self, families: &[FontFamily]) {
+        self.fallback_families = families.to_vec();
+        self.clear_measurements();
+    }
+
+    /// Get the fallback families, see [`Fonts::set_fallback_families`].
+    pub fn fallback_families(&self) -> &[FontFamily] {
+        &self.fallback_families
+    }
+
+    /// Split `text` into spans of the attributes it's rendered with, where characters missing
+    /// from the font of `attrs` use the first fallback family that contains them.
+    pub fn fallback_spans<'a>(
+        &mut self,
+        text: &'a str,
+        attrs: &TextAttributes,
+    ) -> Vec<(&'a str, TextAttributes)> {
+        if self.fallback_families.is_empty() {
+            return vec![(text, attrs.clone())];
+        }
 
-                for font in fonts {
-                    let ids = self.font_system.db_mut().load_font_source(font);
+        let primary = self.query_font(&attrs.family, attrs);
+        let fallbacks: Vec<_> = (self.fallback_families.clone().into_iter())
+            .filter_map(|family| Some((self.query_font(&family, attrs)?, family)))
+            .collect();
+
+        let span = |text, family: Option<usize>| {
+            let family = match family {
+                Some(i) => fallbacks[i].1.clone(),
+                None => attrs.family.clone(),
+            };
+
+            let attrs = TextAttributes {
+                family,
+                ..attrs.clone()
+            };
+
+            (text, attrs)
+        };
 
-                    for id in ids {
-                        let face = self.font_system.db_mut().face(id).unwrap();
+        let mut spans = Vec::new();
+        let mut start = 0;
+        let mut current = None;
+        let mut joined = false;
 
-                        for (family, _) in &face.families {
-                            debug!("Loaded font family: {}", family);
-                        }
-                    }
+        for (i, c) in text.char_indices() {
+            // joiners and modifiers are part of the previous character, e.g. in emoji sequences
+            if joined || continues_cluster(c) {
+                joined = c == '\u{200d}';
+                continue;
+            }
+
+            let family = match c.is_whitespace() || contains(primary.as_deref(), c) {
+                true => None,
+                false => fallbacks
+                    .iter()
+                    .position(|(font, _)| contains(Some(font), c)),
+            };
+
+            if family != current {
+                if i > start {
+                    spans.push(span(&text[start..i], current));
                 }
+
+                start = i;
+                current = family;
             }
         }
 
-        Ok(())
+        if start < text.len() || spans.is_empty() {
+            spans.push(span(&text[start..], current));
+        }
+
+        spans
+    }
+
+    fn query_font(&mut self, family: &FontFamily, attrs: &TextAttributes) -> Option<Arc<Font>> {
+        let query = fontdb::Query {
+            families: &[family.as_fontdb()],
+            weight: attrs.weight.to_fontdb(),
+            stretch: attrs.stretch.to_fontdb(),
+            style: attrs.style.to_fontdb(),
+        };
+
+        let id = self.font_system.db().query(&query)?;
+        self.font_system.get_font(id)
+    }
+
+    /// Measure the size of `text`, wrapped with `wrap` at `width`, without keeping a
+    /// [`TextBuffer`].
+    ///
+    /// Measurements are cached, so measuring the same text repeatedly, e.g. during layout,
+    /// only shapes it once. The cache is cleared when fonts are loaded, see
+    /// [`Fonts::clear_measurements`].
+    ///
+    /// [`TextBuffer`]: super::TextBuffer
+    pub fn measure(
+        &mut self,
+        text: &str,
+        attrs: &TextAttributes,
+        font_size: f32,
+        line_height: f32,
+        wrap: TextWrap,
+        width: f32,
+    ) -> Size {
+        let metrics = [font_size.to_bits(), line_height.to_bits(), width.to_bits()];
+
+        let mut hasher = seahash::SeaHasher::new();
+        (text, attrs, metrics, wrap).hash(&mut hasher);
+        let hash = hasher.finish();
+
+        if let Some(measurement) = self.measurements.get(&hash) {
+            if *measurement.text == *text
+                && measurement.attrs == *attrs
+                && measurement.metrics == metrics
+                && measurement.wrap == wrap
+            {
+                return measurement.size;
+            }
+        }
+
+        let mut buffer = TextBuffer::new(self, font_size, line_height);
+        buffer.set_wrap(self, wrap);
+        buffer.set_bounds(self, Size::new(width, f32::INFINITY));
+        buffer.set_text(self, text, attrs.clone());
+        let size = buffer.size();
+
+        if self.measurements.len() >= MAX_MEASUREMENTS {
+            self.measurements.clear();
+        }
+
+        let measurement = Measurement {
+            text: text.into(),
+            attrs: attrs.clone(),
+            metrics,
+            wrap,
+            size,
+        };
+
+        self.measurements.insert(hash, measurement);
+        size
+    }
+
+    /// Clear the cached measurements of [`Fonts::measure`].
+    ///
+    /// This is done automatically when fonts are loaded with [`Fonts::load_font`], but has to
+    /// be done manually when the [`FontSystem`] is modified directly.
+    pub fn clear_measurements(&mut self) {
+        self.measurements.clear();
     }
 
     /// Calculates the size of a text buffer.
@@ -114,6 +329,12 @@ impl Fonts {
         Size::new(width, height).ceil()
     }
 
+    /// Hit-test a laid out `buffer` at a `point`, relative to the top left of the buffer,
+    /// see [`TextHit`].
+    pub fn hit(buffer: &Buffer, point: Point) -> Option<TextHit> {
+        TextHit::new(buffer, point)
+    }
+
     fn get_glyphs(&mut self, cache_key: CacheKey) -> Arc<Curve> {
         if let Some(curve) = self.curve_cache.get(&cache_key).cloned() {
             return curve;
@@ -191,7 +412,7 @@ impl Fonts {
         if low_performance && size < 64.0 {
             self.draw_buffer_bitmap(canvas, buffer, color, offset, scale);
         } else {
-            self.draw_buffer_outline(canvas, buffer, color, offset);
+            self.draw_buffer_outline(canvas, buffer, color, offset, scale);
         }
     }
 
@@ -201,6 +422,7 @@ impl Fonts {
         buffer: &Buffer,
         color: Color,
         offset: Vector,
+        scale: f32,
     ) {
         let mut paint = Paint::from(color);
         paint.anti_alias = AntiAlias::Full;
@@ -209,6 +431,14 @@ impl Fonts {
             for glyph in run.glyphs {
                 let physical = glyph.physical((0.0, 0.0), 1.0);
                 let curve = self.get_glyphs(physical.cache_key);
+
+                // color glyphs, e.g. emoji, are usually bitmaps without an outline
+                if curve.is_empty() {
+                    let physical = glyph.physical((offset.x, offset.y), scale);
+                    self.draw_glyph_bitmap(canvas, physical, run.line_y, color, scale, true);
+                    continue;
+                }
+
                 let offset = Vector::new(
                     glyph.x + glyph.x_offset,
                     glyph.y + run.line_y + glyph.y_offset,
@@ -232,44 +462,74 @@ impl Fonts {
         for run in buffer.layout_runs() {
             for glyph in run.glyphs {
                 let physical = glyph.physical((offset.x, offset.y), scale);
-                let atlas = self
-                    .font_atlas
-                    .insert(
-                        &mut self.font_system,
-                        &mut self.swash_cache,
-                        physical.cache_key,
-                    )
-                    .unwrap();
+                self.draw_glyph_bitmap(canvas, physical, run.line_y, color, scale, false);
+            }
+        }
+    }
 
-                let offset = Vector::new(
-                    physical.x as f32 + atlas.layout.min.x,
-                    physical.y as f32 + run.line_y * scale - atlas.layout.min.y,
-                );
+    fn draw_glyph_bitmap(
+        &mut self,
+        canvas: &mut Canvas,
+        physical: PhysicalGlyph,
+        line_y: f32,
+        color: Color,
+        scale: f32,
+        only_color: bool,
+    ) {
+        let Some(atlas) = self.font_atlas.insert(
+            &mut self.font_system,
+            &mut self.swash_cache,
+            physical.cache_key,
+        ) else {
+            return;
+        };
 
-                let rect = Rect::min_size(offset.to_point() / scale, atlas.layout.size() / scale);
+        if only_color && !atlas.color {
+            return;
+        }
 
-                let mut transform = Affine::IDENTITY;
-                transform *= Affine::translate(-rect.min.to_vector() + atlas.uv.offset() / scale);
-                transform *= Affine::scale(Vector::all(1.0 / scale));
+        let offset = Vector::new(
+            physical.x as f32 + atlas.layout.min.x,
+            physical.y as f32 + line_y * scale - atlas.layout.min.y,
+        );
 
-                let pattern = Pattern {
-                    image: self.font_atlas.image().clone(),
-                    transform,
-                    color,
-                };
+        let rect = Rect::min_size(offset.to_point() / scale, atlas.layout.size() / scale);
 
-                let paint = Paint {
-                    shader: Shader::Pattern(pattern),
-                    anti_alias: AntiAlias::None,
-                    ..Default::default()
-                };
+        let mut transform = Affine::IDENTITY;
+        transform *= Affine::translate(-rect.min.to_vector() + atlas.uv.offset() / scale);
+        transform *= Affine::scale(Vector::all(1.0 / scale));
 
-                canvas.rect(rect, paint);
-            }
-        }
+        let pattern = Pattern {
+            image: self.font_atlas.image().clone(),
+            transform,
+            color: atlas.tint(color),
+        };
+
+        let paint = Paint {
+            shader: Shader::Pattern(pattern),
+            anti_alias: AntiAlias::None,
+            ..Default::default()
+        };
+
+        canvas.rect(rect, paint);
     }
 }
 
+fn contains(font: Option<&Font>, c: char) -> bool {
+    font.is_some_and(|font| font.as_swash().charmap().map(c) != 0)
+}
+
+fn continues_cluster(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200d}'
+            | '\u{20e3}'
+            | '\u{fe00}'..='\u{fe0f}'
+            | '\u{1f3fb}'..='\u{1f3ff}'
+            | '\u{e0020}'..='\u{e007f}'
+    )
+}
+
 fn decompress_font_bundle(bytes: &[u8]) -> Vec<cosmic_text::fontdb::Source> {
     let mut fonts = Vec::new();
 
@@ -291,3 +551,88 @@ fn decompress_font_bundle(bytes: &[u8]) -> Vec<cosmic_text::fontdb::Source> {
 
     fonts
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fallback_spans() {
+        let mut fonts = Fonts::new();
+
+        let mono = TextAttributes {
+            family: FontFamily::Monospace,
+            ..Default::default()
+        };
+
+        let roboto = fonts.query_font(&FontFamily::from("Roboto"), &mono);
+        let roboto_mono = fonts.query_font(&mono.family, &mono);
+
+        // a character only contained by Roboto
+        let missing = (0x20..0x3000)
+            .filter_map(char::from_u32)
+            .find(|&c| contains(roboto.as_deref(), c) && !contains(roboto_mono.as_deref(), c))
+            .unwrap();
+
+        let text = format!("a{}b", missing);
+        assert_eq!(fonts.fallback_spans(&text, &mono).len(), 1);
+
+        fonts.set_fallback_families(&[FontFamily::from("Roboto")]);
+        let spans = fonts.fallback_spans(&text, &mono);
+
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0], ("a", mono.clone()));
+        assert_eq!(spans[1].0, missing.to_string());
+        assert_eq!(spans[1].1.family, FontFamily::from("Roboto"));
+        assert_eq!(spans[2], ("b", mono.clone()));
+    }
+
+    #[test]
+    fn load_font_named() {
+        let mut fonts = Fonts::new();
+
+        let attrs = TextAttributes {
+            family: FontFamily::from("Brand"),
+            ..Default::default()
+        };
+
+        assert!(fonts.query_font(&attrs.family, &attrs).is_none());
+
+        let data = include_bytes!("../../font/RobotoMono-Regular.ttf");
+        fonts.load_font_named("Brand", &data[..]).unwrap();
+
+        let font = fonts.query_font(&attrs.family, &attrs).unwrap();
+        let face = fonts.font_system.db().face(font.id()).unwrap();
+        assert!(face
+            .families
+            .iter()
+            .any(|(family, _)| family == "Roboto Mono"));
+    }
+
+    #[test]
+    fn measure_cache() {
+        let mut fonts = Fonts::new();
+        let attrs = TextAttributes::default();
+
+        let size = fonts.measure("hello", &attrs, 16.0, 1.2, TextWrap::Word, 100.0);
+        assert_eq!(fonts.measurements.len(), 1);
+
+        // a hit is served from the cache, without shaping the text again
+        let sentinel = Size::new(-1.0, -1.0);
+        fonts
+            .measurements
+            .values_mut()
+            .for_each(|m| m.size = sentinel);
+        let hit = fonts.measure("hello", &attrs, 16.0, 1.2, TextWrap::Word, 100.0);
+        assert_eq!(hit, sentinel);
+
+        // any other parameter is a miss
+        let miss = fonts.measure("hello", &attrs, 16.0, 1.2, TextWrap::Word, 200.0);
+        assert_eq!(miss, size);
+        assert_eq!(fonts.measurements.len(), 2);
+
+        fonts.clear_measurements();
+        let size = fonts.measure("hello", &attrs, 16.0, 1.2, TextWrap::Word, 100.0);
+        assert_ne!(size, sentinel);
+    }
+}
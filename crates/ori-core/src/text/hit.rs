@@ -0,0 +1,53 @@
+use cosmic_text::{Buffer, Cursor};
+
+use crate::layout::Point;
+
+/// The result of hit-testing laid out text, see [`Fonts::hit`](super::Fonts::hit).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TextHit {
+    /// The index of the line that was hit.
+    pub line: usize,
+
+    /// The byte index of the character boundary nearest to the point, within the line.
+    pub index: usize,
+
+    /// The byte offset of the character boundary nearest to the point, within the whole text.
+    pub offset: usize,
+
+    /// Whether the point is inside a glyph, and not e.g. past the end of a line.
+    pub inside: bool,
+}
+
+impl TextHit {
+    /// Hit-test a laid out `buffer` at a `point`, relative to the top left of the buffer.
+    ///
+    /// Points outside the text hit the nearest character boundary, returns `None` only if
+    /// the buffer hasn't been laid out.
+    pub fn new(buffer: &Buffer, point: Point) -> Option<Self> {
+        let cursor = buffer.hit(point.x, point.y)?;
+
+        let inside = buffer.layout_runs().any(|run| {
+            let in_line = (0.0..run.line_height).contains(&(point.y - run.line_top));
+            let mut glyphs = run.glyphs.iter();
+
+            in_line && glyphs.any(|glyph| (glyph.x..glyph.x + glyph.w).contains(&point.x))
+        });
+
+        let offset = (buffer.lines.iter().take(cursor.line))
+            .map(|line| line.text().len() + line.ending().as_str().len())
+            .sum::<usize>()
+            + cursor.index;
+
+        Some(Self {
+            line: cursor.line,
+            index: cursor.index,
+            offset,
+            inside,
+        })
+    }
+
+    /// Get the [`Cursor`] at the hit, e.g. to place the cursor of an editor.
+    pub fn cursor(self) -> Cursor {
+        Cursor::new(self.line, self.index)
+    }
+}
@@ -4,10 +4,12 @@ mod atlas;
 mod attributes;
 mod buffer;
 mod fonts;
+mod hit;
 mod source;
 
 pub use atlas::*;
 pub use attributes::*;
 pub use buffer::*;
 pub use fonts::*;
+pub use hit::*;
 pub use source::*;
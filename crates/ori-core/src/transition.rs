@@ -1,5 +1,12 @@
 //! Transition utilities.
 
+use std::hash::{Hash, Hasher};
+
+use crate::{
+    canvas::Color,
+    layout::{Point, Size, Vector},
+};
+
 /// Create a linear transition with the given `duration`.
 pub fn linear(duration: f32) -> Transition {
     Transition::linear(duration)
@@ -10,8 +17,13 @@ pub fn ease(duration: f32) -> Transition {
     Transition::ease(duration)
 }
 
+/// Create a spring transition with the given `stiffness` and `damping`.
+pub fn spring(stiffness: f32, damping: f32) -> Transition {
+    Transition::spring(stiffness, damping)
+}
+
 /// A transition easing curve.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, Default, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Easing {
     /// A linear transition curve.
@@ -20,15 +32,204 @@ pub enum Easing {
 
     /// An ease transition curve.
     Ease,
+
+    /// A spring transition curve, see [`Spring`].
+    Spring(Spring),
+
+    /// A custom transition curve, a function from `t` where `0 <= t <= 1` to the eased value.
+    ///
+    /// The function should map `0.0` to `0.0` and `1.0` to `1.0`, otherwise the transition
+    /// will jump at its start and end. Custom easings can't be serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(fn(f32) -> f32),
+}
+
+impl PartialEq for Easing {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Linear, Self::Linear) | (Self::Ease, Self::Ease) => true,
+            (Self::Spring(a), Self::Spring(b)) => a == b,
+            // the same function may have different addresses, and vice versa, but this is
+            // only used to detect changes, where a false negative just costs a redraw
+            (Self::Custom(a), Self::Custom(b)) => std::ptr::fn_addr_eq(*a, *b),
+            _ => false,
+        }
+    }
+}
+
+impl Hash for Easing {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            Self::Linear | Self::Ease => {}
+            Self::Spring(spring) => spring.hash(state),
+            Self::Custom(f) => std::ptr::hash(*f as *const (), state),
+        }
+    }
 }
 
 impl Easing {
     /// Evaluate the easing at `t` where `0 <= t <= 1`.
+    ///
+    /// Note that [`Easing::Spring`] can overshoot, returning values outside of `0..=1`.
     pub fn evaluate(self, t: f32) -> f32 {
         match self {
             Easing::Linear => t,
             Easing::Ease => t * t * (3.0 - 2.0 * t),
+            Easing::Spring(spring) => 1.0 - spring.displacement(t * spring.settle_time()),
+            Easing::Custom(f) => f(t),
+        }
+    }
+}
+
+/// A damped spring with unit mass.
+///
+/// The spring starts displaced by `1.0` at rest, and the motion is the solution of
+/// the spring equation `x'' = -stiffness * x - damping * x'`.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "SpringParams", into = "SpringParams"))]
+pub struct Spring {
+    stiffness: f32,
+    damping: f32,
+    // finding the settle time requires simulating the spring, so it's computed once
+    settle_time: f32,
+}
+
+// springs are compared by the bit patterns of their parameters, so that they can be `Eq`
+impl PartialEq for Spring {
+    fn eq(&self, other: &Self) -> bool {
+        self.stiffness.to_bits() == other.stiffness.to_bits()
+            && self.damping.to_bits() == other.damping.to_bits()
+    }
+}
+
+impl Eq for Spring {}
+
+impl Hash for Spring {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.stiffness.to_bits().hash(state);
+        self.damping.to_bits().hash(state);
+    }
+}
+
+// the serialized form of a spring, without the settle time
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct SpringParams {
+    stiffness: f32,
+    damping: f32,
+}
+
+#[cfg(feature = "serde")]
+impl From<SpringParams> for Spring {
+    fn from(params: SpringParams) -> Self {
+        Self::new(params.stiffness, params.damping)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<Spring> for SpringParams {
+    fn from(spring: Spring) -> Self {
+        Self {
+            stiffness: spring.stiffness,
+            damping: spring.damping,
+        }
+    }
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self::new(170.0, 26.0)
+    }
+}
+
+impl Spring {
+    /// The threshold below which the spring is considered at rest.
+    pub const REST_THRESHOLD: f32 = 0.001;
+
+    /// The maximum time a spring is allowed to take to come to rest.
+    pub const MAX_SETTLE_TIME: f32 = 10.0;
+
+    /// Create a new spring with the given `stiffness` and `damping`.
+    pub fn new(stiffness: f32, damping: f32) -> Self {
+        let mut spring = Self {
+            stiffness: stiffness.max(f32::EPSILON),
+            damping: damping.max(0.0),
+            settle_time: 0.0,
+        };
+
+        spring.settle_time = spring.simulate_settle_time();
+        spring
+    }
+
+    /// Get the stiffness of the spring.
+    pub fn stiffness(self) -> f32 {
+        self.stiffness
+    }
+
+    /// Get the damping of the spring.
+    pub fn damping(self) -> f32 {
+        self.damping
+    }
+
+    /// Get the displacement of the spring at `time` seconds.
+    pub fn displacement(self, time: f32) -> f32 {
+        let omega = self.stiffness.sqrt();
+        let zeta = self.damping / (2.0 * omega);
+
+        if zeta < 1.0 {
+            // under damped
+            let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+            let envelope = f32::exp(-zeta * omega * time);
+
+            envelope
+                * (f32::cos(omega_d * time) + zeta * omega / omega_d * f32::sin(omega_d * time))
+        } else if zeta == 1.0 {
+            // critically damped
+            f32::exp(-omega * time) * (1.0 + omega * time)
+        } else {
+            // over damped
+            let root = (zeta * zeta - 1.0).sqrt();
+            let r1 = -omega * (zeta - root);
+            let r2 = -omega * (zeta + root);
+
+            (r2 * f32::exp(r1 * time) - r1 * f32::exp(r2 * time)) / (r2 - r1)
+        }
+    }
+
+    /// Get the velocity of the spring at `time` seconds.
+    pub fn velocity(self, time: f32) -> f32 {
+        const DT: f32 = 0.0001;
+        (self.displacement(time + DT) - self.displacement(time)) / DT
+    }
+
+    /// Get the time in seconds it takes for the spring to come to rest.
+    ///
+    /// The spring is at rest when both the displacement and the velocity are below
+    /// [`Spring::REST_THRESHOLD`].
+    pub fn settle_time(self) -> f32 {
+        self.settle_time
+    }
+
+    fn simulate_settle_time(self) -> f32 {
+        const STEP: f32 = 1.0 / 240.0;
+
+        let mut time = 0.0;
+
+        while time < Self::MAX_SETTLE_TIME {
+            let at_rest = self.displacement(time).abs() < Self::REST_THRESHOLD
+                && self.velocity(time).abs() < Self::REST_THRESHOLD;
+
+            if at_rest {
+                return time;
+            }
+
+            time += STEP;
         }
+
+        Self::MAX_SETTLE_TIME
     }
 }
 
@@ -65,7 +266,41 @@ impl Transition {
         }
     }
 
+    /// Create a transition with the given `duration` and a custom `easing` function.
+    ///
+    /// See [`Easing::Custom`] for the requirements of the function.
+    ///
+    /// # Example
+    /// ```
+    /// # use ori_core::transition::Transition;
+    /// let ease_in = Transition::custom(0.3, |t| t * t * t);
+    /// assert_eq!(ease_in.get(0.5), 0.125);
+    /// ```
+    pub fn custom(duration: f32, easing: fn(f32) -> f32) -> Self {
+        Self {
+            duration,
+            easing: Easing::Custom(easing),
+        }
+    }
+
+    /// Create a spring transition with the given `stiffness` and `damping`.
+    ///
+    /// The duration of the transition is the time it takes for the spring to come to rest,
+    /// see [`Spring::settle_time`].
+    pub fn spring(stiffness: f32, damping: f32) -> Self {
+        let spring = Spring::new(stiffness, damping);
+
+        Self {
+            duration: spring.settle_time(),
+            easing: Easing::Spring(spring),
+        }
+    }
+
     /// Step the transition.
+    ///
+    /// The transition continues from the current `t`, so when `on` changes mid-flight, it
+    /// reverses along the same curve without jumping. For springs this reverses the velocity,
+    /// use [`Animated`] to keep it instead.
     pub fn step(&self, t: &mut f32, on: bool, dt: f32) -> bool {
         let sign = if on { 1.0 } else { -1.0 };
         let step = sign * dt / self.duration;
@@ -91,6 +326,474 @@ impl Transition {
     /// The returned value is how _on_ the transition is at `t`.
     /// This is a range from 0.0 to 1.0.
     pub fn get(&self, t: f32) -> f32 {
-        self.easing.evaluate(t)
+        match self.easing {
+            // we already know the duration of the spring, so there's no need to compute it again
+            Easing::Spring(spring) => 1.0 - spring.displacement(t * self.duration),
+            easing => easing.evaluate(t),
+        }
+    }
+
+    /// Interpolate between two colors at `t`.
+    ///
+    /// The colors are interpolated perceptually in the oklab color space,
+    /// see [`Color::mix_oklab`].
+    pub fn mix(&self, t: f32, from: Color, to: Color) -> Color {
+        from.mix_oklab(to, self.get(t))
+    }
+}
+
+/// Whether the user prefers reduced motion, usually reported by the system.
+///
+/// This is stored in the contexts, see
+/// [`BaseCx::reduced_motion`](crate::context::BaseCx::reduced_motion). When enabled,
+/// transitions should complete instantly, see
+/// [`BaseCx::transition_delta`](crate::context::BaseCx::transition_delta), and indeterminate
+/// animations should be minimized.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReducedMotion(pub bool);
+
+/// A value animated on and off by a [`Transition`], which keeps its velocity when retargeted.
+///
+/// For most transitions this is equivalent to [`Transition::step`] and [`Transition::get`].
+/// Spring transitions are simulated instead, so when `on` changes mid-flight the spring keeps
+/// moving, and is pulled towards the new target.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Animated {
+    t: f32,
+    value: f32,
+    velocity: f32,
+}
+
+impl Animated {
+    /// The longest time step a spring is simulated with, for stability.
+    const MAX_STEP: f32 = 1.0 / 240.0;
+
+    /// Create a new animated value, at rest either on or off.
+    pub fn new(on: bool) -> Self {
+        let value = if on { 1.0 } else { 0.0 };
+
+        Self {
+            t: value,
+            value,
+            velocity: 0.0,
+        }
+    }
+
+    /// Get the value, how _on_ the animation is.
+    ///
+    /// This is a range from 0.0 to 1.0, though springs can overshoot.
+    pub fn get(&self) -> f32 {
+        self.value
+    }
+
+    /// Set the value, stopping any motion, e.g. when it was dragged by the user.
+    pub fn set(&mut self, value: f32) {
+        self.t = value.clamp(0.0, 1.0);
+        self.value = value;
+        self.velocity = 0.0;
+    }
+
+    /// Get the velocity of the value, per second.
+    pub fn velocity(&self) -> f32 {
+        self.velocity
+    }
+
+    /// Interpolate between two colors by the value, see [`Transition::mix`].
+    pub fn mix(&self, from: Color, to: Color) -> Color {
+        from.mix_oklab(to, self.value)
+    }
+
+    /// Check if the animation is complete, i.e. at rest at `on`.
+    pub fn complete(&self, on: bool) -> bool {
+        let target = if on { 1.0 } else { 0.0 };
+        self.value == target && self.velocity == 0.0
+    }
+
+    /// Step the animation towards `on` with a `transition`, returns `true` if it moved.
+    pub fn step(&mut self, transition: &Transition, on: bool, dt: f32) -> bool {
+        let Easing::Spring(spring) = transition.easing else {
+            let value = self.value;
+            let moved = transition.step(&mut self.t, on, dt);
+            self.value = transition.get(self.t);
+
+            self.velocity = match moved && dt > 0.0 {
+                true => (self.value - value) / dt,
+                false => 0.0,
+            };
+
+            return moved;
+        };
+
+        if self.complete(on) {
+            return false;
+        }
+
+        let target = if on { 1.0 } else { 0.0 };
+
+        // any spring settles within this time, so there's no need to simulate it, this happens
+        // when animations are disabled
+        if dt >= Spring::MAX_SETTLE_TIME {
+            self.value = target;
+            self.velocity = 0.0;
+            self.t = target;
+
+            return true;
+        }
+
+        let steps = (dt / Self::MAX_STEP).ceil().max(1.0);
+        let step = dt / steps;
+
+        for _ in 0..steps as usize {
+            let displacement = self.value - target;
+            let acceleration = -spring.stiffness * displacement - spring.damping * self.velocity;
+
+            self.velocity += acceleration * step;
+            self.value += self.velocity * step;
+        }
+
+        let at_rest = (self.value - target).abs() < Spring::REST_THRESHOLD
+            && self.velocity.abs() < Spring::REST_THRESHOLD;
+
+        if at_rest {
+            self.value = target;
+            self.velocity = 0.0;
+        }
+
+        // keep `t` close, in case the transition is changed to a different kind
+        self.t = self.value.clamp(0.0, 1.0);
+
+        true
+    }
+}
+
+/// A value that can be interpolated.
+pub trait Interpolate {
+    /// Interpolate between `self` and `other` at `t`, where `0 <= t <= 1`.
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self * (1.0 - t) + other * t
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.lerp(*other, t)
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, t),
+            self.height.interpolate(&other.height, t),
+        )
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self.mix_oklab(*other, t)
+    }
+}
+
+/// How [`Keyframes`] are played back.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Playback {
+    /// Play the keyframes once, and stay at the last keyframe.
+    #[default]
+    Once,
+
+    /// Play the keyframes from the start again when the end is reached.
+    Loop,
+
+    /// Play the keyframes forwards, then backwards, and repeat.
+    PingPong,
+}
+
+/// A single keyframe of [`Keyframes`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframe<T> {
+    /// The time of the keyframe in seconds.
+    pub time: f32,
+
+    /// The value of the keyframe.
+    pub value: T,
+
+    /// The easing of the segment leading up to the keyframe.
+    pub easing: Easing,
+}
+
+/// An animation that interpolates a value through several keyframes.
+///
+/// Keyframes are sampled with the time since the animation started, usually accumulated from
+/// the `dt` of [`Event::Animate`](crate::event::Event::Animate).
+///
+/// # Example
+/// ```
+/// # use ori_core::{transition::{Easing, Keyframes, Playback}};
+/// let mut pulse = Keyframes::new().playback(Playback::PingPong);
+/// pulse.add(0.0, 1.0, Easing::Linear);
+/// pulse.add(0.5, 1.2, Easing::Ease);
+///
+/// assert_eq!(pulse.sample(0.0), Some(1.0));
+/// assert_eq!(pulse.sample(1.0), Some(1.0));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyframes<T> {
+    keyframes: Vec<Keyframe<T>>,
+    playback: Playback,
+}
+
+impl<T> Default for Keyframes<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Keyframes<T> {
+    /// Create a new empty set of keyframes.
+    pub fn new() -> Self {
+        Self {
+            keyframes: Vec::new(),
+            playback: Playback::Once,
+        }
+    }
+
+    /// Set the playback mode.
+    pub fn playback(mut self, playback: Playback) -> Self {
+        self.playback = playback;
+        self
+    }
+
+    /// Add a keyframe at `time` seconds.
+    ///
+    /// The `easing` is used for the segment leading up to the keyframe.
+    pub fn add(&mut self, time: f32, value: T, easing: Easing) {
+        let index = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+
+        let keyframe = Keyframe {
+            time,
+            value,
+            easing,
+        };
+
+        self.keyframes.insert(index, keyframe);
+    }
+
+    /// Get the keyframes, sorted by time.
+    pub fn keyframes(&self) -> &[Keyframe<T>] {
+        &self.keyframes
+    }
+
+    /// Get the duration of a single pass through the keyframes.
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map_or(0.0, |keyframe| keyframe.time)
+    }
+
+    /// Check if the animation is complete at `time`.
+    ///
+    /// This is only ever `true` for [`Playback::Once`].
+    pub fn is_complete(&self, time: f32) -> bool {
+        self.playback == Playback::Once && time >= self.duration()
+    }
+
+    fn local_time(&self, time: f32) -> f32 {
+        let duration = self.duration();
+
+        if duration <= 0.0 {
+            return 0.0;
+        }
+
+        match self.playback {
+            Playback::Once => time.clamp(0.0, duration),
+            Playback::Loop => time.rem_euclid(duration),
+            Playback::PingPong => {
+                let time = time.rem_euclid(duration * 2.0);
+
+                match time > duration {
+                    true => duration * 2.0 - time,
+                    false => time,
+                }
+            }
+        }
+    }
+}
+
+impl<T: Interpolate + Clone> Keyframes<T> {
+    /// Sample the value at `time` seconds.
+    ///
+    /// Returns `None` if there are no keyframes.
+    pub fn sample(&self, time: f32) -> Option<T> {
+        let time = self.local_time(time);
+
+        let index = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.time <= time);
+
+        if index == 0 {
+            return self
+                .keyframes
+                .first()
+                .map(|keyframe| keyframe.value.clone());
+        }
+
+        let from = &self.keyframes[index - 1];
+
+        let Some(to) = self.keyframes.get(index) else {
+            return Some(from.value.clone());
+        };
+
+        let t = (time - from.time) / (to.time - from.time);
+        let t = to.easing.evaluate(t);
+
+        Some(from.value.interpolate(&to.value, t))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spring_settles() {
+        for (stiffness, damping) in [(170.0, 26.0), (100.0, 20.0), (100.0, 40.0)] {
+            let transition = Transition::spring(stiffness, damping);
+
+            assert!(transition.duration > 0.0);
+            assert!(transition.duration < Spring::MAX_SETTLE_TIME);
+            assert!(transition.get(0.0).abs() < 0.001);
+            assert!((transition.get(1.0) - 1.0).abs() < Spring::REST_THRESHOLD);
+        }
+    }
+
+    #[test]
+    fn spring_overshoots() {
+        let transition = Transition::spring(200.0, 5.0);
+
+        let max = (0..=100)
+            .map(|i| transition.get(i as f32 / 100.0))
+            .fold(0.0, f32::max);
+
+        assert!(max > 1.0);
+    }
+
+    #[test]
+    fn rapid_toggling_is_continuous() {
+        let transition = Transition::ease(0.2);
+        let dt = 1.0 / 60.0;
+
+        // the steepest the ease curve gets is 1.5 times the linear slope
+        let max_change = 1.5 * dt / transition.duration + 0.001;
+
+        let mut t = 0.0;
+        let mut value = transition.get(t);
+
+        for frame in 0..30 {
+            // on for three frames, then off for two
+            let on = frame % 5 < 3;
+            transition.step(&mut t, on, dt);
+
+            let next = transition.get(t);
+            assert!((next - value).abs() <= max_change);
+            value = next;
+        }
+
+        // every five frames the transition moves one frame further on
+        assert!((t - 6.0 * dt / transition.duration).abs() < 0.001);
+    }
+
+    #[test]
+    fn animated_spring_keeps_velocity() {
+        let transition = Transition::spring(170.0, 26.0);
+        let dt = 1.0 / 60.0;
+
+        let mut animated = Animated::new(false);
+
+        for _ in 0..5 {
+            animated.step(&transition, true, dt);
+        }
+
+        let value = animated.get();
+        assert!(animated.velocity() > 0.0);
+
+        // turning it off mid-flight slows it down, rather than reversing it
+        animated.step(&transition, false, dt);
+        assert!(animated.velocity() > 0.0);
+        assert!(animated.get() > value);
+
+        for _ in 0..600 {
+            animated.step(&transition, false, dt);
+        }
+
+        assert!(animated.complete(false));
+        assert!(!animated.step(&transition, false, dt));
+
+        // a time longer than any spring takes to settle, e.g. with animations disabled
+        animated.step(&transition, true, Spring::MAX_SETTLE_TIME);
+        assert!(animated.complete(true));
+    }
+
+    #[test]
+    fn custom_easing_steps() {
+        let transition = Transition::custom(0.5, |t| t * t);
+
+        let mut t = 0.0;
+        assert!(transition.step(&mut t, true, 0.25));
+        assert_eq!(transition.get(t), 0.25);
+
+        assert!(transition.step(&mut t, true, 0.5));
+        assert_eq!(transition.get(t), 1.0);
+        assert!(!transition.step(&mut t, true, 0.25));
+    }
+
+    #[test]
+    fn keyframes_sample() {
+        let mut keyframes = Keyframes::new();
+        keyframes.add(1.0, 10.0, Easing::Linear);
+        keyframes.add(0.0, 0.0, Easing::Linear);
+        keyframes.add(2.0, 0.0, Easing::Linear);
+
+        assert_eq!(keyframes.duration(), 2.0);
+        assert_eq!(keyframes.sample(-1.0), Some(0.0));
+        assert_eq!(keyframes.sample(0.5), Some(5.0));
+        assert_eq!(keyframes.sample(1.5), Some(5.0));
+        assert_eq!(keyframes.sample(3.0), Some(0.0));
+        assert!(keyframes.is_complete(2.0));
+    }
+
+    #[test]
+    fn keyframes_playback() {
+        let mut keyframes = Keyframes::new().playback(Playback::Loop);
+        keyframes.add(0.0, 0.0, Easing::Linear);
+        keyframes.add(1.0, 1.0, Easing::Linear);
+
+        assert_eq!(keyframes.sample(1.25), Some(0.25));
+        assert!(!keyframes.is_complete(10.0));
+
+        let keyframes = keyframes.playback(Playback::PingPong);
+
+        assert_eq!(keyframes.sample(1.25), Some(0.75));
+        assert_eq!(keyframes.sample(2.25), Some(0.25));
+    }
+
+    #[test]
+    fn keyframes_empty() {
+        let keyframes = Keyframes::<f32>::new();
+        assert_eq!(keyframes.sample(0.0), None);
     }
 }
@@ -112,6 +112,8 @@ where
         } else {
             *cx.view_state = Default::default();
             *state = self.dyn_build(&mut cx.as_build_cx(), data);
+
+            cx.layout();
         }
     }
 
@@ -3,12 +3,21 @@ use std::ops::{Deref, DerefMut};
 use crate::{
     canvas::Canvas,
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
-    event::{Event, FocusTarget},
+    event::{Event, FocusTarget, Visibility},
     layout::{Rect, Size, Space},
 };
 
 use super::{View, ViewState};
 
+/// A context enabling pixel snapping, where every [`Pod`] is positioned on whole device
+/// pixels, for crisp text and edges.
+///
+/// Can be enabled for a whole application, or a part of the view tree with
+/// [`pixel_snap`](crate::views::pixel_snap), see
+/// [`Affine::pixel_snap`](crate::layout::Affine::pixel_snap).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PixelSnap(pub bool);
+
 /// The state of a [`Pod`].
 pub struct State<T, V: View<T> + ?Sized> {
     content: V::State,
@@ -184,8 +193,13 @@ impl<V> Pod<V> {
         view_state.set_hovered(cx.window().is_hovered(view_state.id()));
         view_state.prepare();
 
+        let parent_offset = cx.view_state.snap_offset;
+        let (transform, offset) =
+            cx.snap_transform(cx.transform, parent_offset, view_state.transform);
+        view_state.snap_offset = offset;
+
         let mut new_cx = cx.child();
-        new_cx.transform *= view_state.transform;
+        new_cx.transform *= transform;
         new_cx.view_state = view_state;
 
         let handled = f(&mut new_cx, event);
@@ -198,12 +212,22 @@ impl<V> Pod<V> {
     }
 
     /// Call a closure with the [`LayoutCx`] provided by a pod.
+    ///
+    /// Views that haven't requested a layout, and are given the same space as last time, keep
+    /// their size without calling the closure. Since layout requests are propagated to the
+    /// ancestors of a view, only the views on the path to it are laid out again.
     pub(crate) fn layout_with(
         view_state: &mut ViewState,
         cx: &mut LayoutCx,
+        space: Space,
         f: impl FnOnce(&mut LayoutCx) -> Size,
     ) -> Size {
+        if !view_state.needs_layout() && view_state.prev_space == Some(space) {
+            return view_state.size;
+        }
+
         view_state.mark_layed_out();
+        view_state.prev_space = Some(space);
 
         let mut new_cx = cx.child();
         new_cx.view_state = view_state;
@@ -213,6 +237,9 @@ impl<V> Pod<V> {
     }
 
     /// Call a closure with the [`DrawCx`] provided by a pod.
+    ///
+    /// The closure isn't called when the view is outside the visible area, except once when
+    /// it's culled, if any of the views inside it track their visibility.
     pub(crate) fn draw_with(
         view_state: &mut ViewState,
         cx: &mut DrawCx,
@@ -220,14 +247,45 @@ impl<V> Pod<V> {
     ) {
         view_state.mark_drawn();
 
+        let parent_offset = cx.view_state.snap_offset;
+        let (transform, offset) =
+            cx.snap_transform(cx.transform, parent_offset, view_state.transform);
+        view_state.snap_offset = offset;
+
         // create the draw context
         let mut new_cx = cx.child();
         new_cx.view_state = view_state;
 
         // draw the content
-        new_cx.transformed(new_cx.view_state.transform, |cx| {
-            f(cx);
+        new_cx.transformed(transform, |cx| {
+            // views tracking their visibility are notified in the next animation frame, this
+            // is done before culling, so that they are notified when they're hidden
+            if cx.contains_property::<Visibility>() {
+                let fraction = cx.visible_fraction(cx.rect());
+
+                if let Some(visibility) = cx.get_property_mut::<Visibility>() {
+                    if visibility.fraction != fraction {
+                        visibility.fraction = fraction;
+                        cx.view_state.request_animate();
+                    }
+                }
+            }
+
+            // views outside the visible area aren't drawn, but neither would the views tracking
+            // their visibility inside them, so a view is drawn once more when it's culled
+            let culled = !cx.is_visible(cx.rect());
+            let notify = culled && !cx.view_state.culled && cx.view_state.tracks_visibility;
+            cx.view_state.culled = culled;
+
+            if !culled || notify {
+                f(cx);
+            }
         });
+
+        // animation frames can be requested while drawing
+        if view_state.needs_animate() {
+            cx.view_state.request_animate();
+        }
     }
 }
 
@@ -266,6 +324,9 @@ impl<T, V: View<T>> View<T> for Pod<V> {
     }
 
     fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        #[cfg(feature = "debug")]
+        crate::debug::record::<V>(cx, state.view_state.id(), crate::debug::ViewPass::Rebuild);
+
         Self::rebuild_with(&mut state.view_state, cx, |cx| {
             (self.view).rebuild(&mut state.content, cx, data, &old.view);
         });
@@ -290,7 +351,13 @@ impl<T, V: View<T>> View<T> for Pod<V> {
         data: &mut T,
         space: Space,
     ) -> Size {
-        Self::layout_with(&mut state.view_state, cx, |cx| {
+        Self::layout_with(&mut state.view_state, cx, space, |cx| {
+            #[cfg(feature = "debug")]
+            {
+                let id = cx.id();
+                crate::debug::record::<V>(cx, id, crate::debug::ViewPass::Layout);
+            }
+
             (self.view).layout(&mut state.content, cx, data, space)
         })
     }
@@ -301,24 +368,102 @@ impl<T, V: View<T>> View<T> for Pod<V> {
         let needs_draw = state.view_state.needs_draw();
 
         Self::draw_with(&mut state.view_state, cx, |cx| {
-            if !cx.is_visible(cx.rect()) {
-                return;
-            }
+            // a culled view is only drawn to notify the views tracking their visibility
+            let culled = !cx.is_visible(cx.rect());
+
+            // while inspecting, every view is drawn so that it can be recorded
+            #[cfg(feature = "debug")]
+            let inspecting = crate::debug::inspect_enter::<V>(cx);
+            #[cfg(not(feature = "debug"))]
+            let inspecting = false;
 
             // if the visible rect has changed since out last draw, we need to invalidate
             // the cached canvas, since content that previously wasn't visible might be now
             // and vice versa.
             //
             // this fixes a bug with the scroll view
-            if needs_draw || state.prev_visible != cx.visible {
+            if needs_draw || inspecting || culled || state.prev_visible != cx.visible {
                 // if the view needs to be drawn we draw it and save the canvas
                 (self.view).draw(&mut state.content, cx, data);
+
+                #[cfg(feature = "debug")]
+                {
+                    let id = cx.id();
+                    let rect = cx.rect().transform(cx.transform());
+
+                    crate::debug::record::<V>(cx, id, crate::debug::ViewPass::Draw);
+
+                    if let Some(stats) = cx.get_context_mut::<crate::debug::ViewStats>() {
+                        stats.record_rect(id, rect);
+                    }
+                }
+
                 state.prev_canvas = cx.canvas.clone();
                 state.prev_visible = cx.visible;
             } else {
                 // if the view doesn't need to be drawn we just draw the saved canvas
                 *cx.canvas = state.prev_canvas.clone();
             }
+
+            #[cfg(feature = "debug")]
+            if inspecting {
+                crate::debug::inspect_exit(cx);
+            }
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{context::DrawCx, views::testing::ViewTester};
+
+    use super::*;
+
+    // a view that counts how many times it's laid out
+    struct Counter;
+
+    impl View<usize> for Counter {
+        type State = ();
+
+        fn build(&mut self, _cx: &mut BuildCx, _data: &mut usize) -> Self::State {}
+
+        fn rebuild(&mut self, _: &mut (), _cx: &mut RebuildCx, _data: &mut usize, _old: &Self) {}
+
+        fn event(&mut self, _: &mut (), _: &mut EventCx, _: &mut usize, _: &Event) -> bool {
+            false
+        }
+
+        fn layout(&mut self, _: &mut (), _: &mut LayoutCx, data: &mut usize, space: Space) -> Size {
+            *data += 1;
+            space.min
+        }
+
+        fn draw(&mut self, _: &mut (), _: &mut DrawCx, _: &mut usize) {}
+    }
+
+    #[test]
+    fn layout_is_cached() {
+        let mut view = Pod::new(Counter);
+        let mut layouts = 0;
+
+        let mut tester = ViewTester::new(&mut view, &mut layouts);
+        let small = Space::new(Size::all(10.0), Size::all(100.0));
+        let large = Space::new(Size::all(20.0), Size::all(200.0));
+
+        tester.layout(&mut view, &mut layouts, small);
+        tester.layout(&mut view, &mut layouts, small);
+        assert_eq!(layouts, 1);
+
+        // a different space lays out the view again
+        assert_eq!(
+            tester.layout(&mut view, &mut layouts, large),
+            Size::all(20.0)
+        );
+        assert_eq!(layouts, 2);
+
+        // and so does requesting a layout
+        tester.state.request_layout();
+        tester.layout(&mut view, &mut layouts, large);
+        assert_eq!(layouts, 3);
+    }
+}
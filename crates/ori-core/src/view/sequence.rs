@@ -569,7 +569,7 @@ impl<V> PodSeq<V> {
     where
         V: ViewSeq<T>,
     {
-        Pod::<V>::layout_with(&mut state.view_state[n], cx, |cx| {
+        Pod::<V>::layout_with(&mut state.view_state[n], cx, space, |cx| {
             (self.views).layout_nth(n, &mut state.content, cx, data, space)
         })
     }
@@ -585,10 +585,6 @@ impl<V> PodSeq<V> {
         V: ViewSeq<T>,
     {
         Pod::<V>::draw_with(&mut state.view_state[n], cx, |cx| {
-            if !cx.is_visible(cx.rect()) {
-                return;
-            }
-
             (self.views).draw_nth(n, &mut state.content, cx, data)
         });
     }
@@ -2,12 +2,15 @@ use std::{
     any::Any,
     fmt::{Debug, Display},
     num::NonZero,
-    sync::atomic::{AtomicU64, Ordering},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Weak,
+    },
 };
 
 use crate::{
-    event::Ime,
-    layout::{Affine, Point, Rect, Size, Vector},
+    event::{Ime, Visibility},
+    layout::{Affine, Point, Rect, Size, Space, Vector},
     window::Cursor,
 };
 
@@ -127,6 +130,8 @@ pub struct ViewState {
     /* layout */
     pub(crate) size: Size,
     pub(crate) transform: Affine,
+    pub(crate) snap_offset: Vector,
+    pub(crate) prev_space: Option<Space>,
 
     /* cursor */
     pub(crate) cursor: Option<Cursor>,
@@ -135,6 +140,15 @@ pub struct ViewState {
     /* ime */
     pub(crate) ime: Option<Ime>,
     pub(crate) inherited_ime: Option<Ime>,
+
+    /* visibility */
+    // whether the view or any of its descendants has tracked its visibility
+    pub(crate) tracks_visibility: bool,
+    pub(crate) culled: bool,
+
+    /* timers */
+    // dropped with the view state, stopping the timers started by the view
+    pub(crate) alive: Option<Arc<()>>,
 }
 
 impl Default for ViewState {
@@ -160,6 +174,8 @@ impl ViewState {
             /* layout */
             size: Size::ZERO,
             transform: Affine::IDENTITY,
+            snap_offset: Vector::ZERO,
+            prev_space: None,
 
             /* cursor */
             cursor: None,
@@ -168,15 +184,27 @@ impl ViewState {
             /* ime */
             ime: None,
             inherited_ime: None,
+
+            /* visibility */
+            tracks_visibility: false,
+            culled: false,
+
+            /* timers */
+            alive: None,
         }
     }
 
+    // get a handle that is alive as long as the view state
+    pub(crate) fn alive(&mut self) -> Weak<()> {
+        Arc::downgrade(self.alive.get_or_insert_with(Arc::default))
+    }
+
     /// Prepare the view.
     pub fn prepare(&mut self) {
         self.flags.remove(ViewFlags::HAS);
         self.flags |= self.flags.has();
 
-        self.inherited_cursor = self.cursor;
+        self.inherited_cursor = None;
         self.inherited_ime = self.ime.clone();
     }
 
@@ -184,8 +212,10 @@ impl ViewState {
     pub fn propagate(&mut self, child: &mut Self) {
         self.update |= child.update;
         self.flags |= child.flags.has();
-        self.inherited_cursor = self.cursor().or(child.cursor());
+        self.inherited_cursor = child.cursor().or(self.inherited_cursor);
         self.inherited_ime = self.ime().or(child.ime()).cloned();
+        self.tracks_visibility |=
+            child.tracks_visibility || child.properties.contains::<Visibility>();
     }
 
     /// Get the id of the view.
@@ -383,8 +413,11 @@ impl ViewState {
     }
 
     /// Get the cursor of the view.
+    ///
+    /// The cursor of a child view takes precedence, so the innermost view with a cursor
+    /// decides the cursor.
     pub fn cursor(&self) -> Option<Cursor> {
-        self.cursor.or(self.inherited_cursor)
+        self.inherited_cursor.or(self.cursor)
     }
 
     /// Set the cursor of the view.
@@ -479,4 +512,26 @@ mod tests {
         assert_eq!(ViewFlags::FOCUSED.has(), ViewFlags::HAS_FOCUSED);
         assert_eq!(ViewFlags::ACTIVE.has(), ViewFlags::HAS_ACTIVE);
     }
+
+    #[test]
+    fn innermost_cursor() {
+        let mut parent = ViewState::default();
+        let mut child = ViewState::default();
+        let mut sibling = ViewState::default();
+
+        parent.set_cursor(Some(Cursor::Pointer));
+        child.set_cursor(Some(Cursor::Text));
+
+        parent.prepare();
+        parent.propagate(&mut child);
+        parent.propagate(&mut sibling);
+        assert_eq!(parent.cursor(), Some(Cursor::Text));
+
+        child.set_cursor(None);
+
+        parent.prepare();
+        parent.propagate(&mut child);
+        parent.propagate(&mut sibling);
+        assert_eq!(parent.cursor(), Some(Cursor::Pointer));
+    }
 }
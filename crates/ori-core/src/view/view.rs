@@ -88,13 +88,15 @@ impl<T, V: View<T>> View<T> for Option<V> {
         if let Some(view) = self {
             if state.is_none() {
                 *state = Some(view.build(&mut cx.as_build_cx(), data));
+                cx.layout();
             }
 
             if let Some(old_view) = old {
                 view.rebuild(state.as_mut().unwrap(), cx, data, old_view);
             }
-        } else {
+        } else if state.is_some() {
             *state = None;
+            cx.layout();
         }
     }
 
@@ -2,7 +2,7 @@ use crate::{
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
     event::Event,
     layout::{Size, Space},
-    transition::Transition,
+    transition::{Animated, Transition},
     view::View,
 };
 
@@ -17,24 +17,26 @@ pub fn animate<T, V, S>(
 pub fn transition_hovered<T, V>(
     transition: Transition,
     mut view: impl FnMut(&mut EventCx, &mut T, f32) -> V + 'static,
-) -> Animate<T, V, f32> {
+) -> Animate<T, V, Animated> {
     let mut built = false;
 
-    animate(move |t: &mut f32, cx, data: &mut T, event| {
+    animate(move |t: &mut Animated, cx, data: &mut T, event| {
         if cx.is_hovered() || cx.has_hovered_changed() {
             cx.animate();
         }
 
         if let Event::Animate(dt) = event {
-            if transition.step(t, cx.is_hovered() || cx.has_hovered(), *dt) {
+            let dt = cx.transition_delta(*dt);
+
+            if t.step(&transition, cx.is_hovered() || cx.has_hovered(), dt) {
                 cx.animate();
-                return Some(view(cx, data, transition.get(*t)));
+                return Some(view(cx, data, t.get()));
             }
         }
 
         if !built {
             built = true;
-            Some(view(cx, data, transition.get(*t)))
+            Some(view(cx, data, t.get()))
         } else {
             None
         }
@@ -45,24 +47,26 @@ pub fn transition_hovered<T, V>(
 pub fn transition_active<T, V>(
     transition: Transition,
     mut view: impl FnMut(&mut EventCx, &mut T, f32) -> V + 'static,
-) -> Animate<T, V, f32> {
+) -> Animate<T, V, Animated> {
     let mut built = false;
 
-    animate(move |t: &mut f32, cx, data: &mut T, event| {
+    animate(move |t: &mut Animated, cx, data: &mut T, event| {
         if cx.active_changed() || cx.has_active_changed() {
             cx.animate();
         }
 
         if let Event::Animate(dt) = event {
-            if transition.step(t, cx.is_active() || cx.has_active(), *dt) {
+            let dt = cx.transition_delta(*dt);
+
+            if t.step(&transition, cx.is_active() || cx.has_active(), dt) {
                 cx.animate();
-                return Some(view(cx, data, transition.get(*t)));
+                return Some(view(cx, data, t.get()));
             }
         }
 
         if !built {
             built = true;
-            Some(view(cx, data, transition.get(*t)))
+            Some(view(cx, data, t.get()))
         } else {
             None
         }
@@ -73,24 +77,26 @@ pub fn transition_active<T, V>(
 pub fn transition_focused<T, V>(
     transition: Transition,
     mut view: impl FnMut(&mut EventCx, &mut T, f32) -> V + 'static,
-) -> Animate<T, V, f32> {
+) -> Animate<T, V, Animated> {
     let mut built = false;
 
-    animate(move |t: &mut f32, cx, data: &mut T, event| {
+    animate(move |t: &mut Animated, cx, data: &mut T, event| {
         if cx.focused_changed() || cx.has_focused_changed() {
             cx.animate();
         }
 
         if let Event::Animate(dt) = event {
-            if transition.step(t, cx.is_focused() || cx.has_focused(), *dt) {
+            let dt = cx.transition_delta(*dt);
+
+            if t.step(&transition, cx.is_focused() || cx.has_focused(), dt) {
                 cx.animate();
-                return Some(view(cx, data, transition.get(*t)));
+                return Some(view(cx, data, t.get()));
             }
         }
 
         if !built {
             built = true;
-            Some(view(cx, data, transition.get(*t)))
+            Some(view(cx, data, t.get()))
         } else {
             None
         }
@@ -102,20 +108,22 @@ pub fn transition<T, V>(
     transition: Transition,
     active: bool,
     mut view: impl FnMut(&mut EventCx, &mut T, f32) -> V + 'static,
-) -> Animate<T, V, f32> {
+) -> Animate<T, V, Animated> {
     let mut built = false;
 
-    animate(move |t: &mut f32, cx, data: &mut T, event| {
+    animate(move |t: &mut Animated, cx, data: &mut T, event| {
         if let Event::Animate(dt) = event {
-            if transition.step(t, active, *dt) {
+            let dt = cx.transition_delta(*dt);
+
+            if t.step(&transition, active, dt) {
                 cx.animate();
-                return Some(view(cx, data, transition.get(*t)));
+                return Some(view(cx, data, t.get()));
             }
         }
 
         if !built {
             built = true;
-            Some(view(cx, data, transition.get(*t)))
+            Some(view(cx, data, t.get()))
         } else {
             None
         }
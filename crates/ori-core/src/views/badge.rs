@@ -0,0 +1,249 @@
+use ori_macro::{Build, Styled};
+use smol_str::{format_smolstr, SmolStr};
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{pt, Padding, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    text::{FontFamily, FontWeight, Fonts, TextAttributes, TextBuffer},
+    view::{Pod, State, View},
+};
+
+/// Create a new [`Badge`].
+pub fn badge<V>(view: V, label: impl Into<SmolStr>) -> Badge<V> {
+    Badge::new(view, label)
+}
+
+/// Create a new [`Badge`] displaying a `count`, hidden when the count is zero.
+///
+/// Counts above `99` are displayed as `99+`.
+pub fn badge_count<V>(view: V, count: usize) -> Badge<V> {
+    Badge::new(view, count_label(count))
+}
+
+fn count_label(count: usize) -> SmolStr {
+    match count {
+        0 => SmolStr::default(),
+        1..=99 => format_smolstr!("{}", count),
+        _ => SmolStr::new_static("99+"),
+    }
+}
+
+/// A view that displays a small label, e.g. a notification count, on the top right
+/// corner of its content.
+///
+/// The badge is centered on the corner of the content and drawn on top of it, but doesn't
+/// take part in the layout. When the label is empty, the badge is hidden.
+///
+/// Can be styled using the [`BadgeStyle`].
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     badge_count(button(text("Inbox")), data.unread)
+/// }
+/// ```
+#[derive(Styled, Build, Rebuild)]
+pub struct Badge<V> {
+    /// The content.
+    #[build(ignore)]
+    pub content: Pod<V>,
+
+    /// The label of the badge.
+    #[rebuild(draw)]
+    pub label: SmolStr,
+
+    /// The padding of the label.
+    #[rebuild(draw)]
+    #[styled(default = Padding::new(1.0, 5.0, 1.0, 5.0))]
+    pub padding: Styled<Padding>,
+
+    /// The font size of the label.
+    #[rebuild(draw)]
+    #[styled(default = pt(9.0))]
+    pub font_size: Styled<f32>,
+
+    /// The font family of the label.
+    #[rebuild(draw)]
+    #[styled(default)]
+    pub font_family: Styled<FontFamily>,
+
+    /// The font weight of the label.
+    #[rebuild(draw)]
+    #[styled(default = FontWeight::BOLD)]
+    pub font_weight: Styled<FontWeight>,
+
+    /// The color of the label.
+    #[rebuild(draw)]
+    #[styled(default = Color::WHITE)]
+    pub color: Styled<Color>,
+
+    /// The background color of the badge.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::DANGER or Color::RED)]
+    pub background: Styled<Color>,
+
+    /// The border width of the badge.
+    #[rebuild(draw)]
+    #[styled(default)]
+    pub border_width: Styled<BorderWidth>,
+
+    /// The border color of the badge.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::BACKGROUND or Color::WHITE)]
+    pub border_color: Styled<Color>,
+}
+
+impl<V> Badge<V> {
+    /// Create a new [`Badge`].
+    pub fn new(content: V, label: impl Into<SmolStr>) -> Self {
+        Self {
+            content: Pod::new(content),
+            label: label.into(),
+            padding: BadgeStyle::PADDING.into(),
+            font_size: BadgeStyle::FONT_SIZE.into(),
+            font_family: BadgeStyle::FONT_FAMILY.into(),
+            font_weight: BadgeStyle::FONT_WEIGHT.into(),
+            color: BadgeStyle::COLOR.into(),
+            background: BadgeStyle::BACKGROUND.into(),
+            border_width: BadgeStyle::BORDER_WIDTH.into(),
+            border_color: BadgeStyle::BORDER_COLOR.into(),
+        }
+    }
+
+    fn set_attributes(&self, fonts: &mut Fonts, buffer: &mut TextBuffer, style: &BadgeStyle) {
+        buffer.set_metrics(fonts, style.font_size, 1.0);
+        buffer.set_text(
+            fonts,
+            &self.label,
+            TextAttributes {
+                family: style.font_family.clone(),
+                weight: style.font_weight,
+                ..Default::default()
+            },
+        );
+    }
+}
+
+#[doc(hidden)]
+pub struct BadgeState {
+    pub buffer: TextBuffer,
+    pub style: BadgeStyle,
+}
+
+impl<T, V: View<T>> View<T> for Badge<V> {
+    type State = (BadgeState, State<T, V>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let style = BadgeStyle::styled(self, cx.styles());
+
+        let mut state = BadgeState {
+            buffer: TextBuffer::new(cx.fonts(), style.font_size, 1.0),
+            style,
+        };
+
+        state.buffer.set_bounds(cx.fonts(), Size::INFINITY);
+        self.set_attributes(cx.fonts(), &mut state.buffer, &state.style);
+
+        (state, self.content.build(cx, data))
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        let font_size = state.style.font_size;
+        let font_family = state.style.font_family.clone();
+        let font_weight = state.style.font_weight;
+
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+
+        if self.label != old.label
+            || state.style.font_size != font_size
+            || state.style.font_family != font_family
+            || state.style.font_weight != font_weight
+        {
+            self.set_attributes(cx.fonts(), &mut state.buffer, &state.style);
+        }
+
+        self.content.rebuild(content, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        (_, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        self.content.event(content, cx, data, event)
+    }
+
+    fn layout(
+        &mut self,
+        (_, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        self.content.layout(content, cx, data, space)
+    }
+
+    fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.content.draw(content, cx, data);
+
+        if self.label.is_empty() {
+            return;
+        }
+
+        let mut size = state.buffer.size() + state.style.padding.size();
+
+        // a single character is displayed as a circle
+        size.width = size.width.max(size.height);
+
+        let center = content.rect().top_right();
+        let rect = Rect::center_size(center, size).round();
+
+        cx.quad(
+            rect,
+            state.style.background,
+            BorderRadius::all(size.height / 2.0),
+            state.style.border_width,
+            state.style.border_color,
+        );
+
+        let text = state.buffer.size();
+        let offset = Vector::new(
+            (size.width - text.width) / 2.0,
+            (size.height - text.height) / 2.0,
+        );
+
+        cx.text(
+            &state.buffer,
+            state.style.color,
+            rect.min.to_vector() + offset,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_labels() {
+        assert_eq!(count_label(0), "");
+        assert_eq!(count_label(7), "7");
+        assert_eq!(count_label(99), "99");
+        assert_eq!(count_label(100), "99+");
+    }
+}
@@ -1,13 +1,13 @@
 use ori_macro::{example, Build, Styled};
 
 use crate::{
-    canvas::{BorderRadius, BorderWidth, Color},
+    canvas::{BorderRadius, BorderWidth, Color, Shadow},
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
     event::Event,
     layout::{Padding, Size, Space, Vector},
     rebuild::Rebuild,
     style::{Styled, Theme},
-    transition::Transition,
+    transition::{Animated, Transition},
     view::{Pod, State, View},
 };
 
@@ -60,6 +60,11 @@ pub struct Button<V> {
     #[rebuild(draw)]
     #[styled(default -> Theme::OUTLINE or Color::BLACK)]
     pub border_color: Styled<Color>,
+
+    /// The shadow of the button, e.g. [`Theme::ELEVATION_1`].
+    #[rebuild(draw)]
+    #[styled(default -> Theme::ELEVATION_0 or Shadow::NONE)]
+    pub shadow: Styled<Shadow>,
 }
 
 impl<V> Button<V> {
@@ -74,14 +79,15 @@ impl<V> Button<V> {
             border_radius: ButtonStyle::BORDER_RADIUS.into(),
             border_width: ButtonStyle::BORDER_WIDTH.into(),
             border_color: ButtonStyle::BORDER_COLOR.into(),
+            shadow: ButtonStyle::SHADOW.into(),
         }
     }
 }
 
 #[doc(hidden)]
 pub struct ButtonState {
-    pub hovered: f32,
-    pub active: f32,
+    pub hovered: Animated,
+    pub active: Animated,
     pub style: ButtonStyle,
 }
 
@@ -92,8 +98,8 @@ impl<T, V: View<T>> View<T> for Button<V> {
         cx.set_focusable(true);
 
         let state = ButtonState {
-            hovered: 0.0,
-            active: 0.0,
+            hovered: Animated::default(),
+            active: Animated::default(),
             style: ButtonStyle::styled(self, cx.styles()),
         };
 
@@ -131,8 +137,9 @@ impl<T, V: View<T>> View<T> for Button<V> {
         }
 
         if let Event::Animate(dt) = event {
-            let hover = (state.style.transition).step(&mut state.hovered, cx.is_hovered(), *dt);
-            let active = (state.style.transition).step(&mut state.active, cx.is_active(), *dt);
+            let dt = cx.transition_delta(*dt);
+            let hover = (state.hovered).step(&state.style.transition, cx.is_hovered(), dt);
+            let active = (state.active).step(&state.style.transition, cx.is_active(), dt);
 
             if hover || active {
                 cx.animate();
@@ -165,19 +172,15 @@ impl<T, V: View<T>> View<T> for Button<V> {
             let dim = state.style.color.darken(0.025);
             let bright = state.style.color.lighten(0.05);
 
-            let hovered = state.style.transition.get(state.hovered);
-            let active = state.style.transition.get(state.active);
+            let hovered = state.hovered.get();
+            let active = state.active.get();
 
             let face = state.style.color.mix(bright, hovered).mix(dim, active);
 
+            cx.shadow(cx.rect(), state.style.border_radius, state.style.shadow);
+
             if cx.is_focused() {
-                cx.quad(
-                    cx.rect().expand(2.0),
-                    Color::TRANSPARENT,
-                    state.style.border_radius.expand(2.0),
-                    BorderWidth::all(2.0),
-                    cx.styles().get_or(Color::BLUE, Theme::INFO),
-                );
+                cx.draw_focus_ring(cx.rect(), state.style.border_radius);
             }
 
             if state.style.fancy == 0.0 {
@@ -0,0 +1,203 @@
+use ori_macro::{Build, Styled};
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color, Shadow},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Padding, Size, Space},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    transition::{Animated, Transition},
+    view::{Pod, State, View},
+};
+
+/// Create a new [`Card`].
+pub fn card<V>(view: V) -> Card<V> {
+    Card::new(view)
+}
+
+/// A card, a raised surface that groups content.
+///
+/// Can be styled using the [`CardStyle`].
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// fn ui(_data: &mut Data) -> impl View<Data> {
+///     card(text("Hello, World!"))
+///         .hoverable(true)
+///         .hover_shadow(Theme::ELEVATION_4)
+/// }
+/// ```
+#[derive(Styled, Build, Rebuild)]
+pub struct Card<V> {
+    /// The content.
+    #[build(ignore)]
+    pub content: Pod<V>,
+
+    /// The padding.
+    #[rebuild(layout)]
+    #[styled(default = Padding::all(16.0))]
+    pub padding: Styled<Padding>,
+
+    /// The background color.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::SURFACE_HIGH or Color::WHITE)]
+    pub background: Styled<Color>,
+
+    /// The border radius.
+    #[rebuild(draw)]
+    #[styled(default = BorderRadius::all(8.0))]
+    pub border_radius: Styled<BorderRadius>,
+
+    /// The border width.
+    #[rebuild(draw)]
+    #[styled(default)]
+    pub border_width: Styled<BorderWidth>,
+
+    /// The border color.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::OUTLINE_LOW or Color::BLACK)]
+    pub border_color: Styled<Color>,
+
+    /// The shadow of the card.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::ELEVATION_2 or Shadow::NONE)]
+    pub shadow: Styled<Shadow>,
+
+    /// Whether the card is raised to [`Card::hover_shadow`] when hovered.
+    #[rebuild(draw)]
+    #[styled(default = false)]
+    pub hoverable: Styled<bool>,
+
+    /// The shadow of the card when hovered, if [`Card::hoverable`] is set.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::ELEVATION_3 or Shadow::NONE)]
+    pub hover_shadow: Styled<Shadow>,
+
+    /// The transition between the shadows.
+    #[rebuild(draw)]
+    #[styled(default = Transition::ease(0.1))]
+    pub transition: Styled<Transition>,
+}
+
+impl<V> Card<V> {
+    /// Create a new [`Card`].
+    pub fn new(content: V) -> Self {
+        Self {
+            content: Pod::new(content),
+            padding: CardStyle::PADDING.into(),
+            background: CardStyle::BACKGROUND.into(),
+            border_radius: CardStyle::BORDER_RADIUS.into(),
+            border_width: CardStyle::BORDER_WIDTH.into(),
+            border_color: CardStyle::BORDER_COLOR.into(),
+            shadow: CardStyle::SHADOW.into(),
+            hoverable: CardStyle::HOVERABLE.into(),
+            hover_shadow: CardStyle::HOVER_SHADOW.into(),
+            transition: CardStyle::TRANSITION.into(),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct CardState {
+    pub hovered: Animated,
+    pub style: CardStyle,
+}
+
+impl<T, V: View<T>> View<T> for Card<V> {
+    type State = (CardState, State<T, V>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let state = CardState {
+            hovered: Animated::default(),
+            style: CardStyle::styled(self, cx.styles()),
+        };
+
+        (state, self.content.build(cx, data))
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+
+        self.content.rebuild(content, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        let handled = self.content.event(content, cx, data, event);
+
+        if !state.style.hoverable {
+            return handled;
+        }
+
+        if cx.hovered_changed() {
+            cx.animate();
+        }
+
+        if let Event::Animate(dt) = event {
+            let dt = cx.transition_delta(*dt);
+
+            if (state.hovered).step(&state.style.transition, cx.is_hovered(), dt) {
+                cx.animate();
+            }
+
+            cx.draw();
+        }
+
+        handled
+    }
+
+    fn layout(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let content_space = space.shrink(state.style.padding.size());
+        let content_size = self.content.layout(content, cx, data, content_space);
+
+        content.translate(state.style.padding.offset());
+
+        space.fit(content_size + state.style.padding.size())
+    }
+
+    fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        let style = &state.style;
+
+        let mut draw = |cx: &mut DrawCx| {
+            let hovered = state.hovered.get();
+            let shadow = style.shadow.mix(style.hover_shadow, hovered);
+
+            cx.shadow(cx.rect(), style.border_radius, shadow);
+            cx.quad(
+                cx.rect(),
+                style.background,
+                style.border_radius,
+                style.border_width,
+                style.border_color,
+            );
+
+            self.content.draw(content, cx, data);
+        };
+
+        match style.hoverable {
+            true => cx.hoverable(draw),
+            false => draw(cx),
+        }
+    }
+}
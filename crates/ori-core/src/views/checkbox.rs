@@ -7,7 +7,7 @@ use crate::{
     layout::{Point, Size, Space},
     rebuild::Rebuild,
     style::{Styled, Theme},
-    transition::Transition,
+    transition::{Animated, Transition},
     view::View,
 };
 
@@ -85,13 +85,13 @@ impl Checkbox {
 }
 
 impl<T> View<T> for Checkbox {
-    type State = (CheckboxStyle, f32);
+    type State = (CheckboxStyle, Animated);
 
     fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
         cx.set_focusable(true);
 
         let style = CheckboxStyle::styled(self, cx.styles());
-        (style, 0.0)
+        (style, Animated::default())
     }
 
     fn rebuild(
@@ -122,7 +122,7 @@ impl<T> View<T> for Checkbox {
 
         if let Event::Animate(dt) = event {
             let on = cx.is_hovered() && !cx.is_active();
-            if style.transition.step(t, on, *dt) {
+            if t.step(&style.transition, on, cx.transition_delta(*dt)) {
                 cx.animate();
             }
 
@@ -146,10 +146,11 @@ impl<T> View<T> for Checkbox {
         cx.hoverable(|cx| {
             let bright = style.border_color.lighten(0.2);
 
-            let border_color = match cx.is_focused() {
-                true => cx.styles().get_or(Color::BLUE, Theme::INFO),
-                false => style.border_color.mix(bright, style.transition.get(*t)),
-            };
+            let border_color = t.mix(style.border_color, bright);
+
+            if cx.is_focused() {
+                cx.draw_focus_ring(cx.rect(), style.border_radius);
+            }
 
             cx.quad(
                 cx.rect(),
@@ -0,0 +1,164 @@
+use std::mem;
+
+use crate::{
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Size, Space},
+    style::{class_key, Styles},
+    view::View,
+};
+
+/// Create a view that applies the style class `name` to its content, while `active`.
+///
+/// See [`Class`] for more information.
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// struct Data {
+///     email: String,
+/// }
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     let input = text_input().text(&data.email);
+///
+///     class("error", !data.email.contains('@'), input)
+/// }
+///
+/// fn main() {
+///     let error = Styles::new()
+///         .with_style(TextInputStyle::COLOR, Theme::DANGER)
+///         .with_style(TextInputStyle::PLACEHOLDER_COLOR, Theme::DANGER);
+///
+///     let app = App::build()
+///         .style(Styles::new().with_class("error", error))
+///         .window(Window::new(), ui);
+///
+///     let mut data = Data { email: String::new() };
+///     ori::run(app, &mut data).unwrap();
+/// }
+/// ```
+pub fn class<V>(name: &str, active: bool, content: V) -> Class<V> {
+    Class::new(name, active, content)
+}
+
+/// A view that applies a style class to its content.
+///
+/// Classes are registered with [`Styles::insert_class`], and are looked up in the
+/// styles active where the view is built, so they can be overridden like any other style.
+///
+/// # Precedence
+/// When active, the styles of the class are applied on top of the surrounding styles,
+/// so they take precedence over styles from the theme, [`with_style`] views above the
+/// class, and classes applied further out. Styles applied inside the class take precedence
+/// over the class. Inline values set directly on a view, e.g. `.color(Color::RED)`,
+/// are never looked up in the styles, and always take precedence over classes.
+///
+/// Toggling `active` rebuilds the content with the new styles.
+///
+/// [`with_style`]: crate::views::with_style
+pub struct Class<V> {
+    /// The content view.
+    pub content: V,
+
+    /// Whether the class is applied.
+    pub active: bool,
+
+    key: u64,
+}
+
+impl<V> Class<V> {
+    /// Create a new [`Class`] view.
+    pub fn new(name: &str, active: bool, content: V) -> Self {
+        Self {
+            content,
+            active,
+            key: class_key(name),
+        }
+    }
+
+    fn styles(&self, styles: &Styles) -> Styles {
+        let mut styles = styles.clone();
+
+        if !self.active {
+            return styles;
+        }
+
+        if let Some(class) = styles.get_class_key(self.key) {
+            let class = class.clone();
+            styles.extend(class);
+        }
+
+        styles
+    }
+}
+
+#[doc(hidden)]
+pub struct ClassState {
+    computed_styles: Styles,
+}
+
+impl<T, V: View<T>> View<T> for Class<V> {
+    type State = (ClassState, V::State);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let mut styles = self.styles(cx.styles());
+
+        mem::swap(&mut styles, cx.context_mut());
+        let content = self.content.build(cx, data);
+        mem::swap(&mut styles, cx.context_mut());
+
+        let state = ClassState {
+            computed_styles: styles,
+        };
+
+        (state, content)
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        state.computed_styles = self.styles(cx.styles());
+
+        mem::swap(&mut state.computed_styles, cx.context_mut());
+        self.content.rebuild(content, cx, data, &old.content);
+        mem::swap(&mut state.computed_styles, cx.context_mut());
+    }
+
+    fn event(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        mem::swap(&mut state.computed_styles, cx.context_mut());
+        let handled = self.content.event(content, cx, data, event);
+        mem::swap(&mut state.computed_styles, cx.context_mut());
+        handled
+    }
+
+    fn layout(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        mem::swap(&mut state.computed_styles, cx.context_mut());
+        let size = self.content.layout(content, cx, data, space);
+        mem::swap(&mut state.computed_styles, cx.context_mut());
+        size
+    }
+
+    fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        mem::swap(&mut state.computed_styles, cx.context_mut());
+        self.content.draw(content, cx, data);
+        mem::swap(&mut state.computed_styles, cx.context_mut());
+    }
+}
@@ -4,7 +4,7 @@ use ori_macro::Build;
 
 use crate::{
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
-    event::{Event, Key, PointerButton},
+    event::{Code, Event, Key, PointerButton},
     layout::{Size, Space},
     rebuild::Rebuild,
     view::{Pod, State, View},
@@ -102,18 +102,30 @@ where
     }
 }
 
+// whether a key activates a focused clickable
+fn is_activation(key: Key, code: Option<Code>) -> bool {
+    matches!(key, Key::Enter | Key::Space | Key::Character(' ')) || code == Some(Code::Space)
+}
+
 impl<T, V, F> View<T> for Clickable<T, V, F>
 where
     V: View<T>,
     F: FnMut(&mut EventCx, &mut T) + 'static,
 {
-    type State = State<T, V>;
+    // whether the content was activated with the keyboard, and the state of the content
+    type State = (bool, State<T, V>);
 
     fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
-        self.content.build(cx, data)
+        (false, self.content.build(cx, data))
     }
 
-    fn rebuild(&mut self, content: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+    fn rebuild(
+        &mut self,
+        (_, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
         Rebuild::rebuild(self, cx, old);
 
         self.content.rebuild(content, cx, data, &old.content);
@@ -121,7 +133,7 @@ where
 
     fn event(
         &mut self,
-        content: &mut Self::State,
+        (keyboard, content): &mut Self::State,
         cx: &mut EventCx,
         data: &mut T,
         event: &Event,
@@ -137,8 +149,11 @@ where
                 }
 
                 content.set_active(true);
+                *keyboard = false;
             }
-            Event::PointerReleased(e) if content.is_active() && self.is_button(e.button) => {
+            Event::PointerReleased(e)
+                if content.is_active() && !*keyboard && self.is_button(e.button) =>
+            {
                 if self.event == ClickEvent::Release {
                     (self.callback)(cx, data);
                     handled = true;
@@ -151,25 +166,29 @@ where
 
                 content.set_active(false);
             }
-            Event::KeyPressed(e) if content.is_focused() => {
-                if e.is_key(Key::Enter) || e.is_key(' ') {
-                    if matches!(self.event, ClickEvent::Press | ClickEvent::Click) {
+            // the keyboard behaves like a pointer, a click is a press followed by a release
+            Event::KeyPressed(e) if content.is_focused() && is_activation(e.key, e.code) => {
+                // ignore key repeats while held
+                if !content.is_active() {
+                    if self.event == ClickEvent::Press {
                         (self.callback)(cx, data);
-                        handled = true;
                     }
 
                     content.set_active(true);
+                    *keyboard = true;
                 }
-            }
-            Event::KeyReleased(e) if content.is_active() => {
-                if e.is_key(Key::Enter) || e.is_key(' ') {
-                    if self.event == ClickEvent::Release {
-                        (self.callback)(cx, data);
-                        handled = true;
-                    }
 
-                    content.set_active(false);
+                handled = true;
+            }
+            // only a press with the keyboard is completed by a key release
+            Event::KeyReleased(e) if *keyboard && is_activation(e.key, e.code) => {
+                if matches!(self.event, ClickEvent::Release | ClickEvent::Click) {
+                    (self.callback)(cx, data);
                 }
+
+                content.set_active(false);
+                *keyboard = false;
+                handled = true;
             }
             _ => {}
         }
@@ -179,7 +198,7 @@ where
 
     fn layout(
         &mut self,
-        content: &mut Self::State,
+        (_, content): &mut Self::State,
         cx: &mut LayoutCx,
         data: &mut T,
         space: Space,
@@ -187,7 +206,76 @@ where
         self.content.layout(content, cx, data, space)
     }
 
-    fn draw(&mut self, content: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+    fn draw(&mut self, (_, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
         self.content.draw(content, cx, data);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{KeyPressed, KeyReleased, Modifiers, PointerId, PointerPressed},
+        layout::Point,
+        views::{size, testing::ViewTester},
+    };
+
+    use super::*;
+
+    #[test]
+    fn keyboard_click() {
+        let mut view = on_click(size(10.0, ()), |_, clicks: &mut u32| *clicks += 1);
+        let mut clicks = 0;
+
+        let mut tester = ViewTester::new(&mut view, &mut clicks);
+        tester.state.1.set_focused(true);
+
+        let pressed = Event::KeyPressed(KeyPressed {
+            key: Key::Enter,
+            code: Some(Code::Enter),
+            text: None,
+            modifiers: Modifiers::default(),
+        });
+
+        let released = Event::KeyReleased(KeyReleased {
+            key: Key::Enter,
+            code: Some(Code::Enter),
+            modifiers: Modifiers::default(),
+        });
+
+        // the click fires on release, and repeats while held are ignored
+        tester.event(&mut view, &mut clicks, &pressed);
+        tester.event(&mut view, &mut clicks, &pressed);
+        assert_eq!(clicks, 0);
+
+        tester.event(&mut view, &mut clicks, &released);
+        assert_eq!(clicks, 1);
+    }
+
+    #[test]
+    fn pointer_press_ignores_key_release() {
+        let mut view = on_click(size(10.0, ()), |_, clicks: &mut u32| *clicks += 1);
+        let mut clicks = 0;
+
+        let mut tester = ViewTester::new(&mut view, &mut clicks);
+        tester.state.1.set_focused(true);
+        tester.state.1.set_hovered(true);
+
+        let pressed = Event::PointerPressed(PointerPressed {
+            id: PointerId::from_u64(0),
+            position: Point::ZERO,
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+        });
+
+        let released = Event::KeyReleased(KeyReleased {
+            key: Key::Enter,
+            code: Some(Code::Enter),
+            modifiers: Modifiers::default(),
+        });
+
+        tester.event(&mut view, &mut clicks, &pressed);
+        tester.event(&mut view, &mut clicks, &released);
+        assert_eq!(clicks, 0);
+        assert!(tester.state.1.is_active());
+    }
+}
@@ -9,7 +9,7 @@ use crate::{
     layout::{Affine, Point, Rect, Size, Space, Vector},
     rebuild::Rebuild,
     style::{Styled, Theme},
-    transition::Transition,
+    transition::{Animated, Transition},
     view::{Pod, State, View},
 };
 
@@ -111,7 +111,7 @@ pub struct CollapsingState<T, H: View<T>, V: View<T>> {
     header: State<T, H>,
     content: State<T, V>,
     open: bool,
-    t: f32,
+    t: Animated,
 }
 
 impl<T, H: View<T>, V: View<T>> View<T> for Collapsing<T, H, V> {
@@ -125,7 +125,7 @@ impl<T, H: View<T>, V: View<T>> View<T> for Collapsing<T, H, V> {
             header: self.header.build(cx, data),
             content: self.content.build(cx, data),
             open,
-            t: open as u32 as f32,
+            t: Animated::new(open),
         }
     }
 
@@ -168,7 +168,9 @@ impl<T, H: View<T>, V: View<T>> View<T> for Collapsing<T, H, V> {
                 }
             }
             Event::Animate(dt) => {
-                if state.style.transition.step(&mut state.t, state.open, *dt) {
+                let dt = cx.transition_delta(*dt);
+
+                if state.t.step(&state.style.transition, state.open, dt) {
                     cx.animate();
                     cx.layout();
                 }
@@ -189,7 +191,7 @@ impl<T, H: View<T>, V: View<T>> View<T> for Collapsing<T, H, V> {
         data: &mut T,
         space: Space,
     ) -> Size {
-        let t = state.style.transition.get(state.t);
+        let t = state.t.get();
 
         let header_space = space.loosen_height() - Size::new(state.style.icon_size, 0.0);
         let header_size = (self.header).layout(&mut state.header, cx, data, header_space);
@@ -212,7 +214,7 @@ impl<T, H: View<T>, V: View<T>> View<T> for Collapsing<T, H, V> {
     }
 
     fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
-        let t = state.style.transition.get(state.t);
+        let t = state.t.get();
 
         let header_height = state.style.icon_size.max(state.header.size().height);
         let header_size = Size::new(cx.rect().width(), header_height);
@@ -0,0 +1,564 @@
+use ori_macro::{Build, Styled};
+use smol_str::SmolStr;
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Code, Event, Key, Shortcut},
+    layout::{Padding, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    text::{Fonts, TextAttributes, TextBuffer},
+    view::View,
+};
+
+use super::{ListAction, ListNavigation};
+
+/// Create a new [`CommandPalette`].
+pub fn command_palette<T, V>(
+    content: V,
+    commands: impl IntoIterator<Item = PaletteCommand<T>>,
+) -> CommandPalette<T, V> {
+    CommandPalette::new(content, commands)
+}
+
+/// A command that opens the nearest [`CommandPalette`] when sent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct OpenCommandPalette;
+
+/// A named command that can be searched and invoked from a [`CommandPalette`].
+pub struct PaletteCommand<T> {
+    /// The unique id of the command.
+    pub id: SmolStr,
+
+    /// The title of the command, used for searching.
+    pub title: SmolStr,
+
+    /// The shortcut that invokes the command.
+    pub shortcut: Option<Shortcut>,
+
+    /// The callback called when the command is invoked.
+    #[allow(clippy::type_complexity)]
+    pub callback: Box<dyn FnMut(&mut EventCx, &mut T)>,
+}
+
+impl<T> PaletteCommand<T> {
+    /// Create a new [`PaletteCommand`].
+    pub fn new(
+        id: impl Into<SmolStr>,
+        title: impl Into<SmolStr>,
+        callback: impl FnMut(&mut EventCx, &mut T) + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            shortcut: None,
+            callback: Box::new(callback),
+        }
+    }
+
+    /// Set the shortcut of the command.
+    pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+}
+
+/// Score how well `pattern` fuzzy matches `text`, ignoring case.
+///
+/// Every character of `pattern` must appear in `text` in order. Consecutive characters
+/// and characters at the start of words score higher. Returns `None` if there is no match.
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<i32> {
+    let mut score = 0;
+    let mut chars = text.chars().enumerate();
+    let mut previous: Option<usize> = None;
+    let mut last_char = None;
+
+    for p in pattern.chars().filter(|c| !c.is_whitespace()) {
+        let p = p.to_lowercase().next()?;
+
+        loop {
+            let (index, c) = chars.next()?;
+            let word_start = !last_char.is_some_and(|l: char| l.is_alphanumeric());
+            last_char = Some(c);
+
+            if c.to_lowercase().next() != Some(p) {
+                continue;
+            }
+
+            score += 1;
+
+            if word_start {
+                score += 8;
+            }
+
+            match previous {
+                Some(previous) if previous + 1 == index => score += 4,
+                Some(previous) => score -= (index - previous) as i32 / 4,
+                None => score -= index as i32 / 4,
+            }
+
+            previous = Some(index);
+            break;
+        }
+    }
+
+    Some(score)
+}
+
+/// A view that shows a searchable list of commands over its content.
+///
+/// The palette is opened with [`Self::shortcut`], `Ctrl+Shift+P` by default, or by
+/// sending [`OpenCommandPalette`]. While open, typing filters the commands with
+/// [`fuzzy_match`], arrow keys select, `Enter` invokes and `Escape` closes.
+/// Keyboard and pointer events don't reach the content while the palette is open.
+///
+/// The shortcuts of the commands are shown in the palette, and invoke the commands
+/// directly when the key press isn't handled by the content.
+///
+/// Can be styled using the [`CommandPaletteStyle`].
+#[derive(Styled, Build, Rebuild)]
+pub struct CommandPalette<T, V> {
+    /// The content.
+    #[build(ignore)]
+    pub content: V,
+
+    /// The commands.
+    #[build(ignore)]
+    pub commands: Vec<PaletteCommand<T>>,
+
+    /// The shortcut that toggles the palette.
+    pub shortcut: Shortcut,
+
+    /// The placeholder shown when the search is empty.
+    #[rebuild(draw)]
+    pub placeholder: SmolStr,
+
+    /// The maximum number of commands shown.
+    #[rebuild(draw)]
+    pub max_items: usize,
+
+    /// The width of the palette.
+    #[rebuild(draw)]
+    #[styled(default = 480.0)]
+    pub width: Styled<f32>,
+
+    /// The padding of the palette.
+    #[rebuild(draw)]
+    #[styled(default = Padding::all(8.0))]
+    pub padding: Styled<Padding>,
+
+    /// The font size of the text.
+    #[rebuild(draw)]
+    #[styled(default = 14.0)]
+    pub font_size: Styled<f32>,
+
+    /// The color of the text.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::CONTRAST or Color::BLACK)]
+    pub color: Styled<Color>,
+
+    /// The color of the placeholder and shortcuts.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::CONTRAST_LOW or Color::grayscale(0.5))]
+    pub dim_color: Styled<Color>,
+
+    /// The background color of the palette.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::SURFACE_HIGHER or Color::WHITE)]
+    pub background: Styled<Color>,
+
+    /// The background color of the selected command.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::SURFACE_HIGHEST or Color::grayscale(0.9))]
+    pub highlight: Styled<Color>,
+
+    /// The border radius of the palette.
+    #[rebuild(draw)]
+    #[styled(default = BorderRadius::all(8.0))]
+    pub border_radius: Styled<BorderRadius>,
+
+    /// The border width of the palette.
+    #[rebuild(draw)]
+    #[styled(default = BorderWidth::all(1.0))]
+    pub border_width: Styled<BorderWidth>,
+
+    /// The border color of the palette.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::OUTLINE or Color::BLACK)]
+    pub border_color: Styled<Color>,
+}
+
+impl<T, V> CommandPalette<T, V> {
+    /// Create a new [`CommandPalette`].
+    pub fn new(content: V, commands: impl IntoIterator<Item = PaletteCommand<T>>) -> Self {
+        Self {
+            content,
+            commands: commands.into_iter().collect(),
+            shortcut: Shortcut::new(Code::P).ctrl().shift(),
+            placeholder: SmolStr::new_static("Search commands..."),
+            max_items: 8,
+            width: CommandPaletteStyle::WIDTH.into(),
+            padding: CommandPaletteStyle::PADDING.into(),
+            font_size: CommandPaletteStyle::FONT_SIZE.into(),
+            color: CommandPaletteStyle::COLOR.into(),
+            dim_color: CommandPaletteStyle::DIM_COLOR.into(),
+            background: CommandPaletteStyle::BACKGROUND.into(),
+            highlight: CommandPaletteStyle::HIGHLIGHT.into(),
+            border_radius: CommandPaletteStyle::BORDER_RADIUS.into(),
+            border_width: CommandPaletteStyle::BORDER_WIDTH.into(),
+            border_color: CommandPaletteStyle::BORDER_COLOR.into(),
+        }
+    }
+
+    /// Add a command.
+    pub fn command(mut self, command: PaletteCommand<T>) -> Self {
+        self.commands.push(command);
+        self
+    }
+
+    // find the commands matching the query, best match first
+    fn filter(&self, query: &str) -> Vec<usize> {
+        let mut matches = (self.commands.iter().enumerate())
+            .filter_map(|(i, command)| Some((i, fuzzy_match(query, &command.title)?)))
+            .collect::<Vec<_>>();
+
+        // sort_by_key is stable, so commands with equal scores keep their order
+        matches.sort_by_key(|(_, score)| -score);
+        matches.into_iter().map(|(i, _)| i).collect()
+    }
+
+    fn text_buffer(
+        &self,
+        fonts: &mut Fonts,
+        style: &CommandPaletteStyle,
+        text: &str,
+    ) -> TextBuffer {
+        let mut buffer = TextBuffer::new(fonts, style.font_size, 1.2);
+        buffer.set_text(fonts, text, TextAttributes::default());
+        buffer
+    }
+
+    // update the matches and the text buffers
+    fn update(&self, state: &mut CommandPaletteState, fonts: &mut Fonts) {
+        let style = &state.style;
+
+        state.matches = self.filter(&state.query);
+        state.matches.truncate(self.max_items);
+        state.navigation.set_len(state.matches.len());
+
+        if state.navigation.highlighted().is_none() {
+            state.navigation.highlight(Some(0));
+        }
+
+        state.query_buffer = match state.query.is_empty() {
+            true => self.text_buffer(fonts, style, &self.placeholder),
+            false => self.text_buffer(fonts, style, &state.query),
+        };
+
+        let items = state.matches.iter().map(|&i| {
+            let command = &self.commands[i];
+            let title = self.text_buffer(fonts, style, &command.title);
+            let shortcut =
+                (command.shortcut).map(|s| self.text_buffer(fonts, style, &s.to_string()));
+
+            (title, shortcut)
+        });
+
+        state.items = items.collect();
+    }
+
+    fn open(&self, state: &mut CommandPaletteState, cx: &mut EventCx) {
+        state.open = true;
+        state.query.clear();
+        state.navigation.highlight(Some(0));
+
+        self.update(state, cx.fonts());
+        cx.draw();
+    }
+
+    fn close(state: &mut CommandPaletteState, cx: &mut EventCx) {
+        state.open = false;
+        state.item_rects.clear();
+        cx.draw();
+    }
+
+    fn invoke(&mut self, state: &mut CommandPaletteState, cx: &mut EventCx, data: &mut T) {
+        Self::close(state, cx);
+
+        let highlighted = state.navigation.highlighted();
+
+        if let Some(&index) = highlighted.and_then(|i| state.matches.get(i)) {
+            (self.commands[index].callback)(cx, data);
+        }
+    }
+
+    // handle an event while the palette is open, returns true if the event was consumed
+    fn open_event(
+        &mut self,
+        state: &mut CommandPaletteState,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        match event {
+            Event::KeyPressed(e) => {
+                if self.shortcut.matches(e) || e.is_key(Key::Escape) {
+                    Self::close(state, cx);
+                    return true;
+                }
+
+                match state.navigation.key(e) {
+                    Some(ListAction::Activate(_)) => {
+                        self.invoke(state, cx, data);
+                        return true;
+                    }
+                    Some(ListAction::Highlight(_)) => {
+                        cx.draw();
+                        return true;
+                    }
+                    None => {}
+                }
+
+                match e.key {
+                    Key::Enter => self.invoke(state, cx, data),
+                    Key::Backspace => {
+                        state.query.pop();
+                        self.update(state, cx.fonts());
+                        cx.draw();
+                    }
+                    _ if !e.modifiers.ctrl && !e.modifiers.alt && !e.modifiers.meta => {
+                        if let Some(ref text) = e.text {
+                            state.query.extend(text.chars().filter(|c| !c.is_control()));
+                            state.navigation.highlight(Some(0));
+
+                            self.update(state, cx.fonts());
+                            cx.draw();
+                        }
+                    }
+                    _ => {}
+                }
+
+                true
+            }
+            Event::PointerMoved(e) => {
+                let local = cx.local(e.position);
+                let hovered = state.item_rects.iter().position(|r| r.contains(local));
+
+                if let Some(index) = hovered {
+                    if Some(index) != state.navigation.highlighted() {
+                        state.navigation.highlight(Some(index));
+                        cx.draw();
+                    }
+                }
+
+                true
+            }
+            Event::PointerPressed(e) => {
+                let local = cx.local(e.position);
+                let pressed = state.item_rects.iter().position(|r| r.contains(local));
+
+                match pressed {
+                    Some(index) => {
+                        state.navigation.highlight(Some(index));
+                        self.invoke(state, cx, data);
+                    }
+                    None if !state.panel.contains(local) => Self::close(state, cx),
+                    None => {}
+                }
+
+                true
+            }
+            Event::KeyReleased(_)
+            | Event::PointerReleased(_)
+            | Event::PointerScrolled(_)
+            | Event::PointerLeft(_) => true,
+            _ => false,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct CommandPaletteState {
+    style: CommandPaletteStyle,
+    open: bool,
+    query: String,
+    navigation: ListNavigation,
+    matches: Vec<usize>,
+    query_buffer: TextBuffer,
+    items: Vec<(TextBuffer, Option<TextBuffer>)>,
+    item_rects: Vec<Rect>,
+    panel: Rect,
+}
+
+impl<T, V: View<T>> View<T> for CommandPalette<T, V> {
+    type State = (CommandPaletteState, V::State);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let style = CommandPaletteStyle::styled(self, cx.styles());
+        let query_buffer = self.text_buffer(cx.fonts(), &style, "");
+
+        let state = CommandPaletteState {
+            style,
+            open: false,
+            query: String::new(),
+            navigation: ListNavigation::new(0),
+            matches: Vec::new(),
+            query_buffer,
+            items: Vec::new(),
+            item_rects: Vec::new(),
+            panel: Rect::ZERO,
+        };
+
+        (state, self.content.build(cx, data))
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+
+        // the commands might have changed
+        if state.open {
+            self.update(state, cx.fonts());
+            cx.draw();
+        }
+
+        (self.content).rebuild(content, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        if state.open && self.open_event(state, cx, data, event) {
+            return true;
+        }
+
+        if event.is_cmd::<OpenCommandPalette>() {
+            self.open(state, cx);
+            return true;
+        }
+
+        let handled = self.content.event(content, cx, data, event);
+
+        if let (false, Event::KeyPressed(e)) = (handled, event) {
+            if self.shortcut.matches(e) {
+                self.open(state, cx);
+                return true;
+            }
+
+            let command = (self.commands.iter_mut())
+                .find(|command| command.shortcut.is_some_and(|s| s.matches(e)));
+
+            if let Some(command) = command {
+                (command.callback)(cx, data);
+                return true;
+            }
+        }
+
+        handled
+    }
+
+    fn layout(
+        &mut self,
+        (_state, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        self.content.layout(content, cx, data, space)
+    }
+
+    fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.content.draw(content, cx, data);
+
+        if !state.open {
+            return;
+        }
+
+        let style = &state.style;
+        let window = cx.window().size;
+
+        let row_height = state.query_buffer.size().height + style.padding.size().height;
+        let width = f32::min(style.width, window.width - style.padding.size().width);
+        let height = row_height * (state.items.len() + 1) as f32;
+
+        // place the palette at the top center of the window
+        let origin = Point::new((window.width - width) / 2.0, window.height * 0.15);
+        state.panel = Rect::min_size(origin, Size::new(width, height));
+
+        state.item_rects = (0..state.items.len())
+            .map(|i| {
+                let min = origin + Vector::new(0.0, row_height * (i + 1) as f32);
+                Rect::min_size(min, Size::new(width, row_height))
+            })
+            .collect();
+
+        cx.overlay(1, |cx| {
+            cx.quad(
+                state.panel,
+                style.background,
+                style.border_radius,
+                style.border_width,
+                style.border_color,
+            );
+
+            let query_color = match state.query.is_empty() {
+                true => style.dim_color,
+                false => style.color,
+            };
+
+            let offset = Vector::from(origin) + style.padding.offset();
+            cx.text(&state.query_buffer, query_color, offset);
+
+            for (i, (title, shortcut)) in state.items.iter().enumerate() {
+                let rect = state.item_rects[i];
+
+                if Some(i) == state.navigation.highlighted() {
+                    cx.fill_rect(rect, style.highlight);
+                }
+
+                let offset = Vector::from(rect.min) + style.padding.offset();
+                cx.text(title, style.color, offset);
+
+                if let Some(shortcut) = shortcut {
+                    let x = rect.width() - style.padding.right - shortcut.size().width;
+                    let offset = Vector::from(rect.min) + Vector::new(x, style.padding.top);
+                    cx.text(shortcut, style.dim_color, offset);
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_order() {
+        assert!(fuzzy_match("sv", "Save").is_some());
+        assert!(fuzzy_match("vs", "Save").is_none());
+        assert!(fuzzy_match("", "Save").is_some());
+    }
+
+    #[test]
+    fn fuzzy_match_ranking() {
+        let word_start = fuzzy_match("ot", "Open Tab").unwrap();
+        let inner = fuzzy_match("ot", "Reboot").unwrap();
+        assert!(word_start > inner);
+
+        let consecutive = fuzzy_match("sav", "Save File").unwrap();
+        let scattered = fuzzy_match("sav", "Sea dive").unwrap();
+        assert!(consecutive > scattered);
+    }
+}
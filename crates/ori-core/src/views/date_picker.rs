@@ -0,0 +1,608 @@
+use ori_macro::{Build, Styled};
+
+use crate::{
+    canvas::{BorderRadius, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key},
+    layout::{Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    text::{Fonts, TextAttributes, TextBuffer},
+    view::View,
+};
+
+/// A date in the proleptic Gregorian calendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Date {
+    /// The year.
+    pub year: i32,
+
+    /// The month, from 1 to 12.
+    pub month: u32,
+
+    /// The day of the month, starting at 1.
+    pub day: u32,
+}
+
+impl Default for Date {
+    fn default() -> Self {
+        Self::new(1970, 1, 1)
+    }
+}
+
+impl Date {
+    /// Create a new [`Date`], clamping `month` and `day` to valid values.
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        let month = month.clamp(1, 12);
+        let day = day.clamp(1, Self::days_in_month(year, month));
+
+        Self { year, month, day }
+    }
+
+    /// Check if `year` is a leap year.
+    pub fn is_leap_year(year: i32) -> bool {
+        year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+    }
+
+    /// Get the number of days in a `month` of `year`.
+    pub fn days_in_month(year: i32, month: u32) -> u32 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Get the number of days since 1970-01-01.
+    pub fn days_since_epoch(self) -> i64 {
+        // see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+        let year = self.year as i64 - (self.month <= 2) as i64;
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let month = (self.month as i64 + 9) % 12;
+        let day_of_year = (153 * month + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146097 + day_of_era - 719468
+    }
+
+    /// Create a [`Date`] from the number of days since 1970-01-01.
+    pub fn from_days_since_epoch(days: i64) -> Self {
+        // see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719468;
+        let era = days.div_euclid(146097);
+        let day_of_era = days - era * 146097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+        let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+        Self {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+
+    /// Get the day of the week, where 0 is Monday and 6 is Sunday.
+    pub fn weekday(self) -> u32 {
+        // 1970-01-01 was a Thursday
+        (self.days_since_epoch() + 3).rem_euclid(7) as u32
+    }
+
+    /// Add a number of `days` to the date.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Add a number of `months` to the date, clamping the day to the new month.
+    pub fn add_months(self, months: i32) -> Self {
+        let month = self.year * 12 + self.month as i32 - 1 + months;
+        Self::new(
+            month.div_euclid(12),
+            month.rem_euclid(12) as u32 + 1,
+            self.day,
+        )
+    }
+
+    /// Get the first day of the month.
+    pub fn first_of_month(self) -> Self {
+        Self::new(self.year, self.month, 1)
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [&str; 7] = ["Mo", "Tu", "We", "Th", "Fr", "Sa", "Su"];
+
+/// Create a new [`DatePicker`].
+pub fn date_picker<T>(selected: Date) -> DatePicker<T> {
+    DatePicker::new(selected)
+}
+
+/// A calendar showing a month, where a day can be selected.
+///
+/// The arrows in the header move between months. When focused, the arrow keys move the
+/// highlighted day, `PageUp` and `PageDown` move between months, and `Enter` or `Space`
+/// selects the highlighted day.
+///
+/// Can be styled using the [`DatePickerStyle`].
+#[derive(Styled, Build, Rebuild)]
+pub struct DatePicker<T> {
+    /// The selected date.
+    #[build(ignore)]
+    #[rebuild(draw)]
+    pub selected: Date,
+
+    /// A callback that is called when a date is selected.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub on_select: Option<Box<dyn FnMut(&mut EventCx, &mut T, Date)>>,
+
+    /// The size of a day in the calendar.
+    #[rebuild(layout)]
+    #[styled(default = 32.0)]
+    pub cell_size: Styled<f32>,
+
+    /// The font size of the text.
+    #[rebuild(layout)]
+    #[styled(default = 14.0)]
+    pub font_size: Styled<f32>,
+
+    /// The color of the text.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::CONTRAST or Color::BLACK)]
+    pub color: Styled<Color>,
+
+    /// The color of the weekdays.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::CONTRAST_LOW or Color::grayscale(0.5))]
+    pub dim_color: Styled<Color>,
+
+    /// The background color of the selected day.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::PRIMARY or Color::BLUE)]
+    pub selected_color: Styled<Color>,
+
+    /// The color of the text of the selected day.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::BACKGROUND or Color::WHITE)]
+    pub selected_text_color: Styled<Color>,
+
+    /// The background color of hovered days and arrows.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::SURFACE_HIGHER or Color::grayscale(0.9))]
+    pub hover_color: Styled<Color>,
+
+    /// The color of the outline of the highlighted day, when focused.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::OUTLINE or Color::BLACK)]
+    pub highlight_color: Styled<Color>,
+
+    /// The border radius of the days.
+    #[rebuild(draw)]
+    #[styled(default = BorderRadius::all(4.0))]
+    pub border_radius: Styled<BorderRadius>,
+}
+
+impl<T> DatePicker<T> {
+    /// Create a new [`DatePicker`].
+    pub fn new(selected: Date) -> Self {
+        Self {
+            selected,
+            on_select: None,
+            cell_size: DatePickerStyle::CELL_SIZE.into(),
+            font_size: DatePickerStyle::FONT_SIZE.into(),
+            color: DatePickerStyle::COLOR.into(),
+            dim_color: DatePickerStyle::DIM_COLOR.into(),
+            selected_color: DatePickerStyle::SELECTED_COLOR.into(),
+            selected_text_color: DatePickerStyle::SELECTED_TEXT_COLOR.into(),
+            hover_color: DatePickerStyle::HOVER_COLOR.into(),
+            highlight_color: DatePickerStyle::HIGHLIGHT_COLOR.into(),
+            border_radius: DatePickerStyle::BORDER_RADIUS.into(),
+        }
+    }
+
+    /// Set the callback that is called when a date is selected.
+    pub fn on_select(
+        mut self,
+        on_select: impl FnMut(&mut EventCx, &mut T, Date) + 'static,
+    ) -> Self {
+        self.on_select = Some(Box::new(on_select));
+        self
+    }
+
+    fn text_buffer(fonts: &mut Fonts, style: &DatePickerStyle, text: &str) -> TextBuffer {
+        let mut buffer = TextBuffer::new(fonts, style.font_size, 1.2);
+        buffer.set_text(fonts, text, TextAttributes::default());
+        buffer
+    }
+
+    // create the title, the only text that depends on the shown month
+    fn update_title(state: &mut DatePickerState, fonts: &mut Fonts) {
+        let month = state.month;
+        let title = format!("{} {}", MONTHS[month.month as usize - 1], month.year);
+        state.title = Self::text_buffer(fonts, &state.style, &title);
+    }
+
+    // create the text buffers, these only depend on the style and the shown month
+    fn update(state: &mut DatePickerState, fonts: &mut Fonts) {
+        Self::update_title(state, fonts);
+
+        let style = &state.style;
+        state.prev = Self::text_buffer(fonts, style, "<");
+        state.next = Self::text_buffer(fonts, style, ">");
+
+        state.weekdays = WEEKDAYS
+            .iter()
+            .map(|weekday| Self::text_buffer(fonts, style, weekday))
+            .collect();
+
+        state.days = (1..=31)
+            .map(|day| Self::text_buffer(fonts, style, &day.to_string()))
+            .collect();
+    }
+
+    fn select(&mut self, cx: &mut EventCx, data: &mut T, date: Date) {
+        if let Some(ref mut on_select) = self.on_select {
+            on_select(cx, data, date);
+        }
+    }
+}
+
+// a part of the calendar that can be clicked
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DatePickerPart {
+    Prev,
+    Next,
+    Day(Date),
+}
+
+#[doc(hidden)]
+pub struct DatePickerState {
+    style: DatePickerStyle,
+    month: Date,
+    highlighted: Date,
+    hovered: Option<DatePickerPart>,
+    title: TextBuffer,
+    prev: TextBuffer,
+    next: TextBuffer,
+    weekdays: Vec<TextBuffer>,
+    days: Vec<TextBuffer>,
+}
+
+impl DatePickerState {
+    fn cell(&self, column: u32, row: u32) -> Rect {
+        let size = self.style.cell_size;
+        let min = Point::new(column as f32 * size, row as f32 * size);
+        Rect::min_size(min, Size::all(size))
+    }
+
+    // the first row is the header, the second the weekdays, and then six rows of days
+    fn day_cell(&self, date: Date) -> Rect {
+        let slot = self.month.weekday() + date.day - 1;
+        self.cell(slot % 7, slot / 7 + 2)
+    }
+
+    fn part_rect(&self, part: DatePickerPart) -> Rect {
+        match part {
+            DatePickerPart::Prev => self.cell(0, 0),
+            DatePickerPart::Next => self.cell(6, 0),
+            DatePickerPart::Day(date) => self.day_cell(date),
+        }
+    }
+
+    fn part_at(&self, local: Point) -> Option<DatePickerPart> {
+        let size = self.style.cell_size;
+
+        if local.x < 0.0 || local.y < 0.0 || local.x >= size * 7.0 {
+            return None;
+        }
+
+        let column = (local.x / size) as u32;
+        let row = (local.y / size) as u32;
+
+        match (column, row) {
+            (0, 0) => Some(DatePickerPart::Prev),
+            (6, 0) => Some(DatePickerPart::Next),
+            (_, 2..=7) => {
+                let slot = (row - 2) * 7 + column;
+                let day = slot.checked_sub(self.month.weekday())? + 1;
+
+                let days = Date::days_in_month(self.month.year, self.month.month);
+                let date = Date::new(self.month.year, self.month.month, day);
+                (day <= days).then_some(DatePickerPart::Day(date))
+            }
+            _ => None,
+        }
+    }
+
+    // highlight a date, showing its month
+    fn highlight(&mut self, date: Date) -> bool {
+        self.highlighted = date;
+
+        let month = date.first_of_month();
+        let changed = month != self.month;
+        self.month = month;
+
+        changed
+    }
+}
+
+fn centered(buffer: &TextBuffer, rect: Rect) -> Vector {
+    rect.center() - buffer.rect().center()
+}
+
+impl<T> View<T> for DatePicker<T> {
+    type State = DatePickerState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
+        let style = DatePickerStyle::styled(self, cx.styles());
+        let fonts = cx.fonts();
+
+        let mut state = DatePickerState {
+            title: TextBuffer::new(fonts, style.font_size, 1.2),
+            prev: TextBuffer::new(fonts, style.font_size, 1.2),
+            next: TextBuffer::new(fonts, style.font_size, 1.2),
+            weekdays: Vec::new(),
+            days: Vec::new(),
+            style,
+            month: self.selected.first_of_month(),
+            highlighted: self.selected,
+            hovered: None,
+        };
+
+        Self::update(&mut state, cx.fonts());
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        let font_size = state.style.font_size;
+
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+
+        let month_changed = self.selected != old.selected && state.highlight(self.selected);
+
+        if state.style.font_size != font_size {
+            Self::update(state, cx.fonts());
+        } else if month_changed {
+            Self::update_title(state, cx.fonts());
+        }
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        if cx.focused_changed() {
+            cx.draw();
+        }
+
+        match event {
+            Event::PointerMoved(e) => {
+                let hovered = match cx.is_hovered() {
+                    true => state.part_at(cx.local(e.position)),
+                    false => None,
+                };
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    cx.draw();
+                }
+
+                false
+            }
+            Event::PointerPressed(e) if cx.is_hovered() => {
+                cx.focus();
+
+                let month_changed = match state.part_at(cx.local(e.position)) {
+                    Some(DatePickerPart::Prev) => {
+                        let date = state.highlighted.add_months(-1);
+                        state.highlight(date)
+                    }
+                    Some(DatePickerPart::Next) => {
+                        let date = state.highlighted.add_months(1);
+                        state.highlight(date)
+                    }
+                    Some(DatePickerPart::Day(date)) => {
+                        let changed = state.highlight(date);
+                        self.select(cx, data, date);
+                        changed
+                    }
+                    None => false,
+                };
+
+                if month_changed {
+                    Self::update_title(state, cx.fonts());
+                }
+
+                // the hovered day might have moved with the month
+                state.hovered = state.part_at(cx.local(e.position));
+                cx.draw();
+
+                true
+            }
+            Event::KeyPressed(e) if cx.is_focused() => {
+                let date = match e.key {
+                    Key::Left => state.highlighted.add_days(-1),
+                    Key::Right => state.highlighted.add_days(1),
+                    Key::Up => state.highlighted.add_days(-7),
+                    Key::Down => state.highlighted.add_days(7),
+                    Key::PageUp => state.highlighted.add_months(-1),
+                    Key::PageDown => state.highlighted.add_months(1),
+                    Key::Enter | Key::Space => {
+                        let date = state.highlighted;
+                        self.select(cx, data, date);
+                        return true;
+                    }
+                    _ => return false,
+                };
+
+                if state.highlight(date) {
+                    Self::update_title(state, cx.fonts());
+                }
+
+                cx.draw();
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        _cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        let size = state.style.cell_size;
+        space.fit(Size::new(size * 7.0, size * 8.0))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        cx.hoverable(|cx| {
+            cx.trigger(cx.rect());
+
+            let style = &state.style;
+
+            if let Some(hovered) = state.hovered {
+                let rect = state.part_rect(hovered);
+                cx.quad(
+                    rect,
+                    style.hover_color,
+                    style.border_radius,
+                    0.0,
+                    Color::TRANSPARENT,
+                );
+            }
+
+            /* draw the header */
+            let header = Rect::min_size(
+                Point::ZERO,
+                Size::new(style.cell_size * 7.0, style.cell_size),
+            );
+            cx.text(&state.title, style.color, centered(&state.title, header));
+
+            let prev = state.cell(0, 0);
+            cx.text(&state.prev, style.color, centered(&state.prev, prev));
+
+            let next = state.cell(6, 0);
+            cx.text(&state.next, style.color, centered(&state.next, next));
+
+            for (i, weekday) in state.weekdays.iter().enumerate() {
+                let rect = state.cell(i as u32, 1);
+                cx.text(weekday, style.dim_color, centered(weekday, rect));
+            }
+
+            /* draw the days */
+            let month = state.month;
+            let days = Date::days_in_month(month.year, month.month);
+
+            for (i, buffer) in state.days.iter().take(days as usize).enumerate() {
+                let date = Date::new(month.year, month.month, i as u32 + 1);
+                let rect = state.day_cell(date);
+
+                let mut color = style.color;
+
+                if date == self.selected {
+                    let radius = style.border_radius;
+                    cx.quad(rect, style.selected_color, radius, 0.0, Color::TRANSPARENT);
+                    color = style.selected_text_color;
+                }
+
+                if date == state.highlighted && cx.is_focused() {
+                    let radius = style.border_radius;
+                    cx.quad(rect, Color::TRANSPARENT, radius, 1.0, style.highlight_color);
+                }
+
+                cx.text(buffer, color, centered(buffer, rect));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Modifiers, PointerButton, PointerId, PointerPressed},
+        views::testing::ViewTester,
+    };
+
+    use super::*;
+
+    #[test]
+    fn date_math() {
+        assert_eq!(Date::new(1970, 1, 1).days_since_epoch(), 0);
+        assert_eq!(Date::new(2000, 3, 1).days_since_epoch(), 11017);
+        assert_eq!(Date::from_days_since_epoch(11017), Date::new(2000, 3, 1));
+        assert_eq!(Date::from_days_since_epoch(-1), Date::new(1969, 12, 31));
+
+        // 2024-01-01 was a Monday
+        assert_eq!(Date::new(2024, 1, 1).weekday(), 0);
+        assert_eq!(Date::new(2024, 2, 28).add_days(1), Date::new(2024, 2, 29));
+        assert_eq!(Date::new(2023, 2, 28).add_days(1), Date::new(2023, 3, 1));
+
+        assert_eq!(Date::new(2024, 1, 31).add_months(1), Date::new(2024, 2, 29));
+        assert_eq!(
+            Date::new(2024, 1, 15).add_months(-1),
+            Date::new(2023, 12, 15)
+        );
+        assert_eq!(Date::new(2024, 2, 31), Date::new(2024, 2, 29));
+    }
+
+    #[test]
+    fn pressing_keeps_day_buffers() {
+        let mut view = date_picker(Date::new(2024, 1, 1)).cell_size(10.0);
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(&mut view, &mut (), Space::UNBOUNDED);
+        tester.view_state.set_hovered(true);
+
+        let pressed = |x, y| {
+            Event::PointerPressed(PointerPressed {
+                id: PointerId::from_u64(0),
+                position: Point::new(x, y),
+                button: PointerButton::Primary,
+                modifiers: Modifiers::default(),
+            })
+        };
+
+        let days = tester.state.days.as_ptr();
+
+        // 2024-01-02, in the second column of the first week
+        tester.event(&mut view, &mut (), &pressed(15.0, 25.0));
+        assert_eq!(tester.state.highlighted, Date::new(2024, 1, 2));
+
+        // the next month arrow
+        tester.event(&mut view, &mut (), &pressed(65.0, 5.0));
+        assert_eq!(tester.state.month, Date::new(2024, 2, 1));
+
+        assert_eq!(tester.state.days.as_ptr(), days);
+    }
+}
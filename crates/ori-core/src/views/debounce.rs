@@ -0,0 +1,251 @@
+use crate::{
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Size, Space},
+    view::View,
+};
+
+/// Create a new [`Debounce`] that calls `on_settle` when `value` has stopped changing
+/// for `delay` seconds.
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// struct Data {
+///     query: String,
+/// }
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     let input = text_input().on_input(|cx, data: &mut Data, text| {
+///         data.query = text;
+///         cx.rebuild();
+///     });
+///
+///     // only search when the user has stopped typing for 300 ms
+///     debounce(input, data.query.clone(), 0.3, |cx, _data, query| {
+///         cx.cmd_async(search(query.clone()));
+///     })
+/// }
+/// ```
+pub fn debounce<T, V, D: PartialEq>(
+    content: V,
+    value: D,
+    delay: f32,
+    on_settle: impl FnMut(&mut EventCx, &mut T, &D) + 'static,
+) -> Debounce<T, V, D> {
+    Debounce::new(content, value, delay, on_settle)
+}
+
+/// Create a new [`Debounce`] that calls `on_settle` when `value` changes, but at most
+/// once every `interval` seconds.
+pub fn throttle<T, V, D: PartialEq>(
+    content: V,
+    value: D,
+    interval: f32,
+    on_settle: impl FnMut(&mut EventCx, &mut T, &D) + 'static,
+) -> Debounce<T, V, D> {
+    Debounce::new(content, value, interval, on_settle).mode(DebounceMode::Throttle)
+}
+
+/// How a [`Debounce`] limits the calls to its callback.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DebounceMode {
+    /// Call the callback when the value has stopped changing for the duration.
+    #[default]
+    Debounce,
+
+    /// Call the callback at most once per duration, while the value is changing.
+    Throttle,
+}
+
+/// A view that calls a callback when a value changes, limited in rate.
+///
+/// The value is compared on every rebuild, and the timing is driven by
+/// [`Event::Animate`], so the callback is always called from the event loop.
+pub struct Debounce<T, V, D> {
+    /// The content.
+    pub content: V,
+
+    /// The value to watch.
+    pub value: Option<D>,
+
+    /// The duration in seconds.
+    pub duration: f32,
+
+    /// The mode.
+    pub mode: DebounceMode,
+
+    /// The callback.
+    #[allow(clippy::type_complexity)]
+    pub on_settle: Box<dyn FnMut(&mut EventCx, &mut T, &D)>,
+}
+
+impl<T, V, D: PartialEq> Debounce<T, V, D> {
+    /// Create a new [`Debounce`].
+    pub fn new(
+        content: V,
+        value: D,
+        duration: f32,
+        on_settle: impl FnMut(&mut EventCx, &mut T, &D) + 'static,
+    ) -> Self {
+        Self {
+            content,
+            value: Some(value),
+            duration,
+            mode: DebounceMode::Debounce,
+            on_settle: Box::new(on_settle),
+        }
+    }
+
+    /// Set the mode.
+    pub fn mode(mut self, mode: DebounceMode) -> Self {
+        self.mode = mode;
+        self
+    }
+}
+
+#[doc(hidden)]
+pub struct DebounceState<D, S> {
+    content: S,
+    value: Option<D>,
+    pending: bool,
+    elapsed: f32,
+    cooldown: f32,
+}
+
+impl<T, V: View<T>, D: PartialEq> View<T> for Debounce<T, V, D> {
+    type State = DebounceState<D, V::State>;
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        DebounceState {
+            content: self.content.build(cx, data),
+            value: self.value.take(),
+            pending: false,
+            elapsed: 0.0,
+            cooldown: 0.0,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        if self.value.is_some() && self.value != state.value {
+            state.value = self.value.take();
+            state.pending = true;
+
+            // debouncing restarts the timer every time the value changes
+            if self.mode == DebounceMode::Debounce {
+                state.elapsed = 0.0;
+            }
+
+            cx.animate();
+        }
+
+        (self.content).rebuild(&mut state.content, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        if let Event::Animate(dt) = event {
+            let ready = match self.mode {
+                DebounceMode::Debounce => {
+                    state.elapsed += *dt;
+                    state.elapsed >= self.duration
+                }
+                DebounceMode::Throttle => {
+                    state.cooldown = f32::max(state.cooldown - *dt, 0.0);
+                    state.cooldown <= 0.0
+                }
+            };
+
+            if state.pending && ready {
+                state.pending = false;
+                state.elapsed = 0.0;
+
+                if self.mode == DebounceMode::Throttle {
+                    state.cooldown = self.duration;
+                }
+
+                if let Some(ref value) = state.value {
+                    (self.on_settle)(cx, data, value);
+                }
+            }
+
+            // keep animating while there is a pending value, or while throttling
+            if state.pending || state.cooldown > 0.0 {
+                cx.animate();
+            }
+        }
+
+        self.content.event(&mut state.content, cx, data, event)
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        self.content.layout(&mut state.content, cx, data, space)
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.content.draw(&mut state.content, cx, data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{event::Event, views::testing::ViewTester};
+
+    use super::*;
+
+    fn view(value: u32, mode: DebounceMode) -> Debounce<Vec<u32>, (), u32> {
+        Debounce::new((), value, 0.3, |_, data: &mut Vec<u32>, value| {
+            data.push(*value);
+        })
+        .mode(mode)
+    }
+
+    #[test]
+    fn debounce() {
+        let mut data = Vec::new();
+
+        let mut old = view(0, DebounceMode::Debounce);
+        let mut tester = ViewTester::new(&mut old, &mut data);
+
+        for value in 1..=3 {
+            let mut new = view(value, DebounceMode::Debounce);
+            tester.rebuild(&mut new, &mut data, &old);
+            tester.event(&mut new, &mut data, &Event::Animate(0.2));
+            old = new;
+        }
+
+        assert!(data.is_empty());
+
+        tester.event(&mut old, &mut data, &Event::Animate(0.2));
+        assert_eq!(data, vec![3]);
+    }
+
+    #[test]
+    fn throttle() {
+        let mut data = Vec::new();
+
+        let mut old = view(0, DebounceMode::Throttle);
+        let mut tester = ViewTester::new(&mut old, &mut data);
+
+        for value in 1..=3 {
+            let mut new = view(value, DebounceMode::Throttle);
+            tester.rebuild(&mut new, &mut data, &old);
+            tester.event(&mut new, &mut data, &Event::Animate(0.2));
+            old = new;
+        }
+
+        assert_eq!(data, vec![1, 3]);
+    }
+}
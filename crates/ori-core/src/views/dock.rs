@@ -0,0 +1,624 @@
+use ori_macro::{Build, Styled};
+
+use crate::{
+    canvas::Color,
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Axis, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    view::{PodSeq, SeqState, View, ViewSeq},
+    window::Cursor,
+};
+
+/// Create a new [`Dock`].
+pub fn dock<T, V>(layout: DockLayout, content: V) -> Dock<T, V> {
+    Dock::new(layout, content)
+}
+
+/// The layout of a [`Dock`], a tree of splits with panels at the leaves.
+///
+/// Splits are identified by their index in the tree, counted depth-first, starting with the
+/// root. The layout can be serialized with the `serde` feature, to persist it between runs.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DockLayout {
+    /// A panel, the index of a view in the content of the dock.
+    Panel(usize),
+
+    /// A split of the space between two layouts.
+    Split {
+        /// The axis along which the space is split.
+        axis: Axis,
+
+        /// The fraction of the space given to the first layout.
+        fraction: f32,
+
+        /// The first layout, on the left or at the top.
+        first: Box<DockLayout>,
+
+        /// The second layout, on the right or at the bottom.
+        second: Box<DockLayout>,
+    },
+}
+
+impl DockLayout {
+    /// Create a panel, showing the view at `index` in the content of the dock.
+    pub fn panel(index: usize) -> Self {
+        Self::Panel(index)
+    }
+
+    /// Create a split of the space between two layouts along an `axis`.
+    pub fn split(axis: Axis, first: Self, second: Self) -> Self {
+        Self::Split {
+            axis,
+            fraction: 0.5,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// Create a split with `first` on the left and `second` on the right.
+    pub fn hsplit(first: Self, second: Self) -> Self {
+        Self::split(Axis::Horizontal, first, second)
+    }
+
+    /// Create a split with `first` at the top and `second` at the bottom.
+    pub fn vsplit(first: Self, second: Self) -> Self {
+        Self::split(Axis::Vertical, first, second)
+    }
+
+    /// Set the fraction of the space given to the first layout, if this is a split.
+    pub fn with_fraction(mut self, fraction: f32) -> Self {
+        if let Self::Split { fraction: f, .. } = &mut self {
+            *f = fraction.clamp(0.0, 1.0);
+        }
+
+        self
+    }
+
+    /// Get the number of splits in the layout.
+    pub fn split_count(&self) -> usize {
+        match self {
+            Self::Panel(_) => 0,
+            Self::Split { first, second, .. } => 1 + first.split_count() + second.split_count(),
+        }
+    }
+
+    /// Get the fraction of the nth `split`.
+    pub fn fraction(&self, split: usize) -> Option<f32> {
+        self.find(split).ok().map(|layout| match layout {
+            Self::Split { fraction, .. } => *fraction,
+            Self::Panel(_) => unreachable!(),
+        })
+    }
+
+    /// Set the fraction of the nth `split`, returns `false` if there is no such split.
+    pub fn set_fraction(&mut self, split: usize, fraction: f32) -> bool {
+        match self.find_mut(split) {
+            Ok(Self::Split { fraction: f, .. }) => {
+                *f = fraction.clamp(0.0, 1.0);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // find the nth split, or return the number of splits searched
+    fn find(&self, split: usize) -> Result<&Self, usize> {
+        match self {
+            Self::Panel(_) => Err(0),
+            Self::Split { .. } if split == 0 => Ok(self),
+            Self::Split { first, second, .. } => {
+                let skipped = match first.find(split - 1) {
+                    Ok(found) => return Ok(found),
+                    Err(skipped) => skipped,
+                };
+
+                (second.find(split - 1 - skipped)).map_err(|n| 1 + skipped + n)
+            }
+        }
+    }
+
+    fn find_mut(&mut self, split: usize) -> Result<&mut Self, usize> {
+        match self {
+            Self::Panel(_) => Err(0),
+            Self::Split { .. } if split == 0 => Ok(self),
+            Self::Split { first, second, .. } => {
+                let skipped = match first.find_mut(split - 1) {
+                    Ok(found) => return Ok(found),
+                    Err(skipped) => skipped,
+                };
+
+                (second.find_mut(split - 1 - skipped)).map_err(|n| 1 + skipped + n)
+            }
+        }
+    }
+}
+
+/// A view that arranges its content in nested, resizable splits, described by a [`DockLayout`].
+///
+/// Each view in the content is a panel, placed where the layout refers to its index. Views
+/// that the layout doesn't refer to are hidden. The splits are resized by dragging the
+/// dividers between them, after which [`Dock::on_resize`] is called with the new layout.
+///
+/// Can be styled using the [`DockStyle`].
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// struct Data {
+///     layout: DockLayout,
+/// }
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     let panels = (text("Files"), text("Editor"), text("Console"));
+///
+///     dock(data.layout.clone(), panels).on_resize(|_, data: &mut Data, layout| {
+///         data.layout = layout.clone();
+///     })
+/// }
+///
+/// let layout = DockLayout::hsplit(
+///     DockLayout::panel(0),
+///     DockLayout::vsplit(DockLayout::panel(1), DockLayout::panel(2)).with_fraction(0.7),
+/// )
+/// .with_fraction(0.25);
+/// ```
+#[derive(Styled, Build, Rebuild)]
+pub struct Dock<T, V> {
+    /// The content of the dock, the panels.
+    #[build(ignore)]
+    pub content: PodSeq<V>,
+
+    /// The layout of the dock.
+    #[build(ignore)]
+    #[rebuild(layout)]
+    pub layout: DockLayout,
+
+    /// The callback for when a split is resized.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub on_resize: Option<Box<dyn FnMut(&mut EventCx, &mut T, &DockLayout) + 'static>>,
+
+    /// The width of the dividers between splits.
+    #[rebuild(layout)]
+    #[styled(default = 4.0)]
+    pub divider_width: Styled<f32>,
+
+    /// The smallest size a split can be resized to.
+    #[rebuild(layout)]
+    #[styled(default = 40.0)]
+    pub min_size: Styled<f32>,
+
+    /// The color of the dividers.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::OUTLINE_LOW or Color::grayscale(0.8))]
+    pub divider_color: Styled<Color>,
+
+    /// The color of hovered and dragged dividers.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::PRIMARY or Color::BLUE)]
+    pub active_color: Styled<Color>,
+}
+
+impl<T, V> Dock<T, V> {
+    /// Create a new [`Dock`].
+    pub fn new(layout: DockLayout, content: V) -> Self {
+        Self {
+            content: PodSeq::new(content),
+            layout,
+            on_resize: None,
+            divider_width: DockStyle::DIVIDER_WIDTH.into(),
+            min_size: DockStyle::MIN_SIZE.into(),
+            divider_color: DockStyle::DIVIDER_COLOR.into(),
+            active_color: DockStyle::ACTIVE_COLOR.into(),
+        }
+    }
+
+    /// Set the callback for when a split is resized.
+    pub fn on_resize(
+        mut self,
+        on_resize: impl FnMut(&mut EventCx, &mut T, &DockLayout) + 'static,
+    ) -> Self {
+        self.on_resize = Some(Box::new(on_resize));
+        self
+    }
+}
+
+struct Divider {
+    split: usize,
+    axis: Axis,
+    rect: Rect,
+    // the rect of the whole split
+    parent: Rect,
+}
+
+#[doc(hidden)]
+pub struct DockState {
+    style: DockStyle,
+    // the layout, which differs from the view's while a divider is dragged
+    layout: DockLayout,
+    dividers: Vec<Divider>,
+    shown: Vec<bool>,
+    hovered: Option<usize>,
+    dragging: Option<usize>,
+}
+
+impl DockState {
+    fn divider_at(&self, point: Point) -> Option<usize> {
+        // dividers are thin, so they can be grabbed a bit outside
+        let padding = Vector::all(f32::max(6.0 - self.style.divider_width, 0.0) / 2.0);
+
+        (self.dividers.iter()).position(|divider| {
+            let rect = Rect::new(divider.rect.min - padding, divider.rect.max + padding);
+            rect.contains(point)
+        })
+    }
+}
+
+impl<T, V: ViewSeq<T>> Dock<T, V> {
+    #[allow(clippy::too_many_arguments)]
+    fn layout_node(
+        &mut self,
+        node: &DockLayout,
+        rect: Rect,
+        state: &mut DockState,
+        content: &mut SeqState<T, V>,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        split: &mut usize,
+    ) {
+        match node {
+            DockLayout::Panel(index) => {
+                if *index >= self.content.len() {
+                    return;
+                }
+
+                let space = Space::new(rect.size(), rect.size());
+                self.content.layout_nth(*index, content, cx, data, space);
+                content[*index].translate(rect.min.to_vector());
+                state.shown[*index] = true;
+            }
+            DockLayout::Split {
+                axis,
+                fraction,
+                first,
+                second,
+            } => {
+                let index = *split;
+                *split += 1;
+
+                let width = state.style.divider_width;
+                let available = f32::max(axis.major(rect.size()) - width, 0.0);
+
+                // keep both sides at least the minimum size, when there is room for it
+                let min = f32::min(state.style.min_size, available / 2.0);
+                let major = f32::clamp(available * fraction, min, available - min);
+                let minor = axis.minor(rect.size());
+
+                let first_rect = Rect::min_size(rect.min, axis.pack(major, minor));
+                let divider_min = rect.min + axis.pack::<Vector>(major, 0.0);
+                let divider_rect = Rect::min_size(divider_min, axis.pack(width, minor));
+                let second_min = rect.min + axis.pack::<Vector>(major + width, 0.0);
+                let second_rect = Rect::min_size(second_min, axis.pack(available - major, minor));
+
+                state.dividers.push(Divider {
+                    split: index,
+                    axis: *axis,
+                    rect: divider_rect,
+                    parent: rect,
+                });
+
+                self.layout_node(first, first_rect, state, content, cx, data, split);
+                self.layout_node(second, second_rect, state, content, cx, data, split);
+            }
+        }
+    }
+}
+
+impl<T, V: ViewSeq<T>> View<T> for Dock<T, V> {
+    type State = (DockState, SeqState<T, V>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let state = DockState {
+            style: DockStyle::styled(self, cx.styles()),
+            layout: self.layout.clone(),
+            dividers: Vec::new(),
+            shown: vec![false; self.content.len()],
+            hovered: None,
+            dragging: None,
+        };
+
+        (state, self.content.build(cx, data))
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+
+        if self.layout != old.layout {
+            state.layout = self.layout.clone();
+        }
+
+        if self.content.len() != old.content.len() {
+            state.shown.resize(self.content.len(), false);
+            cx.layout();
+        }
+
+        (self.content).rebuild(content, &mut cx.as_build_cx(), data, &old.content);
+
+        for i in 0..self.content.len() {
+            self.content.rebuild_nth(i, content, cx, data, &old.content);
+        }
+    }
+
+    fn event(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        if let Event::PointerMoved(e) = event {
+            let local = cx.local(e.position);
+
+            if let Some(dragging) = state.dragging {
+                match state.dividers.get(dragging) {
+                    Some(divider) => {
+                        let axis = divider.axis;
+
+                        let available =
+                            axis.major(divider.parent.size()) - state.style.divider_width;
+                        let offset = axis.major(local - divider.parent.min)
+                            - state.style.divider_width / 2.0;
+                        let fraction = offset / available.max(1.0);
+
+                        state.layout.set_fraction(divider.split, fraction);
+                        cx.layout();
+                    }
+                    // the layout changed during the drag, and the divider is gone
+                    None => {
+                        state.dragging = None;
+                        cx.set_active(false);
+                    }
+                }
+            }
+
+            let hovered = match cx.is_hovered() || state.dragging.is_some() {
+                true => state.dragging.or(state.divider_at(local)),
+                false => None,
+            };
+
+            if hovered != state.hovered {
+                state.hovered = hovered;
+
+                let cursor = hovered.map(|i| match state.dividers[i].axis {
+                    Axis::Horizontal => Cursor::EwResize,
+                    Axis::Vertical => Cursor::NsResize,
+                });
+
+                cx.set_cursor(cursor);
+                cx.draw();
+            }
+        }
+
+        let handled = self.content.event(content, cx, data, event);
+
+        match event {
+            Event::PointerPressed(_) if !handled && state.hovered.is_some() => {
+                state.dragging = state.hovered;
+                cx.set_active(true);
+                cx.draw();
+
+                true
+            }
+            Event::PointerReleased(_) if state.dragging.is_some() => {
+                state.dragging = None;
+                cx.set_active(false);
+                cx.draw();
+
+                if let Some(ref mut on_resize) = self.on_resize {
+                    on_resize(cx, data, &state.layout);
+                }
+
+                true
+            }
+            _ => handled,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        // the dock fills the available space
+        let size = space.fit(space.max);
+        let layout = state.layout.clone();
+
+        state.dividers.clear();
+        state.shown.iter_mut().for_each(|shown| *shown = false);
+
+        let rect = Rect::min_size(Point::ZERO, size);
+        self.layout_node(&layout, rect, state, content, cx, data, &mut 0);
+
+        // hidden panels are still laid out, so they have a valid size
+        for i in 0..self.content.len() {
+            if !state.shown[i] {
+                self.content.layout_nth(i, content, cx, data, Space::ZERO);
+            }
+        }
+
+        size
+    }
+
+    fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        for i in 0..self.content.len() {
+            if state.shown[i] {
+                self.content.draw_nth(i, content, cx, data);
+            }
+        }
+
+        cx.hoverable(|cx| {
+            for (i, divider) in state.dividers.iter().enumerate() {
+                let color = match state.hovered == Some(i) {
+                    true => state.style.active_color,
+                    false => state.style.divider_color,
+                };
+
+                cx.fill_rect(divider.rect, color);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{
+            Modifiers, PointerButton, PointerId, PointerMoved, PointerPressed, PointerReleased,
+        },
+        views::testing::ViewTester,
+    };
+
+    use super::*;
+
+    fn layout() -> DockLayout {
+        DockLayout::hsplit(
+            DockLayout::panel(0),
+            DockLayout::vsplit(DockLayout::panel(1), DockLayout::panel(2)),
+        )
+        .with_fraction(0.25)
+    }
+
+    #[test]
+    fn fractions() {
+        let mut layout = layout();
+        assert_eq!(layout.split_count(), 2);
+        assert_eq!(layout.fraction(0), Some(0.25));
+        assert_eq!(layout.fraction(1), Some(0.5));
+        assert_eq!(layout.fraction(2), None);
+
+        assert!(layout.set_fraction(1, 0.75));
+        assert!(!layout.set_fraction(2, 0.75));
+        assert_eq!(layout.fraction(1), Some(0.75));
+    }
+
+    #[test]
+    fn nested_splits() {
+        let mut view = dock(layout(), vec![(); 3]).divider_width(0.0);
+        let mut tester = ViewTester::new(&mut view, &mut ());
+
+        let space = Space::new(Size::ZERO, Size::new(400.0, 200.0));
+        tester.layout(&mut view, &mut (), space);
+
+        let (_, content) = &tester.state;
+        let rect = |i: usize| content[i].rect() + content[i].transform().translation;
+
+        assert_eq!(rect(0), Rect::new(Point::ZERO, Point::new(100.0, 200.0)));
+        assert_eq!(
+            rect(1),
+            Rect::new(Point::new(100.0, 0.0), Point::new(400.0, 100.0))
+        );
+        assert_eq!(
+            rect(2),
+            Rect::new(Point::new(100.0, 100.0), Point::new(400.0, 200.0))
+        );
+    }
+
+    #[test]
+    fn drag_divider() {
+        let mut view = dock(layout(), vec![(); 3])
+            .divider_width(4.0)
+            .on_resize(|_, data: &mut Option<DockLayout>, layout| *data = Some(layout.clone()));
+        let mut data = None;
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        let space = Space::new(Size::ZERO, Size::new(404.0, 200.0));
+        tester.layout(&mut view, &mut data, space);
+        tester.view_state.set_hovered(true);
+
+        let moved = |x| {
+            Event::PointerMoved(PointerMoved {
+                id: PointerId::from_u64(0),
+                position: Point::new(x, 50.0),
+                delta: Vector::ZERO,
+                modifiers: Modifiers::default(),
+            })
+        };
+
+        tester.event(&mut view, &mut data, &moved(102.0));
+        tester.event(
+            &mut view,
+            &mut data,
+            &Event::PointerPressed(PointerPressed {
+                id: PointerId::from_u64(0),
+                position: Point::new(102.0, 50.0),
+                button: PointerButton::Primary,
+                modifiers: Modifiers::default(),
+            }),
+        );
+        tester.event(&mut view, &mut data, &moved(202.0));
+        tester.event(
+            &mut view,
+            &mut data,
+            &Event::PointerReleased(PointerReleased {
+                id: PointerId::from_u64(0),
+                position: Point::new(202.0, 50.0),
+                clicked: false,
+                button: PointerButton::Primary,
+                modifiers: Modifiers::default(),
+            }),
+        );
+
+        assert_eq!(data.unwrap().fraction(0), Some(0.5));
+    }
+
+    #[test]
+    fn drag_cancelled_when_divider_removed() {
+        let mut view = dock(layout(), vec![(); 3]).divider_width(4.0);
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        let space = Space::new(Size::ZERO, Size::new(404.0, 200.0));
+        tester.layout(&mut view, &mut (), space);
+        tester.view_state.set_hovered(true);
+
+        let moved = |x| {
+            Event::PointerMoved(PointerMoved {
+                id: PointerId::from_u64(0),
+                position: Point::new(x, 50.0),
+                delta: Vector::ZERO,
+                modifiers: Modifiers::default(),
+            })
+        };
+
+        tester.event(&mut view, &mut (), &moved(102.0));
+        tester.event(
+            &mut view,
+            &mut (),
+            &Event::PointerPressed(PointerPressed {
+                id: PointerId::from_u64(0),
+                position: Point::new(102.0, 50.0),
+                button: PointerButton::Primary,
+                modifiers: Modifiers::default(),
+            }),
+        );
+
+        let mut new = dock(DockLayout::panel(0), vec![(); 3]).divider_width(4.0);
+        tester.rebuild(&mut new, &mut (), &view);
+        tester.layout(&mut new, &mut (), space);
+        tester.event(&mut new, &mut (), &moved(202.0));
+
+        assert_eq!(tester.state.0.dragging, None);
+        assert!(!tester.view_state.is_active());
+    }
+}
@@ -0,0 +1,197 @@
+use ori_macro::{Build, Styled};
+
+use crate::{
+    canvas::{Color, Curve, FillRule, Stroke, StrokeCap, StrokeJoin},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Point, Rect, Size, Space},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    transition::Easing,
+    view::View,
+};
+
+/// Create a new [`EasingPreview`].
+pub fn easing_preview(easing: Easing) -> EasingPreview {
+    EasingPreview::new(easing)
+}
+
+/// A view that plots an [`Easing`], and animates a dot along it, for tuning transitions.
+///
+/// The horizontal axis is the time and the vertical axis is the value of the easing. Room is
+/// left above and below for easings that overshoot, like [`Easing::Spring`].
+///
+/// Can be styled using the [`EasingPreviewStyle`].
+#[derive(Styled, Build, Rebuild)]
+pub struct EasingPreview {
+    /// The easing to plot.
+    #[rebuild(draw)]
+    pub easing: Easing,
+
+    /// The duration in seconds of the animation of the dot.
+    pub duration: f32,
+
+    /// The size of the plot.
+    #[rebuild(layout)]
+    #[styled(default = Size::new(200.0, 160.0))]
+    pub size: Styled<Size>,
+
+    /// The color of the curve and the dot.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::PRIMARY or Color::BLUE)]
+    pub color: Styled<Color>,
+
+    /// The color of the lines at the start and end values.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::OUTLINE_LOW or Color::grayscale(0.8))]
+    pub grid_color: Styled<Color>,
+}
+
+impl EasingPreview {
+    /// The number of segments the curve is plotted with.
+    const SEGMENTS: usize = 64;
+
+    /// The fraction of the height left for overshooting, above and below.
+    const OVERSHOOT: f32 = 0.2;
+
+    /// The time in seconds the dot rests at the end, before starting over.
+    const PAUSE: f32 = 0.5;
+
+    /// Create a new [`EasingPreview`].
+    pub fn new(easing: Easing) -> Self {
+        Self {
+            easing,
+            duration: 1.0,
+            size: EasingPreviewStyle::SIZE.into(),
+            color: EasingPreviewStyle::COLOR.into(),
+            grid_color: EasingPreviewStyle::GRID_COLOR.into(),
+        }
+    }
+
+    // map a time and value of the easing to a point in `rect`
+    fn point(rect: Rect, t: f32, value: f32) -> Point {
+        let range = 1.0 + Self::OVERSHOOT * 2.0;
+        let y = (value + Self::OVERSHOOT) / range;
+
+        Point::new(
+            rect.min.x + rect.width() * t,
+            rect.max.y - rect.height() * y,
+        )
+    }
+
+    fn plot(&self, rect: Rect) -> Curve {
+        let mut curve = Curve::new();
+        curve.move_to(Self::point(rect, 0.0, self.easing.evaluate(0.0)));
+
+        for i in 1..=Self::SEGMENTS {
+            let t = i as f32 / Self::SEGMENTS as f32;
+            curve.line_to(Self::point(rect, t, self.easing.evaluate(t)));
+        }
+
+        curve
+    }
+}
+
+#[doc(hidden)]
+pub struct EasingPreviewState {
+    style: EasingPreviewStyle,
+    time: f32,
+}
+
+impl<T> View<T> for EasingPreview {
+    type State = EasingPreviewState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.animate();
+
+        EasingPreviewState {
+            style: EasingPreviewStyle::styled(self, cx.styles()),
+            time: 0.0,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+
+        if self.easing != old.easing || self.duration != old.duration {
+            state.time = 0.0;
+        }
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        _data: &mut T,
+        event: &Event,
+    ) -> bool {
+        if let Event::Animate(dt) = event {
+            state.time += *dt;
+
+            if state.time > self.duration + Self::PAUSE {
+                state.time = 0.0;
+            }
+
+            cx.animate();
+            cx.draw();
+        }
+
+        false
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        _cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        space.fit(state.style.size)
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        let radius = 4.0;
+        let rect = cx.rect().shrink(radius);
+
+        // the start and end values
+        for value in [0.0, 1.0] {
+            let start = Self::point(rect, 0.0, value);
+            let line = Rect::min_size(start, Size::new(rect.width(), 1.0));
+            cx.fill_rect(line, state.style.grid_color);
+        }
+
+        let stroke = Stroke {
+            width: 2.0,
+            cap: StrokeCap::Round,
+            join: StrokeJoin::Round,
+            ..Default::default()
+        };
+
+        cx.stroke(self.plot(rect), stroke, state.style.color);
+
+        let t = f32::clamp(state.time / self.duration.max(f32::EPSILON), 0.0, 1.0);
+        let dot = Self::point(rect, t, self.easing.evaluate(t));
+        cx.fill(
+            Curve::circle(dot, radius),
+            FillRule::NonZero,
+            state.style.color,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plot_fits() {
+        let rect = Rect::min_size(Point::ZERO, Size::new(100.0, 140.0));
+
+        let bounds = easing_preview(Easing::Ease).plot(rect).bounds();
+
+        // the values 0 and 1 are at the bottom and top, leaving room for overshooting
+        assert!(bounds.min.distance(Point::new(0.0, 20.0)) < 0.001);
+        assert!(bounds.max.distance(Point::new(100.0, 120.0)) < 0.001);
+    }
+}
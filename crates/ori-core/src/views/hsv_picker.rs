@@ -0,0 +1,413 @@
+use ori_macro::{Build, Styled};
+
+use crate::{
+    canvas::{BorderRadius, Color, Curve, FillRule, Pattern},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    image::Image,
+    layout::{Affine, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    view::{Pod, State, View},
+};
+
+use super::{text_input, TextInput};
+
+/// Create a new [`HsvPicker`].
+pub fn hsv_picker<T>() -> HsvPicker<T> {
+    HsvPicker::new()
+}
+
+/// A color picker with a saturation/value square and a hue strip.
+///
+/// Colors are picked in the okhsv color space, see [`Color::okhsv`].
+///
+/// Can be styled using the [`HsvPickerStyle`].
+#[derive(Styled, Build, Rebuild)]
+pub struct HsvPicker<T> {
+    /// The color of the picker.
+    #[rebuild(draw)]
+    pub color: Color,
+
+    /// The on_input callback.
+    ///
+    /// This is called continuously while dragging, and when a hex value is submitted.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub on_input: Option<Box<dyn FnMut(&mut EventCx, &mut T, Color)>>,
+
+    /// Whether to show the hex input.
+    #[rebuild(layout)]
+    pub hex: bool,
+
+    /// The hex input.
+    #[build(ignore)]
+    pub input: Pod<TextInput<Option<String>>>,
+
+    /// The size of the saturation/value square.
+    #[rebuild(layout)]
+    #[styled(default = 160.0)]
+    pub size: Styled<f32>,
+
+    /// The height of the hue strip.
+    #[rebuild(layout)]
+    #[styled(default = 16.0)]
+    pub strip_height: Styled<f32>,
+
+    /// The gap between the parts of the picker.
+    #[rebuild(layout)]
+    #[styled(default = 8.0)]
+    pub gap: Styled<f32>,
+
+    /// The border radius of the parts of the picker.
+    #[rebuild(draw)]
+    #[styled(default = BorderRadius::all(4.0))]
+    pub border_radius: Styled<BorderRadius>,
+
+    /// The border width of the parts of the picker.
+    #[rebuild(draw)]
+    #[styled(default = 2.0)]
+    pub border_width: Styled<f32>,
+
+    /// The border color of the parts of the picker.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::OUTLINE or Color::BLACK)]
+    pub border_color: Styled<Color>,
+}
+
+impl<T> Default for HsvPicker<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> HsvPicker<T> {
+    const IMAGE_SIZE: u32 = 64;
+
+    /// Create a new [`HsvPicker`].
+    pub fn new() -> Self {
+        let input = text_input()
+            .placeholder("#ffffff")
+            .on_submit(|_, submitted: &mut Option<String>, text| *submitted = Some(text));
+
+        Self {
+            color: Color::WHITE,
+            on_input: None,
+            hex: true,
+            input: Pod::new(input),
+            size: HsvPickerStyle::SIZE.into(),
+            strip_height: HsvPickerStyle::STRIP_HEIGHT.into(),
+            gap: HsvPickerStyle::GAP.into(),
+            border_radius: HsvPickerStyle::BORDER_RADIUS.into(),
+            border_width: HsvPickerStyle::BORDER_WIDTH.into(),
+            border_color: HsvPickerStyle::BORDER_COLOR.into(),
+        }
+    }
+
+    /// Set the on_input callback.
+    pub fn on_input(mut self, on_input: impl FnMut(&mut EventCx, &mut T, Color) + 'static) -> Self {
+        self.on_input = Some(Box::new(on_input));
+        self
+    }
+
+    fn square_image(hue: f32) -> Image {
+        let size = Self::IMAGE_SIZE;
+        let mut pixels = vec![0u8; 4 * (size * size) as usize];
+
+        for y in 0..size {
+            for x in 0..size {
+                let saturation = x as f32 / (size - 1) as f32;
+                let value = 1.0 - y as f32 / (size - 1) as f32;
+
+                let i = ((y * size + x) * 4) as usize;
+                let color = Color::okhsv(hue, saturation, value);
+                pixels[i..i + 4].copy_from_slice(&color.to_rgba8());
+            }
+        }
+
+        Image::new(pixels, size, size)
+    }
+
+    fn strip_image() -> Image {
+        let size = Self::IMAGE_SIZE * 4;
+        let mut pixels = vec![0u8; 4 * size as usize];
+
+        for x in 0..size {
+            let hue = x as f32 / (size - 1) as f32 * 360.0;
+
+            let i = (x * 4) as usize;
+            let color = Color::okhsv(hue, 1.0, 1.0);
+            pixels[i..i + 4].copy_from_slice(&color.to_rgba8());
+        }
+
+        Image::new(pixels, size, 1)
+    }
+
+    fn square_rect(state: &HsvPickerState, rect: Rect) -> Rect {
+        Rect::min_size(rect.top_left(), Size::all(state.style.size))
+    }
+
+    fn strip_rect(state: &HsvPickerState, rect: Rect) -> Rect {
+        let min = rect.top_left() + Vector::new(0.0, state.style.size + state.style.gap);
+        Rect::min_size(min, Size::new(state.style.size, state.style.strip_height))
+    }
+
+    fn hex_text(color: Color) -> String {
+        color.to_hex().as_str().to_string()
+    }
+
+    fn input(&mut self, state: &mut HsvPickerState, cx: &mut EventCx, data: &mut T, point: Point) {
+        let local = cx.local(point);
+
+        let square = Self::square_rect(state, cx.rect());
+        let strip = Self::strip_rect(state, cx.rect());
+
+        let (_, s, v, a) = self.color.to_okhsva();
+
+        let color = if state.can_edit(HsvPickerPart::Square, square.contains(local)) {
+            state.edit = Some(HsvPickerPart::Square);
+
+            let saturation = (local.x - square.min.x) / square.width();
+            let value = 1.0 - (local.y - square.min.y) / square.height();
+
+            let saturation = saturation.clamp(0.0, 1.0);
+            let value = value.clamp(0.0, 1.0);
+
+            Color::okhsva(state.hue, saturation, value, a)
+        } else if state.can_edit(HsvPickerPart::Strip, strip.contains(local)) {
+            state.edit = Some(HsvPickerPart::Strip);
+
+            let hue = (local.x - strip.min.x) / strip.width() * 360.0;
+            state.hue = hue.clamp(0.0, 360.0);
+            state.image = None;
+            cx.draw();
+
+            Color::okhsva(state.hue, s, v, a)
+        } else {
+            return;
+        };
+
+        if let Some(ref mut on_input) = self.on_input {
+            on_input(cx, data, color);
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum HsvPickerPart {
+    Square,
+    Strip,
+}
+
+#[doc(hidden)]
+pub struct HsvPickerState {
+    style: HsvPickerStyle,
+    input: State<Option<String>, TextInput<Option<String>>>,
+    image: Option<Image>,
+    strip: Image,
+    edit: Option<HsvPickerPart>,
+    hue: f32,
+}
+
+impl HsvPickerState {
+    fn can_edit(&self, part: HsvPickerPart, inside: bool) -> bool {
+        self.edit.map_or(inside, |edit| edit == part)
+    }
+
+    // the hue is kept in the state, since it is lost when either the saturation or
+    // the value of the color is zero
+    fn update_hue(&mut self, color: Color) {
+        let (h, s, v) = color.to_okhsv();
+
+        if s > 1e-4 && v > 1e-4 && (h - self.hue).abs() > 1e-3 {
+            self.hue = h;
+            self.image = None;
+        }
+    }
+}
+
+impl<T> View<T> for HsvPicker<T> {
+    type State = HsvPickerState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        self.input.text = Some(Self::hex_text(self.color));
+
+        let mut state = HsvPickerState {
+            style: HsvPickerStyle::styled(self, cx.styles()),
+            input: self.input.build(cx, &mut None),
+            image: None,
+            strip: Self::strip_image(),
+            edit: None,
+            hue: 0.0,
+        };
+
+        state.update_hue(self.color);
+
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+
+        // only replace the text of the hex input when the color changes, so that
+        // rebuilds don't discard what the user is currently typing
+        if self.color != old.color {
+            self.input.text = Some(Self::hex_text(self.color));
+            state.update_hue(self.color);
+        }
+
+        (self.input).rebuild(&mut state.input, cx, &mut None, &old.input);
+        self.input.text = Some(Self::hex_text(self.color));
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        let mut handled = match event {
+            Event::PointerPressed(e) if cx.is_hovered() => {
+                self.input(state, cx, data, e.position);
+                cx.set_active(true);
+                true
+            }
+            Event::PointerMoved(e) if cx.is_active() => {
+                self.input(state, cx, data, e.position);
+                false
+            }
+            Event::PointerReleased(_) if cx.is_active() => {
+                cx.set_active(false);
+                state.edit = None;
+                true
+            }
+            _ => false,
+        };
+
+        if self.hex {
+            let mut submitted = None;
+            handled |=
+                (self.input).event_maybe(handled, &mut state.input, cx, &mut submitted, event);
+
+            if let Some(color) = submitted
+                .as_deref()
+                .and_then(|hex| Color::try_hex(hex.trim()))
+            {
+                state.update_hue(color);
+                cx.draw();
+
+                if let Some(ref mut on_input) = self.on_input {
+                    on_input(cx, data, color);
+                }
+            }
+        }
+
+        handled
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        let mut height = state.style.size + state.style.gap + state.style.strip_height;
+
+        if self.hex {
+            let input_space = Space::new(
+                Size::new(state.style.size, 0.0),
+                Size::new(state.style.size, f32::INFINITY),
+            );
+
+            let input_size = (self.input).layout(&mut state.input, cx, &mut None, input_space);
+            state
+                .input
+                .translate(Vector::new(0.0, height + state.style.gap));
+
+            height += state.style.gap + input_size.height;
+        }
+
+        space.fit(Size::new(state.style.size, height))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        let square = Self::square_rect(state, cx.rect());
+        let strip = Self::strip_rect(state, cx.rect());
+
+        let (_, s, v) = self.color.to_okhsv();
+        let hue = state.hue;
+
+        let image = state.image.get_or_insert_with(|| Self::square_image(hue));
+
+        cx.hoverable(|cx| {
+            /* draw the saturation/value square */
+            let pattern = Pattern {
+                image: image.clone(),
+                transform: Affine::translate(square.min.to_vector())
+                    * Affine::scale(Vector::from(square.size() / image.size())),
+                color: Color::WHITE,
+            };
+
+            let mut curve = Curve::new();
+            curve.push_rect_with_radius(square, state.style.border_radius);
+            cx.fill(curve, FillRule::NonZero, pattern);
+
+            cx.quad(
+                square,
+                Color::TRANSPARENT,
+                state.style.border_radius,
+                state.style.border_width,
+                state.style.border_color,
+            );
+
+            /* draw the hue strip */
+            let pattern = Pattern {
+                image: state.strip.clone(),
+                transform: Affine::translate(strip.min.to_vector())
+                    * Affine::scale(Vector::from(strip.size() / state.strip.size())),
+                color: Color::WHITE,
+            };
+
+            let mut curve = Curve::new();
+            curve.push_rect_with_radius(strip, state.style.border_radius);
+            cx.fill(curve, FillRule::NonZero, pattern);
+
+            cx.quad(
+                strip,
+                Color::TRANSPARENT,
+                state.style.border_radius,
+                state.style.border_width,
+                state.style.border_color,
+            );
+        });
+
+        /* draw the handles */
+        let square_handle =
+            square.min + Vector::new(s * square.width(), (1.0 - v) * square.height());
+        let strip_handle = Point::new(strip.min.x + hue / 360.0 * strip.width(), strip.center().y);
+
+        for handle in [square_handle, strip_handle] {
+            cx.fill(Curve::circle(handle, 5.0), FillRule::NonZero, Color::BLACK);
+            cx.fill(Curve::circle(handle, 4.0), FillRule::NonZero, Color::WHITE);
+            cx.fill(Curve::circle(handle, 3.0), FillRule::NonZero, self.color);
+        }
+
+        if self.hex {
+            let offset = state.style.size + state.style.strip_height + state.style.gap * 2.0;
+            let input = state.input.rect() + Vector::new(0.0, offset);
+
+            cx.quad(
+                input.expand(4.0),
+                Color::TRANSPARENT,
+                state.style.border_radius,
+                state.style.border_width,
+                state.style.border_color,
+            );
+
+            self.input.draw(&mut state.input, cx, &mut None);
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{
+    command::CommandProxy,
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Size, Space},
+    view::View,
+};
+
+/// Create a new [`Lifecycle`], calling `appear` when the `content` enters the view tree.
+pub fn on_appear<T, V>(
+    content: V,
+    appear: impl FnOnce(&mut BuildCx, &mut T) + 'static,
+) -> Lifecycle<T, V> {
+    Lifecycle::new(content).on_appear(appear)
+}
+
+/// Create a new [`Lifecycle`], calling `disappear` when the `content` leaves the view tree.
+pub fn on_disappear<T, V>(
+    content: V,
+    disappear: impl FnOnce(&CommandProxy) + 'static,
+) -> Lifecycle<T, V> {
+    Lifecycle::new(content).on_disappear(disappear)
+}
+
+/// A view that is notified when its content enters and leaves the view tree.
+///
+/// This is useful for starting and stopping work tied to a view, e.g. a subscription or
+/// a timer, or pausing a video player when it's no longer shown.
+///
+/// # Ordering
+/// - [`Lifecycle::appear`] is called once, right after the content is built, and before
+///   the content receives any rebuild, event, layout or draw.
+/// - [`Lifecycle::disappear`] is called once, when the state of the view is dropped. This
+///   happens during the rebuild of the parent that removed the view, or when the window
+///   is closed. Since the data isn't available at that point, the callback receives a
+///   [`CommandProxy`], and commands sent with it are handled after the rebuild.
+/// - When a view is replaced by another, e.g. when an `Option` switches from one view to
+///   another, the new view appears before the old one disappears.
+///
+/// The view is recreated on every rebuild, but only the `disappear` callback of the
+/// latest rebuild is kept, so it can capture up to date values.
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// struct StopPolling;
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     on_appear(text("Connected"), |_, data: &mut Data| data.polling = true)
+///         .on_disappear(|proxy| proxy.cmd(StopPolling))
+/// }
+/// ```
+pub struct Lifecycle<T, V> {
+    /// The content.
+    pub content: V,
+
+    /// The callback for when the content enters the view tree.
+    #[allow(clippy::type_complexity)]
+    pub appear: Option<Box<dyn FnOnce(&mut BuildCx, &mut T) + 'static>>,
+
+    /// The callback for when the content leaves the view tree.
+    #[allow(clippy::type_complexity)]
+    pub disappear: Option<Box<dyn FnOnce(&CommandProxy) + 'static>>,
+}
+
+impl<T, V> Lifecycle<T, V> {
+    /// Create a new [`Lifecycle`].
+    pub fn new(content: V) -> Self {
+        Self {
+            content,
+            appear: None,
+            disappear: None,
+        }
+    }
+
+    /// Set the callback for when the content enters the view tree.
+    pub fn on_appear(mut self, appear: impl FnOnce(&mut BuildCx, &mut T) + 'static) -> Self {
+        self.appear = Some(Box::new(appear));
+        self
+    }
+
+    /// Set the callback for when the content leaves the view tree.
+    pub fn on_disappear(mut self, disappear: impl FnOnce(&CommandProxy) + 'static) -> Self {
+        self.disappear = Some(Box::new(disappear));
+        self
+    }
+}
+
+#[doc(hidden)]
+pub struct LifecycleState<S> {
+    pub content: S,
+    proxy: CommandProxy,
+    #[allow(clippy::type_complexity)]
+    disappear: Option<Box<dyn FnOnce(&CommandProxy) + 'static>>,
+}
+
+impl<S> Drop for LifecycleState<S> {
+    fn drop(&mut self) {
+        if let Some(disappear) = self.disappear.take() {
+            disappear(&self.proxy);
+        }
+    }
+}
+
+impl<T, V: View<T>> View<T> for Lifecycle<T, V> {
+    type State = LifecycleState<V::State>;
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let content = self.content.build(cx, data);
+
+        if let Some(appear) = self.appear.take() {
+            appear(cx, data);
+        }
+
+        LifecycleState {
+            content,
+            proxy: cx.proxy(),
+            disappear: self.disappear.take(),
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        if let Some(disappear) = self.disappear.take() {
+            state.disappear = Some(disappear);
+        }
+
+        self.content
+            .rebuild(&mut state.content, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        self.content.event(&mut state.content, cx, data, event)
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        self.content.layout(&mut state.content, cx, data, space)
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.content.draw(&mut state.content, cx, data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use crate::views::testing::ViewTester;
+
+    use super::*;
+
+    #[test]
+    fn appear_and_disappear() {
+        let disappeared = Rc::new(Cell::new(false));
+
+        let flag = disappeared.clone();
+        let mut view =
+            on_appear((), |_, data: &mut u32| *data += 1).on_disappear(move |_| flag.set(true));
+        let mut data = 0;
+
+        let tester = ViewTester::new(&mut view, &mut data);
+        assert_eq!(data, 1);
+        assert!(!disappeared.get());
+
+        drop(tester);
+        assert!(disappeared.get());
+    }
+}
@@ -0,0 +1,250 @@
+use ori_macro::{Build, Styled};
+use smol_str::SmolStr;
+
+use crate::{
+    canvas::Color,
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key},
+    layout::{Point, Rect, Size, Space},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    text::{FontFamily, Fonts, TextAttributes, TextBuffer},
+    view::View,
+    window::Cursor,
+};
+
+/// Create a new [`Link`].
+pub fn link(text: impl Into<SmolStr>, url: impl Into<String>) -> Link {
+    Link::new(text, url)
+}
+
+/// A hyperlink, text that opens a URL when clicked.
+///
+/// The URL is opened with [`BaseCx::open_url`](crate::context::BaseCx::open_url), when the
+/// link is clicked or when enter is pressed while it is focused.
+///
+/// Can be styled using the [`LinkStyle`].
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// fn ui() -> impl View {
+///     link("Documentation", "https://docs.rs/ori")
+/// }
+/// ```
+#[derive(Styled, Build, Rebuild)]
+pub struct Link {
+    /// The text of the link.
+    #[rebuild(layout)]
+    pub text: SmolStr,
+
+    /// The URL opened when the link is clicked.
+    pub url: String,
+
+    /// The font size of the text.
+    #[rebuild(layout)]
+    #[styled(default = 16.0)]
+    pub font_size: Styled<f32>,
+
+    /// The font family of the text.
+    #[rebuild(layout)]
+    #[styled(default)]
+    pub font_family: Styled<FontFamily>,
+
+    /// The color of the text.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::PRIMARY or Color::BLUE)]
+    pub color: Styled<Color>,
+
+    /// Whether the text is underlined.
+    #[rebuild(draw)]
+    #[styled(default = true)]
+    pub underline: Styled<bool>,
+}
+
+impl Link {
+    /// Create a new [`Link`].
+    pub fn new(text: impl Into<SmolStr>, url: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            url: url.into(),
+            font_size: LinkStyle::FONT_SIZE.into(),
+            font_family: LinkStyle::FONT_FAMILY.into(),
+            color: LinkStyle::COLOR.into(),
+            underline: LinkStyle::UNDERLINE.into(),
+        }
+    }
+
+    fn set_text(&self, fonts: &mut Fonts, buffer: &mut TextBuffer, style: &LinkStyle) {
+        let attrs = TextAttributes {
+            family: style.font_family.clone(),
+            ..Default::default()
+        };
+
+        buffer.set_metrics(fonts, style.font_size, 1.2);
+        buffer.set_text(fonts, &self.text, attrs);
+    }
+}
+
+#[doc(hidden)]
+pub struct LinkState {
+    style: LinkStyle,
+    buffer: TextBuffer,
+}
+
+impl<T> View<T> for Link {
+    type State = LinkState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
+        let style = LinkStyle::styled(self, cx.styles());
+        let mut buffer = TextBuffer::new(cx.fonts(), style.font_size, 1.2);
+        self.set_text(cx.fonts(), &mut buffer, &style);
+
+        LinkState { style, buffer }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        let font_size = state.style.font_size;
+        let font_family = state.style.font_family.clone();
+        state.style.rebuild(self, cx);
+
+        if self.text != old.text
+            || state.style.font_size != font_size
+            || state.style.font_family != font_family
+        {
+            self.set_text(cx.fonts(), &mut state.buffer, &state.style);
+            cx.layout();
+        }
+    }
+
+    fn event(
+        &mut self,
+        _state: &mut Self::State,
+        cx: &mut EventCx,
+        _data: &mut T,
+        event: &Event,
+    ) -> bool {
+        if cx.focused_changed() {
+            cx.draw();
+        }
+
+        if cx.is_hovered() {
+            cx.set_cursor(Some(Cursor::Pointer));
+        } else {
+            cx.set_cursor(None);
+        }
+
+        match event {
+            Event::PointerPressed(_) if cx.is_hovered() => {
+                cx.set_active(true);
+
+                true
+            }
+            Event::PointerReleased(e) if cx.is_active() => {
+                cx.set_active(false);
+
+                if e.clicked && cx.is_hovered() {
+                    cx.open_url(self.url.clone());
+                }
+
+                true
+            }
+            Event::KeyPressed(e) if cx.is_focused() && e.is_key(Key::Enter) => {
+                cx.open_url(self.url.clone());
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        state.buffer.set_bounds(cx.fonts(), space.max);
+        space.fit(state.buffer.size())
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        let buffer = &state.buffer;
+
+        cx.hoverable(|cx| {
+            if cx.is_focused() {
+                cx.draw_focus_ring(cx.rect(), 2.0);
+            }
+
+            cx.trigger(cx.rect());
+            cx.text(buffer, state.style.color, Default::default());
+
+            if !state.style.underline {
+                return;
+            }
+
+            let thickness = (state.style.font_size / 14.0).max(1.0);
+
+            for run in buffer.raw().layout_runs() {
+                let start = run.glyphs.iter().map(|g| g.x).fold(f32::INFINITY, f32::min);
+                let end = run.glyphs.iter().map(|g| g.x + g.w).fold(0.0, f32::max);
+
+                if start >= end {
+                    continue;
+                }
+
+                let min = Point::new(start, run.line_y + thickness * 2.0);
+                let rect = Rect::min_size(min, Size::new(end - start, thickness));
+                cx.fill_rect(rect, state.style.color);
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Modifiers, OpenUrl, PointerButton, PointerId, PointerPressed, PointerReleased},
+        views::testing::ViewTester,
+    };
+
+    use super::*;
+
+    #[test]
+    fn click_opens_url() {
+        let mut view = link("Ori", "https://github.com/ChangeCaps/ori");
+        let mut data = ();
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.view_state.set_hovered(true);
+
+        let pressed = Event::PointerPressed(PointerPressed {
+            id: PointerId::from_u64(0),
+            position: Point::new(5.0, 5.0),
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+        });
+
+        let released = Event::PointerReleased(PointerReleased {
+            id: PointerId::from_u64(0),
+            position: Point::new(5.0, 5.0),
+            clicked: true,
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+        });
+
+        tester.event(&mut view, &mut data, &pressed);
+        tester.event(&mut view, &mut data, &released);
+
+        let command = tester.command_rx.try_recv().unwrap();
+        let url = command.get::<OpenUrl>().unwrap();
+        assert_eq!(url.0, "https://github.com/ChangeCaps/ori");
+    }
+}
@@ -0,0 +1,264 @@
+use crate::event::{Key, KeyPressed};
+
+/// What a key did to a [`ListNavigation`], see [`ListNavigation::key`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ListAction {
+    /// The item at the index was highlighted.
+    Highlight(usize),
+
+    /// The highlighted item at the index was activated, by pressing enter.
+    Activate(usize),
+}
+
+/// Keyboard navigation of a list of items, for list-like views.
+///
+/// Tracks the highlighted item, and moves it with the up and down arrows, page up and page
+/// down, and home and end. Enter activates the highlighted item. Items can be skipped, e.g.
+/// because they are disabled, with [`ListNavigation::key_filtered`].
+///
+/// # Example
+/// ```ignore
+/// match event {
+///     Event::KeyPressed(e) if cx.is_focused() => match state.navigation.key(e) {
+///         Some(ListAction::Highlight(_)) => {
+///             cx.draw();
+///             true
+///         }
+///         Some(ListAction::Activate(index)) => {
+///             self.select(cx, data, index);
+///             true
+///         }
+///         None => false,
+///     },
+///     _ => false,
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ListNavigation {
+    highlighted: Option<usize>,
+    len: usize,
+
+    /// Whether moving past either end wraps around to the other end, instead of stopping.
+    pub wrap: bool,
+
+    /// The number of items moved by page up and page down.
+    pub page: usize,
+}
+
+impl Default for ListNavigation {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl ListNavigation {
+    /// Create a new list navigation, of `len` items with none highlighted.
+    pub fn new(len: usize) -> Self {
+        Self {
+            highlighted: None,
+            len,
+            wrap: false,
+            page: 10,
+        }
+    }
+
+    /// Set whether the navigation wraps around, see [`ListNavigation::wrap`].
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Get the number of items.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Get whether there are no items.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Set the number of items, keeping the highlighted item within them.
+    pub fn set_len(&mut self, len: usize) {
+        self.len = len;
+        self.highlighted = match len {
+            0 => None,
+            _ => self.highlighted.map(|index| index.min(len - 1)),
+        };
+    }
+
+    /// Get the index of the highlighted item.
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// Highlight the item at `index`, or no item.
+    pub fn highlight(&mut self, index: Option<usize>) {
+        self.highlighted = index.filter(|&index| index < self.len);
+    }
+
+    /// Handle a key press, returning what it did, or `None` if it wasn't handled.
+    pub fn key(&mut self, event: &KeyPressed) -> Option<ListAction> {
+        self.key_filtered(event, |_| true)
+    }
+
+    /// Handle a key press like [`ListNavigation::key`], skipping items that are not `enabled`.
+    pub fn key_filtered(
+        &mut self,
+        event: &KeyPressed,
+        enabled: impl Fn(usize) -> bool,
+    ) -> Option<ListAction> {
+        if event.modifiers.ctrl || event.modifiers.alt || event.modifiers.meta {
+            return None;
+        }
+
+        let highlighted = match event.key {
+            Key::Enter => {
+                let index = self.highlighted.filter(|&index| enabled(index))?;
+                return Some(ListAction::Activate(index));
+            }
+            Key::Up => self.step(-1, &enabled),
+            Key::Down => self.step(1, &enabled),
+            Key::PageUp => self.step(-(self.page.max(1) as isize), &enabled),
+            Key::PageDown => self.step(self.page.max(1) as isize, &enabled),
+            Key::Home => (0..self.len).find(|&index| enabled(index)),
+            Key::End => (0..self.len).rev().find(|&index| enabled(index)),
+            _ => return None,
+        };
+
+        // the key is handled even when there is nothing to move to
+        if let Some(index) = highlighted {
+            self.highlighted = Some(index);
+        }
+
+        self.highlighted.map(ListAction::Highlight)
+    }
+
+    // find the enabled item `delta` items away from the highlighted one
+    fn step(&self, delta: isize, enabled: &impl Fn(usize) -> bool) -> Option<usize> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let len = self.len as isize;
+        let forward = delta > 0;
+
+        // with nothing highlighted, start from just outside the list
+        let current = match self.highlighted {
+            Some(index) => index as isize,
+            None if forward => -1,
+            None => len,
+        };
+
+        let target = match self.wrap {
+            true if current + delta >= len && current == len - 1 => 0,
+            true if current + delta < 0 && current == 0 => len - 1,
+            _ => (current + delta).clamp(0, len - 1),
+        };
+
+        // search from the target in the direction of movement, then back towards the start
+        let ahead = match forward {
+            true => (target..len).find(|&i| enabled(i as usize)),
+            false => (0..=target).rev().find(|&i| enabled(i as usize)),
+        };
+
+        let behind = || match forward {
+            true => (0..target).rev().find(|&i| enabled(i as usize)),
+            false => (target + 1..len).find(|&i| enabled(i as usize)),
+        };
+
+        ahead.or_else(behind).map(|index| index as usize)
+    }
+
+    /// Get the scroll offset that brings the item spanning `start` to `end` into a `viewport`,
+    /// scrolled to `scroll`, moving it as little as possible.
+    pub fn scroll_into_view(scroll: f32, start: f32, end: f32, viewport: f32) -> f32 {
+        if start < scroll {
+            start
+        } else if end > scroll + viewport {
+            f32::min(end - viewport, start)
+        } else {
+            scroll
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::event::Modifiers;
+
+    use super::*;
+
+    fn press(key: Key) -> KeyPressed {
+        KeyPressed {
+            key,
+            code: None,
+            text: None,
+            modifiers: Modifiers::default(),
+        }
+    }
+
+    #[test]
+    fn clamp_and_wrap() {
+        let mut navigation = ListNavigation::new(3);
+
+        let down = press(Key::Down);
+        assert_eq!(navigation.key(&down), Some(ListAction::Highlight(0)));
+        navigation.key(&down);
+        navigation.key(&down);
+        assert_eq!(navigation.key(&down), Some(ListAction::Highlight(2)));
+
+        navigation.wrap = true;
+        assert_eq!(navigation.key(&down), Some(ListAction::Highlight(0)));
+        assert_eq!(
+            navigation.key(&press(Key::Up)),
+            Some(ListAction::Highlight(2))
+        );
+        assert_eq!(
+            navigation.key(&press(Key::Enter)),
+            Some(ListAction::Activate(2))
+        );
+    }
+
+    #[test]
+    fn skips_disabled() {
+        let mut navigation = ListNavigation::new(5);
+        let enabled = |index| index != 1 && index != 4;
+
+        let down = press(Key::Down);
+        navigation.key_filtered(&down, enabled);
+        assert_eq!(
+            navigation.key_filtered(&down, enabled),
+            Some(ListAction::Highlight(2))
+        );
+
+        let end = press(Key::End);
+        assert_eq!(
+            navigation.key_filtered(&end, enabled),
+            Some(ListAction::Highlight(3))
+        );
+        assert_eq!(
+            navigation.key_filtered(&down, enabled),
+            Some(ListAction::Highlight(3))
+        );
+
+        navigation.set_len(2);
+        assert_eq!(navigation.highlighted(), Some(1));
+    }
+
+    #[test]
+    fn scroll_into_view() {
+        assert_eq!(
+            ListNavigation::scroll_into_view(50.0, 20.0, 40.0, 100.0),
+            20.0
+        );
+        assert_eq!(
+            ListNavigation::scroll_into_view(50.0, 140.0, 160.0, 100.0),
+            60.0
+        );
+        assert_eq!(
+            ListNavigation::scroll_into_view(50.0, 60.0, 80.0, 100.0),
+            50.0
+        );
+    }
+}
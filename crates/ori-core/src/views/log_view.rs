@@ -0,0 +1,770 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    ops::Range,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use ori_macro::{Build, Styled};
+use smol_str::SmolStr;
+
+use crate::{
+    canvas::{BorderRadius, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Key},
+    layout::{Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    text::{FontFamily, TextAttributes, TextBuffer, TextWrap},
+    view::View,
+    window::Cursor,
+};
+
+/// Create a new [`LogView`].
+pub fn log_view(log: &Log) -> LogView {
+    LogView::new(log)
+}
+
+/// The level of a [`LogLine`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// A trace message.
+    Trace,
+
+    /// A debug message.
+    Debug,
+
+    /// An informational message, the default.
+    #[default]
+    Info,
+
+    /// A warning.
+    Warn,
+
+    /// An error.
+    Error,
+}
+
+/// A line of a [`Log`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct LogLine {
+    /// The level of the line.
+    pub level: LogLevel,
+
+    /// The text of the line.
+    pub text: SmolStr,
+}
+
+struct LogLines {
+    lines: VecDeque<LogLine>,
+    capacity: usize,
+    // the number of lines removed from the front, used to identify lines across pushes
+    removed: u64,
+    version: u64,
+    // the number of times the log has been cleared
+    generation: u64,
+}
+
+/// A growing list of lines displayed by a [`LogView`].
+///
+/// A log is a cheap handle to shared lines, so it can be cloned and pushed to from anywhere,
+/// including other threads. When more than [`Log::capacity`] lines are pushed, the oldest
+/// lines are removed.
+#[derive(Clone)]
+pub struct Log {
+    lines: Arc<Mutex<LogLines>>,
+}
+
+impl Default for Log {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Log {
+    /// The default capacity of a log.
+    pub const DEFAULT_CAPACITY: usize = 10_000;
+
+    /// Create a new empty log, with the [`Log::DEFAULT_CAPACITY`].
+    pub fn new() -> Self {
+        Self::with_capacity(Self::DEFAULT_CAPACITY)
+    }
+
+    /// Create a new empty log, that keeps at most `capacity` lines.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let lines = LogLines {
+            lines: VecDeque::new(),
+            capacity: capacity.max(1),
+            removed: 0,
+            version: 0,
+            generation: 0,
+        };
+
+        Self {
+            lines: Arc::new(Mutex::new(lines)),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LogLines> {
+        // a panic while pushing can't leave the lines in an invalid state
+        self.lines.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Push a line with a `level`, every line of `text` is pushed separately.
+    pub fn push(&self, level: LogLevel, text: impl AsRef<str>) {
+        let mut lines = self.lock();
+
+        for text in text.as_ref().lines() {
+            if lines.lines.len() >= lines.capacity {
+                lines.lines.pop_front();
+                lines.removed += 1;
+            }
+
+            let text = SmolStr::new(text);
+            lines.lines.push_back(LogLine { level, text });
+        }
+
+        lines.version += 1;
+    }
+
+    /// Push a [`LogLevel::Trace`] line.
+    pub fn trace(&self, text: impl AsRef<str>) {
+        self.push(LogLevel::Trace, text);
+    }
+
+    /// Push a [`LogLevel::Debug`] line.
+    pub fn debug(&self, text: impl AsRef<str>) {
+        self.push(LogLevel::Debug, text);
+    }
+
+    /// Push a [`LogLevel::Info`] line.
+    pub fn info(&self, text: impl AsRef<str>) {
+        self.push(LogLevel::Info, text);
+    }
+
+    /// Push a [`LogLevel::Warn`] line.
+    pub fn warn(&self, text: impl AsRef<str>) {
+        self.push(LogLevel::Warn, text);
+    }
+
+    /// Push a [`LogLevel::Error`] line.
+    pub fn error(&self, text: impl AsRef<str>) {
+        self.push(LogLevel::Error, text);
+    }
+
+    /// Remove all the lines.
+    pub fn clear(&self) {
+        let mut lines = self.lock();
+        lines.removed += lines.lines.len() as u64;
+        lines.lines.clear();
+        lines.version += 1;
+        lines.generation += 1;
+    }
+
+    /// Get the maximum number of lines kept.
+    pub fn capacity(&self) -> usize {
+        self.lock().capacity
+    }
+
+    /// Get the number of lines.
+    pub fn len(&self) -> usize {
+        self.lock().lines.len()
+    }
+
+    /// Get whether the log is empty.
+    pub fn is_empty(&self) -> bool {
+        self.lock().lines.is_empty()
+    }
+
+    /// Get the line at `index`.
+    pub fn line(&self, index: usize) -> Option<LogLine> {
+        self.lock().lines.get(index).cloned()
+    }
+
+    /// Get the version of the log, which changes every time it is modified.
+    pub fn version(&self) -> u64 {
+        self.lock().version
+    }
+}
+
+/// A view that displays a [`Log`], for example as a console in developer tools.
+///
+/// Only the visible lines are shaped, so logs with many lines are cheap to display. The view
+/// scrolls to the bottom when lines are pushed, unless it has been scrolled up. Lines can be
+/// selected by clicking and dragging, and copied with `Ctrl+C`.
+///
+/// Lines pushed to the log are shown the next time the view is rebuilt.
+///
+/// Can be styled using the [`LogViewStyle`].
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// struct Data {
+///     log: Log,
+/// }
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     let push = button(text("Push")).on_press(|cx, data: &mut Data| {
+///         data.log.warn("Something happened");
+///         cx.rebuild();
+///     });
+///
+///     vstack![push, log_view(&data.log)]
+/// }
+/// ```
+#[derive(Styled, Build, Rebuild)]
+pub struct LogView {
+    /// The log to display.
+    #[build(ignore)]
+    pub log: Log,
+
+    /// The font size of the lines.
+    #[rebuild(layout)]
+    #[styled(default = 14.0)]
+    pub font_size: Styled<f32>,
+
+    /// The font family of the lines.
+    #[rebuild(layout)]
+    #[styled(default = FontFamily::Monospace)]
+    pub font_family: Styled<FontFamily>,
+
+    /// The line height of the lines.
+    #[rebuild(layout)]
+    #[styled(default = 1.3)]
+    pub line_height: Styled<f32>,
+
+    /// The color of [`LogLevel::Trace`] lines.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::CONTRAST_LOW or Color::grayscale(0.5))]
+    pub trace_color: Styled<Color>,
+
+    /// The color of [`LogLevel::Debug`] lines.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::INFO or Color::BLUE)]
+    pub debug_color: Styled<Color>,
+
+    /// The color of [`LogLevel::Info`] lines.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::CONTRAST or Color::BLACK)]
+    pub info_color: Styled<Color>,
+
+    /// The color of [`LogLevel::Warn`] lines.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::WARNING or Color::YELLOW)]
+    pub warn_color: Styled<Color>,
+
+    /// The color of [`LogLevel::Error`] lines.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::DANGER or Color::RED)]
+    pub error_color: Styled<Color>,
+
+    /// The color of the background of selected lines.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::PRIMARY_LOW or Color::BLUE.fade(0.3))]
+    pub selection_color: Styled<Color>,
+
+    /// The color of the scrollbar knob.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::CONTRAST_LOW or Color::grayscale(0.5))]
+    pub knob_color: Styled<Color>,
+}
+
+impl LogView {
+    // the width of the scrollbar knob
+    const KNOB_WIDTH: f32 = 6.0;
+
+    /// Create a new [`LogView`].
+    pub fn new(log: &Log) -> Self {
+        Self {
+            log: log.clone(),
+            font_size: LogViewStyle::FONT_SIZE.into(),
+            font_family: LogViewStyle::FONT_FAMILY.into(),
+            line_height: LogViewStyle::LINE_HEIGHT.into(),
+            trace_color: LogViewStyle::TRACE_COLOR.into(),
+            debug_color: LogViewStyle::DEBUG_COLOR.into(),
+            info_color: LogViewStyle::INFO_COLOR.into(),
+            warn_color: LogViewStyle::WARN_COLOR.into(),
+            error_color: LogViewStyle::ERROR_COLOR.into(),
+            selection_color: LogViewStyle::SELECTION_COLOR.into(),
+            knob_color: LogViewStyle::KNOB_COLOR.into(),
+        }
+    }
+}
+
+impl LogViewStyle {
+    fn line_height(&self) -> f32 {
+        self.font_size * self.line_height
+    }
+
+    fn color(&self, level: LogLevel) -> Color {
+        match level {
+            LogLevel::Trace => self.trace_color,
+            LogLevel::Debug => self.debug_color,
+            LogLevel::Info => self.info_color,
+            LogLevel::Warn => self.warn_color,
+            LogLevel::Error => self.error_color,
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct LogViewState {
+    style: LogViewStyle,
+    version: u64,
+    generation: u64,
+    scroll: f32,
+    // whether the view follows new lines, i.e. is scrolled to the bottom
+    follow: bool,
+    // the selected lines, as the anchor and the head, identified across removals from the front
+    selection: Option<(u64, u64)>,
+    // the shaped visible lines
+    buffers: HashMap<u64, TextBuffer>,
+}
+
+impl LogViewState {
+    // the selection refers to lines that were removed when the log is cleared
+    fn sync_generation(&mut self, lines: &LogLines) {
+        if lines.generation != self.generation {
+            self.generation = lines.generation;
+            self.selection = None;
+        }
+    }
+
+    fn content_height(&self, len: usize) -> f32 {
+        len as f32 * self.style.line_height()
+    }
+
+    fn overflow(&self, len: usize, height: f32) -> f32 {
+        f32::max(self.content_height(len) - height, 0.0)
+    }
+
+    fn clamp_scroll(&mut self, len: usize, height: f32) {
+        let overflow = self.overflow(len, height);
+
+        match self.follow {
+            true => self.scroll = overflow,
+            false => self.scroll = self.scroll.clamp(0.0, overflow),
+        }
+    }
+
+    fn scroll_by(&mut self, delta: f32, len: usize, height: f32) {
+        let overflow = self.overflow(len, height);
+        self.scroll = f32::clamp(self.scroll + delta, 0.0, overflow);
+        self.follow = self.scroll >= overflow - 0.5;
+    }
+
+    // the line identifier at a local y coordinate
+    fn line_at(&self, y: f32, lines: &LogLines) -> Option<u64> {
+        if lines.lines.is_empty() {
+            return None;
+        }
+
+        let index = ((y + self.scroll) / self.style.line_height()).max(0.0) as usize;
+        let index = index.min(lines.lines.len() - 1);
+        Some(lines.removed + index as u64)
+    }
+
+    fn visible(&self, len: usize, height: f32) -> Range<usize> {
+        let line_height = self.style.line_height();
+        let start = (self.scroll / line_height).floor().max(0.0) as usize;
+        let end = ((self.scroll + height) / line_height).ceil().max(0.0) as usize;
+        start.min(len)..end.min(len)
+    }
+
+    fn selected(&self, id: u64) -> bool {
+        match self.selection {
+            Some((anchor, head)) => anchor.min(head) <= id && id <= anchor.max(head),
+            None => false,
+        }
+    }
+
+    fn selected_text(&self, lines: &LogLines) -> Option<String> {
+        let (anchor, head) = self.selection?;
+        let start = anchor.min(head).saturating_sub(lines.removed) as usize;
+        let end = anchor.max(head).saturating_sub(lines.removed) as usize;
+
+        let mut text = String::new();
+
+        for line in lines
+            .lines
+            .range(start..=end.min(lines.lines.len().checked_sub(1)?))
+        {
+            if !text.is_empty() {
+                text.push('\n');
+            }
+
+            text.push_str(&line.text);
+        }
+
+        Some(text)
+    }
+}
+
+impl<T> View<T> for LogView {
+    type State = LogViewState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
+        LogViewState {
+            style: LogViewStyle::styled(self, cx.styles()),
+            version: self.log.version(),
+            generation: self.log.lock().generation,
+            scroll: 0.0,
+            follow: true,
+            selection: None,
+            buffers: HashMap::new(),
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+
+        let font_size = state.style.font_size;
+        let font_family = state.style.font_family.clone();
+        let line_height = state.style.line_height;
+        state.style.rebuild(self, cx);
+
+        if state.style.font_size != font_size
+            || state.style.font_family != font_family
+            || state.style.line_height != line_height
+        {
+            state.buffers.clear();
+        }
+
+        if !Arc::ptr_eq(&self.log.lines, &old.log.lines) {
+            state.buffers.clear();
+            state.selection = None;
+            cx.layout();
+        }
+
+        let version = self.log.version();
+        state.sync_generation(&self.log.lock());
+
+        if version != state.version {
+            state.version = version;
+            cx.layout();
+        }
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        _data: &mut T,
+        event: &Event,
+    ) -> bool {
+        if cx.is_hovered() || cx.is_active() {
+            cx.set_cursor(Some(Cursor::Text));
+        } else {
+            cx.set_cursor(None);
+        }
+
+        let height = cx.size().height;
+        state.sync_generation(&self.log.lock());
+
+        match event {
+            Event::PointerScrolled(e) if cx.is_hovered() => {
+                let len = self.log.len();
+                state.scroll_by(-e.delta.y * 10.0, len, height);
+                cx.draw();
+
+                true
+            }
+            Event::PointerPressed(e) if cx.is_hovered() => {
+                let local = cx.local(e.position);
+                let lines = self.log.lock();
+
+                if let Some(id) = state.line_at(local.y, &lines) {
+                    state.selection = match state.selection {
+                        Some((anchor, _)) if e.modifiers.shift => Some((anchor, id)),
+                        _ => Some((id, id)),
+                    };
+                }
+
+                drop(lines);
+
+                cx.set_active(true);
+                cx.set_focused(true);
+                cx.draw();
+
+                true
+            }
+            Event::PointerMoved(e) if cx.is_active() => {
+                let local = cx.local(e.position);
+                let lines = self.log.lock();
+
+                if let (Some((anchor, _)), Some(id)) =
+                    (state.selection, state.line_at(local.y, &lines))
+                {
+                    state.selection = Some((anchor, id));
+                    cx.draw();
+                }
+
+                true
+            }
+            Event::PointerReleased(_) if cx.is_active() => {
+                cx.set_active(false);
+
+                true
+            }
+            Event::KeyPressed(e) if cx.is_focused() => {
+                let len = self.log.len();
+
+                if e.is_key('c') && e.modifiers.ctrl {
+                    if let Some(text) = state.selected_text(&self.log.lock()) {
+                        cx.clipboard().set(text);
+                    }
+
+                    return true;
+                }
+
+                if e.is_key('a') && e.modifiers.ctrl {
+                    let lines = self.log.lock();
+
+                    if !lines.lines.is_empty() {
+                        let last = lines.removed + lines.lines.len() as u64 - 1;
+                        state.selection = Some((lines.removed, last));
+                    }
+
+                    cx.draw();
+                    return true;
+                }
+
+                let line_height = state.style.line_height();
+
+                match e.key {
+                    Key::Escape => state.selection = None,
+                    Key::Up => state.scroll_by(-line_height, len, height),
+                    Key::Down => state.scroll_by(line_height, len, height),
+                    Key::PageUp => state.scroll_by(-height, len, height),
+                    Key::PageDown => state.scroll_by(height, len, height),
+                    Key::Home => state.scroll_by(f32::NEG_INFINITY, len, height),
+                    Key::End => state.scroll_by(f32::INFINITY, len, height),
+                    _ => return false,
+                }
+
+                cx.draw();
+
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        _cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        let len = self.log.len();
+
+        // the log fills the available width, and grows with its lines until it has to scroll
+        let width = match space.max.width.is_finite() {
+            true => space.max.width,
+            false => space.min.width,
+        };
+
+        let size = space.fit(Size::new(width, state.content_height(len)));
+        state.clamp_scroll(len, size.height);
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        let lines = self.log.lock();
+        let len = lines.lines.len();
+        let size = cx.size();
+
+        state.sync_generation(&lines);
+        state.clamp_scroll(len, size.height);
+
+        let visible = state.visible(len, size.height);
+        let line_height = state.style.line_height();
+
+        // forget the lines that are no longer visible
+        let first = lines.removed + visible.start as u64;
+        let last = lines.removed + visible.end as u64;
+        state.buffers.retain(|id, _| first <= *id && *id < last);
+
+        cx.trigger(cx.rect());
+
+        cx.masked(cx.rect(), |cx| {
+            for index in visible {
+                let line = &lines.lines[index];
+                let id = lines.removed + index as u64;
+                let y = index as f32 * line_height - state.scroll;
+
+                if state.selected(id) {
+                    let rect =
+                        Rect::min_size(Point::new(0.0, y), Size::new(size.width, line_height));
+                    cx.fill_rect(rect, state.style.selection_color);
+                }
+
+                let buffer = state.buffers.entry(id).or_insert_with(|| {
+                    let fonts = cx.fonts();
+                    let mut buffer =
+                        TextBuffer::new(fonts, state.style.font_size, state.style.line_height);
+
+                    let attrs = TextAttributes {
+                        family: state.style.font_family.clone(),
+                        ..Default::default()
+                    };
+
+                    buffer.set_wrap(fonts, TextWrap::None);
+                    buffer.set_text(fonts, &line.text, attrs);
+                    buffer
+                });
+
+                cx.text(buffer, state.style.color(line.level), Vector::new(0.0, y));
+            }
+        });
+
+        let overflow = state.overflow(len, size.height);
+
+        if overflow > 0.0 {
+            let content_height = state.content_height(len);
+            let knob_height = f32::max(size.height * size.height / content_height, 20.0);
+            let knob_y = (size.height - knob_height) * state.scroll / overflow;
+
+            let knob = Rect::min_size(
+                Point::new(size.width - Self::KNOB_WIDTH - 2.0, knob_y),
+                Size::new(Self::KNOB_WIDTH, knob_height),
+            );
+
+            cx.quad(
+                knob,
+                state.style.knob_color.fade(0.6),
+                BorderRadius::all(Self::KNOB_WIDTH / 2.0),
+                0.0,
+                Color::TRANSPARENT,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Modifiers, PointerButton, PointerId, PointerPressed, PointerScrolled},
+        views::testing::ViewTester,
+    };
+
+    use super::*;
+
+    #[test]
+    fn capacity() {
+        let log = Log::with_capacity(2);
+        log.info("a\nb");
+        log.error("c");
+
+        assert_eq!(log.len(), 2);
+        assert_eq!(log.line(0).unwrap().text, "b");
+        assert_eq!(log.line(1).unwrap().level, LogLevel::Error);
+    }
+
+    #[test]
+    fn follows_new_lines() {
+        let log = Log::new();
+        log.info("first");
+
+        let mut view = log_view(&log).font_size(10.0).line_height(1.0);
+        let mut tester = ViewTester::new(&mut view, &mut ());
+
+        let space = Space::new(Size::ZERO, Size::new(100.0, 50.0));
+        tester.layout(&mut view, &mut (), space);
+        assert_eq!(tester.state.scroll, 0.0);
+
+        // the view follows new lines, while scrolled to the bottom
+        for i in 0..10 {
+            log.info(format!("line {}", i));
+        }
+
+        tester.layout(&mut view, &mut (), space);
+        assert_eq!(tester.state.scroll, 60.0);
+
+        // after scrolling up, it stays in place
+        tester.view_state.set_hovered(true);
+
+        let scrolled = Event::PointerScrolled(PointerScrolled {
+            id: PointerId::from_u64(0),
+            position: Point::new(5.0, 5.0),
+            delta: Vector::new(0.0, 1.0),
+            modifiers: Modifiers::default(),
+        });
+
+        tester.event(&mut view, &mut (), &scrolled);
+        log.info("last");
+
+        tester.layout(&mut view, &mut (), space);
+        assert_eq!(tester.state.scroll, 50.0);
+    }
+
+    #[test]
+    fn select_and_copy() {
+        let log = Log::new();
+        log.info("a\nb\nc");
+
+        let mut view = log_view(&log).font_size(10.0).line_height(1.0);
+        let mut tester = ViewTester::new(&mut view, &mut ());
+
+        tester.layout(
+            &mut view,
+            &mut (),
+            Space::new(Size::ZERO, Size::new(100.0, 100.0)),
+        );
+        tester.view_state.set_hovered(true);
+
+        let pressed = |y, shift| {
+            Event::PointerPressed(PointerPressed {
+                id: PointerId::from_u64(0),
+                position: Point::new(5.0, y),
+                button: PointerButton::Primary,
+                modifiers: Modifiers {
+                    shift,
+                    ..Default::default()
+                },
+            })
+        };
+
+        tester.event(&mut view, &mut (), &pressed(15.0, false));
+        tester.event(&mut view, &mut (), &pressed(25.0, true));
+
+        let lines = log.lock();
+        let text = tester.state.selected_text(&lines);
+        assert_eq!(text.as_deref(), Some("b\nc"));
+    }
+
+    #[test]
+    fn clear_resets_selection() {
+        let log = Log::new();
+        log.info("a\nb");
+
+        let mut view = log_view(&log).font_size(10.0).line_height(1.0);
+        let mut tester = ViewTester::new(&mut view, &mut ());
+
+        let space = Space::new(Size::ZERO, Size::new(100.0, 100.0));
+        tester.layout(&mut view, &mut (), space);
+        tester.view_state.set_hovered(true);
+
+        let pressed = Event::PointerPressed(PointerPressed {
+            id: PointerId::from_u64(0),
+            position: Point::new(5.0, 5.0),
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+        });
+
+        tester.event(&mut view, &mut (), &pressed);
+        assert!(tester.state.selection.is_some());
+
+        log.clear();
+        log.info("c");
+
+        let old = log_view(&log).font_size(10.0).line_height(1.0);
+        tester.rebuild(&mut view, &mut (), &old);
+
+        assert_eq!(tester.state.selection, None);
+    }
+}
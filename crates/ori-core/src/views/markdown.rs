@@ -0,0 +1,705 @@
+use ori_macro::{Build, Styled};
+
+use crate::{
+    canvas::Color,
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Align, Point, Rect, Size, Space, Vector},
+    style::{Styled, Theme},
+    text::{FontFamily, FontStyle, FontWeight, Fonts, TextAttributes, TextBuffer},
+    view::{any, BoxedView, Pod, State, View},
+    views::{background, height, hstack_any, hwrap_any, link, pad, text, vstack_any},
+};
+
+/// Create a new [`Markdown`] view.
+pub fn markdown(source: impl Into<String>) -> Markdown {
+    Markdown::new(source)
+}
+
+/// A view that renders a subset of markdown.
+///
+/// Supported are headings, paragraphs, **bold** and *italic* text, `code`, fenced code
+/// blocks, bullet and numbered lists, horizontal rules and links. Links are [`Link`] views,
+/// which open their URL when clicked.
+///
+/// Can be styled using the [`MarkdownStyle`].
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// fn about(_data: &mut Data) -> impl View<Data> {
+///     markdown("# About\n\nMade with [ori](https://github.com/ChangeCaps/ori).")
+/// }
+/// ```
+#[derive(Styled, Build)]
+pub struct Markdown {
+    /// The markdown source.
+    #[build(ignore)]
+    pub source: String,
+
+    /// The font size of paragraphs.
+    #[styled(default = 16.0)]
+    pub font_size: Styled<f32>,
+
+    /// The color of the text.
+    #[styled(default -> Theme::CONTRAST or Color::BLACK)]
+    pub color: Styled<Color>,
+
+    /// The color of links.
+    #[styled(default -> Theme::PRIMARY or Color::BLUE)]
+    pub link_color: Styled<Color>,
+
+    /// The background color of code.
+    #[styled(default -> Theme::SURFACE_HIGH or Color::grayscale(0.9))]
+    pub code_background: Styled<Color>,
+
+    /// The color of horizontal rules.
+    #[styled(default -> Theme::OUTLINE or Color::grayscale(0.5))]
+    pub rule_color: Styled<Color>,
+
+    /// The gap between blocks.
+    #[styled(default = 8.0)]
+    pub gap: Styled<f32>,
+}
+
+impl Markdown {
+    /// Create a new [`Markdown`] view.
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+            font_size: MarkdownStyle::FONT_SIZE.into(),
+            color: MarkdownStyle::COLOR.into(),
+            link_color: MarkdownStyle::LINK_COLOR.into(),
+            code_background: MarkdownStyle::CODE_BACKGROUND.into(),
+            rule_color: MarkdownStyle::RULE_COLOR.into(),
+            gap: MarkdownStyle::GAP.into(),
+        }
+    }
+
+    fn view(&self, style: &MarkdownStyle) -> BoxedView<()> {
+        let mut blocks = vstack_any().align(Align::Stretch).gap(style.gap);
+
+        for block in parse_blocks(&self.source) {
+            blocks.push(block_view(style, block));
+        }
+
+        any(blocks)
+    }
+}
+
+fn style_changed(a: &MarkdownStyle, b: &MarkdownStyle) -> bool {
+    a.font_size != b.font_size
+        || a.color != b.color
+        || a.link_color != b.link_color
+        || a.code_background != b.code_background
+        || a.rule_color != b.rule_color
+        || a.gap != b.gap
+}
+
+#[doc(hidden)]
+pub struct MarkdownState {
+    style: MarkdownStyle,
+    view: Pod<BoxedView<()>>,
+    state: State<(), BoxedView<()>>,
+}
+
+impl<T> View<T> for Markdown {
+    type State = MarkdownState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        let style = MarkdownStyle::styled(self, cx.styles());
+        let mut view = Pod::new(self.view(&style));
+        let state = view.build(cx, &mut ());
+
+        MarkdownState { style, view, state }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        let style = MarkdownStyle::styled(self, cx.styles());
+
+        // the content is only rebuilt when the source or style changes
+        if self.source != old.source || style_changed(&style, &state.style) {
+            let mut view = Pod::new(self.view(&style));
+            view.rebuild(&mut state.state, cx, &mut (), &state.view);
+
+            state.view = view;
+            state.style = style;
+        }
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        _data: &mut T,
+        event: &Event,
+    ) -> bool {
+        state.view.event(&mut state.state, cx, &mut (), event)
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        state.view.layout(&mut state.state, cx, &mut (), space)
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        state.view.draw(&mut state.state, cx, &mut ());
+    }
+}
+
+fn block_view(style: &MarkdownStyle, block: Block) -> BoxedView<()> {
+    match block {
+        Block::Heading(level, spans) => {
+            let scale = match level {
+                1 => 2.0,
+                2 => 1.5,
+                3 => 1.25,
+                _ => 1.0,
+            };
+
+            inline_view(style, style.font_size * scale, true, spans)
+        }
+        Block::Paragraph(spans) => inline_view(style, style.font_size, false, spans),
+        Block::Item(number, spans) => {
+            let marker = match number {
+                Some(number) => format!("{}.", number),
+                None => String::from("\u{2022}"),
+            };
+
+            let marker = text(marker).font_size(style.font_size).color(style.color);
+            let content = inline_view(style, style.font_size, false, spans);
+
+            let mut item = hstack_any().align(Align::Start).gap(style.font_size / 2.0);
+            item.push(any(marker));
+            item.push(content);
+
+            any(pad([0.0, 0.0, 0.0, style.font_size], item))
+        }
+        Block::Code(code) => {
+            let code = text(code.trim_end_matches('\n'))
+                .font_size(style.font_size * 0.9)
+                .font_family(FontFamily::Monospace)
+                .color(style.color);
+
+            any(background(style.code_background, pad(8.0, code)))
+        }
+        Block::Rule => any(height(1.0, background(style.rule_color, ()))),
+    }
+}
+
+// paragraphs are shaped as a whole, except for links which are split out into `Link` views
+fn inline_view(
+    style: &MarkdownStyle,
+    font_size: f32,
+    bold: bool,
+    spans: Vec<Span>,
+) -> BoxedView<()> {
+    let mut paragraph = hwrap_any();
+    let mut runs = Vec::new();
+    let mut spans = spans.into_iter().peekable();
+
+    let inline = |runs: &mut Vec<Span>| Inline {
+        spans: std::mem::take(runs),
+        font_size,
+        bold,
+        color: style.color,
+        code_background: style.code_background,
+    };
+
+    while let Some(span) = spans.next() {
+        let Some(ref url) = span.link else {
+            runs.push(span);
+            continue;
+        };
+
+        if !runs.is_empty() {
+            paragraph.push(any(inline(&mut runs)));
+        }
+
+        // a link label may be split into several spans, e.g. when part of it is bold
+        let mut label = span.text.clone();
+        while let Some(next) = spans.next_if(|next| next.link.as_ref() == Some(url)) {
+            label.push_str(&next.text);
+        }
+
+        let mut view = link(label, url.clone())
+            .font_size(font_size)
+            .color(style.link_color);
+
+        if span.code {
+            view = view.font_family(FontFamily::Monospace);
+        }
+
+        paragraph.push(any(view));
+    }
+
+    if !runs.is_empty() || paragraph.is_empty() {
+        paragraph.push(any(inline(&mut runs)));
+    }
+
+    any(paragraph)
+}
+
+// a run of spans shaped into a single text buffer
+struct Inline {
+    spans: Vec<Span>,
+    font_size: f32,
+    bold: bool,
+    color: Color,
+    code_background: Color,
+}
+
+impl Inline {
+    fn attributes(&self, span: &Span) -> TextAttributes {
+        TextAttributes {
+            family: match span.code {
+                true => FontFamily::Monospace,
+                false => FontFamily::SansSerif,
+            },
+            weight: match self.bold || span.bold {
+                true => FontWeight::BOLD,
+                false => FontWeight::NORMAL,
+            },
+            style: match span.italic {
+                true => FontStyle::Italic,
+                false => FontStyle::Normal,
+            },
+            ..Default::default()
+        }
+    }
+
+    fn set_text(&self, fonts: &mut Fonts, buffer: &mut TextBuffer) {
+        let spans = self.spans.iter().map(|span| {
+            let attributes = self.attributes(span);
+            (span.text.as_str(), attributes)
+        });
+
+        buffer.set_metrics(fonts, self.font_size, 1.2);
+        buffer.set_rich_text(fonts, spans);
+    }
+}
+
+impl View<()> for Inline {
+    type State = TextBuffer;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut ()) -> Self::State {
+        let mut buffer = TextBuffer::new(cx.fonts(), self.font_size, 1.2);
+        self.set_text(cx.fonts(), &mut buffer);
+        buffer
+    }
+
+    fn rebuild(
+        &mut self,
+        buffer: &mut Self::State,
+        cx: &mut RebuildCx,
+        _data: &mut (),
+        old: &Self,
+    ) {
+        if self.spans != old.spans || self.font_size != old.font_size || self.bold != old.bold {
+            self.set_text(cx.fonts(), buffer);
+            cx.layout();
+        }
+
+        if self.color != old.color || self.code_background != old.code_background {
+            cx.draw();
+        }
+    }
+
+    fn event(
+        &mut self,
+        _buffer: &mut Self::State,
+        _cx: &mut EventCx,
+        _data: &mut (),
+        _event: &Event,
+    ) -> bool {
+        false
+    }
+
+    fn layout(
+        &mut self,
+        buffer: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut (),
+        space: Space,
+    ) -> Size {
+        buffer.set_bounds(cx.fonts(), space.max);
+        space.fit(buffer.size())
+    }
+
+    fn draw(&mut self, buffer: &mut Self::State, cx: &mut DrawCx, _data: &mut ()) {
+        for run in buffer.raw().layout_runs() {
+            let mut code = None::<(f32, f32)>;
+
+            let mut fill = |code: Option<(f32, f32)>| {
+                if let Some((start, end)) = code {
+                    let min = Point::new(start, run.line_top);
+                    let rect = Rect::min_size(min, Size::new(end - start, run.line_height));
+                    cx.fill_rect(rect, self.code_background);
+                }
+            };
+
+            // the background of code is drawn behind consecutive glyphs of code spans
+            for glyph in run.glyphs {
+                if self.spans[glyph.metadata].code {
+                    let start = code.map_or(glyph.x, |(start, _)| start);
+                    code = Some((start, glyph.x + glyph.w));
+                } else {
+                    fill(code.take());
+                }
+            }
+
+            fill(code);
+        }
+
+        cx.text(buffer, self.color, Vector::ZERO);
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Block {
+    Heading(u8, Vec<Span>),
+    Paragraph(Vec<Span>),
+    Item(Option<u32>, Vec<Span>),
+    Code(String),
+    Rule,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Span {
+    text: String,
+    bold: bool,
+    italic: bool,
+    code: bool,
+    link: Option<String>,
+}
+
+fn parse_blocks(source: &str) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    let mut paragraph = String::new();
+    let mut code = None::<String>;
+
+    let flush = |blocks: &mut Vec<Block>, paragraph: &mut String| {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(parse_spans(paragraph)));
+            paragraph.clear();
+        }
+    };
+
+    for line in source.lines() {
+        let trimmed = line.trim();
+
+        if let Some(ref mut block) = code {
+            match trimmed.starts_with("```") {
+                true => blocks.push(Block::Code(code.take().unwrap())),
+                false => {
+                    block.push_str(line);
+                    block.push('\n');
+                }
+            }
+
+            continue;
+        }
+
+        if trimmed.starts_with("```") {
+            flush(&mut blocks, &mut paragraph);
+            code = Some(String::new());
+        } else if trimmed.is_empty() {
+            flush(&mut blocks, &mut paragraph);
+        } else if is_rule(trimmed) {
+            flush(&mut blocks, &mut paragraph);
+            blocks.push(Block::Rule);
+        } else if let Some((level, heading)) = parse_heading(trimmed) {
+            flush(&mut blocks, &mut paragraph);
+            blocks.push(Block::Heading(level, parse_spans(heading)));
+        } else if let Some((number, item)) = parse_item(trimmed) {
+            flush(&mut blocks, &mut paragraph);
+            blocks.push(Block::Item(number, parse_spans(item)));
+        } else {
+            if !paragraph.is_empty() {
+                paragraph.push(' ');
+            }
+
+            paragraph.push_str(trimmed);
+        }
+    }
+
+    flush(&mut blocks, &mut paragraph);
+
+    // an unterminated code block runs to the end
+    if let Some(code) = code {
+        blocks.push(Block::Code(code));
+    }
+
+    blocks
+}
+
+fn is_rule(line: &str) -> bool {
+    let mut chars = line.chars().filter(|c| !c.is_whitespace());
+    let first = chars.next();
+
+    matches!(first, Some('-' | '*' | '_'))
+        && line.chars().filter(|c| Some(*c) == first).count() >= 3
+        && chars.all(|c| Some(c) == first)
+}
+
+fn parse_heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let heading = line[level..].strip_prefix(' ')?;
+
+    (1..=6)
+        .contains(&level)
+        .then(|| (level as u8, heading.trim()))
+}
+
+fn parse_item(line: &str) -> Option<(Option<u32>, &str)> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(item) = line.strip_prefix(bullet) {
+            return Some((None, item.trim()));
+        }
+    }
+
+    let (number, item) = line.split_once(". ")?;
+    let number = number.parse().ok()?;
+
+    Some((Some(number), item.trim()))
+}
+
+fn parse_spans(text: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut span = Span::default();
+    let mut chars = text.char_indices().peekable();
+    let mut prev = None::<char>;
+
+    let push = |spans: &mut Vec<Span>, span: &mut Span| {
+        if !span.text.is_empty() {
+            spans.push(span.clone());
+            span.text.clear();
+        }
+    };
+
+    while let Some((i, c)) = chars.next() {
+        let next = chars.peek().map(|(_, c)| *c);
+
+        match c {
+            '\\' if next.is_some() => {
+                let (_, next) = chars.next().unwrap();
+                span.text.push(next);
+            }
+            '*' | '_' if next == Some(c) && (span.bold || closes(&text[i + 2..], c, 2)) => {
+                chars.next();
+                push(&mut spans, &mut span);
+                span.bold = !span.bold;
+            }
+            '*' | '_' if next == Some(c) => {
+                chars.next();
+                span.text.push(c);
+                span.text.push(c);
+            }
+            // underscores inside words, e.g. snake_case, are kept
+            '_' if prev.is_some_and(char::is_alphanumeric)
+                && next.is_some_and(char::is_alphanumeric) =>
+            {
+                span.text.push(c);
+            }
+            '*' | '_' if span.italic || closes(&text[i + 1..], c, 1) => {
+                push(&mut spans, &mut span);
+                span.italic = !span.italic;
+            }
+            '`' => match text[i + 1..].find('`') {
+                Some(len) => {
+                    push(&mut spans, &mut span);
+
+                    let code = &text[i + 1..i + 1 + len];
+                    spans.push(Span {
+                        text: code.to_string(),
+                        code: true,
+                        ..Span::default()
+                    });
+
+                    while chars.next_if(|(j, _)| *j <= i + 1 + len).is_some() {}
+                }
+                None => span.text.push(c),
+            },
+            '[' => match parse_link(&text[i..]) {
+                Some((label, url, len)) => {
+                    push(&mut spans, &mut span);
+
+                    for mut label in parse_spans(label) {
+                        label.bold |= span.bold;
+                        label.italic |= span.italic;
+                        label.link = Some(url.to_string());
+                        spans.push(label);
+                    }
+
+                    while chars.next_if(|(j, _)| *j < i + len).is_some() {}
+                }
+                None => span.text.push(c),
+            },
+            _ => span.text.push(c),
+        }
+
+        prev = Some(c);
+    }
+
+    push(&mut spans, &mut span);
+    spans
+}
+
+// whether a run of exactly `count` delimiters `c` follows in `text`, so that an emphasis
+// isn't opened without being closed
+fn closes(text: &str, c: char, count: usize) -> bool {
+    let mut run = 0;
+
+    for next in text.chars().chain([' ']) {
+        if next == c {
+            run += 1;
+            continue;
+        }
+
+        if run == count {
+            return true;
+        }
+
+        run = 0;
+    }
+
+    false
+}
+
+// parses `[label](url)`, returning the label, the url and the length of the link
+//
+// brackets in the label and parentheses in the url have to be balanced
+fn parse_link(text: &str) -> Option<(&str, &str, usize)> {
+    let label_end = closing(text, '[', ']')?;
+
+    let rest = &text[label_end + 1..];
+    if !rest.starts_with('(') {
+        return None;
+    }
+
+    let url_end = closing(rest, '(', ')')? + label_end + 1;
+
+    let label = &text[1..label_end];
+    let url = &text[label_end + 2..url_end];
+
+    Some((label, url, url_end + 1))
+}
+
+// finds the delimiter closing the one `text` starts with
+fn closing(text: &str, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    let mut escaped = false;
+
+    for (i, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            _ if c == open => depth += 1,
+            _ if c == close => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(text: &str) -> Span {
+        Span {
+            text: text.into(),
+            ..Span::default()
+        }
+    }
+
+    #[test]
+    fn blocks() {
+        let source = "# Title\n\nSome\ntext\n\n- one\n2. two\n\n---\n```\nlet x = 1;\n```";
+
+        assert_eq!(
+            parse_blocks(source),
+            vec![
+                Block::Heading(1, vec![span("Title")]),
+                Block::Paragraph(vec![span("Some text")]),
+                Block::Item(None, vec![span("one")]),
+                Block::Item(Some(2), vec![span("two")]),
+                Block::Rule,
+                Block::Code(String::from("let x = 1;\n")),
+            ]
+        );
+    }
+
+    #[test]
+    fn spans() {
+        let spans = parse_spans("a **b** *c* `d_e` snake_case [f](https://g.h)");
+
+        assert_eq!(
+            spans,
+            vec![
+                span("a "),
+                Span {
+                    bold: true,
+                    ..span("b")
+                },
+                span(" "),
+                Span {
+                    italic: true,
+                    ..span("c")
+                },
+                span(" "),
+                Span {
+                    code: true,
+                    ..span("d_e")
+                },
+                span(" snake_case "),
+                Span {
+                    link: Some(String::from("https://g.h")),
+                    ..span("f")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn balanced_links() {
+        let spans = parse_spans("[a] and [b [c]](https://d.e/(f))");
+
+        assert_eq!(
+            spans,
+            vec![
+                span("[a] and "),
+                Span {
+                    link: Some(String::from("https://d.e/(f)")),
+                    ..span("b [c]")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unclosed_emphasis() {
+        assert_eq!(parse_spans("2 * 3 = 6"), vec![span("2 * 3 = 6")]);
+        assert_eq!(
+            parse_spans("**a * b**"),
+            vec![Span {
+                bold: true,
+                ..span("a * b")
+            }]
+        );
+        assert_eq!(parse_spans("a ** b"), vec![span("a ** b")]);
+    }
+}
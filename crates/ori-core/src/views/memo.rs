@@ -87,3 +87,66 @@ impl<T, V: View<T>, D: PartialEq> View<T> for Memo<T, V, D> {
         state.view.draw(&mut state.state, cx, data);
     }
 }
+
+/// A value derived from explicit dependencies, that is only recomputed when they change.
+///
+/// This is the value counterpart to [`Memo`], useful for caching expensive derivations in
+/// the data of an application, as the `ui` function is called on every rebuild. Only the
+/// dependencies passed to [`Memoized::get`] are compared, so any other data read by the
+/// closure doesn't cause it to be recomputed.
+///
+/// # Example
+/// ```
+/// # use ori_core::views::Memoized;
+/// struct Data {
+///     query: String,
+///     items: Vec<String>,
+///     filtered: Memoized<String, Vec<String>>,
+/// }
+///
+/// fn filtered(data: &mut Data) -> &[String] {
+///     let items = &data.items;
+///
+///     data.filtered.get(&data.query, |query| {
+///         items.iter().filter(|item| item.contains(query)).cloned().collect()
+///     })
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Memoized<D, R> {
+    deps: Option<D>,
+    value: Option<R>,
+}
+
+impl<D, R> Memoized<D, R> {
+    /// Create a new empty [`Memoized`].
+    pub const fn new() -> Self {
+        Self {
+            deps: None,
+            value: None,
+        }
+    }
+
+    /// Get the current value, if it has been computed.
+    pub fn value(&self) -> Option<&R> {
+        self.value.as_ref()
+    }
+
+    /// Clear the value, forcing it to be recomputed on the next [`Memoized::get`].
+    pub fn invalidate(&mut self) {
+        self.deps = None;
+        self.value = None;
+    }
+}
+
+impl<D: PartialEq + Clone, R> Memoized<D, R> {
+    /// Get the value, recomputing it with `compute` only if `deps` changed.
+    pub fn get(&mut self, deps: &D, compute: impl FnOnce(&D) -> R) -> &R {
+        if self.deps.as_ref() != Some(deps) || self.value.is_none() {
+            self.value = Some(compute(deps));
+            self.deps = Some(deps.clone());
+        }
+
+        self.value.as_ref().unwrap()
+    }
+}
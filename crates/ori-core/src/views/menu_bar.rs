@@ -0,0 +1,957 @@
+use ori_macro::{Build, Styled};
+use smol_str::SmolStr;
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color, Curve},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, HoverIntent, Key, KeyPressed},
+    layout::{Anchor, Padding, Point, Rect, Side, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    text::{Fonts, TextAttributes, TextBuffer},
+    view::{Pod, State, View},
+};
+
+use super::PaletteCommand;
+
+/// Create a new [`MenuBar`] above `content`.
+pub fn menu_bar<T, V>(content: V, menus: impl IntoIterator<Item = Menu<T>>) -> MenuBar<T, V> {
+    MenuBar::new(content, menus)
+}
+
+/// Remove the mnemonic marker from a `title`.
+///
+/// The character after a `&` is the mnemonic, and `&&` is a literal `&`. Returns the
+/// title without markers, and the byte index and lowercase character of the mnemonic.
+///
+/// # Example
+/// ```
+/// # use ori_core::views::parse_mnemonic;
+/// assert_eq!(parse_mnemonic("&File"), (String::from("File"), Some((0, 'f'))));
+/// assert_eq!(parse_mnemonic("Save &As"), (String::from("Save As"), Some((5, 'a'))));
+/// assert_eq!(parse_mnemonic("A && B"), (String::from("A & B"), None));
+/// ```
+pub fn parse_mnemonic(title: &str) -> (String, Option<(usize, char)>) {
+    let mut text = String::with_capacity(title.len());
+    let mut mnemonic = None;
+    let mut chars = title.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '&' {
+            text.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('&') => text.push('&'),
+            Some(c) => {
+                if mnemonic.is_none() {
+                    let lower = c.to_lowercase().next().unwrap_or(c);
+                    mnemonic = Some((text.len(), lower));
+                }
+
+                text.push(c);
+            }
+            None => {}
+        }
+    }
+
+    (text, mnemonic)
+}
+
+/// A menu of a [`MenuBar`].
+pub struct Menu<T> {
+    /// The title of the menu, see [`parse_mnemonic`].
+    pub title: SmolStr,
+
+    /// The items of the menu.
+    pub items: Vec<MenuItem<T>>,
+}
+
+impl<T> Menu<T> {
+    /// Create a new [`Menu`], a `&` in the `title` marks the mnemonic.
+    pub fn new(title: impl Into<SmolStr>) -> Self {
+        Self {
+            title: title.into(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Add a command to the menu.
+    ///
+    /// A `&` in the title of the command marks the mnemonic.
+    pub fn command(mut self, command: PaletteCommand<T>) -> Self {
+        self.items.push(MenuItem::Command(command));
+        self
+    }
+
+    /// Add a separator to the menu.
+    pub fn separator(mut self) -> Self {
+        self.items.push(MenuItem::Separator);
+        self
+    }
+
+    /// Add a submenu to the menu.
+    pub fn submenu(mut self, menu: Menu<T>) -> Self {
+        self.items.push(MenuItem::Submenu(menu));
+        self
+    }
+
+    fn next_item(&self, from: Option<usize>, forward: bool) -> Option<usize> {
+        let len = self.items.len();
+
+        (1..=len)
+            .map(|offset| match (from, forward) {
+                (Some(from), true) => (from + offset) % len,
+                (Some(from), false) => (from + len - offset % len) % len,
+                (None, true) => offset - 1,
+                (None, false) => len - offset,
+            })
+            .find(|&i| !matches!(self.items[i], MenuItem::Separator))
+    }
+
+    // whether the menu is shown the same as `other`, only the callbacks might differ
+    fn same_entries(&self, other: &Self) -> bool {
+        self.title == other.title
+            && self.items.len() == other.items.len()
+            && self
+                .items
+                .iter()
+                .zip(&other.items)
+                .all(|items| match items {
+                    (MenuItem::Command(a), MenuItem::Command(b)) => {
+                        a.title == b.title && a.shortcut == b.shortcut
+                    }
+                    (MenuItem::Separator, MenuItem::Separator) => true,
+                    (MenuItem::Submenu(a), MenuItem::Submenu(b)) => a.same_entries(b),
+                    _ => false,
+                })
+    }
+
+    fn find_shortcut(&mut self, pressed: &KeyPressed) -> Option<&mut PaletteCommand<T>> {
+        self.items.iter_mut().find_map(|item| match item {
+            MenuItem::Command(command) if command.shortcut.is_some_and(|s| s.matches(pressed)) => {
+                Some(command)
+            }
+            MenuItem::Submenu(menu) => menu.find_shortcut(pressed),
+            _ => None,
+        })
+    }
+}
+
+/// An item of a [`Menu`].
+pub enum MenuItem<T> {
+    /// A command, invoked when the item is clicked.
+    Command(PaletteCommand<T>),
+
+    /// A line separating groups of items.
+    Separator,
+
+    /// A submenu, opened when the item is hovered.
+    Submenu(Menu<T>),
+}
+
+impl<T> MenuItem<T> {
+    fn title(&self) -> &str {
+        match self {
+            MenuItem::Command(command) => &command.title,
+            MenuItem::Separator => "",
+            MenuItem::Submenu(menu) => &menu.title,
+        }
+    }
+}
+
+/// A bar of menus above some content, like the menu bar of a desktop app.
+///
+/// Clicking a title opens its menu, and while a menu is open, hovering another title
+/// opens that menu instead. Clicking a command invokes it and closes the menu, clicking
+/// outside the menus closes them.
+///
+/// `Alt` and the mnemonic of a title opens its menu. While open, the arrow keys move
+/// between items and menus, `Enter` invokes the selected item, typing the mnemonic of
+/// an item invokes it, and `Escape` closes the innermost menu. The shortcuts of the
+/// commands invoke them directly when the key press isn't handled by the content.
+///
+/// Can be styled using the [`MenuBarStyle`].
+#[derive(Styled, Build, Rebuild)]
+pub struct MenuBar<T, V> {
+    /// The content.
+    #[build(ignore)]
+    pub content: Pod<V>,
+
+    /// The menus.
+    #[build(ignore)]
+    pub menus: Vec<Menu<T>>,
+
+    /// The font size of the text.
+    #[rebuild(layout)]
+    #[styled(default = 14.0)]
+    pub font_size: Styled<f32>,
+
+    /// The padding of the titles and items.
+    #[rebuild(layout)]
+    #[styled(default = Padding::new(4.0, 8.0, 4.0, 8.0))]
+    pub padding: Styled<Padding>,
+
+    /// The minimum width of a menu.
+    #[rebuild(draw)]
+    #[styled(default = 160.0)]
+    pub min_width: Styled<f32>,
+
+    /// The color of the text.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::CONTRAST or Color::BLACK)]
+    pub color: Styled<Color>,
+
+    /// The color of the shortcuts.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::CONTRAST_LOW or Color::grayscale(0.5))]
+    pub dim_color: Styled<Color>,
+
+    /// The background color of the bar.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::SURFACE_HIGH or Color::grayscale(0.95))]
+    pub background: Styled<Color>,
+
+    /// The background color of the menus.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::SURFACE_HIGHER or Color::WHITE)]
+    pub menu_background: Styled<Color>,
+
+    /// The background color of the open title and the selected item.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::SURFACE_HIGHEST or Color::grayscale(0.9))]
+    pub highlight: Styled<Color>,
+
+    /// The color of the separators.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::OUTLINE_LOW or Color::grayscale(0.8))]
+    pub separator_color: Styled<Color>,
+
+    /// The border radius of the menus.
+    #[rebuild(draw)]
+    #[styled(default = BorderRadius::all(4.0))]
+    pub border_radius: Styled<BorderRadius>,
+
+    /// The border width of the menus.
+    #[rebuild(draw)]
+    #[styled(default = BorderWidth::all(1.0))]
+    pub border_width: Styled<BorderWidth>,
+
+    /// The border color of the menus.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::OUTLINE or Color::BLACK)]
+    pub border_color: Styled<Color>,
+
+    /// The radius of the blur of the content behind the menus, `0.0` disables it.
+    ///
+    /// This is only visible with a translucent [`Self::menu_background`], see
+    /// [`Canvas::backdrop_blur`](crate::canvas::Canvas::backdrop_blur).
+    #[rebuild(draw)]
+    #[styled(default = 0.0)]
+    pub backdrop_blur: Styled<f32>,
+
+    /// The time in seconds the pointer has to rest on an item, before a submenu is opened
+    /// or closed by hovering it, see [`HoverIntent`].
+    #[styled(default = 0.15)]
+    pub submenu_delay: Styled<f32>,
+}
+
+impl<T, V> MenuBar<T, V> {
+    /// The height of a separator.
+    pub const SEPARATOR_HEIGHT: f32 = 9.0;
+
+    /// Create a new [`MenuBar`].
+    pub fn new(content: V, menus: impl IntoIterator<Item = Menu<T>>) -> Self {
+        Self {
+            content: Pod::new(content),
+            menus: menus.into_iter().collect(),
+            font_size: MenuBarStyle::FONT_SIZE.into(),
+            padding: MenuBarStyle::PADDING.into(),
+            min_width: MenuBarStyle::MIN_WIDTH.into(),
+            color: MenuBarStyle::COLOR.into(),
+            dim_color: MenuBarStyle::DIM_COLOR.into(),
+            background: MenuBarStyle::BACKGROUND.into(),
+            menu_background: MenuBarStyle::MENU_BACKGROUND.into(),
+            highlight: MenuBarStyle::HIGHLIGHT.into(),
+            separator_color: MenuBarStyle::SEPARATOR_COLOR.into(),
+            border_radius: MenuBarStyle::BORDER_RADIUS.into(),
+            border_width: MenuBarStyle::BORDER_WIDTH.into(),
+            border_color: MenuBarStyle::BORDER_COLOR.into(),
+            backdrop_blur: MenuBarStyle::BACKDROP_BLUR.into(),
+            submenu_delay: MenuBarStyle::SUBMENU_DELAY.into(),
+        }
+    }
+
+    fn label(fonts: &mut Fonts, style: &MenuBarStyle, title: &str) -> MenuLabel {
+        let (text, mnemonic) = parse_mnemonic(title);
+
+        let mut buffer = TextBuffer::new(fonts, style.font_size, 1.2);
+        buffer.set_text(fonts, &text, TextAttributes::default());
+
+        let underline = mnemonic.and_then(|(index, _)| {
+            let run = buffer.raw().layout_runs().next()?;
+            let glyph = run.glyphs.iter().find(|glyph| glyph.start == index)?;
+
+            let min = Point::new(glyph.x, run.line_y + 2.0);
+            Some(Rect::min_size(min, Size::new(glyph.w, 1.0)))
+        });
+
+        MenuLabel {
+            buffer,
+            mnemonic: mnemonic.map(|(_, c)| c),
+            underline,
+        }
+    }
+
+    // get the menu shown at `depth`, following the selected submenus
+    fn menu(&self, state: &MenuBarState, depth: usize) -> Option<&Menu<T>> {
+        let mut menu = self.menus.get(state.open?)?;
+
+        for level in &state.levels[..depth] {
+            match menu.items.get(level.selected?)? {
+                MenuItem::Submenu(submenu) => menu = submenu,
+                _ => return None,
+            }
+        }
+
+        Some(menu)
+    }
+
+    fn menu_mut(&mut self, state: &MenuBarState, depth: usize) -> Option<&mut Menu<T>> {
+        let mut menu = self.menus.get_mut(state.open?)?;
+
+        for level in &state.levels[..depth] {
+            match menu.items.get_mut(level.selected?)? {
+                MenuItem::Submenu(submenu) => menu = submenu,
+                _ => return None,
+            }
+        }
+
+        Some(menu)
+    }
+
+    // create the labels of the titles in the bar
+    fn update_titles(&self, state: &mut MenuBarState, fonts: &mut Fonts) {
+        let style = &state.style;
+        let titles = self
+            .menus
+            .iter()
+            .map(|menu| Self::label(fonts, style, &menu.title));
+        state.titles = titles.collect();
+    }
+
+    // reshape the titles and recreate the open menus, keeping the selection
+    fn reshape(&self, state: &mut MenuBarState, cx: &mut RebuildCx) {
+        self.update_titles(state, cx.fonts());
+
+        let selected: Vec<_> = state.levels.drain(..).map(|level| level.selected).collect();
+        state.open = state.open.filter(|&open| open < self.menus.len());
+
+        for selected in selected {
+            let depth = state.levels.len();
+            self.push_level(state, cx.fonts(), false);
+
+            if state.levels.len() == depth {
+                break;
+            }
+
+            let level = &mut state.levels[depth];
+            level.selected = selected.filter(|&i| i < level.items.len());
+        }
+
+        if state.levels.is_empty() {
+            state.open = None;
+        }
+
+        cx.layout();
+    }
+
+    // open the submenu of the selected item of the innermost menu
+    fn push_level(&self, state: &mut MenuBarState, fonts: &mut Fonts, select_first: bool) {
+        let Some(menu) = self.menu(state, state.levels.len()) else {
+            return;
+        };
+
+        let items = menu.items.iter().map(|item| {
+            let label = Self::label(fonts, &state.style, item.title());
+
+            let detail = match item {
+                MenuItem::Command(command) => command.shortcut.map(|s| s.to_string()),
+                MenuItem::Separator => None,
+                MenuItem::Submenu(_) => Some(String::from(">")),
+            };
+
+            let detail = detail.map(|detail| {
+                let mut buffer = TextBuffer::new(fonts, state.style.font_size, 1.2);
+                buffer.set_text(fonts, &detail, TextAttributes::default());
+                buffer
+            });
+
+            MenuEntry {
+                label,
+                detail,
+                separator: matches!(item, MenuItem::Separator),
+            }
+        });
+
+        let level = MenuLevel {
+            selected: select_first.then(|| menu.next_item(None, true)).flatten(),
+            items: items.collect(),
+            rects: Vec::new(),
+            panel: Rect::ZERO,
+        };
+
+        state.levels.push(level);
+    }
+
+    fn open(&self, state: &mut MenuBarState, cx: &mut EventCx, index: usize, select_first: bool) {
+        state.open = Some(index);
+        state.levels.clear();
+
+        self.push_level(state, cx.fonts(), select_first);
+        cx.draw();
+    }
+
+    fn close(state: &mut MenuBarState, cx: &mut EventCx) {
+        state.open = None;
+        state.levels.clear();
+        state.intent.set(None);
+        cx.draw();
+    }
+
+    // select an item at `depth`, opening it if it's a submenu
+    fn select(&self, state: &mut MenuBarState, cx: &mut EventCx, depth: usize, index: usize) {
+        if state.levels[depth].selected == Some(index) && state.levels.len() > depth + 1 {
+            return;
+        }
+
+        state.levels.truncate(depth + 1);
+        state.levels[depth].selected = Some(index);
+
+        if let Some(MenuItem::Submenu(_)) = self.menu(state, depth).map(|m| &m.items[index]) {
+            self.push_level(state, cx.fonts(), false);
+        }
+
+        cx.draw();
+    }
+
+    // activate the selected item of the innermost menu
+    fn activate(&mut self, state: &mut MenuBarState, cx: &mut EventCx, data: &mut T) {
+        let depth = state.levels.len() - 1;
+
+        let Some(index) = state.levels[depth].selected else {
+            return;
+        };
+
+        let Some(menu) = self.menu_mut(state, depth) else {
+            return;
+        };
+
+        match menu.items[index] {
+            MenuItem::Command(ref mut command) => {
+                let callback = &mut command.callback;
+                Self::close(state, cx);
+                callback(cx, data);
+            }
+            MenuItem::Separator => {}
+            MenuItem::Submenu(_) => {
+                self.push_level(state, cx.fonts(), true);
+                cx.draw();
+            }
+        }
+    }
+
+    fn title_at(state: &MenuBarState, local: Point) -> Option<usize> {
+        state
+            .title_rects
+            .iter()
+            .position(|rect| rect.contains(local))
+    }
+
+    // handle an event while a menu is open, returns true if the event was consumed
+    fn open_event(
+        &mut self,
+        state: &mut MenuBarState,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        let Some(open) = state.open else {
+            return false;
+        };
+
+        match event {
+            Event::KeyPressed(e) => {
+                let depth = state.levels.len() - 1;
+                let selected = state.levels[depth].selected;
+                let menu_count = self.menus.len();
+
+                match e.key {
+                    Key::Escape if depth > 0 => {
+                        state.levels.pop();
+                        cx.draw();
+                    }
+                    Key::Escape => Self::close(state, cx),
+                    Key::Up | Key::Down => {
+                        let menu = self.menu(state, depth);
+                        let next = menu.and_then(|m| m.next_item(selected, e.key == Key::Down));
+
+                        state.levels[depth].selected = next;
+                        cx.draw();
+                    }
+                    Key::Left if depth > 0 => {
+                        state.levels.pop();
+                        cx.draw();
+                    }
+                    Key::Left => self.open(state, cx, (open + menu_count - 1) % menu_count, true),
+                    Key::Right => {
+                        let item = selected.and_then(|i| self.menu(state, depth)?.items.get(i));
+
+                        match item {
+                            Some(MenuItem::Submenu(_)) => self.activate(state, cx, data),
+                            _ => self.open(state, cx, (open + 1) % menu_count, true),
+                        }
+                    }
+                    Key::Enter | Key::Space => self.activate(state, cx, data),
+                    Key::Character(c) => {
+                        let c = c.to_lowercase().next().unwrap_or(c);
+                        let items = &state.levels[depth].items;
+                        let index = items.iter().position(|item| item.label.mnemonic == Some(c));
+
+                        if let Some(index) = index {
+                            state.levels[depth].selected = Some(index);
+                            self.activate(state, cx, data);
+                        }
+                    }
+                    _ => {}
+                }
+
+                true
+            }
+            Event::PointerMoved(e) => {
+                if let Some(index) = Self::title_at(state, cx.local(e.position)) {
+                    if index != open {
+                        self.open(state, cx, index, false);
+                    }
+
+                    return true;
+                }
+
+                // the innermost menus are on top
+                for depth in (0..state.levels.len()).rev() {
+                    let rects = &state.levels[depth].rects;
+                    let index = rects.iter().position(|rect| rect.contains(e.position));
+
+                    if let Some(index) = index {
+                        let menu = self.menu(state, depth);
+                        let item = menu.and_then(|menu| menu.items.get(index));
+
+                        let is_submenu = matches!(item, Some(MenuItem::Submenu(_)));
+                        let is_separator = matches!(item, Some(MenuItem::Separator));
+
+                        // opening and closing submenus waits for the pointer to rest
+                        let submenu_open = state.levels.len() > depth + 1;
+                        let delay = state.style.submenu_delay;
+
+                        if delay > 0.0 && (is_submenu || submenu_open) {
+                            state.intent.delay = delay;
+
+                            if state.intent.hover(Some((depth, index))) {
+                                cx.animate();
+                            }
+                        } else if !is_separator {
+                            state.intent.set(None);
+                            self.select(state, cx, depth, index);
+                        }
+
+                        break;
+                    }
+                }
+
+                true
+            }
+            Event::PointerPressed(e) => {
+                if let Some(index) = Self::title_at(state, cx.local(e.position)) {
+                    match index == open {
+                        true => Self::close(state, cx),
+                        false => self.open(state, cx, index, false),
+                    }
+
+                    return true;
+                }
+
+                for depth in (0..state.levels.len()).rev() {
+                    let level = &state.levels[depth];
+                    let index = level
+                        .rects
+                        .iter()
+                        .position(|rect| rect.contains(e.position));
+
+                    if let Some(index) = index {
+                        state.levels.truncate(depth + 1);
+                        state.levels[depth].selected = Some(index);
+                        self.activate(state, cx, data);
+
+                        return true;
+                    }
+
+                    if level.panel.contains(e.position) {
+                        return true;
+                    }
+                }
+
+                Self::close(state, cx);
+                true
+            }
+            Event::Animate(dt) => {
+                if let Some((depth, index)) = state.intent.step(*dt) {
+                    let items = state.levels.get(depth).map_or(0, |level| level.items.len());
+                    let menu = self.menu(state, depth);
+                    let item = menu.and_then(|menu| menu.items.get(index));
+
+                    if index < items && !matches!(item, Some(MenuItem::Separator)) {
+                        self.select(state, cx, depth, index);
+                    }
+                }
+
+                if state.intent.is_pending() {
+                    cx.animate();
+                }
+
+                false
+            }
+            Event::KeyReleased(_)
+            | Event::PointerReleased(_)
+            | Event::PointerScrolled(_)
+            | Event::PointerLeft(_) => true,
+            _ => false,
+        }
+    }
+}
+
+// the text of a title or item, with its mnemonic
+struct MenuLabel {
+    buffer: TextBuffer,
+    mnemonic: Option<char>,
+    underline: Option<Rect>,
+}
+
+// an item of an open menu, the detail is the shortcut or the submenu arrow
+struct MenuEntry {
+    label: MenuLabel,
+    detail: Option<TextBuffer>,
+    separator: bool,
+}
+
+// an open menu, the first level is the menu of the open title
+struct MenuLevel {
+    selected: Option<usize>,
+    items: Vec<MenuEntry>,
+    rects: Vec<Rect>,
+    panel: Rect,
+}
+
+#[doc(hidden)]
+pub struct MenuBarState {
+    style: MenuBarStyle,
+    open: Option<usize>,
+    hovered: Option<usize>,
+    titles: Vec<MenuLabel>,
+    title_rects: Vec<Rect>,
+    levels: Vec<MenuLevel>,
+    intent: HoverIntent<(usize, usize)>,
+    bar_height: f32,
+}
+
+impl<T, V: View<T>> View<T> for MenuBar<T, V> {
+    type State = (MenuBarState, State<T, V>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let mut state = MenuBarState {
+            style: MenuBarStyle::styled(self, cx.styles()),
+            open: None,
+            hovered: None,
+            titles: Vec::new(),
+            title_rects: Vec::new(),
+            levels: Vec::new(),
+            intent: HoverIntent::default(),
+            bar_height: 0.0,
+        };
+
+        self.update_titles(&mut state, cx.fonts());
+
+        (state, self.content.build(cx, data))
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        Rebuild::rebuild(self, cx, old);
+
+        let font_size = state.style.font_size;
+        state.style.rebuild(self, cx);
+
+        let menus_changed = self.menus.len() != old.menus.len()
+            || (self.menus.iter().zip(&old.menus)).any(|(menu, old)| !menu.same_entries(old));
+
+        if menus_changed || state.style.font_size != font_size {
+            self.reshape(state, cx);
+        }
+
+        (self.content).rebuild(content, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        if state.open.is_some() && self.open_event(state, cx, data, event) {
+            return true;
+        }
+
+        match event {
+            Event::PointerPressed(e) => {
+                if let Some(index) = Self::title_at(state, cx.local(e.position)) {
+                    self.open(state, cx, index, false);
+                    return true;
+                }
+            }
+            Event::PointerMoved(e) => {
+                let hovered = Self::title_at(state, cx.local(e.position));
+
+                if hovered != state.hovered {
+                    state.hovered = hovered;
+                    cx.draw();
+                }
+            }
+            _ => {}
+        }
+
+        let handled = self.content.event(content, cx, data, event);
+
+        if let (false, Event::KeyPressed(e)) = (handled, event) {
+            let modifiers = e.modifiers;
+
+            if let (true, false, false, Key::Character(c)) =
+                (modifiers.alt, modifiers.ctrl, modifiers.meta, e.key)
+            {
+                let c = c.to_lowercase().next().unwrap_or(c);
+                let index = state.titles.iter().position(|t| t.mnemonic == Some(c));
+
+                if let Some(index) = index {
+                    self.open(state, cx, index, true);
+                    return true;
+                }
+            }
+
+            let command = self.menus.iter_mut().find_map(|menu| menu.find_shortcut(e));
+
+            if let Some(command) = command {
+                (command.callback)(cx, data);
+                return true;
+            }
+        }
+
+        handled
+    }
+
+    fn layout(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let padding = state.style.padding;
+        let line_height = state.style.font_size * 1.2;
+        state.bar_height = line_height + padding.size().height;
+
+        let mut x = 0.0;
+        state.title_rects.clear();
+
+        for title in &state.titles {
+            let size = Size::new(title.buffer.size().width, line_height) + padding.size();
+            state
+                .title_rects
+                .push(Rect::min_size(Point::new(x, 0.0), size));
+            x += size.width;
+        }
+
+        let content_space = Space::new(
+            Size::new(
+                space.min.width,
+                (space.min.height - state.bar_height).max(0.0),
+            ),
+            Size::new(space.max.width, space.max.height - state.bar_height),
+        );
+        let content_size = self.content.layout(content, cx, data, content_space);
+        content.translate(Vector::new(0.0, state.bar_height));
+
+        let width = f32::max(x, content_size.width);
+        space.fit(Size::new(width, state.bar_height + content_size.height))
+    }
+
+    fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.content.draw(content, cx, data);
+
+        let style = &state.style;
+
+        /* draw the bar */
+        let bar = Rect::min_size(Point::ZERO, Size::new(cx.size().width, state.bar_height));
+        cx.fill_rect(bar, style.background);
+
+        for (i, title) in state.titles.iter().enumerate() {
+            let rect = state.title_rects[i];
+
+            if state.open == Some(i) || state.hovered == Some(i) {
+                let radius = style.border_radius;
+                cx.quad(rect, style.highlight, radius, 0.0, Color::TRANSPARENT);
+            }
+
+            let offset = Vector::from(rect.min) + style.padding.offset();
+            cx.text(&title.buffer, style.color, offset);
+
+            if let Some(underline) = title.underline {
+                cx.fill_rect(underline + offset, style.color);
+            }
+        }
+
+        /* draw the open menus */
+        let Some(open) = state.open else {
+            return;
+        };
+
+        let row_height = style.font_size * 1.2 + style.padding.size().height;
+        let window_rect = Rect::min_size(Point::ZERO, cx.window().size);
+
+        // the first menu opens below the title, and submenus to the right of their item
+        let mut anchor = state.title_rects[open].transform(cx.transform());
+        let mut side = Side::Bottom;
+
+        for level in &mut state.levels {
+            let mut width = style.min_width;
+
+            for item in &level.items {
+                let detail = (item.detail.as_ref()).map_or(0.0, |d| d.size().width + row_height);
+                let label = item.label.buffer.size().width;
+                width = width.max(label + detail + style.padding.size().width);
+            }
+
+            let height = |item: &MenuEntry| match item.separator {
+                true => Self::SEPARATOR_HEIGHT,
+                false => row_height,
+            };
+
+            let size = Size::new(width, level.items.iter().map(height).sum());
+            let placement = Anchor::new(side)
+                .align(0.0)
+                .place(anchor, size, window_rect);
+
+            let mut y = placement.rect.min.y;
+            level.rects.clear();
+
+            for item in &level.items {
+                let min = Point::new(placement.rect.min.x, y);
+                level
+                    .rects
+                    .push(Rect::min_size(min, Size::new(width, height(item))));
+                y += height(item);
+            }
+
+            level.panel = placement.rect;
+
+            if let Some(selected) = level.selected {
+                anchor = level.rects[selected];
+                side = Side::Right;
+            }
+        }
+
+        let levels = &state.levels;
+
+        cx.overlay(1, |cx| {
+            for level in levels {
+                let mut curve = Curve::new();
+                curve.push_rect_with_radius(level.panel.round(), style.border_radius);
+                cx.backdrop_blur(curve, style.backdrop_blur);
+
+                cx.quad(
+                    level.panel,
+                    style.menu_background,
+                    style.border_radius,
+                    style.border_width,
+                    style.border_color,
+                );
+
+                for (i, item) in level.items.iter().enumerate() {
+                    let rect = level.rects[i];
+
+                    if item.separator {
+                        let y = rect.center().y.floor();
+                        let min = Point::new(rect.min.x + style.padding.left, y);
+                        let max = Point::new(rect.max.x - style.padding.right, y + 1.0);
+                        cx.fill_rect(Rect::new(min, max), style.separator_color);
+                        continue;
+                    }
+
+                    if level.selected == Some(i) {
+                        cx.fill_rect(rect, style.highlight);
+                    }
+
+                    let offset = Vector::from(rect.min) + style.padding.offset();
+                    cx.text(&item.label.buffer, style.color, offset);
+
+                    if let Some(underline) = item.label.underline {
+                        cx.fill_rect(underline + offset, style.color);
+                    }
+
+                    if let Some(ref detail) = item.detail {
+                        let x = rect.width() - style.padding.right - detail.size().width;
+                        let offset = Vector::from(rect.min) + Vector::new(x, style.padding.top);
+                        cx.text(detail, style.dim_color, offset);
+                    }
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_item_skips_separators() {
+        let command = || PaletteCommand::new("", "", |_, _: &mut ()| {});
+        let menu = Menu::new("&Edit")
+            .command(command())
+            .separator()
+            .command(command());
+
+        assert_eq!(menu.next_item(None, true), Some(0));
+        assert_eq!(menu.next_item(Some(0), true), Some(2));
+        assert_eq!(menu.next_item(Some(2), true), Some(0));
+        assert_eq!(menu.next_item(Some(0), false), Some(2));
+        assert_eq!(menu.next_item(None, false), Some(2));
+    }
+
+    #[test]
+    fn same_entries_ignores_callbacks() {
+        let menu = |title| {
+            Menu::new("&File")
+                .command(PaletteCommand::new("open", title, |_, _: &mut ()| {}))
+                .separator()
+        };
+
+        assert!(menu("Open").same_entries(&menu("Open")));
+        assert!(!menu("Open").same_entries(&menu("Open...")));
+        assert!(!menu("Open").same_entries(&menu("Open").separator()));
+    }
+}
@@ -3,33 +3,61 @@
 mod aligned;
 mod animate;
 mod aspect;
+mod badge;
 mod build_handler;
 mod button;
+mod card;
 mod checkbox;
+mod class;
 mod clickable;
 mod collapsing;
 mod color_picker;
+mod command_palette;
 mod constrain;
 mod container;
+mod date_picker;
+mod debounce;
+mod dock;
 mod draw_handler;
+#[cfg(feature = "debug")]
+mod easing_preview;
 mod event_handler;
 mod flex;
 mod focus;
+mod hsv_picker;
 mod image;
+mod lifecycle;
+mod link;
+mod list_navigation;
+mod log_view;
+#[cfg(feature = "markdown")]
+mod markdown;
 mod memo;
+mod menu_bar;
+mod modal;
+mod number_input;
 mod opaque;
 mod pad;
 mod painter;
+mod pixel_snap;
 mod rebuild_handler;
+mod reorder;
 mod scroll;
+mod semantics;
 mod slider;
+mod spinner;
 mod stack;
+mod sticky;
 mod suspense;
+mod switch;
 mod text;
 mod text_input;
 mod tooltip;
 mod transform;
 mod trigger;
+mod validated;
+mod visibility_handler;
+mod with_cursor;
 mod with_state;
 mod with_style;
 mod wrap;
@@ -38,32 +66,60 @@ mod zstack;
 pub use aligned::*;
 pub use animate::*;
 pub use aspect::*;
+pub use badge::*;
 pub use build_handler::*;
 pub use button::*;
+pub use card::*;
 pub use checkbox::*;
+pub use class::*;
 pub use clickable::*;
 pub use collapsing::*;
 pub use color_picker::*;
+pub use command_palette::*;
 pub use constrain::*;
 pub use container::*;
+pub use date_picker::*;
+pub use debounce::*;
+pub use dock::*;
 pub use draw_handler::*;
+#[cfg(feature = "debug")]
+pub use easing_preview::*;
 pub use event_handler::*;
 pub use flex::*;
 pub use focus::*;
+pub use hsv_picker::*;
+pub use lifecycle::*;
+pub use link::*;
+pub use list_navigation::*;
+pub use log_view::*;
+#[cfg(feature = "markdown")]
+pub use markdown::*;
 pub use memo::*;
+pub use menu_bar::*;
+pub use modal::*;
+pub use number_input::*;
 pub use opaque::*;
 pub use pad::*;
 pub use painter::*;
+pub use pixel_snap::*;
 pub use rebuild_handler::*;
+pub use reorder::*;
 pub use scroll::*;
+pub use semantics::*;
 pub use slider::*;
+pub use spinner::*;
 pub use stack::*;
+pub use sticky::*;
 pub use suspense::*;
+pub use switch::*;
 pub use text::*;
 pub use text_input::*;
 pub use tooltip::*;
 pub use transform::*;
 pub use trigger::*;
+pub use validated::*;
+pub use visibility_handler::*;
+pub use with_cursor::*;
 pub use with_state::*;
 pub use with_style::*;
 pub use wrap::*;
@@ -71,14 +127,16 @@ pub use zstack::*;
 
 #[cfg(test)]
 #[allow(dead_code)]
-mod testing {
+pub(crate) mod testing {
     use std::collections::HashMap;
 
     use crate::{
+        canvas::Canvas,
         command::{CommandProxy, CommandReceiver, CommandWaker},
         context::{BaseCx, BuildCx, Contexts, DrawCx, EventCx, LayoutCx, RebuildCx},
         event::Event,
         layout::{Rect, Size, Space},
+        style::Styles,
         view::{View, ViewState},
         window::Window,
     };
@@ -99,6 +157,7 @@ mod testing {
 
             let mut contexts = Contexts::new();
             contexts.insert(window);
+            contexts.insert(Styles::new());
 
             let (mut proxy, rx) = CommandProxy::new(waker);
 
@@ -144,6 +203,16 @@ mod testing {
 
             size
         }
+
+        pub fn draw(&mut self, view: &mut V, data: &mut T) -> Canvas {
+            let mut canvas = Canvas::new();
+
+            let mut base_cx = BaseCx::new(&mut self.contexts, &mut self.command_proxy);
+            let mut draw_cx = DrawCx::new(&mut base_cx, &mut self.view_state, &mut canvas);
+            view.draw(&mut self.state, &mut draw_cx, data);
+
+            canvas
+        }
     }
 
     pub fn test_layout<T>(view: &mut impl View<T>, data: &mut T, space: Space) -> SavedLayouts {
@@ -0,0 +1,367 @@
+use ori_macro::{Build, Styled};
+
+use crate::{
+    canvas::{Color, Curve},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, FocusTarget, Key},
+    layout::{Rect, Size, Space},
+    rebuild::Rebuild,
+    style::Styled,
+    view::{Pod, State, View},
+};
+
+/// Create a new [`Modal`], showing `dialog` over `content` while `open` is true.
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// struct Data {
+///     confirm_delete: bool,
+/// }
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     let content = button(text("Delete")).on_press(|_, data: &mut Data| {
+///         data.confirm_delete = true;
+///     });
+///
+///     let dialog = container(pad(16.0, text("Are you sure?")));
+///
+///     modal(data.confirm_delete, content, dialog).on_dismiss(|_, data: &mut Data| {
+///         data.confirm_delete = false;
+///     })
+/// }
+/// ```
+pub fn modal<T, V, D>(open: bool, content: V, dialog: D) -> Modal<T, V, D> {
+    Modal::new(open, content, dialog)
+}
+
+/// A view that shows a dialog centered over its content.
+///
+/// While the dialog is open, the content is dimmed by a scrim, and pointer, keyboard
+/// and focus events only reach the dialog. Focus is moved into the dialog when it
+/// opens, and `Tab` cycles through the focusable views of the dialog.
+///
+/// The modal doesn't close itself, instead [`Self::on_dismiss`] is called when
+/// `Escape` is pressed or the scrim is clicked, and is expected to set `open` to false.
+/// The modal should usually be placed at the root of the window, so that the scrim
+/// covers the whole window.
+///
+/// Can be styled using the [`ModalStyle`].
+#[derive(Styled, Build, Rebuild)]
+pub struct Modal<T, V, D> {
+    /// The content.
+    #[build(ignore)]
+    pub content: Pod<V>,
+
+    /// The dialog.
+    #[build(ignore)]
+    pub dialog: Pod<D>,
+
+    /// Whether the dialog is open.
+    #[rebuild(layout)]
+    pub open: bool,
+
+    /// The callback for when the dialog is dismissed.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub on_dismiss: Option<Box<dyn FnMut(&mut EventCx, &mut T)>>,
+
+    /// Whether pressing `Escape` dismisses the dialog.
+    pub dismiss_on_escape: bool,
+
+    /// Whether clicking the scrim dismisses the dialog.
+    pub dismiss_on_scrim: bool,
+
+    /// The color of the scrim.
+    #[rebuild(draw)]
+    #[styled(default = Color::rgba(0.0, 0.0, 0.0, 0.5))]
+    pub scrim: Styled<Color>,
+
+    /// The radius of the blur of the content behind the scrim, `0.0` disables it.
+    ///
+    /// Renderers that don't support blurring only dim the content, see
+    /// [`Canvas::backdrop_blur`](crate::canvas::Canvas::backdrop_blur).
+    #[rebuild(draw)]
+    #[styled(default = 0.0)]
+    pub backdrop_blur: Styled<f32>,
+}
+
+impl<T, V, D> Modal<T, V, D> {
+    /// Create a new [`Modal`].
+    pub fn new(open: bool, content: V, dialog: D) -> Self {
+        Self {
+            content: Pod::new(content),
+            dialog: Pod::new(dialog),
+            open,
+            on_dismiss: None,
+            dismiss_on_escape: true,
+            dismiss_on_scrim: true,
+            scrim: ModalStyle::SCRIM.into(),
+            backdrop_blur: ModalStyle::BACKDROP_BLUR.into(),
+        }
+    }
+
+    /// Set the callback for when the dialog is dismissed.
+    pub fn on_dismiss(mut self, on_dismiss: impl FnMut(&mut EventCx, &mut T) + 'static) -> Self {
+        self.on_dismiss = Some(Box::new(on_dismiss));
+        self
+    }
+
+    fn dismiss(&mut self, cx: &mut EventCx, data: &mut T) {
+        if let Some(ref mut on_dismiss) = self.on_dismiss {
+            on_dismiss(cx, data);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct ModalState<T, V: View<T>, D: View<T>> {
+    style: ModalStyle,
+    content: State<T, V>,
+    dialog: Option<State<T, D>>,
+    dialog_rect: Rect,
+    focus_pending: bool,
+}
+
+// events that only reach the dialog while it is open
+fn is_input(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::PointerMoved(_)
+            | Event::PointerLeft(_)
+            | Event::PointerPressed(_)
+            | Event::PointerReleased(_)
+            | Event::PointerScrolled(_)
+            | Event::KeyPressed(_)
+            | Event::KeyReleased(_)
+            | Event::FocusNext
+            | Event::FocusPrev
+            | Event::FocusGiven(FocusTarget::Next | FocusTarget::Prev)
+    )
+}
+
+impl<T, V: View<T>, D: View<T>> View<T> for Modal<T, V, D> {
+    type State = ModalState<T, V, D>;
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let dialog = self.open.then(|| self.dialog.build(cx, data));
+
+        if self.open {
+            cx.animate();
+        }
+
+        ModalState {
+            style: ModalStyle::styled(self, cx.styles()),
+            content: self.content.build(cx, data),
+            dialog,
+            dialog_rect: Rect::ZERO,
+            focus_pending: self.open,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+
+        (self.content).rebuild(&mut state.content, cx, data, &old.content);
+
+        match (self.open, &mut state.dialog) {
+            (true, Some(dialog)) => {
+                (self.dialog).rebuild(dialog, cx, data, &old.dialog);
+            }
+            (true, None) => {
+                state.dialog = Some(self.dialog.build(&mut cx.as_build_cx(), data));
+
+                // focus is moved into the dialog on the next frame
+                state.focus_pending = true;
+                cx.animate();
+            }
+            (false, _) => {
+                state.dialog = None;
+                state.focus_pending = false;
+            }
+        }
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        let Some(ref mut dialog) = state.dialog else {
+            return self.content.event(&mut state.content, cx, data, event);
+        };
+
+        if !is_input(event) {
+            if matches!(event, Event::Animate(_)) && state.focus_pending {
+                state.focus_pending = false;
+
+                // take focus from the content, and give it to the dialog
+                let _ = (self.content).event(&mut state.content, cx, data, &Event::FocusWanted);
+                let focus = Event::FocusGiven(FocusTarget::Next);
+                let _ = self.dialog.event(dialog, cx, data, &focus);
+            }
+
+            let handled = self.content.event(&mut state.content, cx, data, event);
+            return self.dialog.event_maybe(handled, dialog, cx, data, event);
+        }
+
+        let handled = self.dialog.event(dialog, cx, data, event);
+
+        match event {
+            // keep focus inside the dialog, by wrapping around to the other end
+            Event::FocusNext | Event::FocusPrev if !handled => {
+                let target = match event {
+                    Event::FocusNext => FocusTarget::Next,
+                    _ => FocusTarget::Prev,
+                };
+
+                let focus = Event::FocusGiven(target);
+                let _ = self.dialog.event(dialog, cx, data, &focus);
+            }
+            Event::KeyPressed(e) if !handled && self.dismiss_on_escape && e.is_key(Key::Escape) => {
+                self.dismiss(cx, data);
+            }
+            Event::PointerPressed(e) if !handled && self.dismiss_on_scrim => {
+                let position = cx.local(e.position);
+
+                if !state.dialog_rect.contains(position) {
+                    self.dismiss(cx, data);
+                }
+            }
+            _ => {}
+        }
+
+        // the content never sees input events while the dialog is open
+        true
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let size = self.content.layout(&mut state.content, cx, data, space);
+
+        if let Some(ref mut dialog) = state.dialog {
+            let dialog_space = Space::new(Size::ZERO, size);
+            let dialog_size = self.dialog.layout(dialog, cx, data, dialog_space);
+
+            let offset = (size - dialog_size) / 2.0;
+            dialog.translate(offset.to_vector());
+
+            state.dialog_rect = Rect::min_size(offset.to_point(), dialog_size);
+        }
+
+        size
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.content.draw(&mut state.content, cx, data);
+
+        if let Some(ref mut dialog) = state.dialog {
+            cx.backdrop_blur(Curve::rect(cx.rect()), state.style.backdrop_blur);
+            cx.fill_rect(cx.rect(), state.style.scrim);
+            self.dialog.draw(dialog, cx, data);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{KeyPressed, Modifiers, PointerButton, PointerId, PointerPressed},
+        layout::{Point, Size, Space},
+        views::{on_event, size, testing::ViewTester},
+    };
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Data {
+        content_events: usize,
+        dismissed: usize,
+    }
+
+    fn view(open: bool) -> impl View<Data> {
+        let content = on_event((), |_, data: &mut Data, event| {
+            if matches!(event, Event::KeyPressed(_) | Event::PointerPressed(_)) {
+                data.content_events += 1;
+            }
+
+            false
+        });
+
+        let dialog = size([100.0, 50.0], ());
+
+        modal(open, content, dialog).on_dismiss(|_, data: &mut Data| data.dismissed += 1)
+    }
+
+    fn press(x: f32, y: f32) -> Event {
+        Event::PointerPressed(PointerPressed {
+            id: PointerId::from_u64(0),
+            position: Point::new(x, y),
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+        })
+    }
+
+    fn escape() -> Event {
+        Event::KeyPressed(KeyPressed {
+            key: Key::Escape,
+            code: None,
+            text: None,
+            modifiers: Modifiers::default(),
+        })
+    }
+
+    #[test]
+    fn modal_closed() {
+        let mut data = Data::default();
+
+        let mut view = view(false);
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(
+            &mut view,
+            &mut data,
+            Space::new(Size::ZERO, Size::all(400.0)),
+        );
+
+        tester.event(&mut view, &mut data, &press(10.0, 10.0));
+        tester.event(&mut view, &mut data, &escape());
+
+        assert_eq!(data.content_events, 2);
+        assert_eq!(data.dismissed, 0);
+    }
+
+    #[test]
+    fn modal_open() {
+        let mut data = Data::default();
+
+        let mut view = view(true);
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(
+            &mut view,
+            &mut data,
+            Space::new(Size::all(400.0), Size::all(400.0)),
+        );
+
+        // inside the dialog
+        tester.event(&mut view, &mut data, &press(200.0, 200.0));
+        assert_eq!(data.dismissed, 0);
+
+        // on the scrim
+        tester.event(&mut view, &mut data, &press(10.0, 10.0));
+        assert_eq!(data.dismissed, 1);
+
+        tester.event(&mut view, &mut data, &escape());
+        assert_eq!(data.dismissed, 2);
+
+        assert_eq!(data.content_events, 0);
+    }
+}
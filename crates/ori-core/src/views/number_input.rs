@@ -0,0 +1,358 @@
+use ori_macro::Build;
+
+use crate::{
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Size, Space, Vector},
+    view::{Pod, State, View},
+};
+
+use super::{button, on_click, text, text_input, Button, Clickable, Text, TextInput};
+
+/// Create a new [`NumberInput`].
+pub fn number_input<T>(value: f64) -> NumberInput<T> {
+    NumberInput::new(value)
+}
+
+// the data of the views inside the number input
+#[derive(Default)]
+struct NumberEdit {
+    text: String,
+    commit: bool,
+    steps: i32,
+}
+
+type StepButton = Clickable<NumberEdit, Button<Text>, fn(&mut EventCx, &mut NumberEdit)>;
+
+/// A number input, with buttons to step the value up and down.
+///
+/// The value is edited as text, and parsed when the input is submitted or loses focus.
+/// The value is clamped to [`NumberInput::min`] and [`NumberInput::max`], and text that
+/// isn't a number is discarded. Scrolling over the input also steps the value.
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     number_input(data.count)
+///         .min(0.0)
+///         .max(10.0)
+///         .on_change(|cx, data: &mut Data, count| {
+///             data.count = count;
+///             cx.rebuild();
+///         })
+/// }
+/// ```
+#[derive(Build)]
+pub struct NumberInput<T> {
+    /// The value.
+    #[build(ignore)]
+    pub value: f64,
+
+    /// The minimum value.
+    pub min: f64,
+
+    /// The maximum value.
+    pub max: f64,
+
+    /// The amount the value is changed by the buttons and scrolling.
+    pub step: f64,
+
+    /// The gap between the input and the buttons.
+    pub gap: f32,
+
+    /// A callback that is called when the value is committed or stepped.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub on_change: Option<Box<dyn FnMut(&mut EventCx, &mut T, f64)>>,
+
+    #[build(ignore)]
+    input: Pod<TextInput<NumberEdit>>,
+
+    #[build(ignore)]
+    decrement: Pod<StepButton>,
+
+    #[build(ignore)]
+    increment: Pod<StepButton>,
+}
+
+impl<T> NumberInput<T> {
+    /// Create a new [`NumberInput`].
+    pub fn new(value: f64) -> Self {
+        let input = text_input()
+            .placeholder(String::new())
+            .on_input(|_, edit: &mut NumberEdit, text| edit.text = text)
+            .on_submit(|_, edit: &mut NumberEdit, _| edit.commit = true);
+
+        let decrement: fn(&mut EventCx, &mut NumberEdit) = |_, edit| edit.steps -= 1;
+        let increment: fn(&mut EventCx, &mut NumberEdit) = |_, edit| edit.steps += 1;
+
+        Self {
+            value,
+            min: f64::NEG_INFINITY,
+            max: f64::INFINITY,
+            step: 1.0,
+            gap: 4.0,
+            on_change: None,
+            input: Pod::new(input),
+            decrement: Pod::new(on_click(button(text("-")), decrement)),
+            increment: Pod::new(on_click(button(text("+")), increment)),
+        }
+    }
+
+    /// Set the callback that is called when the value is committed or stepped.
+    pub fn on_change(mut self, on_change: impl FnMut(&mut EventCx, &mut T, f64) + 'static) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    fn clamp(&self, value: f64) -> f64 {
+        value.max(self.min).min(self.max)
+    }
+
+    fn change(&mut self, cx: &mut EventCx, data: &mut T, value: f64) {
+        if value == self.value {
+            return;
+        }
+
+        if let Some(ref mut on_change) = self.on_change {
+            on_change(cx, data, value);
+        }
+    }
+}
+
+/// Format a number for a [`NumberInput`], without floating point noise like
+/// `0.30000000000000004`.
+pub fn format_number(value: f64) -> String {
+    let rounded = (value * 1e9).round() / 1e9;
+
+    match rounded == 0.0 {
+        // avoid showing `-0`
+        true => String::from("0"),
+        false => rounded.to_string(),
+    }
+}
+
+#[doc(hidden)]
+pub struct NumberInputState {
+    edit: NumberEdit,
+    input: State<NumberEdit, TextInput<NumberEdit>>,
+    decrement: State<NumberEdit, StepButton>,
+    increment: State<NumberEdit, StepButton>,
+}
+
+impl<T> View<T> for NumberInput<T> {
+    type State = NumberInputState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        let mut edit = NumberEdit {
+            text: format_number(self.value),
+            ..Default::default()
+        };
+
+        self.input.view.text = Some(edit.text.clone());
+
+        let input = self.input.build(cx, &mut edit);
+        let decrement = self.decrement.build(cx, &mut edit);
+        let increment = self.increment.build(cx, &mut edit);
+
+        NumberInputState {
+            edit,
+            input,
+            decrement,
+            increment,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        // keep the text being edited, unless the value was changed
+        if self.value != old.value {
+            state.edit.text = format_number(self.value);
+        }
+
+        self.input.view.text = Some(state.edit.text.clone());
+
+        let edit = &mut state.edit;
+        (self.input).rebuild(&mut state.input, cx, edit, &old.input);
+        (self.decrement).rebuild(&mut state.decrement, cx, edit, &old.decrement);
+        (self.increment).rebuild(&mut state.increment, cx, edit, &old.increment);
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        let was_focused = state.input.is_focused();
+
+        let edit = &mut state.edit;
+        let mut handled = self.input.event(&mut state.input, cx, edit, event);
+        handled = (self.decrement).event_maybe(handled, &mut state.decrement, cx, edit, event);
+        handled = (self.increment).event_maybe(handled, &mut state.increment, cx, edit, event);
+
+        if let Event::PointerScrolled(e) = event {
+            if cx.has_hovered() && !handled && e.delta.y != 0.0 {
+                state.edit.steps += e.delta.y.signum() as i32;
+                handled = true;
+            }
+        }
+
+        if state.edit.steps != 0 {
+            let steps = std::mem::take(&mut state.edit.steps);
+            let value = self.clamp(self.value + self.step * steps as f64);
+            self.change(cx, data, value);
+        }
+
+        let lost_focus = was_focused && !state.input.is_focused();
+
+        if std::mem::take(&mut state.edit.commit) || lost_focus {
+            let value = match state.edit.text.trim().parse::<f64>() {
+                Ok(value) if value.is_finite() => self.clamp(value),
+                _ => self.value,
+            };
+
+            self.change(cx, data, value);
+
+            // format the text, even if the value didn't change
+            state.edit.text = format_number(value);
+            cx.rebuild();
+        }
+
+        handled
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        let edit = &mut state.edit;
+
+        let button_space = Space::new(Size::ZERO, space.max);
+        let decrement = (self.decrement).layout(&mut state.decrement, cx, edit, button_space);
+        let increment = (self.increment).layout(&mut state.increment, cx, edit, button_space);
+
+        // the input takes the width that the buttons don't
+        let buttons = decrement.width + increment.width + self.gap * 2.0;
+        let input_space = Space::new(
+            Size::new((space.min.width - buttons).max(0.0), space.min.height),
+            Size::new((space.max.width - buttons).max(0.0), space.max.height),
+        );
+        let input = self.input.layout(&mut state.input, cx, edit, input_space);
+
+        let height = input.height.max(decrement.height).max(increment.height);
+        let center = |size: Size| (height - size.height) / 2.0;
+
+        let mut x = 0.0;
+
+        state.input.translate(Vector::new(x, center(input)));
+        x += input.width + self.gap;
+
+        state.decrement.translate(Vector::new(x, center(decrement)));
+        x += decrement.width + self.gap;
+
+        state.increment.translate(Vector::new(x, center(increment)));
+        x += increment.width;
+
+        space.fit(Size::new(x, height))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        let edit = &mut state.edit;
+
+        self.input.draw(&mut state.input, cx, edit);
+        self.decrement.draw(&mut state.decrement, cx, edit);
+        self.increment.draw(&mut state.increment, cx, edit);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Modifiers, PointerId, PointerScrolled},
+        layout::Point,
+        views::testing::ViewTester,
+    };
+
+    use super::*;
+
+    fn view(value: f64) -> NumberInput<Option<f64>> {
+        number_input(value)
+            .min(0.0)
+            .max(10.0)
+            .step(2.0)
+            .on_change(|_, data: &mut Option<f64>, value| *data = Some(value))
+    }
+
+    fn commit(text: &str) -> (Option<f64>, String) {
+        let mut view = view(5.0);
+        let mut data = None;
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.state.edit.text = String::from(text);
+        tester.state.edit.commit = true;
+        tester.event(&mut view, &mut data, &Event::Animate(0.0));
+
+        (data, tester.state.edit.text.clone())
+    }
+
+    #[test]
+    fn format_numbers() {
+        assert_eq!(format_number(1.0), "1");
+        assert_eq!(format_number(0.1 + 0.2), "0.3");
+        assert_eq!(format_number(-2.5), "-2.5");
+        assert_eq!(format_number(-0.0), "0");
+    }
+
+    #[test]
+    fn commit_parses_text() {
+        assert_eq!(commit(" 7.5 "), (Some(7.5), String::from("7.5")));
+        assert_eq!(commit("1e0"), (Some(1.0), String::from("1")));
+
+        // text that isn't a finite number restores the value
+        assert_eq!(commit("abc"), (None, String::from("5")));
+        assert_eq!(commit("inf"), (None, String::from("5")));
+    }
+
+    #[test]
+    fn commit_clamps_value() {
+        assert_eq!(commit("42"), (Some(10.0), String::from("10")));
+        assert_eq!(commit("-3"), (Some(0.0), String::from("0")));
+    }
+
+    #[test]
+    fn scrolling_steps_value() {
+        let scrolled = |y| {
+            Event::PointerScrolled(PointerScrolled {
+                id: PointerId::from_u64(0),
+                position: Point::ZERO,
+                delta: Vector::new(0.0, y),
+                modifiers: Modifiers::default(),
+            })
+        };
+
+        let mut view = view(5.0);
+        let mut data = None;
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.view_state.set_hovered(true);
+
+        tester.event(&mut view, &mut data, &scrolled(1.0));
+        assert_eq!(data, Some(7.0));
+
+        tester.event(&mut view, &mut data, &scrolled(-1.0));
+        assert_eq!(data, Some(3.0));
+
+        // stepping past the bounds clamps the value
+        let mut view = self::view(9.0);
+        tester.rebuild(&mut view, &mut data, &self::view(5.0));
+        tester.event(&mut view, &mut data, &scrolled(1.0));
+        assert_eq!(data, Some(10.0));
+    }
+}
@@ -110,6 +110,9 @@ impl<T, V: View<T>> View<T> for Pad<V> {
     }
 
     fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        #[cfg(feature = "debug")]
+        crate::debug::inspect_padding(cx, self.padding);
+
         self.content.draw(state, cx, data);
     }
 }
@@ -1,7 +1,12 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
 use ori_macro::Build;
 
 use crate::{
-    canvas::{Curve, FillRule, Paint},
+    canvas::{Canvas, Curve, FillRule, Paint},
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
     event::Event,
     layout::{Size, Space},
@@ -14,6 +19,37 @@ pub fn painter<T>(draw: impl FnMut(&mut DrawCx, &mut T) + 'static) -> Painter<T>
     Painter::new(draw)
 }
 
+/// Create a new [`Painter`] view that draws directly to the [`Canvas`].
+///
+/// The draw function is called with the canvas and the size of the view, and can be
+/// used to draw custom shapes without implementing [`View`]. Use [`Painter::redraw_on`]
+/// to redraw when the data drawn changes.
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     let points = data.points.clone();
+///
+///     canvas_view(move |canvas, size| {
+///         canvas.rect(Rect::min_size(Point::ZERO, size), Color::WHITE);
+///
+///         for point in &points {
+///             canvas.fill(Curve::circle(*point, 4.0), FillRule::NonZero, Color::BLUE);
+///         }
+///     })
+///     // `revision` is incremented whenever `points` changes
+///     .redraw_on(data.revision)
+/// }
+/// ```
+pub fn canvas_view<T>(mut draw: impl FnMut(&mut Canvas, Size) + 'static) -> Painter<T> {
+    Painter::new(move |cx, _| {
+        let size = cx.size();
+        draw(cx.canvas(), size);
+    })
+}
+
 /// Create a new [`Painter`] view that draws a circle.
 pub fn circle<T>(radius: f32, paint: impl Into<Paint>) -> Painter<T> {
     Painter::new({
@@ -57,6 +93,9 @@ pub fn rect<T>(size: Size, paint: impl Into<Paint>) -> Painter<T> {
 /// A view that draws something.
 ///
 /// The painter takes up as much space as possible.
+///
+/// The painter is only redrawn when it's requested, so the draw function isn't called
+/// again when the data changes, unless the key set with [`Painter::redraw_on`] changes.
 #[derive(Build, Rebuild)]
 pub struct Painter<T> {
     /// The draw function.
@@ -65,6 +104,11 @@ pub struct Painter<T> {
 
     /// The size of the view.
     pub size: Option<Size>,
+
+    /// The hash of the key, that redraws the painter when changed.
+    #[build(ignore)]
+    #[rebuild(draw)]
+    pub redraw_key: Option<u64>,
 }
 
 impl<T> Painter<T> {
@@ -73,8 +117,18 @@ impl<T> Painter<T> {
         Self {
             draw: Box::new(draw),
             size: None,
+            redraw_key: None,
         }
     }
+
+    /// Redraw the painter whenever `key` changes.
+    pub fn redraw_on(mut self, key: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+
+        self.redraw_key = Some(hasher.finish());
+        self
+    }
 }
 
 impl<T> View<T> for Painter<T> {
@@ -0,0 +1,94 @@
+use crate::{
+    context::{BaseCx, BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Size, Space},
+    view::{PixelSnap, Pod, State, View},
+};
+
+/// Create a view that enables or disables pixel snapping for its content.
+///
+/// # Example
+/// ```
+/// # use ori_core::{view::*, views::*};
+/// pub fn ui<T>(_data: T) -> impl View<T> {
+///     pixel_snap(true, text("Crisp text"))
+/// }
+/// ```
+pub fn pixel_snap<V>(snap: bool, content: V) -> PixelSnapped<V> {
+    PixelSnapped::new(snap, content)
+}
+
+/// A view that enables or disables pixel snapping for its content, see [`PixelSnap`].
+///
+/// This overrides the setting of the application, for the content and all its descendants.
+pub struct PixelSnapped<V> {
+    /// The content view.
+    pub content: Pod<V>,
+
+    /// Whether the content is snapped to device pixels.
+    pub snap: bool,
+}
+
+impl<V> PixelSnapped<V> {
+    /// Create a new [`PixelSnapped`] view.
+    pub fn new(snap: bool, content: V) -> Self {
+        Self {
+            content: Pod::new(content),
+            snap,
+        }
+    }
+}
+
+// replace the pixel snap context, returning the previous one
+fn swap_snap(cx: &mut BaseCx, snap: Option<PixelSnap>) -> Option<PixelSnap> {
+    match snap {
+        Some(snap) => cx.insert_context(snap),
+        None => cx.remove_context::<PixelSnap>(),
+    }
+}
+
+impl<T, V: View<T>> View<T> for PixelSnapped<V> {
+    type State = State<T, V>;
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        self.content.build(cx, data)
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        if self.snap != old.snap {
+            cx.draw();
+        }
+
+        self.content.rebuild(state, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        let prev = swap_snap(cx, Some(PixelSnap(self.snap)));
+        let handled = self.content.event(state, cx, data, event);
+        swap_snap(cx, prev);
+
+        handled
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        self.content.layout(state, cx, data, space)
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        let prev = swap_snap(cx, Some(PixelSnap(self.snap)));
+        self.content.draw(state, cx, data);
+        swap_snap(cx, prev);
+    }
+}
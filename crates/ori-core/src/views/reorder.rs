@@ -0,0 +1,495 @@
+use ori_macro::{Build, Styled};
+
+use crate::{
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, PointerButton},
+    layout::{Axis, Point, Rect, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::Styled,
+    transition::{Animated, Transition},
+    view::{PodSeq, SeqState, View, ViewSeq},
+    window::Cursor,
+};
+
+/// Create a new vertical [`Reorder`].
+pub fn reorder<T, V>(content: V) -> Reorder<T, V> {
+    Reorder::new(Axis::Vertical, content)
+}
+
+/// Create a new horizontal [`Reorder`].
+pub fn hreorder<T, V>(content: V) -> Reorder<T, V> {
+    Reorder::new(Axis::Horizontal, content)
+}
+
+/// A list of views that can be reordered by dragging them.
+///
+/// A drag is started by holding a row for [`Reorder::delay`] seconds, or when the delay is
+/// zero, by moving the pointer farther than
+/// [`Window::drag_threshold`](crate::window::Window::drag_threshold). The dragged row
+/// follows the pointer on top of the other rows, which move to make room for it. When
+/// released, [`Reorder::on_reorder`] is called with the index the row was dragged from,
+/// and the index it was dropped at, and is expected to move the item in the data.
+///
+/// Rows that handle a pointer press themselves, like buttons, don't start a drag.
+///
+/// Can be styled using the [`ReorderStyle`].
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// struct Data {
+///     songs: Vec<String>,
+/// }
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     let rows = data.songs.iter().map(|song| container(pad(8.0, text(song)))).collect();
+///
+///     reorder(rows).on_reorder(|_, data: &mut Data, from, to| {
+///         let song = data.songs.remove(from);
+///         data.songs.insert(to, song);
+///     })
+/// }
+/// ```
+#[derive(Styled, Build, Rebuild)]
+pub struct Reorder<T, V> {
+    /// The content.
+    #[build(ignore)]
+    pub content: PodSeq<V>,
+
+    /// The axis of the list.
+    #[rebuild(layout)]
+    pub axis: Axis,
+
+    /// The gap between rows.
+    #[rebuild(layout)]
+    pub gap: f32,
+
+    /// The time in seconds a row has to be held before it is dragged.
+    pub delay: f32,
+
+    /// The callback for when a row is dropped at a new index.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub on_reorder: Option<Box<dyn FnMut(&mut EventCx, &mut T, usize, usize)>>,
+
+    /// The transition of the rows making room for the dragged row.
+    #[styled(default = Transition::ease(0.15))]
+    pub transition: Styled<Transition>,
+}
+
+impl<T, V> Reorder<T, V> {
+    /// Create a new [`Reorder`].
+    pub fn new(axis: Axis, content: V) -> Self {
+        Self {
+            content: PodSeq::new(content),
+            axis,
+            gap: 0.0,
+            delay: 0.3,
+            on_reorder: None,
+            transition: ReorderStyle::TRANSITION.into(),
+        }
+    }
+
+    /// Set the callback for when a row is dropped at a new index.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl FnMut(&mut EventCx, &mut T, usize, usize) + 'static,
+    ) -> Self {
+        self.on_reorder = Some(Box::new(on_reorder));
+        self
+    }
+}
+
+struct Pressed {
+    index: usize,
+    position: Point,
+    held: f32,
+}
+
+struct Drag {
+    from: usize,
+    to: usize,
+    grab: Vector,
+    position: Point,
+}
+
+#[doc(hidden)]
+pub struct ReorderState<T, V: ViewSeq<T>> {
+    style: ReorderStyle,
+    content: SeqState<T, V>,
+    rects: Vec<Rect>,
+    shifts: Vec<Animated>,
+    pressed: Option<Pressed>,
+    drag: Option<Drag>,
+}
+
+impl<T, V: ViewSeq<T>> ReorderState<T, V> {
+    fn resize(&mut self, len: usize) {
+        self.rects.resize(len, Rect::ZERO);
+        self.shifts.resize(len, Animated::default());
+    }
+
+    fn start_drag(&mut self, cx: &mut EventCx, index: usize, position: Point) {
+        self.pressed = None;
+        self.drag = Some(Drag {
+            from: index,
+            to: index,
+            grab: position - self.rects[index].min,
+            position,
+        });
+
+        cx.set_active(true);
+        cx.set_cursor(Some(Cursor::Grabbing));
+        cx.animate();
+        cx.draw();
+    }
+}
+
+impl<T, V> Reorder<T, V> {
+    // the index the dragged row would be dropped at
+    fn target(&self, rects: &[Rect], drag: &Drag) -> usize {
+        let size = rects[drag.from].size();
+        let min = self.axis.major(drag.position - drag.grab);
+        let center = min + self.axis.major(size) / 2.0;
+
+        let before = rects
+            .iter()
+            .enumerate()
+            .filter(|(i, rect)| *i != drag.from && self.axis.major(rect.center()) < center);
+
+        before.count()
+    }
+
+    // the offset of a row along the axis, when it is making room for the dragged row
+    fn shift(&self, rects: &[Rect], drag: &Drag, index: usize) -> f32 {
+        let room = self.axis.major(rects[drag.from].size()) + self.gap;
+
+        match index > drag.from {
+            true => -room,
+            false => room,
+        }
+    }
+}
+
+fn is_displaced(drag: &Drag, index: usize) -> bool {
+    let (from, to) = (drag.from, drag.to);
+    (from < index && index <= to) || (to <= index && index < from)
+}
+
+fn is_pointer(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::PointerMoved(_)
+            | Event::PointerLeft(_)
+            | Event::PointerPressed(_)
+            | Event::PointerReleased(_)
+            | Event::PointerScrolled(_)
+    )
+}
+
+impl<T, V: ViewSeq<T>> View<T> for Reorder<T, V> {
+    type State = ReorderState<T, V>;
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let len = self.content.len();
+
+        ReorderState {
+            style: ReorderStyle::styled(self, cx.styles()),
+            content: self.content.build(cx, data),
+            rects: vec![Rect::ZERO; len],
+            shifts: vec![Animated::default(); len],
+            pressed: None,
+            drag: None,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+
+        if self.content.len() != old.content.len() {
+            state.resize(self.content.len());
+            state.pressed = None;
+            state.drag = None;
+            cx.layout();
+        }
+
+        let content = &mut state.content;
+        (self.content).rebuild(content, &mut cx.as_build_cx(), data, &old.content);
+
+        for i in 0..self.content.len() {
+            (self.content).rebuild_nth(i, content, cx, data, &old.content);
+        }
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        if let Event::Animate(dt) = event {
+            if let Some(pressed) = state.pressed.as_mut().filter(|_| self.delay > 0.0) {
+                pressed.held += *dt;
+
+                match pressed.held >= self.delay {
+                    true => {
+                        let (index, position) = (pressed.index, pressed.position);
+                        state.start_drag(cx, index, position);
+                    }
+                    false => cx.animate(),
+                }
+            }
+
+            if let Some(ref drag) = state.drag {
+                let transition = state.style.transition;
+                let dt = cx.transition_delta(*dt);
+                let mut animating = false;
+
+                for (i, shift) in state.shifts.iter_mut().enumerate() {
+                    animating |= shift.step(&transition, is_displaced(drag, i), dt);
+                }
+
+                if animating {
+                    cx.animate();
+                    cx.draw();
+                }
+            }
+        }
+
+        // while dragging, the rows don't receive any pointer events
+        if let Some(ref mut drag) = state.drag {
+            if !is_pointer(event) {
+                return self.content.event(&mut state.content, cx, data, event);
+            }
+
+            match event {
+                Event::PointerMoved(e) => {
+                    drag.position = cx.local(e.position);
+
+                    let to = self.target(&state.rects, drag);
+                    if to != drag.to {
+                        drag.to = to;
+                        cx.animate();
+                    }
+
+                    cx.draw();
+                }
+                Event::PointerReleased(_) => {
+                    let (from, to) = (drag.from, drag.to);
+
+                    state.drag = None;
+                    state.shifts.fill(Animated::default());
+
+                    cx.set_active(false);
+                    cx.set_cursor(None);
+                    cx.layout();
+
+                    if from != to {
+                        if let Some(ref mut on_reorder) = self.on_reorder {
+                            on_reorder(cx, data, from, to);
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            return true;
+        }
+
+        let handled = self.content.event(&mut state.content, cx, data, event);
+
+        match event {
+            Event::PointerPressed(e) if !handled && e.button == PointerButton::Primary => {
+                let local = cx.local(e.position);
+                let index = state.rects.iter().position(|rect| rect.contains(local));
+
+                if let Some(index) = index {
+                    state.pressed = Some(Pressed {
+                        index,
+                        position: local,
+                        held: 0.0,
+                    });
+
+                    if self.delay > 0.0 {
+                        cx.animate();
+                    }
+                }
+            }
+            Event::PointerMoved(e) if state.pressed.is_some() => {
+                if !cx.window().is_dragging(e.id) {
+                    return handled;
+                }
+
+                let local = cx.local(e.position);
+
+                // without a delay the drag starts when the pointer moves, otherwise the
+                // pointer moved too far, so the user is probably scrolling
+                if let Some(pressed) = state.pressed.take() {
+                    if self.delay <= 0.0 {
+                        state.start_drag(cx, pressed.index, pressed.position);
+
+                        if let Some(ref mut drag) = state.drag {
+                            drag.position = local;
+                            drag.to = self.target(&state.rects, drag);
+                        }
+
+                        return true;
+                    }
+                }
+            }
+            Event::PointerReleased(_) => {
+                state.pressed = None;
+            }
+            _ => {}
+        }
+
+        handled
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let (_, min_minor) = self.axis.unpack(space.min);
+        let (_, max_minor) = self.axis.unpack(space.max);
+
+        let content_space = Space::new(
+            self.axis.pack(0.0, min_minor.min(max_minor)),
+            self.axis.pack(f32::INFINITY, max_minor),
+        );
+
+        let mut major = 0.0;
+        let mut minor = 0.0f32;
+
+        for i in 0..self.content.len() {
+            if i > 0 {
+                major += self.gap;
+            }
+
+            let size = (self.content).layout_nth(i, &mut state.content, cx, data, content_space);
+            let (size_major, size_minor) = self.axis.unpack(size);
+
+            let offset = self.axis.pack::<Point>(major, 0.0);
+            state.rects[i] = Rect::min_size(offset, size);
+            state.content[i].translate(offset.to_vector());
+
+            major += size_major;
+            minor = minor.max(size_minor);
+        }
+
+        space.fit(self.axis.pack(major, minor))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        for i in 0..self.content.len() {
+            let mut offset = state.rects[i].min.to_vector();
+
+            if let Some(ref drag) = state.drag {
+                if i == drag.from {
+                    continue;
+                }
+
+                let shift = self.shift(&state.rects, drag, i);
+                let t = state.shifts[i].get();
+                offset += self.axis.pack::<Vector>(shift * t, 0.0);
+            }
+
+            state.content[i].translate(offset);
+            (self.content).draw_nth(i, &mut state.content, cx, data);
+        }
+
+        // the dragged row is drawn on top of everything else, following the pointer
+        if let Some(ref drag) = state.drag {
+            let rect = state.rects[drag.from];
+            let major = self.axis.major(drag.position - drag.grab);
+            let minor = self.axis.minor(rect.min);
+
+            let offset = self.axis.pack::<Vector>(major, minor);
+            state.content[drag.from].translate(offset);
+
+            let transform = cx.transform();
+            cx.overlay(1, |cx| {
+                cx.transformed(transform, |cx| {
+                    (self.content).draw_nth(drag.from, &mut state.content, cx, data);
+                });
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Modifiers, PointerId, PointerMoved, PointerPressed, PointerReleased},
+        views::{size, testing::ViewTester},
+        window::{Pointer, Window},
+    };
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Data {
+        reordered: Option<(usize, usize)>,
+    }
+
+    #[test]
+    fn reorder_rows() {
+        let mut data = Data::default();
+
+        let rows = (0..4).map(|_| size([100.0, 20.0], ())).collect::<Vec<_>>();
+        let mut view = reorder(rows)
+            .delay(0.0)
+            .on_reorder(|_, data: &mut Data, from, to| {
+                data.reordered = Some((from, to));
+            });
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        let space = Space::new(Size::ZERO, Size::all(400.0));
+        let size = tester.layout(&mut view, &mut data, space);
+        assert_eq!(size, Size::new(100.0, 80.0));
+
+        let id = PointerId::from_u64(0);
+        let window = tester.contexts.get_mut::<Window>().unwrap();
+        window
+            .pointers_mut()
+            .push(Pointer::new(id, Point::new(50.0, 10.0)));
+        window.press_pointer(id, PointerButton::Primary);
+
+        let pressed = Event::PointerPressed(PointerPressed {
+            id,
+            position: Point::new(50.0, 10.0),
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+        });
+        tester.event(&mut view, &mut data, &pressed);
+
+        // drag the first row past the center of the third
+        let window = tester.contexts.get_mut::<Window>().unwrap();
+        window.move_pointer(id, Point::new(50.0, 55.0));
+
+        let moved = Event::PointerMoved(PointerMoved {
+            id,
+            position: Point::new(50.0, 55.0),
+            delta: Vector::new(0.0, 45.0),
+            modifiers: Modifiers::default(),
+        });
+        tester.event(&mut view, &mut data, &moved);
+
+        let released = Event::PointerReleased(PointerReleased {
+            id,
+            position: Point::new(50.0, 55.0),
+            clicked: false,
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+        });
+        tester.event(&mut view, &mut data, &released);
+
+        assert_eq!(data.reordered, Some((0, 2)));
+    }
+}
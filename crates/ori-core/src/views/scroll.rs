@@ -7,7 +7,7 @@ use crate::{
     layout::{Axis, Rect, Size, Space, Vector},
     rebuild::Rebuild,
     style::{Styled, Theme},
-    transition::Transition,
+    transition::{Animated, Transition},
     view::{Pod, State, View},
 };
 
@@ -22,6 +22,10 @@ pub fn vscroll<V>(view: V) -> Scroll<V> {
 }
 
 /// A scrollable view.
+///
+/// On mobile the content can be dragged, and flung when released while moving, after
+/// which it keeps scrolling with decelerating momentum. With [`Scroll::overscroll`]
+/// enabled, the content can be pulled past its ends, and springs back when released.
 #[example(name = "scroll", width = 400, height = 300)]
 #[derive(Styled, Build, Rebuild)]
 pub struct Scroll<V> {
@@ -33,10 +37,17 @@ pub struct Scroll<V> {
     #[rebuild(layout)]
     pub axis: Axis,
 
+    /// Whether the content can be dragged or flung past its ends.
+    pub overscroll: bool,
+
     /// The transition of the scrollbar.
     #[styled(default = Transition::ease(0.1))]
     pub transition: Styled<Transition>,
 
+    /// The transition used to spring back from an overscroll.
+    #[styled(default = Transition::ease(0.3))]
+    pub overscroll_transition: Styled<Transition>,
+
     /// The inset of the scrollbar.
     #[rebuild(draw)]
     #[styled(default = 8.0)]
@@ -47,6 +58,11 @@ pub struct Scroll<V> {
     #[styled(default = 6.0)]
     pub width: Styled<f32>,
 
+    /// The padding around the scrollbar that can be grabbed, making it easier to hit
+    /// on touch screens.
+    #[styled(default = 8.0)]
+    pub hit_padding: Styled<f32>,
+
     /// The radius of the scrollbar.
     #[rebuild(draw)]
     #[styled(default = BorderRadius::all(3.0))]
@@ -64,14 +80,26 @@ pub struct Scroll<V> {
 }
 
 impl<V> Scroll<V> {
+    /// The rate at which the velocity of a fling decays, per second.
+    const FLING_FRICTION: f32 = 4.0;
+
+    /// The velocity in pixels per second, below which a fling stops.
+    const FLING_MIN_VELOCITY: f32 = 20.0;
+
+    /// The maximum overscroll, as a fraction of the size of the view.
+    const MAX_OVERSCROLL: f32 = 0.25;
+
     /// Create a new scrollable view.
     pub fn new(axis: Axis, content: V) -> Self {
         Self {
             content: Pod::new(content),
             axis,
+            overscroll: false,
             transition: ScrollStyle::TRANSITION.into(),
+            overscroll_transition: ScrollStyle::OVERSCROLL_TRANSITION.into(),
             inset: ScrollStyle::INSET.into(),
             width: ScrollStyle::WIDTH.into(),
+            hit_padding: ScrollStyle::HIT_PADDING.into(),
             border_radius: ScrollStyle::BORDER_RADIUS.into(),
             color: ScrollStyle::COLOR.into(),
             knob_color: ScrollStyle::KNOB_COLOR.into(),
@@ -93,6 +121,13 @@ impl<V> Scroll<V> {
         )
     }
 
+    fn scrollbar_hit_rect(&self, style: &ScrollStyle, rect: Rect) -> Rect {
+        let scrollbar_rect = self.scrollbar_rect(style, rect);
+        let padding = self.axis.pack::<Vector>(0.0, style.hit_padding);
+
+        Rect::new(scrollbar_rect.min - padding, scrollbar_rect.max + padding)
+    }
+
     fn scrollbar_knob_rect(
         &self,
         style: &ScrollStyle,
@@ -120,6 +155,57 @@ impl<V> Scroll<V> {
     fn overflow(&self, content: Size, size: Size) -> f32 {
         self.axis.major(content - size).max(0.0)
     }
+
+    // scroll by `delta`, pulling the content past the ends if overscroll is enabled
+    fn scroll_by(&self, state: &mut ScrollState, delta: f32, overflow: f32, size: Size) {
+        if !self.overscroll {
+            state.scroll = (state.scroll + delta).clamp(0.0, overflow);
+            return;
+        }
+
+        let max = self.axis.major(size) * Self::MAX_OVERSCROLL;
+        let mut delta = delta;
+
+        // past an end the content moves at half speed, so moving back towards the bounds
+        // first takes up the overscroll, the rest of the delta then scrolls at full speed
+        if state.overscroll * delta < 0.0 {
+            let overscroll = state.overscroll + delta * 0.5;
+
+            if overscroll * state.overscroll > 0.0 {
+                state.overscroll = overscroll;
+                delta = 0.0;
+            } else {
+                state.overscroll = 0.0;
+                delta = overscroll * 2.0;
+            }
+        }
+
+        if state.overscroll == 0.0 {
+            let target = state.scroll + delta;
+            state.scroll = target.clamp(0.0, overflow);
+            delta = target - state.scroll;
+        }
+
+        // only the part of the delta past the end is halved, and the further the content is
+        // pulled past the end, the harder it gets
+        if delta != 0.0 {
+            let resistance = 1.0 - (state.overscroll.abs() / max).min(1.0);
+            let overscroll = state.overscroll + delta * resistance * 0.5;
+            state.overscroll = overscroll.clamp(-max, max);
+        }
+
+        state.bouncing = false;
+    }
+
+    // spring back from an overscroll
+    fn bounce(state: &mut ScrollState, cx: &mut EventCx) {
+        if state.overscroll != 0.0 {
+            state.bounce_from = state.overscroll;
+            state.bounce = Animated::new(true);
+            state.bouncing = true;
+            cx.animate();
+        }
+    }
 }
 
 #[doc(hidden)]
@@ -128,7 +214,14 @@ pub struct ScrollState {
     dragging: bool,
     scrollbar_hovered: bool,
     scroll: f32,
-    t: f32,
+    t: Animated,
+    drag_delta: f32,
+    velocity: f32,
+    flinging: bool,
+    overscroll: f32,
+    bounce_from: f32,
+    bounce: Animated,
+    bouncing: bool,
 }
 
 impl<T, V: View<T>> View<T> for Scroll<V> {
@@ -140,7 +233,14 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
             dragging: false,
             scrollbar_hovered: false,
             scroll: 0.0,
-            t: 0.0,
+            t: Animated::default(),
+            drag_delta: 0.0,
+            velocity: 0.0,
+            flinging: false,
+            overscroll: 0.0,
+            bounce_from: 0.0,
+            bounce: Animated::default(),
+            bouncing: false,
         };
         let content = self.content.build(cx, data);
         (state, content)
@@ -173,9 +273,12 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
             let local = cx.local(e.position);
 
             let scrollbar_rect = self.scrollbar_rect(&state.style, cx.rect());
-            state.scrollbar_hovered = scrollbar_rect.contains(local);
+            let hit_rect = self.scrollbar_hit_rect(&state.style, cx.rect());
+            state.scrollbar_hovered = hit_rect.contains(local);
+
+            let dragging = cx.window().is_dragging(e.id);
 
-            if cx.is_active() {
+            if cx.is_active() && dragging {
                 let scroll_start = self.axis.major(scrollbar_rect.min);
                 let scroll_end = self.axis.major(scrollbar_rect.max);
                 let local_major = self.axis.major(local);
@@ -187,9 +290,11 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
                 content.translate(self.axis.pack(-state.scroll, 0.0));
 
                 cx.draw();
-            } else if state.dragging {
-                state.scroll -= self.axis.major(e.delta);
-                state.scroll = state.scroll.clamp(0.0, overflow);
+            } else if state.dragging && dragging {
+                let delta = self.axis.major(e.delta);
+                state.drag_delta += delta;
+
+                self.scroll_by(state, -delta, overflow, cx.size());
                 cx.draw();
             }
         }
@@ -214,21 +319,101 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
         if is_mobile!() && !handled {
             if matches!(event, Event::PointerPressed(_)) && cx.has_hovered() {
                 state.dragging = true;
+                state.flinging = false;
+                state.bouncing = false;
+                state.drag_delta = 0.0;
+                state.velocity = 0.0;
+
+                // the velocity is sampled every frame while dragging
+                cx.animate();
             }
 
             if matches!(event, Event::PointerReleased(_)) && state.dragging {
                 state.dragging = false;
+
+                // flinging is skipped when the user prefers reduced motion
+                let fling = state.velocity.abs() > Self::FLING_MIN_VELOCITY && !cx.reduced_motion();
+
+                if fling && state.overscroll == 0.0 {
+                    state.flinging = true;
+                    cx.animate();
+                } else {
+                    Self::bounce(state, cx);
+                }
+            }
+        }
+
+        if let Event::Animate(dt) = event {
+            if state.dragging && *dt > 0.0 {
+                // smooth the velocity a bit, since pointer events don't arrive evenly
+                let velocity = -state.drag_delta / dt;
+                state.velocity = (state.velocity + velocity) / 2.0;
+                state.drag_delta = 0.0;
+
+                cx.animate();
+            }
+
+            if state.flinging {
+                // slow down much faster when past the ends
+                let friction = match state.overscroll == 0.0 {
+                    true => Self::FLING_FRICTION,
+                    false => Self::FLING_FRICTION * 8.0,
+                };
+
+                // the distance travelled while slowing down, which stays bounded for long frames,
+                // the fling is stepped like the bounce, so it completes at once when animations
+                // are disabled
+                let dt = cx.transition_delta(*dt);
+                let decay = f32::exp(-friction * dt);
+                let distance = state.velocity * (1.0 - decay) / friction;
+
+                self.scroll_by(state, distance, overflow, cx.size());
+                state.velocity *= decay;
+
+                // stop at the ends, unless overscroll is enabled
+                if !self.overscroll && (state.scroll <= 0.0 || state.scroll >= overflow) {
+                    state.velocity = 0.0;
+                }
+
+                state.flinging = state.velocity.abs() > Self::FLING_MIN_VELOCITY;
+
+                match state.flinging {
+                    true => cx.animate(),
+                    false => Self::bounce(state, cx),
+                }
+
+                cx.draw();
+            }
+
+            if state.bouncing {
+                let transition = state.style.overscroll_transition;
+
+                if state
+                    .bounce
+                    .step(&transition, false, cx.transition_delta(*dt))
+                {
+                    state.overscroll = state.bounce_from * state.bounce.get();
+                    cx.animate();
+                } else {
+                    state.overscroll = 0.0;
+                    state.bouncing = false;
+                }
+
+                cx.draw();
             }
         }
 
         let on = cx.is_hovered() || cx.has_hovered() || cx.is_active() || state.scrollbar_hovered;
 
-        if !state.style.transition.complete(state.t, on) {
+        if !state.t.complete(on) {
             cx.animate();
         }
 
         if let Event::Animate(dt) = event {
-            if (state.style.transition).step(&mut state.t, on, *dt) {
+            if state
+                .t
+                .step(&state.style.transition, on, cx.transition_delta(*dt))
+            {
                 cx.animate();
                 cx.draw();
             }
@@ -237,6 +422,9 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
         if let Event::PointerScrolled(e) = event {
             if on && !handled {
                 handled = true;
+                state.flinging = false;
+                state.bouncing = false;
+                state.overscroll = 0.0;
 
                 state.scroll -= e.delta.y * 10.0;
                 state.scroll = state.scroll.clamp(0.0, overflow);
@@ -279,7 +467,10 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
     fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
         let overflow = self.overflow(content.size(), cx.size());
         state.scroll = state.scroll.clamp(0.0, overflow);
-        content.translate(self.axis.pack(-state.scroll, 0.0));
+
+        // the content is offset by the overscroll, but the scrollbar is not
+        let offset = state.scroll + state.overscroll;
+        content.translate(self.axis.pack(-offset, 0.0));
 
         cx.trigger(cx.rect());
         cx.masked(cx.rect(), |cx| {
@@ -297,7 +488,7 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
 
         cx.quad(
             self.scrollbar_rect(&state.style, cx.rect()),
-            track_color.fade(state.style.transition.get(state.t)),
+            track_color.fade(state.t.get()),
             state.style.border_radius,
             0.0,
             Color::TRANSPARENT,
@@ -305,10 +496,40 @@ impl<T, V: View<T>> View<T> for Scroll<V> {
 
         cx.quad(
             self.scrollbar_knob_rect(&state.style, cx.rect(), overflow, state.scroll),
-            knob_color.fade(state.style.transition.get(state.t)),
+            knob_color.fade(state.t.get()),
             state.style.border_radius,
             0.0,
             Color::TRANSPARENT,
         );
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        transition::ReducedMotion,
+        views::{size, testing::ViewTester},
+    };
+
+    use super::*;
+
+    #[test]
+    fn fling_completes_with_reduced_motion() {
+        let mut view = vscroll(size((10.0, 1000.0), ()));
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        tester.layout(
+            &mut view,
+            &mut (),
+            Space::from_size(Size::new(100.0, 100.0)),
+        );
+
+        tester.contexts.insert(ReducedMotion(true));
+        tester.state.0.velocity = 400.0;
+        tester.state.0.flinging = true;
+
+        // the fling stops where it would have come to rest
+        tester.event(&mut view, &mut (), &Event::Animate(0.016));
+        assert!(!tester.state.0.flinging);
+        assert_eq!(tester.state.0.scroll, 400.0 / Scroll::<()>::FLING_FRICTION);
+    }
+}
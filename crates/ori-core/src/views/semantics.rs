@@ -0,0 +1,233 @@
+use smol_str::SmolStr;
+
+use crate::{
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Size, Space},
+    view::View,
+};
+
+/// Annotate a view with a [`Role`].
+pub fn role<V>(role: Role, content: V) -> Semantic<V> {
+    Semantic::new(content).role(role)
+}
+
+/// Annotate a view with a label, describing it to the user.
+pub fn label<V>(label: impl Into<SmolStr>, content: V) -> Semantic<V> {
+    Semantic::new(content).label(label)
+}
+
+/// The role of a view, describing what kind of widget it is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Role {
+    /// A generic container, with no specific meaning.
+    Group,
+
+    /// A button, that performs an action when clicked.
+    Button,
+
+    /// A checkbox, that can be checked and unchecked.
+    Checkbox,
+
+    /// A switch, that can be toggled on and off.
+    Switch,
+
+    /// A slider, selecting a value in a range.
+    Slider,
+
+    /// A field for editing text.
+    TextInput,
+
+    /// A piece of static text.
+    Text,
+
+    /// A heading, describing a section.
+    Heading,
+
+    /// A link to another location.
+    Link,
+
+    /// An image.
+    Image,
+
+    /// A list of items.
+    List,
+
+    /// An item in a list.
+    ListItem,
+
+    /// A menu of actions.
+    Menu,
+
+    /// An item in a menu.
+    MenuItem,
+
+    /// A tab, selecting a page.
+    Tab,
+
+    /// A dialog, displayed on top of other content.
+    Dialog,
+
+    /// A tooltip, describing another view.
+    Tooltip,
+
+    /// A progress indicator.
+    ProgressIndicator,
+
+    /// A scrollable area.
+    ScrollView,
+}
+
+/// The semantics of a view, stored as a property on the [`ViewState`].
+///
+/// This is metadata describing the view to the user, and is intended to drive
+/// accessibility, as well as things like tooltips and test queries.
+///
+/// [`ViewState`]: crate::view::ViewState
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Semantics {
+    /// The role of the view.
+    pub role: Option<Role>,
+
+    /// The label of the view.
+    pub label: Option<SmolStr>,
+}
+
+/// A view that annotates its content with [`Semantics`].
+///
+/// The semantics are stored on the view state of the nearest [`Pod`], so
+/// `label("Close", button(...))` annotates the pod containing the button.
+///
+/// [`Pod`]: crate::view::Pod
+pub struct Semantic<V> {
+    /// The content of the view.
+    pub content: V,
+
+    /// The semantics of the content.
+    pub semantics: Semantics,
+}
+
+impl<V> Semantic<V> {
+    /// Create a new [`Semantic`] view, with no semantics.
+    pub fn new(content: V) -> Self {
+        Self {
+            content,
+            semantics: Semantics::default(),
+        }
+    }
+
+    /// Set the role.
+    pub fn role(mut self, role: Role) -> Self {
+        self.semantics.role = Some(role);
+        self
+    }
+
+    /// Set the label.
+    pub fn label(mut self, label: impl Into<SmolStr>) -> Self {
+        self.semantics.label = Some(label.into());
+        self
+    }
+
+    // merge the semantics with the ones already set by the content
+    fn merge(&self, semantics: &mut Semantics) {
+        if let Some(role) = self.semantics.role {
+            semantics.role = Some(role);
+        }
+
+        if let Some(ref label) = self.semantics.label {
+            semantics.label = Some(label.clone());
+        }
+    }
+
+    // clear the semantics that were set by `old`, but aren't anymore
+    fn clear_removed(&self, semantics: &mut Semantics, old: &Self) {
+        if self.semantics.role.is_none() && old.semantics.role.is_some() {
+            semantics.role = None;
+        }
+
+        if self.semantics.label.is_none() && old.semantics.label.is_some() {
+            semantics.label = None;
+        }
+    }
+}
+
+impl<T, V: View<T>> View<T> for Semantic<V> {
+    type State = V::State;
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let state = self.content.build(cx, data);
+        self.merge(cx.property_or_default());
+        state
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        // cleared before the content is rebuilt, so the content can set them again
+        self.clear_removed(cx.property_or_default(), old);
+
+        self.content.rebuild(state, cx, data, &old.content);
+        self.merge(cx.property_or_default());
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        self.content.event(state, cx, data, event)
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        self.content.layout(state, cx, data, space)
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.content.draw(state, cx, data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::views::testing::ViewTester;
+
+    use super::*;
+
+    #[test]
+    fn nested_semantics() {
+        let mut view = label("Close", role(Role::Button, ()));
+        let tester = ViewTester::new(&mut view, &mut ());
+
+        let semantics = tester.view_state.get_property::<Semantics>().unwrap();
+        assert_eq!(semantics.role, Some(Role::Button));
+        assert_eq!(semantics.label.as_deref(), Some("Close"));
+    }
+
+    #[test]
+    fn removed_semantics_are_cleared() {
+        let mut old = label("Close", role(Role::Button, ()));
+        let mut tester = ViewTester::new(&mut old, &mut ());
+
+        // the label is removed, the role is now only set by the content
+        let mut new = Semantic::new(role(Role::Button, ()));
+        tester.rebuild(&mut new, &mut (), &old);
+
+        let semantics = tester.view_state.get_property::<Semantics>().unwrap();
+        assert_eq!(semantics.role, Some(Role::Button));
+        assert_eq!(semantics.label, None);
+
+        // the role is removed as well
+        let mut newer = Semantic::new(Semantic::new(()));
+        tester.rebuild(&mut newer, &mut (), &new);
+
+        let semantics = tester.view_state.get_property::<Semantics>().unwrap();
+        assert_eq!(semantics.role, None);
+    }
+}
@@ -147,7 +147,7 @@ impl<T> View<T> for Slider<T> {
             Event::PointerMoved(e) => {
                 let local = cx.local(e.position);
 
-                if cx.is_active() {
+                if cx.is_active() && cx.window().is_dragging(e.id) {
                     let value = self.axis.unpack(local).0 / style.length;
                     let value = denormalize(value, &self.range);
 
@@ -0,0 +1,175 @@
+use std::f32::consts::{PI, TAU};
+
+use ori_macro::{Build, Styled};
+
+use crate::{
+    canvas::{Color, Curve, Stroke, StrokeCap, StrokeJoin},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Point, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    view::View,
+};
+
+/// Create a new [`Spinner`].
+pub fn spinner() -> Spinner {
+    Spinner::new()
+}
+
+/// An indeterminate spinner, drawing a rotating arc.
+///
+/// The spinner only requests animation frames while it is being drawn, so it stops
+/// animating when it is scrolled out of view. When the user prefers reduced motion, see
+/// [`BaseCx::reduced_motion`](crate::context::BaseCx::reduced_motion), the arc stands still.
+///
+/// Can be styled using the [`SpinnerStyle`].
+#[derive(Styled, Build, Rebuild)]
+pub struct Spinner {
+    /// The size of the spinner.
+    #[rebuild(layout)]
+    #[styled(default = 32.0)]
+    pub size: Styled<f32>,
+
+    /// The thickness of the arc.
+    #[rebuild(draw)]
+    #[styled(default = 4.0)]
+    pub thickness: Styled<f32>,
+
+    /// The color of the arc.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::PRIMARY or Color::BLUE)]
+    pub color: Styled<Color>,
+}
+
+impl Default for Spinner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Spinner {
+    /// The rotation speed of the spinner in radians per second.
+    const SPEED: f32 = TAU * 0.8;
+
+    /// The number of segments used to draw a full circle.
+    const SEGMENTS: usize = 64;
+
+    /// Create a new [`Spinner`].
+    pub fn new() -> Self {
+        Self {
+            size: SpinnerStyle::SIZE.into(),
+            thickness: SpinnerStyle::THICKNESS.into(),
+            color: SpinnerStyle::COLOR.into(),
+        }
+    }
+
+    fn arc(center: Point, radius: f32, start: f32, sweep: f32) -> Curve {
+        let segments = (Self::SEGMENTS as f32 * sweep / TAU).ceil().max(1.0) as usize;
+
+        let mut curve = Curve::new();
+        curve.move_to(center + Vector::from_angle(start) * radius);
+
+        for i in 1..=segments {
+            let angle = start + sweep * i as f32 / segments as f32;
+            curve.line_to(center + Vector::from_angle(angle) * radius);
+        }
+
+        curve
+    }
+}
+
+#[doc(hidden)]
+pub struct SpinnerState {
+    style: SpinnerStyle,
+    time: f32,
+    drawn: bool,
+    animating: bool,
+}
+
+impl<T> View<T> for Spinner {
+    type State = SpinnerState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.animate();
+
+        SpinnerState {
+            style: SpinnerStyle::styled(self, cx.styles()),
+            time: 0.0,
+            drawn: true,
+            animating: true,
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        _data: &mut T,
+        event: &Event,
+    ) -> bool {
+        match event {
+            Event::Animate(dt) => {
+                // if the spinner wasn't drawn since the last frame, it isn't visible,
+                // and we stop animating until the next event
+                state.animating = state.drawn && !cx.reduced_motion();
+
+                if state.animating {
+                    state.drawn = false;
+                    state.time += *dt;
+
+                    cx.animate();
+                    cx.draw();
+                }
+            }
+            _ if !state.animating && !cx.reduced_motion() => {
+                // give the spinner a frame to be drawn, if it is visible
+                state.animating = true;
+                state.drawn = true;
+
+                cx.animate();
+                cx.draw();
+            }
+            _ => {}
+        }
+
+        false
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        _cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        space.fit(Size::all(state.style.size))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        state.drawn = true;
+
+        let center = cx.rect().center();
+        let radius = (cx.size().min_element() - state.style.thickness) / 2.0;
+
+        // the arc grows and shrinks while rotating
+        let phase = state.time * Self::SPEED;
+        let sweep = PI * (0.9 + 0.6 * f32::sin(phase * 0.5));
+
+        let curve = Self::arc(center, radius, phase * 1.5, sweep);
+
+        let stroke = Stroke {
+            width: state.style.thickness,
+            cap: StrokeCap::Round,
+            join: StrokeJoin::Round,
+            ..Default::default()
+        };
+
+        cx.stroke(curve, stroke, state.style.color);
+    }
+}
@@ -5,7 +5,7 @@ use ori_macro::{example, Build};
 use crate::{
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
     event::Event,
-    layout::{Align, Axis, Justify, Size, Space},
+    layout::{Align, Axis, Direction, Justify, Size, Space},
     rebuild::Rebuild,
     view::{AnyView, PodSeq, SeqState, View, ViewSeq},
 };
@@ -169,14 +169,16 @@ pub struct StackState {
     flex_sum: f32,
     majors: Vec<f32>,
     minors: Vec<f32>,
+    direction: Direction,
 }
 
 impl StackState {
-    fn new(len: usize) -> Self {
+    fn new(len: usize, direction: Direction) -> Self {
         Self {
             flex_sum: 0.0,
             majors: vec![0.0; len],
             minors: vec![0.0; len],
+            direction,
         }
     }
 
@@ -205,7 +207,7 @@ impl<T, V: ViewSeq<T>> View<T> for Stack<V> {
 
     fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
         (
-            StackState::new(self.content.len()),
+            StackState::new(self.content.len(), cx.direction()),
             self.content.build(cx, data),
         )
     }
@@ -224,6 +226,12 @@ impl<T, V: ViewSeq<T>> View<T> for Stack<V> {
             cx.layout();
         }
 
+        // horizontal stacks are mirrored when the direction changes
+        if state.direction != cx.direction() {
+            state.direction = cx.direction();
+            cx.layout();
+        }
+
         (self.content).rebuild(content, &mut cx.as_build_cx(), data, &old.content);
 
         for i in 0..self.content.len() {
@@ -283,10 +291,17 @@ impl<T, V: ViewSeq<T>> View<T> for Stack<V> {
         let major = f32::clamp(state.major() + total_gap, min_major, max_major);
         let minor = f32::clamp(state.minor(), min_minor, max_minor);
 
+        // horizontal stacks are laid out from the right in right-to-left interfaces
+        let direction = match self.axis {
+            Axis::Horizontal => state.direction,
+            Axis::Vertical => Direction::Ltr,
+        };
+
         for (i, child_major) in (self.justify)
             .layout(&state.majors, major, self.gap)
             .enumerate()
         {
+            let child_major = direction.mirror(child_major, state.majors[i], major);
             let child_align = self.align.align(minor, state.minors[i]);
             let offset = self.axis.pack(child_major, child_align);
             content[i].translate(offset);
@@ -387,3 +402,51 @@ fn layout<T, V: ViewSeq<T>>(
         state.minors[i] = stack.axis.minor(size);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        layout::{Direction, Rect, Size, Space},
+        style::{Styles, Theme},
+        view::View,
+        views::{
+            size,
+            testing::{save_layout, test_layout, ViewTester},
+            with_style,
+        },
+    };
+
+    #[test]
+    fn right_to_left() {
+        let content = hstack![
+            save_layout(size(10.0, ()), "first"),
+            save_layout(size(20.0, ()), "second"),
+        ]
+        .gap(5.0);
+
+        let styles = Styles::new().with(Theme::DIRECTION, Direction::Rtl);
+        let mut view = with_style::<_, ()>(styles, content);
+
+        let layouts = test_layout(&mut view, &mut (), Space::from_size(Size::new(100.0, 20.0)));
+
+        assert_eq!(layouts["first"], Rect::from([90.0, 5.0, 100.0, 15.0]));
+        assert_eq!(layouts["second"], Rect::from([65.0, 0.0, 85.0, 20.0]));
+    }
+
+    #[test]
+    fn toggled_child_is_laid_out() {
+        fn content(show: bool) -> impl View<()> {
+            vstack![size(10.0, ()), show.then(|| size(20.0, ()))]
+        }
+
+        let space = Space::new(Size::ZERO, Size::all(100.0));
+
+        let mut view = content(true);
+        let mut tester = ViewTester::new(&mut view, &mut ());
+        assert_eq!(tester.layout(&mut view, &mut (), space).height, 30.0);
+
+        let mut new = content(false);
+        tester.rebuild(&mut new, &mut (), &view);
+        assert_eq!(tester.layout(&mut new, &mut (), space).height, 10.0);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::{
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Size, Space, Vector},
+    view::{Pod, State, View},
+};
+
+/// Create a new [`Sticky`] section, with a `header` above `content`.
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// fn section(title: &str, items: &[String]) -> impl View<Data> {
+///     let header = container(pad(8.0, text(title)));
+///     let items = vstack_vec(items.iter().map(text).collect());
+///
+///     sticky(header, items)
+/// }
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     vscroll(vstack![section("Fruits", &data.fruits), section("Vegetables", &data.vegetables)])
+/// }
+/// ```
+pub fn sticky<H, V>(header: H, content: V) -> Sticky<H, V> {
+    Sticky::new(header, content)
+}
+
+/// A section with a header that sticks to the top of the visible area, when the section
+/// is scrolled past it.
+///
+/// The header stays inside the section, so the header of the next section pushes it out
+/// as it scrolls into view. The header is drawn over the content, and should usually
+/// have a background.
+pub struct Sticky<H, V> {
+    /// The header.
+    pub header: Pod<H>,
+
+    /// The content.
+    pub content: Pod<V>,
+}
+
+impl<H, V> Sticky<H, V> {
+    /// Create a new [`Sticky`].
+    pub fn new(header: H, content: V) -> Self {
+        Self {
+            header: Pod::new(header),
+            content: Pod::new(content),
+        }
+    }
+}
+
+impl<T, H: View<T>, V: View<T>> View<T> for Sticky<H, V> {
+    type State = (State<T, H>, State<T, V>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let header = self.header.build(cx, data);
+        let content = self.content.build(cx, data);
+
+        (header, content)
+    }
+
+    fn rebuild(
+        &mut self,
+        (header, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        (self.header).rebuild(header, cx, data, &old.header);
+        (self.content).rebuild(content, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        (header, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        // the header is on top of the content, so it gets the event first
+        let handled = self.header.event(header, cx, data, event);
+        self.content.event_maybe(handled, content, cx, data, event)
+    }
+
+    fn layout(
+        &mut self,
+        (header, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        let header_space = Space::new(
+            Size::new(space.min.width, 0.0),
+            Size::new(space.max.width, space.max.height),
+        );
+        let header_size = self.header.layout(header, cx, data, header_space);
+
+        let content_space = Space::new(
+            Size::new(
+                space.min.width,
+                (space.min.height - header_size.height).max(0.0),
+            ),
+            Size::new(space.max.width, space.max.height - header_size.height),
+        );
+        let content_size = self.content.layout(content, cx, data, content_space);
+        content.translate(Vector::new(0.0, header_size.height));
+
+        let width = f32::max(header_size.width, content_size.width);
+        space.fit(Size::new(width, header_size.height + content_size.height))
+    }
+
+    fn draw(&mut self, (header, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        // pin the header to the top of the visible area, but keep it inside the section
+        let max_offset = (cx.size().height - header.size().height).max(0.0);
+        let offset = cx.visible.min.y.clamp(0.0, max_offset);
+        header.translate(Vector::new(0.0, offset));
+
+        self.content.draw(content, cx, data);
+        self.header.draw(header, cx, data);
+    }
+}
@@ -0,0 +1,358 @@
+use ori_macro::{Build, Styled};
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Point, Rect, Size, Space},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    transition::{Animated, Transition},
+    view::View,
+};
+
+/// Create a new [`Switch`].
+pub fn switch<T>(on: bool) -> Switch<T> {
+    Switch::new(on)
+}
+
+/// A switch, a knob that slides between off and on inside a track.
+///
+/// The switch is toggled by clicking it, dragging the knob, or pressing space when it is
+/// focused, after which [`Switch::on_change`] is called with the new value.
+///
+/// Can be styled using the [`SwitchStyle`].
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     switch(data.dark_mode).on_change(|cx, data: &mut Data, on| {
+///         data.dark_mode = on;
+///         cx.rebuild();
+///     })
+/// }
+/// ```
+#[derive(Styled, Build, Rebuild)]
+pub struct Switch<T> {
+    /// Whether the switch is on.
+    #[rebuild(draw)]
+    pub on: bool,
+
+    /// The callback for when the switch is toggled.
+    #[build(ignore)]
+    #[allow(clippy::type_complexity)]
+    pub on_change: Option<Box<dyn FnMut(&mut EventCx, &mut T, bool) + 'static>>,
+
+    /// The transition of the knob.
+    #[rebuild(draw)]
+    #[styled(default = Transition::ease(0.1))]
+    pub transition: Styled<Transition>,
+
+    /// The width of the track.
+    #[rebuild(layout)]
+    #[styled(default = 40.0)]
+    pub width: Styled<f32>,
+
+    /// The height of the track.
+    #[rebuild(layout)]
+    #[styled(default = 22.0)]
+    pub height: Styled<f32>,
+
+    /// The distance between the knob and the edge of the track.
+    #[rebuild(draw)]
+    #[styled(default = 3.0)]
+    pub inset: Styled<f32>,
+
+    /// The color of the track when the switch is on.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::PRIMARY or Color::BLUE)]
+    pub color: Styled<Color>,
+
+    /// The color of the track when the switch is off.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::SURFACE_HIGHER or Color::grayscale(0.8))]
+    pub background: Styled<Color>,
+
+    /// The color of the knob.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::CONTRAST or Color::WHITE)]
+    pub knob_color: Styled<Color>,
+
+    /// The border width of the track.
+    #[rebuild(draw)]
+    #[styled(default)]
+    pub border_width: Styled<BorderWidth>,
+
+    /// The border color of the track.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::OUTLINE or Color::BLACK)]
+    pub border_color: Styled<Color>,
+}
+
+impl<T> Switch<T> {
+    /// Create a new [`Switch`].
+    pub fn new(on: bool) -> Self {
+        Self {
+            on,
+            on_change: None,
+            transition: SwitchStyle::TRANSITION.into(),
+            width: SwitchStyle::WIDTH.into(),
+            height: SwitchStyle::HEIGHT.into(),
+            inset: SwitchStyle::INSET.into(),
+            color: SwitchStyle::COLOR.into(),
+            background: SwitchStyle::BACKGROUND.into(),
+            knob_color: SwitchStyle::KNOB_COLOR.into(),
+            border_width: SwitchStyle::BORDER_WIDTH.into(),
+            border_color: SwitchStyle::BORDER_COLOR.into(),
+        }
+    }
+
+    /// Set the callback for when the switch is toggled.
+    pub fn on_change(
+        mut self,
+        on_change: impl FnMut(&mut EventCx, &mut T, bool) + 'static,
+    ) -> Self {
+        self.on_change = Some(Box::new(on_change));
+        self
+    }
+
+    fn change(&mut self, cx: &mut EventCx, data: &mut T, on: bool) {
+        if on == self.on {
+            return;
+        }
+
+        if let Some(ref mut on_change) = self.on_change {
+            on_change(cx, data, on);
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct SwitchState {
+    // the position of the knob, from 0 (off) to 1 (on)
+    pub t: Animated,
+    pub drag: Option<f32>,
+    pub style: SwitchStyle,
+}
+
+impl SwitchState {
+    fn knob_size(&self) -> f32 {
+        self.style.height - self.style.inset * 2.0
+    }
+
+    fn travel(&self) -> f32 {
+        self.style.width - self.style.height
+    }
+}
+
+impl<T> View<T> for Switch<T> {
+    type State = SwitchState;
+
+    fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
+        cx.set_focusable(true);
+
+        SwitchState {
+            t: Animated::new(self.on),
+            drag: None,
+            style: SwitchStyle::styled(self, cx.styles()),
+        }
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+
+        if self.on != old.on {
+            cx.animate();
+        }
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        if cx.focused_changed() {
+            cx.draw();
+        }
+
+        match event {
+            Event::PointerPressed(_) if cx.is_hovered() => {
+                state.drag = None;
+                cx.set_active(true);
+
+                true
+            }
+            Event::PointerMoved(e) if cx.is_active() => {
+                let local = cx.local(e.position);
+
+                if state.drag.is_none() && !cx.window().is_dragging(e.id) {
+                    return false;
+                }
+
+                // keep the knob centered under the pointer
+                let offset = local.x - state.style.height / 2.0;
+                let t = offset / state.travel().max(1.0);
+
+                state.drag = Some(t.clamp(0.0, 1.0));
+                cx.draw();
+
+                true
+            }
+            Event::PointerReleased(_) if cx.is_active() => {
+                cx.set_active(false);
+
+                let on = match state.drag.take() {
+                    Some(t) => {
+                        state.t.set(t);
+                        t > 0.5
+                    }
+                    None => !self.on,
+                };
+
+                self.change(cx, data, on);
+                cx.animate();
+
+                true
+            }
+            Event::KeyPressed(e) if cx.is_focused() && e.is_key(' ') => {
+                self.change(cx, data, !self.on);
+                cx.animate();
+
+                true
+            }
+            Event::Animate(dt) => {
+                let dt = cx.transition_delta(*dt);
+
+                if state.drag.is_none() && state.t.step(&state.style.transition, self.on, dt) {
+                    cx.animate();
+                }
+
+                cx.draw();
+
+                false
+            }
+            _ => false,
+        }
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        _cx: &mut LayoutCx,
+        _data: &mut T,
+        space: Space,
+    ) -> Size {
+        space.fit(Size::new(state.style.width, state.style.height))
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        cx.hoverable(|cx| {
+            let t = match state.drag {
+                Some(t) => t,
+                None => state.t.get(),
+            };
+
+            let height = cx.size().height;
+            let radius = BorderRadius::all(height / 2.0);
+
+            if cx.is_focused() {
+                cx.draw_focus_ring(cx.rect(), radius);
+            }
+
+            cx.quad(
+                cx.rect(),
+                state.style.background.mix(state.style.color, t),
+                radius,
+                state.style.border_width,
+                state.style.border_color,
+            );
+
+            let knob = state.knob_size();
+            let center = Point::new(height / 2.0 + state.travel() * t, height / 2.0);
+
+            cx.quad(
+                Rect::center_size(center, Size::all(knob)),
+                state.style.knob_color,
+                BorderRadius::all(knob / 2.0),
+                BorderWidth::ZERO,
+                Color::TRANSPARENT,
+            );
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Modifiers, PointerButton, PointerId, PointerPressed, PointerReleased},
+        transition::ReducedMotion,
+        views::testing::ViewTester,
+    };
+
+    use super::*;
+
+    #[test]
+    fn click_toggles() {
+        let mut view = switch(false).on_change(|_, data: &mut bool, on| *data = on);
+        let mut data = false;
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.layout(&mut view, &mut data, Space::UNBOUNDED);
+        tester.view_state.set_hovered(true);
+
+        let pressed = Event::PointerPressed(PointerPressed {
+            id: PointerId::from_u64(0),
+            position: Point::new(5.0, 5.0),
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+        });
+
+        let released = Event::PointerReleased(PointerReleased {
+            id: PointerId::from_u64(0),
+            position: Point::new(5.0, 5.0),
+            clicked: true,
+            button: PointerButton::Primary,
+            modifiers: Modifiers::default(),
+        });
+
+        tester.event(&mut view, &mut data, &pressed);
+        tester.event(&mut view, &mut data, &released);
+
+        assert!(data);
+    }
+
+    #[test]
+    fn reduced_motion_completes_instantly() {
+        let mut view = switch(false);
+        let mut data = ();
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.contexts.insert(ReducedMotion(true));
+
+        view.on = true;
+        tester.event(&mut view, &mut data, &Event::Animate(0.016));
+        assert_eq!(tester.state.t.get(), 1.0);
+    }
+
+    #[test]
+    fn spring_keeps_velocity_when_toggled() {
+        let mut view = switch(false).transition(Transition::spring(170.0, 26.0));
+        let mut data = ();
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+
+        view.on = true;
+        tester.event(&mut view, &mut data, &Event::Animate(0.05));
+        assert!(tester.state.t.velocity() > 0.0);
+
+        // toggling back mid-flight pulls the knob back, instead of reversing it instantly
+        view.on = false;
+        tester.event(&mut view, &mut data, &Event::Animate(0.001));
+        assert!(tester.state.t.velocity() > 0.0);
+    }
+}
@@ -11,7 +11,7 @@ use crate::{
     style::{Styled, Theme},
     text::{
         FontFamily, FontStretch, FontStyle, FontWeight, Fonts, TextAlign, TextAttributes,
-        TextBuffer, TextWrap,
+        TextBuffer, TextOverflow, TextWrap,
     },
     view::View,
 };
@@ -35,6 +35,12 @@ pub fn text(text: impl Into<SmolStr>) -> Text {
     Text::new(text)
 }
 
+/// A property inserted into [`Text`] views, while their text is truncated.
+///
+/// See [`TextOverflow::Ellipsis`] and [`Tooltip::truncated_only`](super::Tooltip::truncated_only).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Truncated;
+
 /// A view that displays text.
 ///
 /// Can be styled using the [`TextStyle`].
@@ -79,6 +85,10 @@ pub struct Text {
     /// The text wrap of the text.
     #[styled(default)]
     pub wrap: Styled<TextWrap>,
+
+    /// How the text is handled when it doesn't fit.
+    #[styled(default)]
+    pub overflow: Styled<TextOverflow>,
 }
 
 impl Text {
@@ -95,22 +105,31 @@ impl Text {
             align: TextStyle::ALIGN.into(),
             line_height: TextStyle::LINE_HEIGHT.into(),
             wrap: TextStyle::WRAP.into(),
+            overflow: TextStyle::OVERFLOW.into(),
         }
     }
 
     fn set_attributes(&self, fonts: &mut Fonts, buffer: &mut TextBuffer, style: &TextStyle) {
-        buffer.set_wrap(fonts, style.wrap);
+        buffer.set_wrap(fonts, Self::effective_wrap(style));
         buffer.set_align(style.align);
-        buffer.set_text(
-            fonts,
-            &self.text,
-            TextAttributes {
-                family: style.font_family.clone(),
-                stretch: style.font_stretch,
-                weight: style.font_weight,
-                style: style.font_style,
-            },
-        );
+        buffer.set_text(fonts, &self.text, Self::attributes(style));
+    }
+
+    fn attributes(style: &TextStyle) -> TextAttributes {
+        TextAttributes {
+            family: style.font_family.clone(),
+            stretch: style.font_stretch,
+            weight: style.font_weight,
+            style: style.font_style,
+        }
+    }
+
+    // truncated text is always on a single line
+    fn effective_wrap(style: &TextStyle) -> TextWrap {
+        match style.overflow {
+            TextOverflow::Visible => style.wrap,
+            TextOverflow::Ellipsis => TextWrap::None,
+        }
     }
 }
 
@@ -118,33 +137,52 @@ impl Text {
 pub struct TextState {
     style: TextStyle,
     buffer: TextBuffer,
+    // the bounds of the last layout, the buffer is only reshaped to them when drawn
+    bounds: Size,
+    truncated: bool,
 }
 
 impl<T> View<T> for Text {
     type State = TextState;
 
     fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
-        let style = TextStyle::styled(self, cx.styles());
+        let mut style = TextStyle::styled(self, cx.styles());
+        style.align = style.align.resolve(cx.direction());
         let mut buffer = TextBuffer::new(cx.fonts(), style.font_size, style.line_height);
         self.set_attributes(cx.fonts(), &mut buffer, &style);
 
-        TextState { style, buffer }
+        TextState {
+            style,
+            buffer,
+            bounds: Size::ZERO,
+            truncated: false,
+        }
     }
 
     fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
-        let style = TextStyle::styled(self, cx.styles());
+        let mut style = TextStyle::styled(self, cx.styles());
+        style.align = style.align.resolve(cx.direction());
+
+        // the text is truncated to the metrics and wrapping, so it's restored when they change
+        let mut reset_text = self.text != old.text
+            || style.font_family != state.style.font_family
+            || style.font_weight != state.style.font_weight
+            || style.font_stretch != state.style.font_stretch
+            || style.font_style != state.style.font_style;
 
         if style.font_size != state.style.font_size || style.line_height != state.style.line_height
         {
             (state.buffer).set_metrics(cx.fonts(), style.font_size, style.line_height);
+            reset_text |= state.truncated;
 
             cx.layout();
         }
 
-        if style.wrap != state.style.wrap {
-            state.buffer.set_wrap(cx.fonts(), style.wrap);
-
-            cx.draw();
+        if style.wrap != state.style.wrap || style.overflow != state.style.overflow {
+            state
+                .buffer
+                .set_wrap(cx.fonts(), Self::effective_wrap(&style));
+            reset_text = true;
         }
 
         if style.align != state.style.align {
@@ -153,22 +191,11 @@ impl<T> View<T> for Text {
             cx.draw();
         }
 
-        if self.text != old.text
-            || style.font_family != state.style.font_family
-            || style.font_weight != state.style.font_weight
-            || style.font_stretch != state.style.font_stretch
-            || style.font_style != state.style.font_style
-        {
-            state.buffer.set_text(
-                cx.fonts(),
-                &self.text,
-                TextAttributes {
-                    family: style.font_family.clone(),
-                    stretch: style.font_stretch,
-                    weight: style.font_weight,
-                    style: style.font_style,
-                },
-            );
+        if reset_text {
+            state
+                .buffer
+                .set_text(cx.fonts(), &self.text, Self::attributes(&style));
+            state.truncated = false;
 
             cx.layout();
         }
@@ -197,14 +224,57 @@ impl<T> View<T> for Text {
         _data: &mut T,
         space: Space,
     ) -> Size {
+        state.bounds = space.max;
+
+        // text that isn't truncated is measured, so that laying it out repeatedly, e.g. when
+        // a stack measures its children, doesn't reshape the buffer every time
+        if state.style.overflow == TextOverflow::Visible {
+            cx.remove_property::<Truncated>();
+
+            let size = cx.fonts().measure(
+                &self.text,
+                &Self::attributes(&state.style),
+                state.style.font_size,
+                state.style.line_height,
+                state.style.wrap,
+                space.max.width,
+            );
+
+            return space.fit(size);
+        }
+
         if state.buffer.bounds() != space.max {
+            // restore the full text, so that it can be truncated to the new bounds
+            if state.truncated {
+                let attributes = Self::attributes(&state.style);
+                state.buffer.set_text(cx.fonts(), &self.text, attributes);
+                state.truncated = false;
+            }
+
             state.buffer.set_bounds(cx.fonts(), space.max);
         }
 
+        if !state.truncated {
+            let attributes = Self::attributes(&state.style);
+            let width = space.max.width;
+            state.truncated = (state.buffer).truncate(cx.fonts(), &self.text, attributes, width);
+        }
+
+        match state.truncated {
+            true => cx.insert_property(Truncated),
+            false => {
+                cx.remove_property::<Truncated>();
+            }
+        }
+
         space.fit(state.buffer.size())
     }
 
     fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, _data: &mut T) {
+        if state.buffer.bounds() != state.bounds {
+            state.buffer.set_bounds(cx.fonts(), state.bounds);
+        }
+
         let offset = cx.rect().center() - state.buffer.rect().center();
         cx.text(&state.buffer, state.style.color, offset);
     }
@@ -217,3 +287,70 @@ impl From<fmt::Arguments<'_>> for Text {
         Text::new(w)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        layout::{Size, Space},
+        views::testing::ViewTester,
+    };
+
+    use super::*;
+
+    #[test]
+    fn text_ellipsis() {
+        let mut view =
+            text("a long line of text that doesn't fit").overflow(TextOverflow::Ellipsis);
+        let mut tester = ViewTester::new(&mut view, &mut ());
+
+        let space = Space::new(Size::ZERO, Size::new(60.0, 100.0));
+        let size = tester.layout(&mut view, &mut (), space);
+
+        assert!(size.width <= 60.0);
+        assert!(tester.view_state.contains_property::<Truncated>());
+
+        let space = Space::new(Size::ZERO, Size::new(1000.0, 100.0));
+        tester.layout(&mut view, &mut (), space);
+
+        assert!(!tester.view_state.contains_property::<Truncated>());
+    }
+
+    #[test]
+    fn metrics_restore_truncated_text() {
+        let content = "a long line of text that doesn't fit";
+        let mut view = text(content).overflow(TextOverflow::Ellipsis);
+        let mut tester = ViewTester::new(&mut view, &mut ());
+
+        let space = Space::new(Size::ZERO, Size::new(100.0, 100.0));
+        tester.layout(&mut view, &mut (), space);
+        assert!(tester.view_state.contains_property::<Truncated>());
+
+        // the text fits at a smaller font size, without the bounds changing
+        let mut new = text(content)
+            .overflow(TextOverflow::Ellipsis)
+            .font_size(2.0);
+        tester.rebuild(&mut new, &mut (), &view);
+        assert!(tester.view_state.needs_layout());
+
+        tester.layout(&mut new, &mut (), space);
+        assert!(!tester.view_state.contains_property::<Truncated>());
+    }
+
+    #[test]
+    fn layout_measures_text() {
+        let mut view = text("some text that wraps");
+        let mut tester = ViewTester::new(&mut view, &mut ());
+
+        let space = Space::new(Size::ZERO, Size::new(60.0, 100.0));
+        let size = tester.layout(&mut view, &mut (), space);
+        tester.layout(&mut view, &mut (), Space::UNBOUNDED);
+        tester.layout(&mut view, &mut (), space);
+
+        // the buffer is only shaped to the bounds of the last layout when drawn
+        assert_ne!(tester.state.buffer.bounds(), space.max);
+
+        tester.draw(&mut view, &mut ());
+        assert_eq!(tester.state.buffer.bounds(), space.max);
+        assert_eq!(space.fit(tester.state.buffer.size()), size);
+    }
+}
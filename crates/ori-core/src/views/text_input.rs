@@ -5,15 +5,16 @@ use cosmic_text::{
 use ori_macro::{example, Build, Styled};
 
 use crate::{
-    canvas::Color,
+    canvas::{BorderRadius, Color},
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
     event::{Capitalize, Event, Ime, Key, KeyPressed},
-    layout::{Point, Rect, Size, Space, Vector},
+    layout::{Affine, Point, Rect, Size, Space, Vector},
     style::{Styled, Theme},
     text::{
         FontFamily, FontStretch, FontStyle, FontWeight, Fonts, TextAlign, TextAttributes,
         TextBuffer, TextWrap,
     },
+    transition::{Animated, Transition},
     view::View,
     window::Cursor,
 };
@@ -46,6 +47,12 @@ pub struct TextInput<T> {
     /// Placeholder text to display when the input is empty.
     pub placeholder: String,
 
+    /// A label that floats above the input when it is focused or has text.
+    ///
+    /// When the input is empty and not focused, the label is shown in place of the placeholder.
+    #[build(ignore)]
+    pub label: Option<String>,
+
     /// Whether the input is multi-line.
     ///
     /// When disabled (the default), the input will only accept a single line of text.
@@ -85,6 +92,14 @@ pub struct TextInput<T> {
     #[styled(default -> Theme::CONTRAST_LOW or Color::grayscale(0.9))]
     pub placeholder_color: Styled<Color>,
 
+    /// The color of the label.
+    #[styled(default -> Theme::CONTRAST_LOW or Color::grayscale(0.9))]
+    pub label_color: Styled<Color>,
+
+    /// The transition of the label, between resting in the input and floating above it.
+    #[styled(default = Transition::ease(0.1))]
+    pub label_transition: Styled<Transition>,
+
     /// The vertical alignment of the text.
     #[styled(default)]
     pub align: Styled<TextAlign>,
@@ -96,6 +111,12 @@ pub struct TextInput<T> {
     /// The text wrap of the text.
     #[styled(default)]
     pub wrap: Styled<TextWrap>,
+
+    /// The number of times per second the cursor blinks, `0.0` shows a solid cursor.
+    ///
+    /// The cursor only blinks while the input is focused.
+    #[styled(default = 1.6)]
+    pub blink_rate: Styled<f32>,
 }
 
 impl<T> Default for TextInput<T> {
@@ -112,6 +133,7 @@ impl<T> TextInput<T> {
             on_input: None,
             on_submit: None,
             placeholder: String::from("..."),
+            label: None,
             multiline: false,
             capitalize: Capitalize::Sentences,
             font_size: TextInputStyle::FONT_SIZE.into(),
@@ -121,9 +143,12 @@ impl<T> TextInput<T> {
             font_style: TextInputStyle::FONT_STYLE.into(),
             color: TextInputStyle::COLOR.into(),
             placeholder_color: TextInputStyle::PLACEHOLDER_COLOR.into(),
+            label_color: TextInputStyle::LABEL_COLOR.into(),
+            label_transition: TextInputStyle::LABEL_TRANSITION.into(),
             align: TextInputStyle::ALIGN.into(),
             line_height: TextInputStyle::LINE_HEIGHT.into(),
             wrap: TextInputStyle::WRAP.into(),
+            blink_rate: TextInputStyle::BLINK_RATE.into(),
         }
     }
 
@@ -133,6 +158,12 @@ impl<T> TextInput<T> {
         self
     }
 
+    /// Set the floating label of the input, see [`TextInput::label`].
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
     /// Set the callback that is called when an input is received.
     ///
     /// Note that this doesn't trigger a rebuild automatically.
@@ -154,12 +185,6 @@ impl<T> TextInput<T> {
     }
 
     fn set_attributes(&self, fonts: &mut Fonts, state: &mut TextInputState) {
-        let attrs = TextAttributes {
-            family: state.style.font_family.clone(),
-            stretch: state.style.font_stretch,
-            weight: state.style.font_weight,
-            style: state.style.font_style,
-        };
         let placeholder_attrs = TextAttributes {
             family: state.style.font_family.clone(),
             stretch: state.style.font_stretch,
@@ -172,12 +197,45 @@ impl<T> TextInput<T> {
         };
 
         /* editor */
-        let wrap = state.style.wrap.to_cosmic_text();
+        let wrap = self.effective_wrap(&state.style);
+        let wrap = wrap.to_cosmic_text();
         let buffer = state.buffer_mut();
         buffer.set_wrap(&mut fonts.font_system, wrap);
         buffer.set_metrics(&mut fonts.font_system, metrics);
 
-        let mut text = state.text();
+        let text = state.text();
+        self.set_text(fonts, state, &text);
+
+        /* placeholder */
+        state
+            .placeholder
+            .set_wrap(fonts, self.effective_wrap(&state.style));
+        (state.placeholder).set_metrics(fonts, state.style.font_size, state.style.line_height);
+        (state.placeholder).set_text(fonts, &self.placeholder, placeholder_attrs.clone());
+
+        /* label */
+        (state.label).set_metrics(fonts, state.style.font_size, state.style.line_height);
+        let label = self.label.as_deref().unwrap_or_default();
+        (state.label).set_text(fonts, label, placeholder_attrs);
+    }
+
+    // the height reserved above the text for the floating label
+    fn label_height(&self, style: &TextInputStyle) -> f32 {
+        match self.label {
+            Some(_) => style.font_size * style.line_height * LABEL_SCALE,
+            None => 0.0,
+        }
+    }
+
+    fn set_text(&self, fonts: &mut Fonts, state: &mut TextInputState, text: &str) {
+        let attrs = TextAttributes {
+            family: state.style.font_family.clone(),
+            stretch: state.style.font_stretch,
+            weight: state.style.font_weight,
+            style: state.style.font_style,
+        };
+
+        let mut text = text.to_string();
 
         if text.ends_with('\n') {
             text.push('\n');
@@ -190,10 +248,23 @@ impl<T> TextInput<T> {
             Shaping::Advanced,
         );
 
-        /* placeholder */
-        state.placeholder.set_wrap(fonts, state.style.wrap);
-        (state.placeholder).set_metrics(fonts, state.style.font_size, state.style.line_height);
-        (state.placeholder).set_text(fonts, &self.placeholder, placeholder_attrs);
+        let align = state.style.align;
+        state.set_align(align);
+    }
+
+    // restore a snapshot from the undo history
+    fn restore(&self, fonts: &mut Fonts, state: &mut TextInputState, snapshot: TextSnapshot) {
+        self.set_text(fonts, state, &snapshot.text);
+        state.editor.set_cursor(snapshot.cursor);
+        state.editor.set_selection(snapshot.selection);
+    }
+
+    // single-line inputs never wrap, they scroll horizontally instead
+    fn effective_wrap(&self, style: &TextInputStyle) -> TextWrap {
+        match self.multiline {
+            true => style.wrap,
+            false => TextWrap::None,
+        }
     }
 
     fn set_attrs_list(&self, buffer: &mut Buffer, style: &TextInputStyle) {
@@ -212,13 +283,40 @@ impl<T> TextInput<T> {
     }
 }
 
+// the scale of the label when it floats above the input
+const LABEL_SCALE: f32 = 0.75;
+
+// the maximum number of edits that can be undone
+const UNDO_LIMIT: usize = 100;
+
+// the kind of an edit, consecutive edits of the same kind are undone together
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum EditKind {
+    Insert,
+    Delete,
+    Other,
+}
+
+// a snapshot of the text before an edit
+struct TextSnapshot {
+    text: String,
+    cursor: cosmic_text::Cursor,
+    selection: Selection,
+}
+
 #[doc(hidden)]
 pub struct TextInputState {
     style: TextInputStyle,
     editor: Editor<'static>,
     placeholder: TextBuffer,
+    label: TextBuffer,
+    label_float: Animated,
     dragging: bool,
     blink: f32,
+    scroll: Vector,
+    undo: Vec<TextSnapshot>,
+    redo: Vec<TextSnapshot>,
+    last_edit: Option<EditKind>,
 }
 
 impl TextInputState {
@@ -236,6 +334,14 @@ impl TextInputState {
         }
     }
 
+    fn set_align(&mut self, align: TextAlign) {
+        for line in self.buffer_mut().lines.iter_mut() {
+            line.set_align(Some(align.to_cosmic_text()));
+        }
+
+        self.placeholder.set_align(align);
+    }
+
     fn text(&self) -> String {
         let mut text = String::new();
 
@@ -265,6 +371,78 @@ impl TextInputState {
         }
     }
 
+    fn snapshot(&self) -> TextSnapshot {
+        TextSnapshot {
+            text: self.text(),
+            cursor: self.editor.cursor(),
+            selection: self.editor.selection(),
+        }
+    }
+
+    // record the current text in the undo history, before it is edited
+    fn record(&mut self, kind: EditKind) {
+        self.redo.clear();
+
+        // coalesce consecutive typing into a single undo step
+        if kind != EditKind::Other && self.last_edit == Some(kind) {
+            return;
+        }
+
+        self.undo.push(self.snapshot());
+        self.last_edit = Some(kind);
+
+        if self.undo.len() > UNDO_LIMIT {
+            self.undo.remove(0);
+        }
+    }
+
+    // the rect of the cursor, relative to the text
+    fn cursor_rect(&self) -> Option<Rect> {
+        let (x, y) = self.editor.cursor_position()?;
+        let height = self.style.font_size * self.style.line_height;
+
+        let min = Point::new(x as f32, y as f32);
+        Some(Rect::min_size(min, Size::new(1.0, height)))
+    }
+
+    // the bounds of the laid out text, relative to the text
+    fn text_bounds(&self) -> Rect {
+        let height = self.style.font_size * self.style.line_height;
+        let mut bounds: Option<Rect> = None;
+
+        for run in self.buffer().layout_runs() {
+            let min_x = run.glyphs.first().map_or(0.0, |glyph| glyph.x);
+            let max_x = run.glyphs.last().map_or(0.0, |glyph| glyph.x + glyph.w);
+
+            let min = Point::new(min_x, run.line_top);
+            let max = Point::new(max_x, run.line_top + height);
+            let rect = Rect::new(min, max);
+
+            bounds = Some(match bounds {
+                Some(bounds) => bounds.union(rect),
+                None => rect,
+            });
+        }
+
+        bounds.unwrap_or_default()
+    }
+
+    // scroll the text so that the cursor is visible within `size`
+    fn scroll_to_cursor(&mut self, size: Size) {
+        if let Some(cursor) = self.cursor_rect() {
+            self.scroll.x = f32::max(self.scroll.x.min(cursor.min.x), cursor.max.x - size.width);
+            self.scroll.y = f32::max(self.scroll.y.min(cursor.min.y), cursor.max.y - size.height);
+        }
+
+        // never scroll past the text
+        let bounds = self.text_bounds();
+        let min = bounds.min.min(Point::ZERO);
+        let max = Point::new(bounds.max.x - size.width, bounds.max.y - size.height).max(min);
+
+        self.scroll.x = self.scroll.x.clamp(min.x, max.x);
+        self.scroll.y = self.scroll.y.clamp(min.y, max.y);
+    }
+
     fn clear_text(&mut self) {
         self.buffer_mut().lines = vec![BufferLine::new(
             "",
@@ -292,6 +470,11 @@ fn move_key(e: &KeyPressed) -> Option<Motion> {
         Key::Right => Some(Motion::Right),
         Key::Up => Some(Motion::Up),
         Key::Down => Some(Motion::Down),
+        Key::Home if e.modifiers.ctrl => Some(Motion::BufferStart),
+        Key::End if e.modifiers.ctrl => Some(Motion::BufferEnd),
+        // home and end move within the visual line, so wrapped lines are handled too
+        Key::Home => Some(Motion::Home),
+        Key::End => Some(Motion::End),
         _ => None,
     }
 }
@@ -310,7 +493,8 @@ impl<T> View<T> for TextInput<T> {
     fn build(&mut self, cx: &mut BuildCx, _data: &mut T) -> Self::State {
         cx.set_focusable(true);
 
-        let style = TextInputStyle::styled(self, cx.styles());
+        let mut style = TextInputStyle::styled(self, cx.styles());
+        style.align = style.align.resolve(cx.direction());
 
         let editor = Editor::new(Buffer::new(
             &mut cx.fonts().font_system,
@@ -321,13 +505,20 @@ impl<T> View<T> for TextInput<T> {
         ));
 
         let placeholder = TextBuffer::new(cx.fonts(), style.font_size, style.line_height);
+        let label = TextBuffer::new(cx.fonts(), style.font_size, style.line_height);
 
         let mut state = TextInputState {
             style,
             editor,
             placeholder,
+            label,
+            label_float: Animated::default(),
             dragging: false,
             blink: 0.0,
+            scroll: Vector::ZERO,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            last_edit: None,
         };
 
         if let Some(ref text) = self.text {
@@ -344,6 +535,11 @@ impl<T> View<T> for TextInput<T> {
                 attrs.to_cosmic_text(),
                 Shaping::Advanced,
             );
+
+            // an input that starts with text, starts with the label floating
+            if !text.is_empty() {
+                state.label_float = Animated::new(true);
+            }
         }
 
         self.set_attributes(cx.fonts(), &mut state);
@@ -352,7 +548,8 @@ impl<T> View<T> for TextInput<T> {
     }
 
     fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, _data: &mut T, old: &Self) {
-        let style = TextInputStyle::styled(self, cx.styles());
+        let mut style = TextInputStyle::styled(self, cx.styles());
+        style.align = style.align.resolve(cx.direction());
 
         if style.font_size != state.style.font_size || style.line_height != state.style.line_height
         {
@@ -363,26 +560,21 @@ impl<T> View<T> for TextInput<T> {
 
             (state.buffer_mut()).set_metrics(&mut cx.fonts().font_system, metrics);
             (state.placeholder).set_metrics(cx.fonts(), style.font_size, style.line_height);
+            (state.label).set_metrics(cx.fonts(), style.font_size, style.line_height);
 
             cx.layout();
         }
 
-        if style.wrap != state.style.wrap {
-            let wrap = style.wrap.to_cosmic_text();
-            (state.buffer_mut()).set_wrap(&mut cx.fonts().font_system, wrap);
-            state.placeholder.set_wrap(cx.fonts(), style.wrap);
+        if style.wrap != state.style.wrap || self.multiline != old.multiline {
+            let wrap = self.effective_wrap(&style);
+            (state.buffer_mut()).set_wrap(&mut cx.fonts().font_system, wrap.to_cosmic_text());
+            state.placeholder.set_wrap(cx.fonts(), wrap);
 
             cx.layout();
         }
 
         if style.align != state.style.align {
-            let align = style.align.to_cosmic_text();
-
-            for line in state.buffer_mut().lines.iter_mut() {
-                line.set_align(Some(align));
-            }
-
-            state.placeholder.set_align(style.align);
+            state.set_align(style.align);
 
             cx.layout();
         }
@@ -411,7 +603,10 @@ impl<T> View<T> for TextInput<T> {
                     attrs.to_cosmic_text(),
                     Shaping::Advanced,
                 );
+                state.set_align(style.align);
 
+                // the label might need to float
+                cx.animate();
                 cx.layout();
             }
         } else if attrs_changed {
@@ -440,6 +635,27 @@ impl<T> View<T> for TextInput<T> {
             cx.layout();
         }
 
+        if self.label != old.label || attrs_changed {
+            state.label.set_text(
+                cx.fonts(),
+                self.label.as_deref().unwrap_or_default(),
+                TextAttributes {
+                    family: style.font_family.clone(),
+                    stretch: style.font_stretch,
+                    weight: style.font_weight,
+                    style: style.font_style,
+                },
+            );
+
+            cx.layout();
+        }
+
+        // restart the blinking, or show a solid cursor
+        if style.blink_rate != state.style.blink_rate {
+            state.blink = 0.0;
+            cx.animate();
+        }
+
         state.style = style;
     }
 
@@ -475,6 +691,8 @@ impl<T> View<T> for TextInput<T> {
 
                 if !e.modifiers.ctrl && !e.modifiers.alt && !e.modifiers.meta {
                     if let Some(ref text) = e.text {
+                        state.record(EditKind::Insert);
+
                         for c in text.chars() {
                             (state.editor).action(&mut cx.fonts().font_system, Action::Insert(c));
                         }
@@ -503,6 +721,19 @@ impl<T> View<T> for TextInput<T> {
                         }
                     }
 
+                    // cosmic-text backspaces a single char, which would leave e.g. half a
+                    // flag behind, so the whole grapheme cluster is selected first
+                    if matches!(action, Action::Backspace)
+                        && state.editor.selection() == Selection::None
+                    {
+                        let cursor = state.editor.cursor();
+                        state.editor.set_selection(Selection::Normal(cursor));
+
+                        let previous = Action::Motion(Motion::Previous);
+                        state.editor.action(&mut cx.fonts().font_system, previous);
+                    }
+
+                    state.record(EditKind::Delete);
                     state.editor.action(&mut cx.fonts().font_system, action);
                     cx.layout();
                     state.blink = 0.0;
@@ -519,6 +750,7 @@ impl<T> View<T> for TextInput<T> {
                 }
 
                 if e.is_key(Key::Enter) && self.multiline {
+                    state.record(EditKind::Other);
                     (state.editor).action(&mut cx.fonts().font_system, Action::Enter);
                     cx.layout();
                     state.blink = 0.0;
@@ -535,6 +767,7 @@ impl<T> View<T> for TextInput<T> {
                 }
 
                 if let Some(motion) = move_key(e) {
+                    state.last_edit = None;
                     (state.editor).action(&mut cx.fonts().font_system, Action::Motion(motion));
                     cx.draw();
                     state.blink = 0.0;
@@ -542,6 +775,19 @@ impl<T> View<T> for TextInput<T> {
                     handled = true;
                 }
 
+                if e.is_key('a') && e.modifiers.ctrl {
+                    state.last_edit = None;
+
+                    let start = cosmic_text::Cursor::new(0, 0);
+                    state.editor.set_selection(Selection::Normal(start));
+
+                    let motion = Action::Motion(Motion::BufferEnd);
+                    state.editor.action(&mut cx.fonts().font_system, motion);
+                    cx.draw();
+
+                    handled = true;
+                }
+
                 if e.is_key('c') && e.modifiers.ctrl {
                     if let Some(selection) = state.editor.copy_selection() {
                         cx.clipboard().set(selection);
@@ -556,6 +802,7 @@ impl<T> View<T> for TextInput<T> {
                         cx.layout();
                     }
 
+                    state.record(EditKind::Other);
                     state.editor.delete_selection();
 
                     changed = true;
@@ -564,6 +811,7 @@ impl<T> View<T> for TextInput<T> {
 
                 if e.is_key('v') && e.modifiers.ctrl {
                     let text = cx.clipboard().get();
+                    state.record(EditKind::Other);
                     state.editor.insert_string(&text, None);
 
                     cx.layout();
@@ -572,6 +820,36 @@ impl<T> View<T> for TextInput<T> {
                     handled = true;
                 }
 
+                if e.is_key('z') && e.modifiers.ctrl && !e.modifiers.shift {
+                    if let Some(snapshot) = state.undo.pop() {
+                        state.redo.push(state.snapshot());
+                        self.restore(cx.fonts(), state, snapshot);
+
+                        cx.layout();
+                        state.blink = 0.0;
+                        changed = true;
+                    }
+
+                    state.last_edit = None;
+                    handled = true;
+                }
+
+                if e.is_key('z') && e.modifiers.ctrl && e.modifiers.shift
+                    || e.is_key('y') && e.modifiers.ctrl
+                {
+                    if let Some(snapshot) = state.redo.pop() {
+                        state.undo.push(state.snapshot());
+                        self.restore(cx.fonts(), state, snapshot);
+
+                        cx.layout();
+                        state.blink = 0.0;
+                        changed = true;
+                    }
+
+                    state.last_edit = None;
+                    handled = true;
+                }
+
                 if !(changed || submit) {
                     return handled;
                 }
@@ -606,8 +884,10 @@ impl<T> View<T> for TextInput<T> {
 
                 state.blink = 0.0;
                 state.dragging = true;
+                state.last_edit = None;
 
-                let local = cx.local(e.position);
+                let offset = Vector::new(0.0, self.label_height(&state.style));
+                let local = cx.local(e.position) - offset + state.scroll;
                 state.editor.action(
                     &mut cx.fonts().font_system,
                     Action::Click {
@@ -626,7 +906,8 @@ impl<T> View<T> for TextInput<T> {
                 true
             }
             Event::PointerMoved(e) if state.dragging => {
-                let local = cx.local(e.position);
+                let offset = Vector::new(0.0, self.label_height(&state.style));
+                let local = cx.local(e.position) - offset + state.scroll;
 
                 state.editor.action(
                     &mut cx.fonts().font_system,
@@ -642,10 +923,25 @@ impl<T> View<T> for TextInput<T> {
             }
             Event::Animate(dt) => {
                 if cx.is_focused() {
-                    cx.animate();
                     cx.draw();
 
-                    state.blink += *dt * 10.0;
+                    // a solid cursor doesn't need to be animated, the cursor is also solid
+                    // when the user prefers reduced motion
+                    if cx.reduced_motion() {
+                        state.blink = 0.0;
+                    } else if state.style.blink_rate > 0.0 {
+                        cx.animate();
+                        state.blink += *dt * state.style.blink_rate * std::f32::consts::TAU;
+                    }
+                }
+
+                let float = cx.is_focused() || !state.text().is_empty();
+                let transition = state.style.label_transition;
+                let dt = cx.transition_delta(*dt);
+
+                if self.label.is_some() && state.label_float.step(&transition, float, dt) {
+                    cx.animate();
+                    cx.draw();
                 }
 
                 false
@@ -661,12 +957,15 @@ impl<T> View<T> for TextInput<T> {
         _data: &mut T,
         space: Space,
     ) -> Size {
-        state.buffer_mut().set_size(
-            &mut cx.fonts().font_system,
-            Some(space.max.width),
-            Some(space.max.height),
-        );
-        state.placeholder.set_bounds(cx.fonts(), space.max);
+        let label_height = self.label_height(&state.style);
+        let text_max = Size::new(space.max.width, space.max.height - label_height);
+
+        // the height is left unbounded, overflowing text is scrolled when drawn
+        (state.buffer_mut()).set_size(&mut cx.fonts().font_system, Some(space.max.width), None);
+        state
+            .placeholder
+            .set_bounds(cx.fonts(), text_max.max(Size::ZERO));
+        state.label.set_bounds(cx.fonts(), space.max);
 
         // FIXME: this is bad
         (state.editor).shape_as_needed(&mut cx.fonts().font_system, true);
@@ -678,7 +977,7 @@ impl<T> View<T> for TextInput<T> {
             state.placeholder.size()
         };
 
-        size.height = f32::max(size.height, state.style.font_size);
+        size.height = f32::max(size.height, state.style.font_size) + label_height;
         space.fit(size)
     }
 
@@ -686,64 +985,283 @@ impl<T> View<T> for TextInput<T> {
         cx.hoverable(|cx| {
             cx.trigger(cx.rect());
 
+            if cx.is_focused() {
+                cx.draw_focus_ring(cx.rect(), BorderRadius::ZERO);
+            }
+
             // FIXME: this is bad
             (state.editor).shape_as_needed(&mut cx.fonts().font_system, true);
 
-            let cursor = state.editor.cursor();
-
-            /* draw the highlights and the cursor */
-            // FIXME: this is bad
-            for (i, run) in state.buffer().layout_runs().enumerate() {
-                if !cx.is_focused() {
-                    break;
-                }
+            // the text is drawn below the label
+            let label_height = self.label_height(&state.style);
+            let text_rect = Rect::new(Point::new(0.0, label_height), cx.rect().max);
 
-                if let Some((start, end)) = state.editor.selection_bounds() {
-                    if let Some((start, width)) = run.highlight(start, end) {
-                        let min =
-                            Point::new(cx.rect().min.x + start, cx.rect().min.y + run.line_top);
-                        let size =
-                            Size::new(width, state.style.font_size * state.style.line_height);
+            state.scroll_to_cursor(text_rect.size());
 
-                        let highlight = Rect::min_size(min, size);
+            /* draw the label */
+            if self.label.is_some() {
+                let t = state.label_float.get();
+                let scale = 1.0 + (LABEL_SCALE - 1.0) * t;
 
-                        cx.fill_rect(highlight, state.style.color.fade(0.2));
-                    }
-                }
+                // the label rests where the text is, and floats to the top of the input
+                let translation = Vector::new(0.0, label_height * (1.0 - t));
+                let transform = Affine::translate(translation) * Affine::scale(Vector::all(scale));
 
-                if i == cursor.line {
-                    let size = Size::new(1.0, state.style.font_size * state.style.line_height);
+                cx.transformed(transform, |cx| {
+                    cx.text(&state.label, state.style.label_color, Vector::ZERO);
+                });
+            }
 
-                    let min = match run.glyphs.get(cursor.index) {
-                        Some(glyph) => {
-                            let physical = glyph.physical((cx.rect().min.x, cx.rect().min.y), 1.0);
-                            Point::new(physical.x as f32, run.line_top + physical.y as f32)
+            // the placeholder is replaced by the label, unless the label is floating
+            let show_placeholder = self.label.is_none() || cx.is_focused();
+
+            // the text is clipped to the input, and shifted by the scroll
+            cx.masked(text_rect, |cx| {
+                cx.translated(text_rect.min.to_vector() - state.scroll, |cx| {
+                    /* draw the highlights and the cursor */
+                    // FIXME: this is bad
+                    for run in state.buffer().layout_runs() {
+                        if !cx.is_focused() {
+                            break;
                         }
-                        None if cursor.index == 0 => {
-                            Point::new(cx.rect().min.x, cx.rect().min.y + run.line_top)
-                        }
-                        None => {
-                            Point::new(cx.rect().min.x + run.line_w, cx.rect().min.y + run.line_top)
+
+                        if let Some((start, end)) = state.editor.selection_bounds() {
+                            if let Some((start, width)) = run.highlight(start, end) {
+                                let min = Point::new(start, run.line_top);
+                                let size = Size::new(
+                                    width,
+                                    state.style.font_size * state.style.line_height,
+                                );
+
+                                let highlight = Rect::min_size(min, size);
+
+                                cx.fill_rect(highlight, state.style.color.fade(0.2));
+                            }
                         }
-                    };
+                    }
 
-                    let cursor = Rect::min_size(min.round(), size);
+                    if let Some(cursor) = state.cursor_rect().filter(|_| cx.is_focused()) {
+                        let cursor = Rect::min_size(cursor.min.round(), cursor.size());
 
-                    let blink = state.blink.cos() * 0.5 + 0.5;
-                    cx.fill_rect(cursor, state.style.color.fade(blink));
-                }
-            }
+                        let blink = match state.style.blink_rate > 0.0 {
+                            true => state.blink.cos() * 0.5 + 0.5,
+                            false => 1.0,
+                        };
 
-            /* draw the text */
-            if !state.text().is_empty() {
-                cx.text_raw(state.buffer(), state.style.color, Vector::ZERO)
-            } else {
-                cx.text(
-                    &state.placeholder,
-                    state.style.placeholder_color,
-                    Vector::ZERO,
-                )
-            };
+                        cx.fill_rect(cursor, state.style.color.fade(blink));
+                    }
+
+                    /* draw the text */
+                    if !state.text().is_empty() {
+                        cx.text_raw(state.buffer(), state.style.color, Vector::ZERO)
+                    } else if show_placeholder {
+                        cx.text(
+                            &state.placeholder,
+                            state.style.placeholder_color,
+                            Vector::ZERO,
+                        )
+                    };
+                });
+            });
         });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Event, Key, KeyPressed, Modifiers},
+        views::testing::ViewTester,
+    };
+
+    use super::*;
+
+    fn key(key: Key, text: Option<&str>, modifiers: Modifiers) -> Event {
+        Event::KeyPressed(KeyPressed {
+            key,
+            code: None,
+            text: text.map(String::from),
+            modifiers,
+        })
+    }
+
+    fn type_text(text: &str) -> Event {
+        let c = text.chars().next().unwrap();
+        key(Key::Character(c), Some(text), Modifiers::default())
+    }
+
+    #[test]
+    fn undo_redo() {
+        let mut view = text_input().on_input(|_, data: &mut String, text| *data = text);
+        let mut data = String::new();
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.view_state.set_focused(true);
+
+        let ctrl = Modifiers {
+            ctrl: true,
+            ..Default::default()
+        };
+        let ctrl_shift = Modifiers {
+            shift: true,
+            ..ctrl
+        };
+
+        // consecutive typing is coalesced, moving the cursor breaks it up
+        tester.event(&mut view, &mut data, &type_text("a"));
+        tester.event(&mut view, &mut data, &type_text("b"));
+        let left = key(Key::Left, None, Modifiers::default());
+        tester.event(&mut view, &mut data, &left);
+        tester.event(&mut view, &mut data, &type_text("c"));
+        assert_eq!(data, "acb");
+
+        let undo = key(Key::Character('z'), None, ctrl);
+        tester.event(&mut view, &mut data, &undo);
+        assert_eq!(data, "ab");
+
+        tester.event(&mut view, &mut data, &undo);
+        assert_eq!(data, "");
+
+        let redo = key(Key::Character('z'), None, ctrl_shift);
+        tester.event(&mut view, &mut data, &redo);
+        assert_eq!(data, "ab");
+
+        // the cursor is restored too, and editing clears the redo history
+        tester.event(&mut view, &mut data, &type_text("d"));
+        tester.event(&mut view, &mut data, &redo);
+        assert_eq!(data, "adb");
+    }
+
+    #[test]
+    fn home_end_select_all() {
+        let mut view = text_input().on_input(|_, data: &mut String, text| *data = text);
+        let mut data = String::new();
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.view_state.set_focused(true);
+
+        let ctrl = Modifiers {
+            ctrl: true,
+            ..Default::default()
+        };
+
+        tester.event(&mut view, &mut data, &type_text("b"));
+        tester.event(&mut view, &mut data, &type_text("c"));
+
+        let home = key(Key::Home, None, Modifiers::default());
+        tester.event(&mut view, &mut data, &home);
+        tester.event(&mut view, &mut data, &type_text("a"));
+        assert_eq!(data, "abc");
+
+        let end = key(Key::End, None, Modifiers::default());
+        tester.event(&mut view, &mut data, &end);
+        tester.event(&mut view, &mut data, &type_text("d"));
+        assert_eq!(data, "abcd");
+
+        // typing replaces the selection
+        let select_all = key(Key::Character('a'), None, ctrl);
+        tester.event(&mut view, &mut data, &select_all);
+        tester.event(&mut view, &mut data, &type_text("e"));
+        assert_eq!(data, "e");
+    }
+
+    #[test]
+    fn delete_forward() {
+        let mut view = text_input().on_input(|_, data: &mut String, text| *data = text);
+        let mut data = String::new();
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.view_state.set_focused(true);
+
+        tester.event(&mut view, &mut data, &type_text("a"));
+        tester.event(&mut view, &mut data, &type_text("é"));
+        tester.event(&mut view, &mut data, &type_text("b"));
+
+        // the character after the cursor is removed, even when it's multiple bytes
+        let home = key(Key::Home, None, Modifiers::default());
+        let right = key(Key::Right, None, Modifiers::default());
+        let delete = key(Key::Delete, None, Modifiers::default());
+        tester.event(&mut view, &mut data, &home);
+        tester.event(&mut view, &mut data, &right);
+        tester.event(&mut view, &mut data, &delete);
+        assert_eq!(data, "ab");
+
+        // deleting at the end does nothing
+        let end = key(Key::End, None, Modifiers::default());
+        tester.event(&mut view, &mut data, &end);
+        tester.event(&mut view, &mut data, &delete);
+        assert_eq!(data, "ab");
+
+        // the selection is removed instead, if there is one
+        let ctrl = Modifiers {
+            ctrl: true,
+            ..Default::default()
+        };
+        let select_all = key(Key::Character('a'), None, ctrl);
+        tester.event(&mut view, &mut data, &select_all);
+        tester.event(&mut view, &mut data, &delete);
+        assert_eq!(data, "");
+    }
+
+    #[test]
+    fn static_when_unfocused() {
+        let mut view = text_input::<()>();
+        let mut data = ();
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.view_state.mark_animated();
+        tester.view_state.mark_drawn();
+
+        // an unfocused input doesn't blink, and shouldn't request any more frames
+        tester.event(&mut view, &mut data, &Event::Animate(0.016));
+        assert!(!tester.view_state.needs_animate());
+        assert!(!tester.view_state.needs_draw());
+
+        tester.view_state.set_focused(true);
+        tester.event(&mut view, &mut data, &Event::Animate(0.016));
+        assert!(tester.view_state.needs_animate());
+
+        // neither does a focused input with a solid cursor
+        let mut solid = text_input::<()>().blink_rate(0.0);
+        let mut tester = ViewTester::new(&mut solid, &mut data);
+        tester.view_state.set_focused(true);
+        tester.view_state.prev_flags = tester.view_state.flags();
+        tester.view_state.mark_animated();
+
+        tester.event(&mut solid, &mut data, &Event::Animate(0.016));
+        assert!(!tester.view_state.needs_animate());
+    }
+
+    #[test]
+    fn grapheme_clusters() {
+        let mut view = text_input().on_input(|_, data: &mut String, text| *data = text);
+        let mut data = String::new();
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.view_state.set_focused(true);
+
+        // a flag is two regional indicators, and the accent is a combining character
+        tester.event(
+            &mut view,
+            &mut data,
+            &type_text("a\u{1f1f3}\u{1f1f4}e\u{301}"),
+        );
+
+        // the cursor steps over whole clusters
+        let left = key(Key::Left, None, Modifiers::default());
+        tester.event(&mut view, &mut data, &left);
+        tester.event(&mut view, &mut data, &left);
+        tester.event(&mut view, &mut data, &type_text("b"));
+        assert_eq!(data, "ab\u{1f1f3}\u{1f1f4}e\u{301}");
+
+        // backspace deletes whole clusters
+        let end = key(Key::End, None, Modifiers::default());
+        let backspace = key(Key::Backspace, None, Modifiers::default());
+        tester.event(&mut view, &mut data, &end);
+        tester.event(&mut view, &mut data, &backspace);
+        assert_eq!(data, "ab\u{1f1f3}\u{1f1f4}");
+
+        tester.event(&mut view, &mut data, &backspace);
+        assert_eq!(data, "ab");
+    }
+}
@@ -5,7 +5,7 @@ use crate::{
     canvas::{BorderRadius, BorderWidth, Color},
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
     event::Event,
-    layout::{pt, Padding, Point, Rect, Size, Space, Vector},
+    layout::{pt, Anchor, Padding, Point, Rect, Side, Size, Space, Vector},
     rebuild::Rebuild,
     style::{Styled, Theme},
     text::{
@@ -15,6 +15,8 @@ use crate::{
     view::{Pod, State, View},
 };
 
+use super::Truncated;
+
 /// Create a new [`Tooltip`] view.
 pub fn tooltip<V>(view: V, text: impl Into<SmolStr>) -> Tooltip<V> {
     Tooltip::new(view, text)
@@ -33,6 +35,11 @@ pub struct Tooltip<V> {
     #[rebuild(layout)]
     pub text: SmolStr,
 
+    /// Whether the tooltip is only displayed when the content is a truncated [`Text`](super::Text).
+    ///
+    /// This is useful for showing the full text of a [`TextOverflow::Ellipsis`](crate::text::TextOverflow::Ellipsis) label.
+    pub truncated_only: bool,
+
     /// The delay before the tooltip is displayed.
     #[styled(default = 0.2)]
     pub delay: Styled<f32>,
@@ -106,6 +113,7 @@ impl<V> Tooltip<V> {
         Self {
             content: Pod::new(content),
             text: text.into(),
+            truncated_only: false,
             delay: TooltipStyle::DELAY.into(),
             padding: TooltipStyle::PADDING.into(),
             font_size: TooltipStyle::FONT_SIZE.into(),
@@ -124,6 +132,12 @@ impl<V> Tooltip<V> {
         }
     }
 
+    /// Only display the tooltip when the content is a truncated [`Text`](super::Text).
+    pub fn truncated_only(mut self) -> Self {
+        self.truncated_only = true;
+        self
+    }
+
     fn set_attributes(&self, fonts: &mut Fonts, buffer: &mut TextBuffer, style: &TooltipStyle) {
         buffer.set_wrap(fonts, style.wrap);
         buffer.set_align(style.align);
@@ -254,7 +268,9 @@ impl<T, V: View<T>> View<T> for Tooltip<V> {
                 handled
             }
             Event::Animate(dt) => {
-                if content.has_hovered() && state.timer < 1.0 {
+                let visible = !self.truncated_only || content.contains_property::<Truncated>();
+
+                if content.has_hovered() && visible && state.timer < 1.0 {
                     state.timer += dt / state.style.delay;
                     cx.animate();
                 }
@@ -300,22 +316,17 @@ impl<T, V: View<T>> View<T> for Tooltip<V> {
             return;
         }
 
-        // we need to try to move the tooltip so it fits on the screen
+        // place the tooltip below the pointer, keeping it inside the window
         let window_rect = Rect::min_size(Point::ZERO, cx.window().size);
 
         let size = state.buffer.size() + state.style.padding.size();
-        let mut offset = Vector::new(-size.width / 2.0, 20.0);
-
-        let rect = Rect::min_size(state.position + offset, size);
-
-        let tl_delta = window_rect.top_left() - rect.top_left();
-        let br_delta = rect.bottom_right() - window_rect.bottom_right();
-
-        offset += Vector::max(tl_delta, Vector::ZERO);
-        offset -= Vector::max(br_delta, Vector::ZERO);
+        let pointer = Rect::center_size(state.position, Size::ZERO);
+        let placement = Anchor::new(Side::Bottom)
+            .gap(20.0)
+            .place(pointer, size, window_rect);
 
         cx.overlay(0, |cx| {
-            cx.translated(Vector::from(state.position + offset), |cx| {
+            cx.translated(Vector::from(placement.rect.min), |cx| {
                 cx.quad(
                     Rect::min_size(Point::ZERO, size),
                     state.style.background.fade(alpha),
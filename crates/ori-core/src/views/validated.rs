@@ -0,0 +1,304 @@
+use ori_macro::{Build, Styled};
+
+use crate::{
+    canvas::{BorderRadius, BorderWidth, Color},
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{pt, Size, Space, Vector},
+    rebuild::Rebuild,
+    style::{Styled, Theme},
+    text::{Fonts, TextAttributes, TextBuffer},
+    view::{Pod, State, View},
+    views::Class,
+};
+
+/// Create a new [`Validated`] view.
+pub fn validated<V>(validation: Validation, content: V) -> Validated<V> {
+    Validated::new(validation, content)
+}
+
+/// The validity of the value of a form field, see [`Validated`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Validation {
+    /// The value hasn't been validated, e.g. because it hasn't been edited yet.
+    #[default]
+    Unknown,
+
+    /// The value is valid.
+    Valid,
+
+    /// The value is invalid, with a message explaining why.
+    ///
+    /// The message is shown below the field, unless it's empty.
+    Invalid(String),
+}
+
+impl Validation {
+    /// The style class applied to the content of a [`Validated`] when valid.
+    pub const VALID_CLASS: &'static str = "valid";
+
+    /// The style class applied to the content of a [`Validated`] when invalid.
+    pub const INVALID_CLASS: &'static str = "invalid";
+
+    /// Create a new [`Validation::Invalid`] with a `message`.
+    pub fn invalid(message: impl Into<String>) -> Self {
+        Self::Invalid(message.into())
+    }
+
+    /// Create a [`Validation`] that is valid when `valid` is true, and otherwise invalid
+    /// with a `message`.
+    pub fn check(valid: bool, message: impl Into<String>) -> Self {
+        match valid {
+            true => Self::Valid,
+            false => Self::invalid(message),
+        }
+    }
+
+    /// Get whether the value is valid.
+    pub fn is_valid(&self) -> bool {
+        matches!(self, Self::Valid)
+    }
+
+    /// Get whether the value is invalid.
+    pub fn is_invalid(&self) -> bool {
+        matches!(self, Self::Invalid(_))
+    }
+
+    /// Get the message of an invalid value, if any.
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Self::Invalid(message) if !message.is_empty() => Some(message),
+            _ => None,
+        }
+    }
+
+    fn class(&self) -> &'static str {
+        match self {
+            Self::Unknown => "",
+            Self::Valid => Self::VALID_CLASS,
+            Self::Invalid(_) => Self::INVALID_CLASS,
+        }
+    }
+}
+
+/// A view that shows the validity of a form field, e.g. a [`TextInput`].
+///
+/// The border of the content is colored by the [`Validation`], and the message of an
+/// invalid value is shown below the content. The content is styled with the
+/// [`Validation::VALID_CLASS`] and [`Validation::INVALID_CLASS`] style classes, so the
+/// appearance of invalid fields can be defined centrally, see [`Class`].
+///
+/// Can be styled using the [`ValidatedStyle`].
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// fn ui(data: &mut Data) -> impl View<Data> {
+///     let validation = Validation::check(data.email.contains('@'), "Not an email address");
+///
+///     let input = text_input()
+///         .text(&data.email)
+///         .on_input(|cx, data: &mut Data, text| {
+///             data.email = text;
+///             cx.rebuild();
+///         });
+///
+///     validated(validation, input)
+/// }
+/// ```
+///
+/// [`TextInput`]: crate::views::TextInput
+#[derive(Styled, Build, Rebuild)]
+pub struct Validated<V> {
+    /// The content.
+    #[build(ignore)]
+    pub content: Pod<Class<V>>,
+
+    /// The validity of the content.
+    #[rebuild(layout)]
+    pub validation: Validation,
+
+    /// The color of the border and message when valid.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::SUCCESS or Color::GREEN)]
+    pub valid_color: Styled<Color>,
+
+    /// The color of the border and message when invalid.
+    #[rebuild(draw)]
+    #[styled(default -> Theme::DANGER or Color::RED)]
+    pub invalid_color: Styled<Color>,
+
+    /// The border width of the content, when validated.
+    #[rebuild(draw)]
+    #[styled(default = BorderWidth::all(1.0))]
+    pub border_width: Styled<BorderWidth>,
+
+    /// The border radius of the content.
+    #[rebuild(draw)]
+    #[styled(default)]
+    pub border_radius: Styled<BorderRadius>,
+
+    /// The font size of the message.
+    #[rebuild(layout)]
+    #[styled(default = pt(12.0))]
+    pub font_size: Styled<f32>,
+
+    /// The gap between the content and the message.
+    #[rebuild(layout)]
+    #[styled(default = 4.0)]
+    pub gap: Styled<f32>,
+}
+
+impl<V> Validated<V> {
+    /// Create a new [`Validated`] view.
+    pub fn new(validation: Validation, content: V) -> Self {
+        let class = validation.class();
+
+        Self {
+            content: Pod::new(Class::new(class, !class.is_empty(), content)),
+            validation,
+            valid_color: ValidatedStyle::VALID_COLOR.into(),
+            invalid_color: ValidatedStyle::INVALID_COLOR.into(),
+            border_width: ValidatedStyle::BORDER_WIDTH.into(),
+            border_radius: ValidatedStyle::BORDER_RADIUS.into(),
+            font_size: ValidatedStyle::FONT_SIZE.into(),
+            gap: ValidatedStyle::GAP.into(),
+        }
+    }
+
+    fn set_message(&self, fonts: &mut Fonts, state: &mut ValidatedState) {
+        let message = self.validation.message().unwrap_or_default();
+
+        state.message.set_metrics(fonts, state.style.font_size, 1.2);
+        (state.message).set_text(fonts, message, TextAttributes::default());
+    }
+
+    fn color(&self, style: &ValidatedStyle) -> Option<Color> {
+        match self.validation {
+            Validation::Unknown => None,
+            Validation::Valid => Some(style.valid_color),
+            Validation::Invalid(_) => Some(style.invalid_color),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct ValidatedState {
+    pub message: TextBuffer,
+    pub style: ValidatedStyle,
+}
+
+impl<T, V: View<T>> View<T> for Validated<V> {
+    type State = (ValidatedState, State<T, Class<V>>);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        let style = ValidatedStyle::styled(self, cx.styles());
+
+        let mut state = ValidatedState {
+            message: TextBuffer::new(cx.fonts(), style.font_size, 1.2),
+            style,
+        };
+
+        self.set_message(cx.fonts(), &mut state);
+
+        (state, self.content.build(cx, data))
+    }
+
+    fn rebuild(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        let font_size = state.style.font_size;
+
+        Rebuild::rebuild(self, cx, old);
+        state.style.rebuild(self, cx);
+
+        if self.validation != old.validation || state.style.font_size != font_size {
+            self.set_message(cx.fonts(), state);
+        }
+
+        self.content.rebuild(content, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        (_, content): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        self.content.event(content, cx, data, event)
+    }
+
+    fn layout(
+        &mut self,
+        (state, content): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        state.message.set_bounds(cx.fonts(), space.max);
+
+        let message = match self.validation.message() {
+            Some(_) => state.message.size() + Size::new(0.0, state.style.gap),
+            None => Size::ZERO,
+        };
+
+        let content_space = space.shrink(Size::new(0.0, message.height));
+        let mut size = self.content.layout(content, cx, data, content_space);
+
+        size.width = size.width.max(message.width);
+        size.height += message.height;
+
+        space.fit(size)
+    }
+
+    fn draw(&mut self, (state, content): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.content.draw(content, cx, data);
+
+        let Some(color) = self.color(&state.style) else {
+            return;
+        };
+
+        cx.quad(
+            content.rect(),
+            Color::TRANSPARENT,
+            state.style.border_radius,
+            state.style.border_width,
+            color,
+        );
+
+        if self.validation.message().is_some() {
+            let offset = Vector::new(0.0, content.size().height + state.style.gap);
+            cx.text(&state.message, color, offset);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::views::{size, testing::ViewTester};
+
+    use super::*;
+
+    #[test]
+    fn message_below_content() {
+        let mut view = validated(Validation::invalid("Required"), size(100.0, ()));
+        let mut tester = ViewTester::new(&mut view, &mut ());
+
+        let layout = tester.layout(&mut view, &mut (), Space::UNBOUNDED);
+        assert_eq!(layout.width, 100.0);
+        assert!(layout.height > 104.0);
+
+        // an invalid value without a message doesn't reserve space for it
+        let mut view = validated(Validation::invalid(""), size(100.0, ()));
+        let mut tester = ViewTester::new(&mut view, &mut ());
+
+        let layout = tester.layout(&mut view, &mut (), Space::UNBOUNDED);
+        assert_eq!(layout, Size::all(100.0));
+    }
+}
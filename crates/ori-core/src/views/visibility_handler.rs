@@ -0,0 +1,190 @@
+use crate::{
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::{Event, Visibility, VisibilityChanged},
+    layout::{Size, Space},
+    view::View,
+};
+
+/// Create a new [`VisibilityHandler`].
+pub fn on_visibility<T, V>(
+    content: V,
+    handler: impl FnMut(&mut EventCx, &mut T, &VisibilityChanged) + 'static,
+) -> VisibilityHandler<T, V> {
+    VisibilityHandler::new(content, handler)
+}
+
+/// A view that is notified when its content becomes visible or hidden.
+///
+/// The visibility is computed while drawing, by intersecting the content with the window and
+/// the masks it's drawn in, e.g. the viewport of a scroll view, see [`Visibility`]. The
+/// content is visible when the visible fraction is greater than zero, and at least
+/// [`VisibilityHandler::threshold`].
+///
+/// This is useful for lazily loading content, e.g. images in a long list, only once they
+/// are scrolled into view.
+///
+/// # Example
+/// ```ignore
+/// use ori::prelude::*;
+///
+/// fn row(index: usize) -> impl View<Data> {
+///     on_visibility(text!("Row {}", index), move |cx, data: &mut Data, e| {
+///         if e.visible {
+///             data.load(index);
+///             cx.rebuild();
+///         }
+///     })
+///     .threshold(0.5)
+/// }
+/// ```
+pub struct VisibilityHandler<T, V> {
+    /// The content.
+    pub content: V,
+
+    /// The fraction of the content that has to be visible, for it to be considered visible.
+    pub threshold: f32,
+
+    /// The callback for when the visibility changes.
+    #[allow(clippy::type_complexity)]
+    pub handler: Box<dyn FnMut(&mut EventCx, &mut T, &VisibilityChanged) + 'static>,
+}
+
+impl<T, V> VisibilityHandler<T, V> {
+    /// Create a new [`VisibilityHandler`].
+    pub fn new(
+        content: V,
+        handler: impl FnMut(&mut EventCx, &mut T, &VisibilityChanged) + 'static,
+    ) -> Self {
+        Self {
+            content,
+            threshold: 0.0,
+            handler: Box::new(handler),
+        }
+    }
+
+    /// Set the fraction of the content that has to be visible, for it to be considered visible.
+    pub fn threshold(mut self, threshold: f32) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    fn is_visible(&self, fraction: f32) -> bool {
+        fraction > 0.0 && fraction >= self.threshold
+    }
+}
+
+impl<T, V: View<T>> View<T> for VisibilityHandler<T, V> {
+    type State = (bool, V::State);
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        cx.insert_property(Visibility::default());
+
+        (false, self.content.build(cx, data))
+    }
+
+    fn rebuild(
+        &mut self,
+        (_, state): &mut Self::State,
+        cx: &mut RebuildCx,
+        data: &mut T,
+        old: &Self,
+    ) {
+        self.content.rebuild(state, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        (visible, state): &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        let handled = self.content.event(state, cx, data, event);
+
+        if let Event::Animate(_) = event {
+            let fraction = cx.get_property::<Visibility>().map_or(0.0, |v| v.fraction);
+
+            if self.is_visible(fraction) != *visible {
+                *visible = !*visible;
+
+                let changed = VisibilityChanged {
+                    visible: *visible,
+                    fraction,
+                };
+
+                (self.handler)(cx, data, &changed);
+            }
+        }
+
+        handled
+    }
+
+    fn layout(
+        &mut self,
+        (_, state): &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        self.content.layout(state, cx, data, space)
+    }
+
+    fn draw(&mut self, (_, state): &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        self.content.draw(state, cx, data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        event::{Modifiers, PointerId, PointerScrolled},
+        layout::{Point, Vector},
+        views::{size, testing::ViewTester, vscroll, vstack},
+    };
+
+    use super::*;
+
+    #[test]
+    fn scrolled_out_of_view() {
+        // the handler is nested in a stack, so the pod of the outer stack is culled first
+        let handler = on_visibility(size(100.0, ()), |_, data: &mut Vec<bool>, e| {
+            data.push(e.visible);
+        });
+
+        let mut view = vscroll(vstack![vstack![handler.threshold(0.5)], size(1000.0, ())]);
+        let mut data = Vec::new();
+
+        let mut tester = ViewTester::new(&mut view, &mut data);
+        tester.view_state.set_hovered(true);
+
+        let space = Space::new(Size::ZERO, Size::new(100.0, 200.0));
+        tester.layout(&mut view, &mut data, space);
+
+        // scroll the handler by `delta` lines, and draw the next frame
+        let mut scroll = |delta: f32, data: &mut Vec<bool>| {
+            let event = Event::PointerScrolled(PointerScrolled {
+                id: PointerId::from_u64(0),
+                position: Point::ZERO,
+                delta: Vector::new(0.0, -delta),
+                modifiers: Modifiers::default(),
+            });
+
+            tester.event(&mut view, data, &event);
+            tester.draw(&mut view, data);
+            tester.event(&mut view, data, &Event::Animate(0.0));
+        };
+
+        scroll(0.0, &mut data);
+        assert_eq!(data, [true]);
+
+        // still above the threshold
+        scroll(3.0, &mut data);
+        assert_eq!(data, [true]);
+
+        scroll(12.0, &mut data);
+        assert_eq!(data, [true, false]);
+
+        scroll(-15.0, &mut data);
+        assert_eq!(data, [true, false, true]);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{
+    context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
+    event::Event,
+    layout::{Size, Space},
+    view::{Pod, State, View},
+    window::Cursor,
+};
+
+/// Create a view that sets the cursor while its content is hovered.
+///
+/// # Example
+/// ```
+/// # use ori_core::{view::*, views::*, window::Cursor};
+/// pub fn ui<T>(_data: T) -> impl View<T> {
+///     with_cursor(Cursor::Pointer, text("Click me!"))
+/// }
+/// ```
+pub fn with_cursor<V>(cursor: Cursor, content: V) -> WithCursor<V> {
+    WithCursor::new(cursor, content)
+}
+
+/// A view that sets the cursor while its content is hovered.
+///
+/// When nested, the innermost hovered view with a cursor decides the cursor.
+pub struct WithCursor<V> {
+    /// The content view.
+    pub content: Pod<V>,
+
+    /// The cursor to set.
+    pub cursor: Cursor,
+}
+
+impl<V> WithCursor<V> {
+    /// Create a new [`WithCursor`] view.
+    pub fn new(cursor: Cursor, content: V) -> Self {
+        Self {
+            content: Pod::new(content),
+            cursor,
+        }
+    }
+}
+
+impl<T, V: View<T>> View<T> for WithCursor<V> {
+    type State = State<T, V>;
+
+    fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
+        self.content.build(cx, data)
+    }
+
+    fn rebuild(&mut self, state: &mut Self::State, cx: &mut RebuildCx, data: &mut T, old: &Self) {
+        self.content.rebuild(state, cx, data, &old.content);
+    }
+
+    fn event(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut EventCx,
+        data: &mut T,
+        event: &Event,
+    ) -> bool {
+        let handled = self.content.event(state, cx, data, event);
+
+        let cursor = cx.has_hovered().then_some(self.cursor);
+        if cx.view_state.cursor != cursor {
+            cx.set_cursor(cursor);
+        }
+
+        handled
+    }
+
+    fn layout(
+        &mut self,
+        state: &mut Self::State,
+        cx: &mut LayoutCx,
+        data: &mut T,
+        space: Space,
+    ) -> Size {
+        self.content.layout(state, cx, data, space)
+    }
+
+    fn draw(&mut self, state: &mut Self::State, cx: &mut DrawCx, data: &mut T) {
+        // make sure the content can be hovered, even if it doesn't draw anything hoverable
+        cx.trigger(cx.rect());
+
+        self.content.draw(state, cx, data);
+    }
+}
@@ -5,7 +5,7 @@ use ori_macro::{example, Build};
 use crate::{
     context::{BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
     event::Event,
-    layout::{Align, Axis, Justify, Size, Space},
+    layout::{Align, Axis, Direction, Justify, Size, Space},
     rebuild::Rebuild,
     view::{AnyView, PodSeq, SeqState, View, ViewSeq},
 };
@@ -186,14 +186,16 @@ pub struct WrapState {
     majors: Vec<f32>,
     runs: Vec<Range<usize>>,
     run_minors: Vec<f32>,
+    direction: Direction,
 }
 
 impl WrapState {
-    fn new(len: usize) -> Self {
+    fn new(len: usize, direction: Direction) -> Self {
         Self {
             majors: vec![0.0; len],
             runs: Vec::new(),
             run_minors: Vec::new(),
+            direction,
         }
     }
 
@@ -211,7 +213,7 @@ impl<T, V: ViewSeq<T>> View<T> for Wrap<V> {
 
     fn build(&mut self, cx: &mut BuildCx, data: &mut T) -> Self::State {
         (
-            WrapState::new(self.content.len()),
+            WrapState::new(self.content.len(), cx.direction()),
             self.content.build(cx, data),
         )
     }
@@ -230,6 +232,12 @@ impl<T, V: ViewSeq<T>> View<T> for Wrap<V> {
             cx.layout();
         }
 
+        // horizontal runs are mirrored when the direction changes
+        if state.direction != cx.direction() {
+            state.direction = cx.direction();
+            cx.layout();
+        }
+
         (self.content).rebuild(content, &mut cx.as_build_cx(), data, &old.content);
 
         for i in 0..self.content.len() {
@@ -301,6 +309,12 @@ impl<T, V: ViewSeq<T>> View<T> for Wrap<V> {
         let major = f32::clamp(major, min_major, max_major);
         let minor = f32::clamp(state.minor() + total_minor_gap, min_minor, max_minor);
 
+        // horizontal runs are laid out from the right in right-to-left interfaces
+        let direction = match self.axis {
+            Axis::Horizontal => state.direction,
+            Axis::Vertical => Direction::Ltr,
+        };
+
         for (i, run_position) in (self.justify_cross)
             .layout(&state.run_minors, minor, minor_gap)
             .enumerate()
@@ -312,6 +326,8 @@ impl<T, V: ViewSeq<T>> View<T> for Wrap<V> {
                 .layout(&state.majors[run.clone()], major, major_gap)
                 .zip(run)
             {
+                let child_major = self.axis.major(content[j].size());
+                let child_position = direction.mirror(child_position, child_major, major);
                 let child_minor = self.axis.minor(content[j].size());
                 let child_align = self.align.align(run_minor, child_minor);
                 let offset = self.axis.pack(child_position, run_position + child_align);
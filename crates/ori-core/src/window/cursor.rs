@@ -82,3 +82,21 @@ impl Cursor {
         }
     }
 }
+
+/// How the cursor is grabbed by a window.
+///
+/// On Wayland, grabbing the cursor requires the compositor to support the
+/// `zwp_pointer_constraints_v1` protocol.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CursorGrab {
+    /// The cursor is free to move.
+    #[default]
+    None,
+
+    /// The cursor is confined to the window.
+    Confined,
+
+    /// The cursor is locked in place, this is not supported on X11 where the cursor is
+    /// confined instead.
+    Locked,
+}
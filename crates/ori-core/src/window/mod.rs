@@ -1,9 +1,13 @@
 //! Windowing system abstraction layer.
 
 mod cursor;
+mod monitor;
 mod pointer;
+mod stats;
 mod window;
 
 pub use cursor::*;
+pub use monitor::*;
 pub use pointer::*;
+pub use stats::*;
 pub use window::*;
@@ -0,0 +1,156 @@
+use crate::layout::{Point, Rect};
+
+/// A stable identifier for a monitor.
+///
+/// The identifier is derived from the name of the monitor, e.g. the connector it's plugged
+/// into, so it stays the same between runs of the application and can be stored to
+/// remember where windows were placed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MonitorId {
+    hash: u64,
+}
+
+impl MonitorId {
+    /// Create a new [`MonitorId`] from the name of a monitor.
+    pub fn from_name(name: &str) -> Self {
+        // FNV-1a, since the hash has to be stable across builds and platforms
+        let mut hash = 0xcbf29ce484222325u64;
+
+        for byte in name.bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+
+        Self { hash }
+    }
+
+    /// Create a new [`MonitorId`] from a [`u64`], see [`MonitorId::as_u64`].
+    pub fn from_u64(hash: u64) -> Self {
+        Self { hash }
+    }
+
+    /// Get the [`MonitorId`] as a [`u64`].
+    pub fn as_u64(self) -> u64 {
+        self.hash
+    }
+}
+
+/// A monitor connected to the system.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Monitor {
+    /// The identifier of the monitor.
+    pub id: MonitorId,
+
+    /// The name of the monitor, e.g. `HDMI-A-1`.
+    pub name: String,
+
+    /// The bounds of the monitor in the virtual desktop, in logical pixels.
+    pub bounds: Rect,
+
+    /// The part of the bounds available to windows, excluding e.g. panels and docks.
+    ///
+    /// This is the same as the bounds if the platform doesn't report it.
+    pub work_area: Rect,
+
+    /// The scale factor of the monitor.
+    pub scale: f32,
+
+    /// Whether the monitor is the primary monitor.
+    pub primary: bool,
+}
+
+/// The monitors connected to the system.
+///
+/// This is a context reported by the platform, and is empty until it's known, see
+/// [`BaseCx::monitors`](crate::context::BaseCx::monitors).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Monitors {
+    monitors: Vec<Monitor>,
+}
+
+impl Monitors {
+    /// Create a new [`Monitors`].
+    pub fn new(monitors: Vec<Monitor>) -> Self {
+        Self { monitors }
+    }
+
+    /// Get whether there are no known monitors.
+    pub fn is_empty(&self) -> bool {
+        self.monitors.is_empty()
+    }
+
+    /// Get the number of monitors.
+    pub fn len(&self) -> usize {
+        self.monitors.len()
+    }
+
+    /// Iterate over the monitors.
+    pub fn iter(&self) -> impl Iterator<Item = &Monitor> {
+        self.monitors.iter()
+    }
+
+    /// Get the monitor with `id`.
+    pub fn get(&self, id: MonitorId) -> Option<&Monitor> {
+        self.iter().find(|monitor| monitor.id == id)
+    }
+
+    /// Get the primary monitor, or the first monitor if none is marked as primary.
+    pub fn primary(&self) -> Option<&Monitor> {
+        (self.iter().find(|monitor| monitor.primary)).or(self.monitors.first())
+    }
+
+    /// Get the monitor containing `point` in the virtual desktop.
+    pub fn at(&self, point: Point) -> Option<&Monitor> {
+        self.iter().find(|monitor| monitor.bounds.contains(point))
+    }
+}
+
+impl<'a> IntoIterator for &'a Monitors {
+    type Item = &'a Monitor;
+    type IntoIter = std::slice::Iter<'a, Monitor>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.monitors.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layout::Size;
+
+    use super::*;
+
+    fn monitor(name: &str, x: f32, primary: bool) -> Monitor {
+        let bounds = Rect::min_size(Point::new(x, 0.0), Size::new(1920.0, 1080.0));
+
+        Monitor {
+            id: MonitorId::from_name(name),
+            name: name.into(),
+            bounds,
+            work_area: bounds,
+            scale: 1.0,
+            primary,
+        }
+    }
+
+    #[test]
+    fn find_monitors() {
+        let monitors = Monitors::new(vec![
+            monitor("DP-1", 0.0, false),
+            monitor("HDMI-A-1", 1920.0, true),
+        ]);
+
+        let id = MonitorId::from_name("HDMI-A-1");
+        assert_eq!(monitors.primary().map(|m| m.id), Some(id));
+        assert_eq!(
+            monitors.at(Point::new(2000.0, 10.0)).map(|m| m.id),
+            Some(id)
+        );
+        assert_eq!(monitors.get(id).map(|m| m.name.as_str()), Some("HDMI-A-1"));
+
+        // the id must be stable between builds
+        assert_eq!(MonitorId::from_name("").as_u64(), 0xcbf29ce484222325);
+        assert_ne!(id, MonitorId::from_name("DP-1"));
+    }
+}
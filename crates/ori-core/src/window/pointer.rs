@@ -9,6 +9,7 @@ use crate::{
 pub struct Pointer {
     id: PointerId,
     pressed: Vec<(PointerButton, Point)>,
+    dragging: bool,
 
     /// The position of the pointer.
     ///
@@ -27,6 +28,7 @@ impl Pointer {
         Self {
             id,
             pressed: Vec::new(),
+            dragging: false,
             position,
             hovering: None,
         }
@@ -52,16 +54,47 @@ impl Pointer {
         }
     }
 
-    /// Release a button, returning whether the button was clicked.
+    /// Get the position the pointer was at when `button` was pressed.
+    pub fn press_position(&self, button: PointerButton) -> Option<Point> {
+        let (_, position) = self.pressed.iter().find(|(b, _)| b == &button)?;
+        Some(*position)
+    }
+
+    /// Get whether the pointer is dragging.
+    ///
+    /// A pointer starts dragging once it has moved farther than the drag threshold from where
+    /// any of its buttons were pressed, see [`Window::drag_threshold`](super::Window::drag_threshold),
+    /// and keeps dragging until all its buttons are released, even if it moves back.
+    pub fn is_dragging(&self) -> bool {
+        self.dragging
+    }
+
+    /// Start dragging if the pointer has moved farther than `threshold` from where any of its
+    /// buttons were pressed.
+    ///
+    /// This is rarely what you want to do, do not use this unless you
+    /// really know what you are doing.
+    pub fn update_dragging(&mut self, threshold: f32) {
+        let position = self.position;
+        let exceeded = (self.pressed.iter()).any(|(_, p)| p.distance(position) > threshold);
+        self.dragging |= exceeded;
+    }
+
+    /// Release a button, returning whether the button was clicked, that is whether the
+    /// pointer moved less than `threshold` since the button was pressed.
     ///
     /// This is rarely what you want to do, do not use this unless you
     /// really know what you are doing.
-    pub fn release(&mut self, button: PointerButton) -> bool {
+    pub fn release(&mut self, button: PointerButton, threshold: f32) -> bool {
         match self.pressed.iter().position(|(b, _)| b == &button) {
             Some(index) => {
                 let (_, position) = self.pressed.remove(index);
-                let distance = position.distance(self.position);
-                distance < 10.0
+
+                if self.pressed.is_empty() {
+                    self.dragging = false;
+                }
+
+                position.distance(self.position) < threshold
             }
             None => false,
         }
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+/// The reason a window was redrawn.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RedrawReason {
+    /// The window was redrawn because the platform requested it, for example after
+    /// being resized or exposed.
+    #[default]
+    Requested,
+
+    /// A view requested to be drawn.
+    Draw,
+
+    /// A view requested to be laid out.
+    Layout,
+
+    /// A view requested an animation frame.
+    Animate,
+}
+
+/// Timing statistics of the last frame of a window.
+///
+/// The statistics are updated by the application every time the window is drawn, and
+/// can be read with [`Window::frame_stats`](super::Window::frame_stats), for example to
+/// display a frame time counter.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameStats {
+    /// The number of frames drawn.
+    pub frames: u64,
+
+    /// The CPU time spent animating, laying out and drawing the last frame.
+    pub frame_time: Duration,
+
+    /// The time spent rendering the last frame, if reported by the platform.
+    pub render_time: Duration,
+
+    /// The time between the start of the last two frames.
+    pub frame_interval: Duration,
+
+    /// The number of primitives drawn in the last frame.
+    pub primitives: usize,
+
+    /// The reason the last frame was drawn.
+    pub reason: RedrawReason,
+}
+
+impl FrameStats {
+    /// Get the frames per second, computed from [`Self::frame_interval`].
+    pub fn fps(&self) -> f32 {
+        match self.frame_interval.is_zero() {
+            true => 0.0,
+            false => 1.0 / self.frame_interval.as_secs_f32(),
+        }
+    }
+}
@@ -11,7 +11,11 @@ use crate::{
     view::ViewId,
 };
 
-use super::{Cursor, Pointer};
+use raw_window_handle::{
+    DisplayHandle, HandleError, RawDisplayHandle, RawWindowHandle, WindowHandle,
+};
+
+use super::{Cursor, CursorGrab, FrameStats, Pointer};
 
 /// A unique identifier for a window.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -49,6 +53,13 @@ pub enum WindowSizing {
     /// The root [`View`](crate::view::View) will have [`Space::UNBOUNDED`](crate::layout::Space), and the window will
     /// resize to fit the content.
     Content,
+
+    /// The window will resize to fit the content once, after the first layout, and then
+    /// behave like [`WindowSizing::Fixed`], so it can be resized by the user.
+    ///
+    /// The content is laid out with [`Window::size`] as the maximum size, which is useful for
+    /// small dialogs and popups.
+    FitContent,
 }
 
 impl Default for WindowSizing {
@@ -62,6 +73,8 @@ impl Default for WindowSizing {
 pub struct Window {
     id: WindowId,
     pointers: Vec<Pointer>,
+    frame_stats: FrameStats,
+    raw_handles: Option<RawHandles>,
 
     /// The title of the window.
     pub title: String,
@@ -69,6 +82,13 @@ pub struct Window {
     /// The icon of the window.
     pub icon: Option<Image>,
 
+    /// The application id of the window.
+    ///
+    /// This is used by Wayland compositors to match the window with the `.desktop` file
+    /// of the application, which provides the icon of the window. When `None`, the name of
+    /// the executable is used.
+    pub app_id: Option<String>,
+
     /// The size of the window.
     pub size: Size,
 
@@ -95,8 +115,40 @@ pub struct Window {
 
     /// The color of the window.
     pub color: Option<Color>,
+
+    /// Whether the cursor is visible when over the window.
+    pub cursor_visible: bool,
+
+    /// How the cursor is grabbed by the window.
+    pub cursor_grab: CursorGrab,
+
+    /// The distance a pointer has to move while pressed before it starts a drag.
+    ///
+    /// Releasing a pointer that moved less than this counts as a click, and views like
+    /// sliders, scrollbars and reorderable lists only start dragging once it's exceeded.
+    pub drag_threshold: f32,
+
+    /// Whether focus rings are only shown after focus was moved with the keyboard.
+    ///
+    /// This follows the convention of most platforms, where focus rings are hidden when
+    /// a view is focused by clicking it, see [`Window::is_focus_visible`].
+    pub keyboard_focus_ring: bool,
+
+    keyboard_navigation: bool,
 }
 
+#[derive(Clone, Copy, Debug)]
+struct RawHandles {
+    window: RawWindowHandle,
+    display: RawDisplayHandle,
+}
+
+// SAFETY: the handles are never dereferenced by ori, they're only handed out by the unsafe
+// `Window::window_handle` and `Window::display_handle`, whose callers are responsible for
+// using them on the thread and during the lifetime required by the platform
+unsafe impl Send for RawHandles {}
+unsafe impl Sync for RawHandles {}
+
 impl Default for Window {
     fn default() -> Self {
         Self::new()
@@ -109,8 +161,11 @@ impl Window {
         Self {
             id: WindowId::new(),
             pointers: Vec::new(),
+            frame_stats: FrameStats::default(),
+            raw_handles: None,
             title: String::from("Ori window"),
             icon: None,
+            app_id: None,
             size: Size::new(800.0, 600.0),
             sizing: WindowSizing::Fixed,
             scale: 1.0,
@@ -119,6 +174,11 @@ impl Window {
             maximized: false,
             visible: true,
             color: None,
+            cursor_visible: true,
+            cursor_grab: CursorGrab::None,
+            drag_threshold: 10.0,
+            keyboard_focus_ring: false,
+            keyboard_navigation: false,
         }
     }
 
@@ -127,6 +187,48 @@ impl Window {
         self.id
     }
 
+    /// Set the raw handles of the native window.
+    ///
+    /// This is called by the platform when the native window is created.
+    ///
+    /// # Safety
+    /// The handles must stay valid until the window is closed.
+    pub unsafe fn set_raw_handles(&mut self, window: RawWindowHandle, display: RawDisplayHandle) {
+        self.raw_handles = Some(RawHandles { window, display });
+    }
+
+    /// Get the handle of the native window, for embedding e.g. a third-party renderer or a
+    /// platform control.
+    ///
+    /// This is only available once the platform has created the native window, which is
+    /// currently done by the X11 and Wayland platforms, otherwise [`HandleError::Unavailable`]
+    /// is returned.
+    ///
+    /// # Safety
+    /// The [`Window`] available to views and delegates is a copy of the state of the native
+    /// window, which may be closed while the copy is still alive. The handle must only be
+    /// used while the native window is open, e.g. within the callback it was obtained in,
+    /// and only on the threads the platform allows.
+    pub unsafe fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        match self.raw_handles {
+            // SAFETY: the caller guarantees the native window is still open
+            Some(handles) => Ok(unsafe { WindowHandle::borrow_raw(handles.window) }),
+            None => Err(HandleError::Unavailable),
+        }
+    }
+
+    /// Get the handle of the display connection the window belongs to.
+    ///
+    /// # Safety
+    /// The same requirements as for [`Window::window_handle`] apply.
+    pub unsafe fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        match self.raw_handles {
+            // SAFETY: the caller guarantees the native window is still open
+            Some(handles) => Ok(unsafe { DisplayHandle::borrow_raw(handles.display) }),
+            None => Err(HandleError::Unavailable),
+        }
+    }
+
     /// Set the title of the window.
     pub fn title(mut self, title: impl ToString) -> Self {
         self.title = title.to_string();
@@ -134,11 +236,50 @@ impl Window {
     }
 
     /// Set the icon of the window.
+    ///
+    /// Wayland doesn't support setting the icon of a window, instead the compositor uses the
+    /// icon of the `.desktop` file matching the app id of the window.
     pub fn icon(mut self, icon: impl Into<Option<Image>>) -> Self {
         self.icon = icon.into();
         self
     }
 
+    /// Set the application id of the window, see [`Window::app_id`].
+    pub fn app_id(mut self, app_id: impl ToString) -> Self {
+        self.app_id = Some(app_id.to_string());
+        self
+    }
+
+    /// Load the icon of the window from an image file at `path`, see [`Window::icon`].
+    ///
+    /// If the image fails to load, an error is logged and the icon is left unchanged.
+    #[cfg(feature = "image")]
+    pub fn load_icon(mut self, path: impl AsRef<std::path::Path>) -> Self {
+        match Image::try_load(path.as_ref()) {
+            Ok(icon) => self.icon = Some(icon),
+            Err(err) => {
+                let path = path.as_ref().display();
+                tracing::error!("Failed to load window icon: {}: {}", path, err);
+            }
+        }
+
+        self
+    }
+
+    /// Load the icon of the window from encoded image `data`, eg. the contents of a PNG
+    /// file, see [`Window::icon`].
+    ///
+    /// If the image fails to load, an error is logged and the icon is left unchanged.
+    #[cfg(feature = "image")]
+    pub fn load_icon_data(mut self, data: impl Into<Vec<u8>>) -> Self {
+        match Image::try_load_data(data.into()) {
+            Ok(icon) => self.icon = Some(icon),
+            Err(err) => tracing::error!("Failed to load window icon data: {}", err),
+        }
+
+        self
+    }
+
     /// Set the size of the window.
     pub fn size(mut self, width: u32, height: u32) -> Self {
         self.size = Size::new(width as f32, height as f32);
@@ -158,6 +299,14 @@ impl Window {
         self
     }
 
+    /// Set the sizing to [`WindowSizing::FitContent`], fitting the content once within
+    /// `max_width` and `max_height`.
+    pub fn fit_content_once(mut self, max_width: u32, max_height: u32) -> Self {
+        self.sizing = WindowSizing::FitContent;
+        self.size = Size::new(max_width as f32, max_height as f32);
+        self
+    }
+
     /// Set the scale of the window.
     pub fn scale(mut self, scale: f32) -> Self {
         self.scale = scale;
@@ -194,6 +343,30 @@ impl Window {
         self
     }
 
+    /// Set whether the cursor is visible when over the window.
+    pub fn cursor_visible(mut self, visible: bool) -> Self {
+        self.cursor_visible = visible;
+        self
+    }
+
+    /// Set how the cursor is grabbed by the window, see [`CursorGrab`].
+    pub fn cursor_grab(mut self, grab: CursorGrab) -> Self {
+        self.cursor_grab = grab;
+        self
+    }
+
+    /// Set the distance a pointer has to move while pressed before it starts a drag.
+    pub fn drag_threshold(mut self, threshold: f32) -> Self {
+        self.drag_threshold = threshold;
+        self
+    }
+
+    /// Set whether focus rings are only shown after focus was moved with the keyboard.
+    pub fn keyboard_focus_ring(mut self, keyboard_focus_ring: bool) -> Self {
+        self.keyboard_focus_ring = keyboard_focus_ring;
+        self
+    }
+
     /// Get the size of the window in physical pixels.
     ///
     /// This is a shorthand for `self.size * self.scale`.
@@ -221,6 +394,18 @@ impl Window {
         &mut self.pointers
     }
 
+    /// Get the timing statistics of the last frame.
+    pub fn frame_stats(&self) -> &FrameStats {
+        &self.frame_stats
+    }
+
+    /// Get the timing statistics of the last frame mutably.
+    ///
+    /// You probably don't want to set this directly.
+    pub fn frame_stats_mut(&mut self) -> &mut FrameStats {
+        &mut self.frame_stats
+    }
+
     /// Get whether a specific view is hovered.
     pub fn is_hovered(&self, view_id: ViewId) -> bool {
         (self.pointers.iter()).any(|pointer| pointer.hovering == Some(view_id))
@@ -259,18 +444,47 @@ impl Window {
     /// This is rarely what you want to do, do not use this unless you
     /// really know what you are doing.
     pub fn release_pointer(&mut self, pointer_id: PointerId, button: PointerButton) -> bool {
+        let threshold = self.drag_threshold;
+
         match self.get_pointer_mut(pointer_id) {
-            Some(pointer) => pointer.release(button),
+            Some(pointer) => pointer.release(button, threshold),
             None => false,
         }
     }
 
+    /// Get whether the pointer with `pointer_id` is dragging, that is whether it has moved
+    /// farther than [`Window::drag_threshold`] since one of its buttons was pressed.
+    pub fn is_dragging(&self, pointer_id: PointerId) -> bool {
+        match self.get_pointer(pointer_id) {
+            Some(pointer) => pointer.is_dragging(),
+            None => false,
+        }
+    }
+
+    /// Set whether focus was last moved with the keyboard, returning whether it changed.
+    ///
+    /// This is rarely what you want to do, do not use this unless you
+    /// really know what you are doing.
+    pub fn set_keyboard_navigation(&mut self, keyboard_navigation: bool) -> bool {
+        let changed = self.keyboard_navigation != keyboard_navigation;
+        self.keyboard_navigation = keyboard_navigation;
+        changed
+    }
+
+    /// Get whether focus rings should be drawn, see [`Window::keyboard_focus_ring`].
+    pub fn is_focus_visible(&self) -> bool {
+        !self.keyboard_focus_ring || self.keyboard_navigation
+    }
+
     /// Move a pointer, returning the movement.
     pub fn move_pointer(&mut self, pointer_id: PointerId, position: Point) -> Vector {
+        let threshold = self.drag_threshold;
+
         match self.get_pointer_mut(pointer_id) {
             Some(pointer) => {
                 let delta = position - pointer.position;
                 pointer.position = position;
+                pointer.update_dragging(threshold);
 
                 delta
             }
@@ -302,6 +516,8 @@ impl Window {
             WindowUpdate::Maximized(self.maximized),
             WindowUpdate::Visible(self.visible),
             WindowUpdate::Color(self.color),
+            WindowUpdate::CursorVisible(self.cursor_visible),
+            WindowUpdate::CursorGrab(self.cursor_grab),
         ]
     }
 
@@ -317,6 +533,8 @@ impl Window {
             maximized: self.maximized,
             visible: self.visible,
             color: self.color,
+            cursor_visible: self.cursor_visible,
+            cursor_grab: self.cursor_grab,
         }
     }
 }
@@ -354,6 +572,12 @@ pub enum WindowUpdate {
     /// Set the cursor of the window.
     Cursor(Cursor),
 
+    /// Set whether the cursor is visible.
+    CursorVisible(bool),
+
+    /// Set how the cursor is grabbed.
+    CursorGrab(CursorGrab),
+
     /// Set the ime state of the window.
     Ime(Option<Ime>),
 }
@@ -387,6 +611,12 @@ pub struct WindowSnapshot {
 
     /// The color of the window.
     pub color: Option<Color>,
+
+    /// Whether the cursor is visible.
+    pub cursor_visible: bool,
+
+    /// How the cursor is grabbed.
+    pub cursor_grab: CursorGrab,
 }
 
 impl WindowSnapshot {
@@ -430,6 +660,41 @@ impl WindowSnapshot {
             updates.push(WindowUpdate::Color(window.color));
         }
 
+        if self.cursor_visible != window.cursor_visible {
+            updates.push(WindowUpdate::CursorVisible(window.cursor_visible));
+        }
+
+        if self.cursor_grab != window.cursor_grab {
+            updates.push(WindowUpdate::CursorGrab(window.cursor_grab));
+        }
+
         updates
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dragging_is_latched() {
+        let mut window = Window::new();
+        let id = PointerId::from_hash(&0);
+
+        window.move_pointer(id, Point::ZERO);
+        window.press_pointer(id, PointerButton::Primary);
+
+        window.move_pointer(id, Point::new(5.0, 0.0));
+        assert!(!window.is_dragging(id));
+
+        window.move_pointer(id, Point::new(20.0, 0.0));
+        assert!(window.is_dragging(id));
+
+        // moving back within the threshold keeps dragging
+        window.move_pointer(id, Point::new(5.0, 0.0));
+        assert!(window.is_dragging(id));
+
+        assert!(window.release_pointer(id, PointerButton::Primary));
+        assert!(!window.is_dragging(id));
+    }
+}
@@ -0,0 +1,29 @@
+#version 300 es
+precision highp float;
+
+in vec2 v_uv;
+
+out vec4 f_color;
+
+uniform sampler2D source;
+
+// the size of a texel, along the direction of the blur
+uniform vec2 direction;
+uniform float sigma;
+
+void main() {
+    int radius = int(ceil(sigma * 3.0));
+
+    vec4 color = vec4(0.0);
+    float total = 0.0;
+
+    for (int i = -radius; i <= radius; i++) {
+        float x = float(i);
+        float weight = exp(-(x * x) / (2.0 * sigma * sigma));
+
+        color += texture(source, v_uv + direction * x) * weight;
+        total += weight;
+    }
+
+    f_color = color / total;
+}
@@ -2,7 +2,7 @@
 
 //! Glow renderer for Ori.
 
-use std::{collections::HashMap, ffi, mem, slice};
+use std::{collections::HashMap, ffi, mem, slice, sync::Arc};
 
 use glow::HasContext;
 use ori_core::{
@@ -10,7 +10,8 @@ use ori_core::{
         AntiAlias, Canvas, Color, Curve, CurveSegment, FillRule, Paint, Primitive, Shader, Stroke,
     },
     image::{ImageData, WeakImage},
-    layout::{Affine, Matrix, Point, Vector},
+    layout::{Affine, Matrix, Point, Rect, Vector},
+    log::{trace, trace_span},
 };
 
 /// OpenGL error.
@@ -26,6 +27,22 @@ impl From<String> for GlError {
     }
 }
 
+/// Statistics about the uploads and draw calls of a frame, see [`GlowRenderer::stats`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RenderStats {
+    /// The number of batches drawn, each being a draw call.
+    pub batches: usize,
+
+    /// The number of instances drawn, one for every filled curve.
+    pub instances: usize,
+
+    /// The number of bytes uploaded to the point, band and instance buffers.
+    pub uploaded_bytes: usize,
+
+    /// The number of times the point and band buffers were reallocated to grow.
+    pub reallocations: usize,
+}
+
 #[repr(C)]
 #[derive(Debug)]
 struct Instance {
@@ -44,11 +61,31 @@ const VERB_QUAD: u8 = 2;
 const VERB_CUBIC: u8 = 3;
 
 const NON_ZERO_BIT: u32 = 1 << 31;
+const BACKDROP_BIT: u32 = 1 << 30;
+const SOLID_BIT: u32 = 1 << 29;
 
 unsafe fn slice_as_bytes<T>(slice: &[T]) -> &[u8] {
     slice::from_raw_parts(slice.as_ptr() as *const u8, mem::size_of_val(slice))
 }
 
+// premultiply the alpha of rgba8 pixels
+//
+// textures are stored premultiplied, so that filtering doesn't blend in the color of
+// transparent pixels, which shows as dark fringes around the edges of images and glyphs
+fn premultiply(pixels: &[u8]) -> Vec<u8> {
+    let mut premultiplied = pixels.to_vec();
+
+    for pixel in premultiplied.chunks_exact_mut(4) {
+        let alpha = pixel[3] as u16;
+
+        for channel in &mut pixel[..3] {
+            *channel = ((*channel as u16 * alpha + 127) / 255) as u8;
+        }
+    }
+
+    premultiplied
+}
+
 struct Mask {
     texture: glow::Texture,
     framebuffer: glow::Framebuffer,
@@ -99,25 +136,102 @@ impl Mask {
     }
 }
 
+// two color targets the size of the window, a backdrop is blurred back and forth between them
+struct Backdrop {
+    textures: [glow::Texture; 2],
+    framebuffers: [glow::Framebuffer; 2],
+}
+
+impl Backdrop {
+    unsafe fn new(gl: &glow::Context, width: u32, height: u32) -> Self {
+        let textures = [(); 2].map(|_| {
+            let texture = gl.create_texture().unwrap();
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                glow::RGBA8 as i32,
+                width as i32,
+                height as i32,
+                0,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                None,
+            );
+
+            let parameters = [
+                (glow::TEXTURE_MIN_FILTER, glow::NEAREST),
+                (glow::TEXTURE_MAG_FILTER, glow::NEAREST),
+                (glow::TEXTURE_WRAP_S, glow::CLAMP_TO_EDGE),
+                (glow::TEXTURE_WRAP_T, glow::CLAMP_TO_EDGE),
+            ];
+
+            for (parameter, value) in parameters {
+                gl.tex_parameter_i32(glow::TEXTURE_2D, parameter, value as i32);
+            }
+
+            texture
+        });
+
+        let framebuffers = textures.map(|texture| {
+            let framebuffer = gl.create_framebuffer().unwrap();
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+            gl.framebuffer_texture_2d(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::TEXTURE_2D,
+                Some(texture),
+                0,
+            );
+
+            framebuffer
+        });
+
+        gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+
+        Self {
+            textures,
+            framebuffers,
+        }
+    }
+
+    unsafe fn delete(self, gl: &glow::Context) {
+        for texture in self.textures {
+            gl.delete_texture(texture);
+        }
+
+        for framebuffer in self.framebuffers {
+            gl.delete_framebuffer(framebuffer);
+        }
+    }
+}
+
 /// A glow renderer.
 pub struct GlowRenderer {
     gl: glow::Context,
     program: glow::Program,
+    blur_program: glow::Program,
+    blur_sigma: Option<glow::UniformLocation>,
+    blur_direction: Option<glow::UniformLocation>,
     width: u32,
     height: u32,
     points: Vec<[f32; 2]>,
     bands: Vec<Vec<[u32; 2]>>,
     band_data: Vec<[u32; 2]>,
+    band_cache: HashMap<usize, (u32, u32)>,
     instances: Vec<Instance>,
     point_buffer: glow::Texture,
     band_buffer: glow::Texture,
     point_buffer_height: usize,
     band_buffer_height: usize,
     instance_buffer: glow::Buffer,
+    batch_size: usize,
+    stats: RenderStats,
     vertex_array: glow::VertexArray,
     images: HashMap<WeakImage, glow::Texture>,
     masks: Vec<Mask>,
     mask: Option<usize>,
+    backdrop: Option<Backdrop>,
     default_image: glow::Texture,
     active_image: Option<glow::Texture>,
     scratch_curve: Curve,
@@ -127,6 +241,7 @@ impl Drop for GlowRenderer {
     fn drop(&mut self) {
         unsafe {
             self.gl.delete_program(self.program);
+            self.gl.delete_program(self.blur_program);
             self.gl.delete_texture(self.point_buffer);
             self.gl.delete_texture(self.band_buffer);
             self.gl.delete_buffer(self.instance_buffer);
@@ -137,6 +252,7 @@ impl Drop for GlowRenderer {
             }
 
             self.clear_masks();
+            self.clear_backdrop();
             self.gl.delete_texture(self.default_image);
         }
     }
@@ -144,29 +260,42 @@ impl Drop for GlowRenderer {
 
 impl GlowRenderer {
     const TEXTURE_BUFFER_WIDTH: usize = 256;
-    const MAX_INSTANCES: usize = 256;
+    /// The default number of instances in a batch, see [`GlowRenderer::set_batch_size`].
+    pub const DEFAULT_BATCH_SIZE: usize = 256;
+
     const MAX_BANDS: usize = 256;
 
+    /// The largest standard deviation of a backdrop blur in pixels, larger blurs are clamped.
+    const MAX_BLUR_SIGMA: f32 = 32.0;
+
     /// # Safety
     /// - This can never truly be safe, this is loading opengl functions, here be dragons.
     pub unsafe fn new(loader: impl FnMut(&str) -> *const ffi::c_void) -> Result<Self, GlError> {
         let gl = glow::Context::from_loader_function(loader);
+        let blur_program =
+            Self::create_program(&gl, include_str!("blur.vert"), include_str!("blur.frag"))?;
         let program = Self::create_program(
             &gl,
             include_str!("shader.vert"),
             include_str!("shader.frag"),
         )?;
 
+        // the samplers are bound to the same texture units for every draw, so they're set once
+        Self::set_samplers(
+            &gl,
+            program,
+            &[("image", 0), ("mask", 1), ("points", 2), ("bands", 3)],
+        );
+        Self::set_samplers(&gl, blur_program, &[("source", 0)]);
+
+        let blur_sigma = gl.get_uniform_location(blur_program, "sigma");
+        let blur_direction = gl.get_uniform_location(blur_program, "direction");
+
         let point_buffer = Self::create_point_buffer(&gl, 1);
         let band_buffer = Self::create_band_buffer(&gl, 1);
         let instance_buffer = gl.create_buffer()?;
 
-        gl.bind_buffer(glow::ARRAY_BUFFER, Some(instance_buffer));
-        gl.buffer_data_size(
-            glow::ARRAY_BUFFER,
-            size_of::<Instance>() as i32 * Self::MAX_INSTANCES as i32,
-            glow::STATIC_DRAW,
-        );
+        Self::allocate_instance_buffer(&gl, instance_buffer, Self::DEFAULT_BATCH_SIZE);
 
         let vertex_array = Self::create_vertex_array(&gl, instance_buffer).unwrap();
 
@@ -180,27 +309,63 @@ impl GlowRenderer {
         Ok(Self {
             gl,
             program,
+            blur_program,
+            blur_sigma,
+            blur_direction,
             width: 0,
             height: 0,
             points: Vec::new(),
             bands: Vec::with_capacity(Self::MAX_BANDS),
             band_data: Vec::new(),
-            instances: Vec::with_capacity(Self::MAX_INSTANCES),
+            band_cache: HashMap::new(),
+            instances: Vec::with_capacity(Self::DEFAULT_BATCH_SIZE),
             point_buffer,
             band_buffer,
             point_buffer_height: 1,
             band_buffer_height: 1,
             instance_buffer,
+            batch_size: Self::DEFAULT_BATCH_SIZE,
+            stats: RenderStats::default(),
             vertex_array,
             images: HashMap::new(),
             masks: Vec::new(),
             mask: None,
+            backdrop: None,
             default_image,
             active_image: None,
             scratch_curve: Curve::new(),
         })
     }
 
+    /// Get the maximum number of instances drawn in a single batch.
+    pub fn batch_size(&self) -> usize {
+        self.batch_size
+    }
+
+    /// Set the maximum number of instances drawn in a single batch.
+    ///
+    /// Larger batches need fewer draw calls for frames with many curves, like text-heavy
+    /// frames, at the cost of a larger instance buffer. Use [`GlowRenderer::stats`] to tune
+    /// it, the default is [`GlowRenderer::DEFAULT_BATCH_SIZE`].
+    ///
+    /// # Safety
+    /// - This can never truly be safe, this is calling opengl functions, here be dragons.
+    pub unsafe fn set_batch_size(&mut self, batch_size: usize) {
+        let batch_size = batch_size.max(1);
+
+        if self.batch_size != batch_size {
+            Self::allocate_instance_buffer(&self.gl, self.instance_buffer, batch_size);
+
+            self.batch_size = batch_size;
+            self.instances = Vec::with_capacity(batch_size);
+        }
+    }
+
+    /// Get the [`RenderStats`] of the last rendered frame.
+    pub fn stats(&self) -> RenderStats {
+        self.stats
+    }
+
     /// # Safety
     /// - This can never truly be safe, this is calling opengl functions, here be dragons.
     pub unsafe fn render(
@@ -211,17 +376,35 @@ impl GlowRenderer {
         height: u32,
         scale_factor: f32,
     ) -> Result<(), GlError> {
+        let _span = trace_span!(
+            "render",
+            width,
+            height,
+            primitives = canvas.primitives().map(Primitive::count).sum::<usize>(),
+        )
+        .entered();
+
         self.clean();
+        self.stats = RenderStats::default();
 
         if self.width != width || self.height != height {
             self.clear_masks();
+            self.clear_backdrop();
         }
 
         self.width = width;
         self.height = height;
         self.mask = None;
 
-        self.gl.clear_color(color.r, color.g, color.b, color.a);
+        // colors are written to the framebuffer in sRGB as they are, and premultiplied like
+        // the content drawn over them
+        let [r, g, b, a] = [
+            color.r * color.a,
+            color.g * color.a,
+            color.b * color.a,
+            color.a,
+        ];
+        self.gl.clear_color(r, g, b, a);
         self.gl.clear(glow::COLOR_BUFFER_BIT);
 
         self.gl.viewport(0, 0, width as i32, height as i32);
@@ -249,6 +432,14 @@ impl GlowRenderer {
             panic!("OpenGL error");
         }
 
+        trace!(
+            batches = self.stats.batches,
+            instances = self.stats.instances,
+            uploaded_bytes = self.stats.uploaded_bytes,
+            reallocations = self.stats.reallocations,
+            "rendered frame",
+        );
+
         Ok(())
     }
 
@@ -259,6 +450,12 @@ impl GlowRenderer {
         }
     }
 
+    unsafe fn clear_backdrop(&mut self) {
+        if let Some(backdrop) = self.backdrop.take() {
+            backdrop.delete(&self.gl);
+        }
+    }
+
     unsafe fn create_point_buffer(gl: &glow::Context, height: u32) -> glow::Texture {
         let texture = gl.create_texture().unwrap();
         gl.bind_texture(glow::TEXTURE_2D, Some(texture));
@@ -321,6 +518,8 @@ impl GlowRenderer {
         let texture = gl.create_texture().unwrap();
         gl.bind_texture(glow::TEXTURE_2D, Some(texture));
 
+        let pixels = premultiply(data.data());
+
         gl.tex_image_2d(
             glow::TEXTURE_2D,
             0,
@@ -330,7 +529,7 @@ impl GlowRenderer {
             0,
             glow::RGBA,
             glow::UNSIGNED_BYTE,
-            Some(data.data()),
+            Some(&pixels),
         );
 
         let filter = match data.filter() {
@@ -387,6 +586,27 @@ impl GlowRenderer {
         Ok(vertex_array)
     }
 
+    unsafe fn allocate_instance_buffer(gl: &glow::Context, buffer: glow::Buffer, size: usize) {
+        gl.bind_buffer(glow::ARRAY_BUFFER, Some(buffer));
+        gl.buffer_data_size(
+            glow::ARRAY_BUFFER,
+            (size_of::<Instance>() * size) as i32,
+            glow::STATIC_DRAW,
+        );
+        gl.bind_buffer(glow::ARRAY_BUFFER, None);
+    }
+
+    unsafe fn set_samplers(gl: &glow::Context, program: glow::Program, samplers: &[(&str, i32)]) {
+        gl.use_program(Some(program));
+
+        for &(name, unit) in samplers {
+            let location = gl.get_uniform_location(program, name);
+            gl.uniform_1_i32(location.as_ref(), unit);
+        }
+
+        gl.use_program(None);
+    }
+
     unsafe fn clean(&mut self) {
         self.images.retain(|weak, &mut texture| {
             if weak.strong_count() == 0 {
@@ -445,7 +665,9 @@ impl GlowRenderer {
         #[allow(clippy::single_match)]
         match primitive {
             Primitive::Fill { curve, fill, paint } => {
-                self.fill_curve(curve, fill, paint, transform)?;
+                // curves shared between fills, like glyphs, only have to be uploaded once
+                let key = Arc::as_ptr(curve) as usize;
+                self.fill_curve(curve, Some(key), fill, paint, transform)?;
             }
             Primitive::Stroke {
                 curve,
@@ -479,6 +701,7 @@ impl GlowRenderer {
 
                     self.fill_curve(
                         &mask.curve,
+                        None,
                         &mask.fill,
                         &Paint {
                             shader: Shader::Solid(Color::WHITE),
@@ -510,11 +733,126 @@ impl GlowRenderer {
                     }
                 }
             }
+            Primitive::BackdropBlur { curve, radius } => {
+                self.backdrop_blur(curve, *radius, transform)?;
+            }
         }
 
         Ok(())
     }
 
+    // the pixels covered by `rect` in clip space, expanded by `margin`, in window coordinates
+    fn pixel_region(&self, rect: Rect, margin: f32) -> Option<[i32; 4]> {
+        let width = self.width as f32;
+        let height = self.height as f32;
+
+        let min_x = ((rect.min.x + 1.0) / 2.0 * width - margin).floor().max(0.0);
+        let min_y = ((rect.min.y + 1.0) / 2.0 * height - margin)
+            .floor()
+            .max(0.0);
+        let max_x = ((rect.max.x + 1.0) / 2.0 * width + margin)
+            .ceil()
+            .min(width);
+        let max_y = ((rect.max.y + 1.0) / 2.0 * height + margin)
+            .ceil()
+            .min(height);
+
+        if min_x >= max_x || min_y >= max_y {
+            return None;
+        }
+
+        Some([
+            min_x as i32,
+            min_y as i32,
+            (max_x - min_x) as i32,
+            (max_y - min_y) as i32,
+        ])
+    }
+
+    unsafe fn backdrop_blur(
+        &mut self,
+        curve: &Curve,
+        radius: f32,
+        transform: Affine,
+    ) -> Result<(), GlError> {
+        // the transform maps to clip space, so we undo that to get the scale in pixels
+        let clip_area = 4.0 / (self.width as f32 * self.height as f32);
+        let scale = f32::sqrt(transform.matrix.determinant().abs() / clip_area);
+        let sigma = f32::min(radius * scale, Self::MAX_BLUR_SIGMA);
+
+        if sigma <= 0.0 {
+            return Ok(());
+        }
+
+        // the blurred pixels, and the pixels the blur samples from
+        let bounds = curve.bounds().transform(transform);
+        let Some(target) = self.pixel_region(bounds, 0.0) else {
+            return Ok(());
+        };
+        let Some(source) = self.pixel_region(bounds, (sigma * 3.0).ceil()) else {
+            return Ok(());
+        };
+
+        // everything drawn so far has to be rendered before it can be sampled
+        self.dispatch();
+
+        let (width, height) = (self.width, self.height);
+        let backdrop =
+            (self.backdrop).get_or_insert_with(|| Backdrop::new(&self.gl, width, height));
+
+        let [x, y, width, height] = source;
+        self.gl.active_texture(glow::TEXTURE0);
+        (self.gl).bind_texture(glow::TEXTURE_2D, Some(backdrop.textures[0]));
+        (self.gl).copy_tex_sub_image_2d(glow::TEXTURE_2D, 0, x, y, x, y, width, height);
+
+        self.gl.disable(glow::BLEND);
+        self.gl.enable(glow::SCISSOR_TEST);
+
+        self.gl.use_program(Some(self.blur_program));
+        self.gl.bind_vertex_array(Some(self.vertex_array));
+
+        (self.gl).uniform_1_f32(self.blur_sigma.as_ref(), sigma);
+
+        // blur horizontally into the second texture, then vertically back into the first,
+        // the horizontal pass also covers the pixels the vertical pass samples from
+        let passes = [
+            (source, 0, 1, [1.0 / self.width as f32, 0.0]),
+            (target, 1, 0, [0.0, 1.0 / self.height as f32]),
+        ];
+
+        for ([x, y, width, height], from, to, [dx, dy]) in passes {
+            (self.gl).bind_framebuffer(glow::FRAMEBUFFER, Some(backdrop.framebuffers[to]));
+            (self.gl).bind_texture(glow::TEXTURE_2D, Some(backdrop.textures[from]));
+            self.gl.scissor(x, y, width, height);
+
+            (self.gl).uniform_2_f32(self.blur_direction.as_ref(), dx, dy);
+
+            self.gl.draw_arrays(glow::TRIANGLES, 0, 3);
+        }
+
+        let blurred = backdrop.textures[0];
+
+        self.gl.bind_vertex_array(None);
+        self.gl.use_program(None);
+
+        self.gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+        self.gl.disable(glow::SCISSOR_TEST);
+        self.gl.enable(glow::BLEND);
+
+        // draw the blurred pixels within the curve, this is the only instance in the batch
+        let paint = Paint::from(Color::WHITE);
+        self.fill_curve(curve, None, &FillRule::NonZero, &paint, transform)?;
+
+        if let Some(instance) = self.instances.last_mut() {
+            instance.flags |= BACKDROP_BIT;
+        }
+
+        self.active_image = Some(blurred);
+        self.dispatch();
+
+        Ok(())
+    }
+
     unsafe fn stroke_curve(
         &mut self,
         curve: &Curve,
@@ -522,11 +860,17 @@ impl GlowRenderer {
         paint: &Paint,
         transform: Affine,
     ) -> Result<(), GlError> {
+        // the transform maps to clip space, so we undo that to get the scale in pixels,
+        // this keeps strokes smooth when zoomed in, and cheap when zoomed out
+        let clip_area = 4.0 / (self.width as f32 * self.height as f32);
+        let scale = f32::sqrt(transform.matrix.determinant().abs() / clip_area);
+        let tolerance = Curve::TOLERANCE / scale.max(0.01);
+
         let mut scratch_curve = mem::take(&mut self.scratch_curve);
         scratch_curve.clear();
-        scratch_curve.stroke_curve(curve, *stroke);
+        scratch_curve.stroke_curve_with_tolerance(curve, *stroke, tolerance);
 
-        self.fill_curve(&scratch_curve, &FillRule::NonZero, paint, transform)?;
+        self.fill_curve(&scratch_curve, None, &FillRule::NonZero, paint, transform)?;
         self.scratch_curve = scratch_curve;
 
         Ok(())
@@ -547,6 +891,7 @@ impl GlowRenderer {
 
             self.point_buffer_height = height;
             self.point_buffer = Self::create_point_buffer(&self.gl, height as u32);
+            self.stats.reallocations += 1;
         }
 
         if band_buffer_height > self.band_buffer_height {
@@ -556,6 +901,7 @@ impl GlowRenderer {
 
             self.band_buffer_height = height;
             self.band_buffer = Self::create_band_buffer(&self.gl, height as u32);
+            self.stats.reallocations += 1;
         }
 
         (self.gl).bind_texture(glow::TEXTURE_2D, Some(self.point_buffer));
@@ -607,18 +953,6 @@ impl GlowRenderer {
         self.gl.active_texture(glow::TEXTURE3);
         (self.gl).bind_texture(glow::TEXTURE_2D, Some(self.band_buffer));
 
-        let location = self.gl.get_uniform_location(self.program, "image");
-        self.gl.uniform_1_i32(location.as_ref(), 0);
-
-        let location = self.gl.get_uniform_location(self.program, "mask");
-        self.gl.uniform_1_i32(location.as_ref(), 1);
-
-        let location = self.gl.get_uniform_location(self.program, "points");
-        self.gl.uniform_1_i32(location.as_ref(), 2);
-
-        let location = self.gl.get_uniform_location(self.program, "bands");
-        self.gl.uniform_1_i32(location.as_ref(), 3);
-
         self.gl.bind_vertex_array(Some(self.vertex_array));
 
         (self.gl).draw_arrays_instanced(glow::TRIANGLE_STRIP, 0, 6, self.instances.len() as i32);
@@ -628,8 +962,15 @@ impl GlowRenderer {
 
         self.gl.bind_buffer(glow::ARRAY_BUFFER, None);
 
+        self.stats.batches += 1;
+        self.stats.instances += self.instances.len();
+        self.stats.uploaded_bytes += mem::size_of_val(self.points.as_slice())
+            + mem::size_of_val(self.band_data.as_slice())
+            + mem::size_of_val(self.instances.as_slice());
+
         self.points.clear();
         self.band_data.clear();
+        self.band_cache.clear();
         self.instances.clear();
         self.active_image = None;
     }
@@ -642,18 +983,20 @@ impl GlowRenderer {
         Self::TEXTURE_BUFFER_WIDTH * self.band_buffer_height
     }
 
+    // `key` identifies a curve that is drawn more than once, so its bands can be reused
     unsafe fn fill_curve(
         &mut self,
         curve: &Curve,
+        key: Option<usize>,
         fill: &FillRule,
         paint: &Paint,
         transform: Affine,
     ) -> Result<(), GlError> {
-        if self.instances.len() >= Self::MAX_INSTANCES {
+        if self.instances.len() >= self.batch_size {
             self.dispatch();
         }
 
-        let (mut band_index, mut band_count) = self.push_bands(curve);
+        let (mut band_index, mut band_count) = self.push_bands_cached(curve, key);
 
         if self.points.len() >= self.point_buffer_cap()
             || self.band_data.len() >= self.band_buffer_cap()
@@ -662,7 +1005,7 @@ impl GlowRenderer {
             self.band_data.truncate(self.band_buffer_cap());
             self.dispatch();
 
-            let (index, count) = self.push_bands(curve);
+            let (index, count) = self.push_bands_cached(curve, key);
             band_index = index;
             band_count = count;
         }
@@ -688,15 +1031,23 @@ impl GlowRenderer {
             Shader::Solid(_) => (None, Matrix::IDENTITY.into(), [0.0, 0.0, 1.0]),
         };
 
-        if self.active_image != image && !self.instances.is_empty() {
+        // solid fills don't sample the image, so they can share a batch with any image, and
+        // only fills with different images have to be drawn in separate batches
+        let conflicts = self
+            .active_image
+            .is_some_and(|active| image.is_some_and(|i| i != active));
+
+        if conflicts && !self.instances.is_empty() {
             self.dispatch();
 
-            let (index, count) = self.push_bands(curve);
+            let (index, count) = self.push_bands_cached(curve, key);
             band_index = index;
             band_count = count;
         }
 
-        self.active_image = image;
+        if image.is_some() {
+            self.active_image = image;
+        }
 
         let color = match paint.shader {
             Shader::Solid(color) => color,
@@ -709,6 +1060,10 @@ impl GlowRenderer {
             flags |= NON_ZERO_BIT;
         }
 
+        if image.is_none() {
+            flags |= SOLID_BIT;
+        }
+
         if cfg!(any(target_os = "android", target_os = "ios")) {
             match paint.anti_alias {
                 AntiAlias::None => flags |= 0 << 8,
@@ -742,6 +1097,20 @@ impl GlowRenderer {
         Ok(())
     }
 
+    unsafe fn push_bands_cached(&mut self, curve: &Curve, key: Option<usize>) -> (u32, u32) {
+        if let Some(&bands) = key.and_then(|key| self.band_cache.get(&key)) {
+            return bands;
+        }
+
+        let bands = self.push_bands(curve);
+
+        if let Some(key) = key {
+            self.band_cache.insert(key, bands);
+        }
+
+        bands
+    }
+
     unsafe fn push_bands(&mut self, curve: &Curve) -> (u32, u32) {
         let count = curve.bounds().height() / 5.0;
         let count = usize::clamp(count.ceil() as usize, 1, Self::MAX_BANDS - 1);
@@ -854,3 +1223,37 @@ impl GlowRenderer {
         (index, count as u32)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn premultiplied_filtering_has_no_fringe() {
+        // a translucent red pixel next to a transparent black one, as at the edge of a glyph
+        let pixels = [255, 0, 0, 128, 0, 0, 0, 0];
+
+        // linear filtering halfway between the pixels, then blending over a white background
+        let blend = |texel: [f32; 4], premultiplied: bool| {
+            let alpha = texel[3];
+            let color = match premultiplied {
+                true => [texel[0], texel[1], texel[2]],
+                false => [texel[0] * alpha, texel[1] * alpha, texel[2] * alpha],
+            };
+
+            color.map(|c| c + 1.0 - alpha)
+        };
+
+        let sample = |pixels: &[u8]| -> [f32; 4] {
+            [0, 1, 2, 3].map(|i| (pixels[i] as f32 + pixels[i + 4] as f32) / 2.0 / 255.0)
+        };
+
+        // straight alpha darkens the edge, premultiplied alpha gives a light red
+        let straight = blend(sample(&pixels), false);
+        assert!(straight[0] < 0.9);
+
+        let premultiplied = blend(sample(&premultiply(&pixels)), true);
+        assert!((premultiplied[0] - 1.0).abs() < 0.01);
+        assert!((premultiplied[1] - 0.75).abs() < 0.01);
+    }
+}
@@ -17,6 +17,8 @@ out vec4 f_color;
 const uint TEXTURE_BUFFER_WIDTH = 256u;
 
 const uint NON_ZERO_BIT = 1u << 31u;
+const uint BACKDROP_BIT = 1u << 30u;
+const uint SOLID_BIT = 1u << 29u;
 const uint AA_SAMPLES_MASK = 0x0000ff00u;
 const uint BAND_COUNT_MASK = 0x000000ffu;
 
@@ -540,13 +542,24 @@ void main() {
 
     if (alpha == 0.0) discard;
 
-    vec2 image_size = vec2(textureSize(image, 0));
-    vec2 image_uv = v_image_transform * (v_vertex + v_image_offset_opacity.xy);
-    vec4 color = texture(image, image_uv / image_size);
-    color *= v_image_offset_opacity.z;
+    if ((v_flags & BACKDROP_BIT) != 0u) {
+        // the blurred backdrop is sampled in screen space, and is already premultiplied
+        f_color = texture(image, v_uv) * alpha * mask;
+        return;
+    }
+
+    // solid fills don't sample the image, since they're batched with fills of any image
+    vec4 color = vec4(1.0);
+
+    if ((v_flags & SOLID_BIT) == 0u) {
+        // images are uploaded with premultiplied alpha, so only the color needs premultiplying
+        vec2 image_size = vec2(textureSize(image, 0));
+        vec2 image_uv = v_image_transform * (v_vertex + v_image_offset_opacity.xy);
+        color = texture(image, image_uv / image_size);
+        color *= v_image_offset_opacity.z;
+    }
 
-    f_color = v_color * color;
-    f_color.rgb *= f_color.a; // premultiply alpha
+    f_color = vec4(v_color.rgb * v_color.a, v_color.a) * color;
     f_color *= alpha; // apply curve alpha
     f_color *= mask; // apply mask alpha
 }
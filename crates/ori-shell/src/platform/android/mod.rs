@@ -4,6 +4,7 @@ mod clipboard;
 mod error;
 mod keyboard;
 mod permissions;
+mod reduced_motion;
 mod run;
 
 pub use error::*;
@@ -0,0 +1,43 @@
+use android_activity::AndroidApp;
+use jni::{
+    objects::{JObject, JValue},
+    JavaVM,
+};
+
+use super::AndroidError;
+
+/// Query whether the user prefers reduced motion.
+///
+/// Android has no dedicated setting, but removing animations in the accessibility settings
+/// sets the global `animator_duration_scale` to zero, which is what this checks.
+pub fn query(app: &AndroidApp) -> Result<bool, AndroidError> {
+    let vm = unsafe { JavaVM::from_raw(app.vm_as_ptr() as _) }?;
+    let activity = unsafe { JObject::from_raw(app.activity_as_ptr() as _) };
+    let mut env = vm.attach_current_thread()?;
+
+    let resolver = env
+        .call_method(
+            &activity,
+            "getContentResolver",
+            "()Landroid/content/ContentResolver;",
+            &[],
+        )?
+        .l()?;
+
+    let name = env.new_string("animator_duration_scale")?;
+
+    let scale = env
+        .call_static_method(
+            "android/provider/Settings$Global",
+            "getFloat",
+            "(Landroid/content/ContentResolver;Ljava/lang/String;F)F",
+            &[
+                JValue::Object(&resolver),
+                JValue::Object(&name),
+                JValue::Float(1.0),
+            ],
+        )?
+        .f()?;
+
+    Ok(scale == 0.0)
+}
@@ -1,3 +1,5 @@
+use std::time::{Duration, Instant};
+
 use android_activity::{
     input::{InputEvent, KeyAction, KeyEvent, KeyMapChar, Keycode, MotionAction, MotionEvent},
     AndroidApp, AndroidAppWaker, InputStatus, MainEvent, PollEvent,
@@ -18,7 +20,7 @@ use crate::platform::egl::{EglContext, EglNativeDisplay, EglSurface};
 use super::{
     clipboard::AndroidClipboard,
     keyboard::{ImeEvent, ImeState},
-    AndroidError, ANDROID_APP,
+    reduced_motion, AndroidError, ANDROID_APP,
 };
 
 /// Run the app on Android.
@@ -54,7 +56,11 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), AndroidError> {
     let mut init = false;
 
     while state.running {
-        android.poll_events(None, |event| {
+        // wake up in time for the next timer
+        let timeout = (state.app.next_timer())
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()));
+
+        android.poll_events(timeout, |event| {
             match event {
                 PollEvent::Wake => {}
                 PollEvent::Timeout => {}
@@ -68,6 +74,7 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), AndroidError> {
                     MainEvent::InitWindow { .. } => {
                         if !init {
                             state.app.init(data);
+                            update_reduced_motion(&mut state, data);
                             init = true;
                         } else {
                             recreate_window(&mut state);
@@ -81,9 +88,18 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), AndroidError> {
                     MainEvent::LowMemory => {}
                     MainEvent::Pause => {}
                     MainEvent::RedrawNeeded { .. } => {
+                        // the system needs a new frame, even if nothing changed
+                        if let Some(ref mut window) = state.window {
+                            window.needs_present = true;
+                        }
+
                         request_redraw(&mut state);
                     }
-                    MainEvent::Resume { .. } => {}
+                    MainEvent::Resume { .. } => {
+                        if init {
+                            update_reduced_motion(&mut state, data);
+                        }
+                    }
                     MainEvent::SaveState { .. } => {}
                     MainEvent::Start => {}
                     MainEvent::Stop => {}
@@ -104,6 +120,9 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), AndroidError> {
                 handle_input_events(&mut state, &android, data);
                 handle_ime_events(&mut state, data);
 
+                state.app.handle_timers(data);
+                handle_requests(&mut state, data);
+
                 render_window(&mut state, data);
                 handle_requests(&mut state, data);
 
@@ -143,6 +162,8 @@ struct WindowState {
     physical_height: u32,
     scale_factor: f32,
     needs_redraw: bool,
+    // whether the last frame is no longer on screen, e.g. after a resize
+    needs_present: bool,
     egl_surface: EglSurface,
     renderer: SkiaRenderer,
 }
@@ -210,6 +231,10 @@ fn handle_request<T>(state: &mut AppState<T>, data: &mut T, request: AppRequest<
             WindowUpdate::Visible(_) => warn!("Window visible is not supported on Android"),
             WindowUpdate::Color(_) => warn!("Window color is not supported on Android"),
             WindowUpdate::Cursor(_) => warn!("Window cursor is not supported on Android"),
+            WindowUpdate::CursorVisible(_) => {
+                warn!("Window cursor visibility is not supported on Android")
+            }
+            WindowUpdate::CursorGrab(_) => warn!("Window cursor grab is not supported on Android"),
             WindowUpdate::Ime(ime) => match ime {
                 Some(ime) => {
                     state.ime_state.show(&state.android).unwrap();
@@ -220,6 +245,9 @@ fn handle_request<T>(state: &mut AppState<T>, data: &mut T, request: AppRequest<
                 }
             },
         },
+        AppRequest::OpenUrl(_) => {
+            warn!("Opening URLs is not supported on Android");
+        }
         AppRequest::Quit => {
             state.running = false;
         }
@@ -290,6 +318,7 @@ fn recreate_window<T>(state: &mut AppState<T>) {
             physical_height,
             scale_factor,
             needs_redraw: true,
+            needs_present: true,
             egl_surface,
             renderer,
         };
@@ -298,6 +327,15 @@ fn recreate_window<T>(state: &mut AppState<T>) {
     }
 }
 
+// android can't notify us when the animation scale changes, so it's queried whenever the app
+// is resumed, e.g. after returning from the settings
+fn update_reduced_motion<T>(state: &mut AppState<T>, data: &mut T) {
+    match reduced_motion::query(&state.android) {
+        Ok(reduced) => state.app.reduced_motion_changed(data, reduced),
+        Err(err) => warn!("Failed to query the animation scale: {}", err),
+    }
+}
+
 fn render_window<T>(state: &mut AppState<T>, data: &mut T) {
     if let Some(ref mut window) = state.window {
         if !window.needs_redraw {
@@ -307,6 +345,16 @@ fn render_window<T>(state: &mut AppState<T>, data: &mut T) {
         window.needs_redraw = false;
 
         if let Some(draw) = state.app.draw_window(data, window.id) {
+            // nothing changed, and the last frame is still on screen
+            if !draw.changed && !window.needs_present {
+                state.app.frame_rendered(window.id, Duration::ZERO);
+                return;
+            }
+
+            window.needs_present = false;
+
+            let start = Instant::now();
+
             window.egl_surface.make_current().unwrap();
 
             window.renderer.render(
@@ -318,6 +366,8 @@ fn render_window<T>(state: &mut AppState<T>, data: &mut T) {
             );
 
             window.egl_surface.swap_buffers().unwrap();
+
+            state.app.frame_rendered(window.id, start.elapsed());
         }
     }
 }
@@ -334,6 +384,7 @@ fn window_resized<T>(state: &mut AppState<T>, data: &mut T) {
 
         window.physical_width = native_window.width() as u32;
         window.physical_height = native_window.height() as u32;
+        window.needs_present = true;
 
         state.app.window_resized(
             data,
@@ -1,2 +1,4 @@
+pub mod open;
+pub mod settings;
 #[allow(unused)]
 pub mod xkb;
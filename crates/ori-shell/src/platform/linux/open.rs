@@ -0,0 +1,49 @@
+use tracing::warn;
+
+// the schemes that are passed to the desktop, anything else might launch arbitrary handlers
+#[cfg(feature = "open-url")]
+const ALLOWED_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// Open a URL with the default handler of the desktop, e.g. a browser.
+///
+/// This uses `xdg-open`, which is part of the freedesktop `xdg-utils`. Only `http`, `https`
+/// and `mailto` URLs are opened, and only with the `open-url` feature enabled.
+#[cfg(feature = "open-url")]
+pub fn open_url(url: &str) {
+    use std::process::{Command, Stdio};
+
+    // the scheme must start with a letter, so the url is never parsed as an option
+    let scheme = url.split_once(':').map(|(scheme, _)| scheme);
+    let allowed = scheme.is_some_and(|scheme| {
+        (ALLOWED_SCHEMES.iter()).any(|allowed| scheme.eq_ignore_ascii_case(allowed))
+    });
+
+    if !allowed {
+        warn!(
+            "Refusing to open `{}`, only http, https and mailto urls are allowed",
+            url
+        );
+        return;
+    }
+
+    let child = Command::new("xdg-open")
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    match child {
+        // reap the child when it exits, so it doesn't linger as a zombie
+        Ok(mut child) => drop(std::thread::spawn(move || child.wait())),
+        Err(err) => warn!("Failed to open `{}`: {}", url, err),
+    }
+}
+
+/// Open a URL with the default handler of the desktop, e.g. a browser.
+///
+/// This requires the `open-url` feature, without it the request is ignored.
+#[cfg(not(feature = "open-url"))]
+pub fn open_url(url: &str) {
+    warn!("Cannot open `{}`, the `open-url` feature is disabled", url);
+}
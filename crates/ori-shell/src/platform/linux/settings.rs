@@ -0,0 +1,122 @@
+use std::{
+    env,
+    io::{BufRead, BufReader},
+    process::{Child, Command, Stdio},
+    thread,
+};
+
+use ori_core::{command::CommandProxy, style::ColorScheme, transition::ReducedMotion};
+use tracing::debug;
+
+const SCHEMA: &str = "org.gnome.desktop.interface";
+
+/// A watcher of the desktop settings, see [`watch`].
+///
+/// The monitor process is killed when the watcher is dropped.
+pub struct SettingsWatcher {
+    child: Child,
+}
+
+impl Drop for SettingsWatcher {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+/// Watch the desktop settings, sending commands to `proxy` with their current values, and
+/// whenever they change.
+///
+/// This uses the freedesktop `enable-animations` setting through `gsettings`, sending a
+/// [`ReducedMotion`] command, animations being disabled means reduced motion is preferred.
+/// The `color-scheme` setting is also watched, sending a [`ColorScheme`] command, falling
+/// back to the `GTK_THEME` environment variable.
+///
+/// Both settings are watched by a single `gsettings` process, and the current values are
+/// queried on a separate thread, so this doesn't block.
+pub fn watch(proxy: CommandProxy) -> Option<SettingsWatcher> {
+    let child = Command::new("gsettings")
+        .args(["monitor", SCHEMA])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn();
+
+    let mut watcher = match child {
+        Ok(child) => SettingsWatcher { child },
+        Err(err) => {
+            debug!("failed to watch the desktop settings: {}", err);
+
+            // gsettings isn't available, but GTK_THEME might be
+            if let Some(scheme) = gtk_theme() {
+                proxy.cmd(scheme);
+            }
+
+            return None;
+        }
+    };
+
+    let stdout = watcher.child.stdout.take()?;
+
+    thread::spawn(move || {
+        query(&proxy);
+
+        // each line has the form `color-scheme: 'prefer-dark'`
+        for line in BufReader::new(stdout).lines() {
+            let Ok(line) = line else { break };
+
+            if let Some((key, value)) = line.split_once(':') {
+                changed(&proxy, key.trim(), value.trim());
+            }
+        }
+    });
+
+    Some(watcher)
+}
+
+// query the current values of all the settings with a single `gsettings` process
+fn query(proxy: &CommandProxy) {
+    let output = Command::new("gsettings")
+        .args(["list-recursively", SCHEMA])
+        .stderr(Stdio::null())
+        .output();
+
+    let output = match output {
+        Ok(output) if output.status.success() => output,
+        _ => {
+            if let Some(scheme) = gtk_theme() {
+                proxy.cmd(scheme);
+            }
+
+            return;
+        }
+    };
+
+    // each line has the form `org.gnome.desktop.interface color-scheme 'prefer-dark'`
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        let mut parts = line.splitn(3, ' ').skip(1);
+
+        if let (Some(key), Some(value)) = (parts.next(), parts.next()) {
+            changed(proxy, key, value.trim());
+        }
+    }
+}
+
+fn changed(proxy: &CommandProxy, key: &str, value: &str) {
+    match key {
+        "color-scheme" => match value.contains("prefer-dark") {
+            true => proxy.cmd(ColorScheme::Dark),
+            false => proxy.cmd(ColorScheme::Light),
+        },
+        "enable-animations" => proxy.cmd(ReducedMotion(value == "false")),
+        _ => {}
+    }
+}
+
+fn gtk_theme() -> Option<ColorScheme> {
+    let theme = env::var("GTK_THEME").ok()?;
+
+    Some(match theme.to_lowercase().ends_with(":dark") {
+        true => ColorScheme::Dark,
+        false => ColorScheme::Light,
+    })
+}
@@ -1,5 +1,6 @@
 use std::{
-    ffi,
+    env,
+    ffi::{self, CString},
     fs::File,
     os::fd::OwnedFd,
     ptr::{self, NonNull},
@@ -9,15 +10,22 @@ use std::{
 
 use ori_core::event::{Key, Modifiers};
 use xkbcommon_dl::{
-    xkb_context, xkb_context_flags::XKB_CONTEXT_NO_FLAGS, xkb_keymap,
+    xkb_compose_compile_flags::XKB_COMPOSE_COMPILE_NO_FLAGS, xkb_compose_feed_result,
+    xkb_compose_state, xkb_compose_state_flags::XKB_COMPOSE_STATE_NO_FLAGS, xkb_compose_status,
+    xkb_compose_table, xkb_context, xkb_context_flags::XKB_CONTEXT_NO_FLAGS, xkb_keymap,
     xkb_keymap_compile_flags::XKB_KEYMAP_COMPILE_NO_FLAGS,
-    xkb_keymap_format::XKB_KEYMAP_FORMAT_TEXT_V1, xkb_state, xkb_state_component, xkbcommon_handle,
-    XkbCommon, XKB_MOD_NAME_ALT, XKB_MOD_NAME_CTRL, XKB_MOD_NAME_LOGO, XKB_MOD_NAME_SHIFT,
+    xkb_keymap_format::XKB_KEYMAP_FORMAT_TEXT_V1, xkb_state, xkb_state_component,
+    xkbcommon_compose_option, xkbcommon_handle, XkbCommon, XkbCommonCompose, XKB_MOD_NAME_ALT,
+    XKB_MOD_NAME_CTRL, XKB_MOD_NAME_LOGO, XKB_MOD_NAME_SHIFT,
 };
 use xkeysym::Keysym;
 
 static XKB: LazyLock<&'static XkbCommon> = LazyLock::new(xkbcommon_handle);
 
+// the compose module is optional, without it dead keys just won't compose
+static XKBCOMPOSE: LazyLock<Option<&'static XkbCommonCompose>> =
+    LazyLock::new(xkbcommon_compose_option);
+
 #[cfg(x11_platform)]
 static XKBX11: LazyLock<&'static xkbcommon_dl::x11::XkbCommonX11> =
     LazyLock::new(xkbcommon_dl::x11::xkbcommon_x11_handle);
@@ -25,6 +33,7 @@ static XKBX11: LazyLock<&'static xkbcommon_dl::x11::XkbCommonX11> =
 pub struct XkbKeyboard {
     state: Option<XkbState>,
     keymap: Option<XkbKeymap>,
+    compose: Option<XkbCompose>,
     context: XkbContext,
 }
 
@@ -33,6 +42,7 @@ impl XkbKeyboard {
         Some(Self {
             state: None,
             keymap: None,
+            compose: XkbCompose::new(context),
             context: context.clone(),
         })
     }
@@ -45,6 +55,7 @@ impl XkbKeyboard {
         Some(Self {
             state: Some(state),
             keymap: Some(keymap),
+            compose: XkbCompose::new(context),
             context: context.clone(),
         })
     }
@@ -89,6 +100,23 @@ impl XkbKeyboard {
         Some(String::from_utf8_lossy(&buffer[..len]).to_string())
     }
 
+    /// Get the text of a pressed key, composing dead keys and compose sequences.
+    ///
+    /// Keys that are part of an unfinished sequence produce no text, and the key finishing
+    /// the sequence produces the composed text.
+    pub fn key_text(&mut self, keysym: Keysym) -> Option<String> {
+        let status = match self.compose {
+            Some(ref mut compose) => compose.feed(keysym),
+            None => ComposeStatus::Ignored,
+        };
+
+        match status {
+            ComposeStatus::Ignored => self.keysym_to_utf8(keysym),
+            ComposeStatus::Composing | ComposeStatus::Cancelled => None,
+            ComposeStatus::Composed(text) => text,
+        }
+    }
+
     pub fn keysym_to_key(&self, keysym: Keysym) -> Key {
         if let Some(utf8) = self.keysym_to_utf8(keysym) {
             let mut chars = utf8.chars();
@@ -104,6 +132,105 @@ impl XkbKeyboard {
     }
 }
 
+enum ComposeStatus {
+    Ignored,
+    Composing,
+    Composed(Option<String>),
+    Cancelled,
+}
+
+struct XkbCompose {
+    compose: &'static XkbCommonCompose,
+    table: NonNull<xkb_compose_table>,
+    state: NonNull<xkb_compose_state>,
+}
+
+impl XkbCompose {
+    fn new(context: &XkbContext) -> Option<Self> {
+        let compose = (*XKBCOMPOSE)?;
+
+        // the compose table depends on the locale, like it does for every other application
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .into_iter()
+            .filter_map(|var| env::var(var).ok())
+            .find(|locale| !locale.is_empty())
+            .unwrap_or_else(|| String::from("C"));
+        let locale = CString::new(locale).ok()?;
+
+        unsafe {
+            let table = (compose.xkb_compose_table_new_from_locale)(
+                context.ptr(),
+                locale.as_ptr(),
+                XKB_COMPOSE_COMPILE_NO_FLAGS,
+            );
+            let table = NonNull::new(table)?;
+
+            let state = (compose.xkb_compose_state_new)(table.as_ptr(), XKB_COMPOSE_STATE_NO_FLAGS);
+
+            let Some(state) = NonNull::new(state) else {
+                (compose.xkb_compose_table_unref)(table.as_ptr());
+                return None;
+            };
+
+            Some(Self {
+                compose,
+                table,
+                state,
+            })
+        }
+    }
+
+    fn feed(&mut self, keysym: Keysym) -> ComposeStatus {
+        let state = self.state.as_ptr();
+
+        unsafe {
+            let result = (self.compose.xkb_compose_state_feed)(state, keysym.into());
+
+            if result == xkb_compose_feed_result::XKB_COMPOSE_FEED_IGNORED {
+                return ComposeStatus::Ignored;
+            }
+
+            match (self.compose.xkb_compose_state_get_status)(state) {
+                xkb_compose_status::XKB_COMPOSE_NOTHING => ComposeStatus::Ignored,
+                xkb_compose_status::XKB_COMPOSE_COMPOSING => ComposeStatus::Composing,
+                xkb_compose_status::XKB_COMPOSE_COMPOSED => {
+                    let mut buffer = [0u8; 64];
+
+                    let written = (self.compose.xkb_compose_state_get_utf8)(
+                        state,
+                        buffer.as_mut_ptr().cast(),
+                        buffer.len(),
+                    );
+
+                    (self.compose.xkb_compose_state_reset)(state);
+
+                    if written <= 0 {
+                        return ComposeStatus::Composed(None);
+                    }
+
+                    // the written length excludes the null terminator
+                    let len = (written as usize).min(buffer.len() - 1);
+                    let text = String::from_utf8_lossy(&buffer[..len]).to_string();
+                    ComposeStatus::Composed(Some(text))
+                }
+                xkb_compose_status::XKB_COMPOSE_CANCELLED => {
+                    (self.compose.xkb_compose_state_reset)(state);
+                    ComposeStatus::Cancelled
+                }
+            }
+        }
+    }
+}
+
+impl Drop for XkbCompose {
+    fn drop(&mut self) {
+        unsafe {
+            (self.compose.xkb_compose_state_unref)(self.state.as_ptr());
+            (self.compose.xkb_compose_table_unref)(self.table.as_ptr());
+        }
+    }
+}
+
 pub struct XkbKeymap {
     keymap: NonNull<xkb_keymap>,
 }
@@ -1,19 +1,27 @@
-use std::{mem, num::NonZero, sync::Arc, time::Duration};
+use std::{
+    mem,
+    num::NonZero,
+    ptr::NonNull,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use ori_app::{App, AppBuilder, AppRequest, UiBuilder};
 use ori_core::{
     clipboard::{Clipboard, ClipboardBackend},
     command::CommandWaker,
     event::{Code, Key, PointerButton, PointerId},
-    layout::{Point, Vector},
-    window::{Cursor, Window, WindowId, WindowUpdate},
+    layout::{Point, Rect, Size, Vector},
+    raw_window_handle::{WaylandDisplayHandle, WaylandWindowHandle},
+    window::{Cursor, CursorGrab, Monitor, MonitorId, Window, WindowId, WindowUpdate},
 };
 use ori_glow::GlowRenderer;
 use sctk_adwaita::{AdwaitaFrame, FrameConfig};
 use smithay_client_toolkit::{
     compositor::{CompositorHandler, CompositorState, SurfaceData},
-    delegate_compositor, delegate_output, delegate_pointer, delegate_registry, delegate_seat,
-    delegate_shm, delegate_subcompositor, delegate_xdg_shell, delegate_xdg_window,
+    delegate_compositor, delegate_output, delegate_pointer, delegate_pointer_constraints,
+    delegate_registry, delegate_relative_pointer, delegate_seat, delegate_shm,
+    delegate_subcompositor, delegate_xdg_shell, delegate_xdg_window,
     output::{OutputHandler, OutputState},
     reexports::{
         calloop::{
@@ -21,7 +29,22 @@ use smithay_client_toolkit::{
             EventLoop, LoopHandle, RegistrationToken,
         },
         calloop_wayland_source::WaylandSource,
-        protocols::xdg::shell::client::xdg_toplevel::ResizeEdge as XdgResizeEdge,
+        protocols::{
+            wp::{
+                fractional_scale::v1::client::{
+                    wp_fractional_scale_manager_v1::WpFractionalScaleManagerV1,
+                    wp_fractional_scale_v1::{Event as FractionalScaleEvent, WpFractionalScaleV1},
+                },
+                pointer_constraints::zv1::client::{
+                    zwp_confined_pointer_v1::ZwpConfinedPointerV1,
+                    zwp_locked_pointer_v1::ZwpLockedPointerV1,
+                    zwp_pointer_constraints_v1::Lifetime,
+                },
+                relative_pointer::zv1::client::zwp_relative_pointer_v1::ZwpRelativePointerV1,
+                viewporter::client::{wp_viewport::WpViewport, wp_viewporter::WpViewporter},
+            },
+            xdg::shell::client::xdg_toplevel::ResizeEdge as XdgResizeEdge,
+        },
     },
     registry::{ProvidesRegistryState, RegistryState},
     registry_handlers,
@@ -30,6 +53,8 @@ use smithay_client_toolkit::{
             CursorIcon, PointerData, PointerEvent, PointerEventKind, PointerHandler, ThemeSpec,
             ThemedPointer,
         },
+        pointer_constraints::{PointerConstraintsHandler, PointerConstraintsState},
+        relative_pointer::{RelativeMotionEvent, RelativePointerHandler, RelativePointerState},
         Capability, SeatHandler, SeatState,
     },
     shell::{
@@ -45,7 +70,7 @@ use smithay_client_toolkit::{
     shm::{Shm, ShmHandler},
     subcompositor::SubcompositorState,
 };
-use tracing::{debug, warn};
+use tracing::{debug, trace_span, warn};
 use wayland_client::{
     backend::ObjectId,
     globals::registry_queue_init,
@@ -62,11 +87,13 @@ use wayland_csd_frame::{
     DecorationsFrame, FrameAction, FrameClick, ResizeEdge, WindowState as CsdWindowState,
 };
 use wayland_egl::WlEglSurface;
-use xkeysym::Keysym;
 
 use crate::platform::{
     egl::{EglContext, EglNativeDisplay, EglSurface},
-    linux::xkb::{XkbContext, XkbKeyboard},
+    linux::{
+        open, settings,
+        xkb::{XkbContext, XkbKeyboard},
+    },
 };
 
 use super::error::WaylandError;
@@ -102,8 +129,14 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), WaylandError> {
     .unwrap();
     let xdg_shell = XdgShell::bind(&globals, &qhandle).unwrap();
     let seat = SeatState::new(&globals, &qhandle);
+    let pointer_constraints = PointerConstraintsState::bind(&globals, &qhandle);
+    let relative_pointer = RelativePointerState::bind(&globals, &qhandle);
     let shm = Shm::bind(&globals, &qhandle).unwrap();
 
+    // these are optional, not all compositors support fractional scaling
+    let fractional_scale = globals.bind(&qhandle, 1..=1, ()).ok();
+    let viewporter = globals.bind(&qhandle, 1..=1, ()).ok();
+
     let output = OutputState::new(&globals, &qhandle);
     let registry = RegistryState::new(&globals);
 
@@ -116,6 +149,10 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), WaylandError> {
     app.add_context(Clipboard::new(Box::new(clipboard)));
     app.init(data);
 
+    // watch whether the user prefers reduced motion, and the system color scheme, the
+    // watcher is stopped when it's dropped at exit
+    let _settings = settings::watch(app.proxy());
+
     let mut state = State {
         running: true,
 
@@ -129,7 +166,11 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), WaylandError> {
         subcompositor: Arc::new(subcompositor),
         xdg_shell,
         seat,
+        pointer_constraints,
+        relative_pointer,
         shm,
+        fractional_scale,
+        viewporter,
 
         output,
         registry,
@@ -142,9 +183,11 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), WaylandError> {
     };
 
     while state.running {
-        let timeout = match state.needs_redraw() {
-            true => Some(Duration::from_millis(2)),
-            false => None,
+        // sleep until the next timer is due, if nothing needs to be redrawn
+        let timeout = match (state.needs_redraw(), app.next_timer()) {
+            (true, _) => Some(Duration::from_millis(2)),
+            (false, Some(deadline)) => Some(deadline.saturating_duration_since(Instant::now())),
+            (false, None) => None,
         };
 
         event_loop.dispatch(timeout, &mut state).unwrap();
@@ -153,6 +196,9 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), WaylandError> {
         handle_events(&mut app, data, &mut state)?;
         handle_app_requests(&mut app, data, &mut state, &qhandle)?;
 
+        app.handle_timers(data);
+        handle_app_requests(&mut app, data, &mut state, &qhandle)?;
+
         render_windows(&mut app, data, &mut state)?;
         handle_app_requests(&mut app, data, &mut state, &qhandle)?;
 
@@ -191,6 +237,7 @@ fn handle_app_request<T>(
         AppRequest::CloseWindow(id) => {
             if let Some(index) = window_index_by_id(&state.windows, id) {
                 state.windows.remove(index);
+                app.remove_window(id);
             }
         }
 
@@ -225,23 +272,24 @@ fn handle_app_request<T>(
                 }
                 WindowUpdate::Icon(_) => {
                     warn!(
-                        "Setting window icons is not supported on Wayland, set it a .desktop file"
+                        "Setting window icons is not supported on Wayland, set it in the .desktop file matching the app id"
                     );
                 }
                 WindowUpdate::Size(size) => {
-                    let physical_width = (size.width * window.scale_factor) as u32;
-                    let physical_height = (size.height * window.scale_factor) as u32;
+                    // wayland sizes are in logical pixels
+                    let width = size.width as u32;
+                    let height = size.height as u32;
 
                     if let Some(ref mut configure) = window.last_configure {
                         let one = NonZero::new(1).unwrap();
-                        let width = NonZero::new(physical_width).unwrap_or(one);
-                        let height = NonZero::new(physical_height).unwrap_or(one);
+                        let width = NonZero::new(width).unwrap_or(one);
+                        let height = NonZero::new(height).unwrap_or(one);
 
                         configure.new_size = (Some(width), Some(height));
 
                         if !window.resizable {
-                            window.physical_width = physical_width;
-                            window.physical_height = physical_height;
+                            window.width = width.get();
+                            window.height = height.get();
                         }
 
                         if let Some(event) = window.resize() {
@@ -252,8 +300,9 @@ fn handle_app_request<T>(
                     }
                 }
                 WindowUpdate::Scale(scale) => {
-                    window.scale_factor = scale;
-                    window.needs_redraw = true;
+                    if let Some(event) = window.rescale(scale) {
+                        state.events.push(event);
+                    }
                 }
                 WindowUpdate::Resizable(resizable) => {
                     set_resizable(window, resizable);
@@ -295,16 +344,39 @@ fn handle_app_request<T>(
                     window.cursor_icon = cursor_icon(cursor);
                     window.set_cursor_icon = true;
                 }
+                WindowUpdate::CursorVisible(visible) => {
+                    window.cursor_visible = visible;
+                    window.set_cursor_icon = true;
+                }
+                WindowUpdate::CursorGrab(grab) => {
+                    window.cursor_grab = grab;
+                    set_cursor_grab(window, &state.pointers, &state.pointer_constraints, qhandle);
+                }
                 WindowUpdate::Ime(_) => {}
             }
         }
 
+        AppRequest::OpenUrl(url) => open::open_url(&url),
+
         AppRequest::Quit => state.running = false,
     }
 
     Ok(())
 }
 
+// the name of the executable, with characters not allowed in app ids replaced
+fn default_app_id() -> String {
+    let exe = std::env::current_exe().ok();
+    let name = exe.as_deref().and_then(|exe| exe.file_stem()?.to_str());
+
+    let allowed = |c: char| c.is_ascii_alphanumeric() || c == '.' || c == '-';
+
+    (name.unwrap_or("ori"))
+        .chars()
+        .map(|c| if allowed(c) { c } else { '_' })
+        .collect()
+}
+
 fn cursor_icon(cursor: Cursor) -> CursorIcon {
     match cursor {
         Cursor::Default => CursorIcon::Default,
@@ -350,13 +422,24 @@ fn open_window<T>(
     data: &mut T,
     state: &mut State,
     qhandle: &QueueHandle<State>,
-    window: Window,
+    mut window: Window,
     ui: UiBuilder<T>,
 ) -> Result<(), WaylandError> {
-    let physical_width = window.width();
-    let physical_height = window.height();
+    let width = window.width();
+    let height = window.height();
 
     let surface = state.compositor.create_surface(qhandle);
+
+    // fractional scaling needs a viewport to map the buffer to the surface, without both
+    // we fall back to the integer scale reported by the compositor
+    let (fractional_scale, viewport) = match (&state.fractional_scale, &state.viewporter) {
+        (Some(manager), Some(viewporter)) => (
+            Some(manager.get_fractional_scale(&surface, qhandle, ())),
+            Some(viewporter.get_viewport(&surface, qhandle, ())),
+        ),
+        _ => (None, None),
+    };
+
     let xdg_window = state.xdg_shell.create_window(
         surface,
         // We prefer to use the server-side decorations.
@@ -365,29 +448,30 @@ fn open_window<T>(
     );
 
     xdg_window.set_title(&window.title);
+    xdg_window.set_app_id(window.app_id.clone().unwrap_or_else(default_app_id));
     xdg_window.commit();
 
-    xdg_window.xdg_surface().set_window_geometry(
-        0,
-        0,
-        physical_width as i32,
-        physical_height as i32,
-    );
+    xdg_window
+        .xdg_surface()
+        .set_window_geometry(0, 0, width as i32, height as i32);
 
     if window.icon.is_some() {
-        debug!("Window icons are not supported on Wayland, set it a .desktop file");
+        debug!("Window icons are not supported on Wayland, set it in the .desktop file matching the app id");
     }
 
-    let window_state = WindowState {
+    let mut window_state = WindowState {
         id: window.id(),
 
         needs_redraw: true,
-        physical_width,
-        physical_height,
+        needs_present: true,
+        width,
+        height,
         scale_factor: 1.0,
         cursor_icon: CursorIcon::Default,
         frame_cursor_icon: None,
         set_cursor_icon: false,
+        cursor_visible: window.cursor_visible,
+        cursor_grab: window.cursor_grab,
         title: window.title.clone(),
         maximized: window.maximized,
         resizable: window.resizable,
@@ -401,16 +485,39 @@ fn open_window<T>(
         egl_surface: None,
         renderer: None,
 
+        fractional_scale,
+        viewport,
+        constraints: Vec::new(),
         frame: None,
         xdg_window,
     };
 
+    if window.cursor_grab != CursorGrab::None {
+        set_cursor_grab(
+            &mut window_state,
+            &state.pointers,
+            &state.pointer_constraints,
+            qhandle,
+        );
+    }
+
     set_resizable(&window_state, window.resizable);
 
     if window.maximized {
         window_state.xdg_window.set_maximized();
     }
 
+    let surface_ptr = window_state.xdg_window.wl_surface().id().as_ptr();
+    let display_ptr = state.conn.backend().display_ptr();
+
+    if let (Some(surface), Some(display)) = (NonNull::new(surface_ptr), NonNull::new(display_ptr)) {
+        let window_handle = WaylandWindowHandle::new(surface.cast());
+        let display_handle = WaylandDisplayHandle::new(display.cast());
+
+        // SAFETY: the connection outlives the surface, which is destroyed when it's closed
+        unsafe { window.set_raw_handles(window_handle.into(), display_handle.into()) };
+    }
+
     state.windows.push(window_state);
     app.add_window(data, ui, window);
 
@@ -422,7 +529,7 @@ fn set_resizable(window: &WindowState, resizable: bool) {
         window.xdg_window.set_min_size(None);
         window.xdg_window.set_max_size(None);
     } else {
-        let size = Some((window.physical_width, window.physical_height));
+        let size = Some((window.width, window.height));
         window.xdg_window.set_min_size(size);
         window.xdg_window.set_max_size(size);
     }
@@ -449,30 +556,85 @@ fn render_windows<T>(
         window.needs_redraw = false;
 
         if let Some(draw_state) = app.draw_window(data, window.id) {
+            // nothing changed, and the last frame is still on screen
+            if !draw_state.changed && !window.needs_present {
+                app.frame_rendered(window.id, Duration::ZERO);
+                continue;
+            }
+
+            window.needs_present = false;
+
+            let start = Instant::now();
+            let _span = trace_span!("present", window = ?window.id).entered();
+
             let egl_surface = window.egl_surface.as_ref().unwrap();
             let renderer = window.renderer.as_mut().unwrap();
 
             egl_surface.make_current()?;
 
+            let (width, height) = window.buffer_size();
+
             unsafe {
                 renderer
                     .render(
                         draw_state.canvas,
                         draw_state.clear_color,
-                        window.physical_width,
-                        window.physical_height,
+                        width,
+                        height,
                         window.scale_factor,
                     )
                     .unwrap();
             }
 
             egl_surface.swap_buffers()?;
+
+            app.frame_rendered(window.id, start.elapsed());
         }
     }
 
     Ok(())
 }
 
+fn set_cursor_grab(
+    window: &mut WindowState,
+    pointers: &[PointerState],
+    pointer_constraints: &PointerConstraintsState,
+    qhandle: &QueueHandle<State>,
+) {
+    for constraint in window.constraints.drain(..) {
+        constraint.destroy();
+    }
+
+    let surface = window.xdg_window.wl_surface();
+
+    for pointer in pointers {
+        let pointer = pointer.pointer.pointer();
+        let lifetime = Lifetime::Persistent;
+
+        let constraint = match window.cursor_grab {
+            CursorGrab::None => return,
+            CursorGrab::Confined => pointer_constraints
+                .confine_pointer(surface, pointer, None, lifetime, qhandle)
+                .map(PointerConstraint::Confined),
+            CursorGrab::Locked => pointer_constraints
+                .lock_pointer(surface, pointer, None, lifetime, qhandle)
+                .map(PointerConstraint::Locked),
+        };
+
+        match constraint {
+            Ok(constraint) => window.constraints.push(constraint),
+            Err(err) => {
+                warn!(
+                    "Failed to grab the cursor, the compositor might not support pointer constraints: {}",
+                    err
+                );
+
+                return;
+            }
+        }
+    }
+}
+
 fn set_cursor_icons(state: &mut State) {
     for window in &mut state.windows {
         if !window.set_cursor_icon {
@@ -483,11 +645,22 @@ fn set_cursor_icons(state: &mut State) {
 
         let cursor_icon = window.frame_cursor_icon.unwrap_or(window.cursor_icon);
 
+        // the cursor is always shown over the decorations
+        let hidden = !window.cursor_visible && window.frame_cursor_icon.is_none();
+
         for pointer in &state.pointers {
             if !window.pointers.contains(&pointer.pointer.pointer().id()) {
                 continue;
             }
 
+            if hidden {
+                if let Err(err) = pointer.pointer.hide_cursor() {
+                    warn!("Failed to hide cursor: {}", err);
+                }
+
+                continue;
+            }
+
             if let Err(err) = pointer.pointer.set_cursor(&state.conn, cursor_icon) {
                 warn!("Failed to set cursor icon: {}", err);
             }
@@ -544,12 +717,9 @@ fn handle_event<T>(
             object_id,
             position,
         } => {
-            if let Some(window) = window_by_id(&mut state.windows, id) {
-                let position = position / window.scale_factor;
-                let pointer_id = PointerId::from_hash(&object_id);
-
-                app.pointer_moved(data, id, pointer_id, position);
-            }
+            // wayland pointer positions are already in logical pixels
+            let pointer_id = PointerId::from_hash(&object_id);
+            app.pointer_moved(data, id, pointer_id, position);
         }
 
         Event::PointerButton {
@@ -571,6 +741,15 @@ fn handle_event<T>(
             app.pointer_scrolled(data, id, pointer_id, delta);
         }
 
+        Event::PointerRelativeMoved {
+            id,
+            object_id,
+            delta,
+        } => {
+            let pointer_id = PointerId::from_hash(&object_id);
+            app.pointer_relative_moved(data, id, pointer_id, delta);
+        }
+
         Event::Keyboard {
             id,
             key,
@@ -584,6 +763,10 @@ fn handle_event<T>(
         Event::Modifiers { modifiers } => {
             app.modifiers_changed(modifiers);
         }
+
+        Event::Monitors { monitors } => {
+            app.monitors_changed(data, monitors);
+        }
     }
 
     Ok(())
@@ -602,7 +785,11 @@ struct State {
     subcompositor: Arc<SubcompositorState>,
     xdg_shell: XdgShell,
     seat: SeatState,
+    pointer_constraints: PointerConstraintsState,
+    relative_pointer: RelativePointerState,
     shm: Shm,
+    fractional_scale: Option<WpFractionalScaleManagerV1>,
+    viewporter: Option<WpViewporter>,
 
     output: OutputState,
     registry: RegistryState,
@@ -618,11 +805,68 @@ impl State {
     fn needs_redraw(&self) -> bool {
         self.windows.iter().any(|w| w.needs_redraw)
     }
+
+    // report the outputs as monitors, wayland doesn't expose the work area
+    fn update_monitors(&mut self) {
+        let mut monitors = Vec::new();
+
+        for output in self.output.outputs() {
+            let Some(info) = self.output.info(&output) else {
+                continue;
+            };
+
+            let scale = info.scale_factor as f32;
+            let (x, y) = info.logical_position.unwrap_or(info.location);
+
+            let (width, height) = match info.logical_size {
+                Some((width, height)) => (width as f32, height as f32),
+                None => {
+                    let mode = info.modes.iter().find(|mode| mode.current);
+                    let (width, height) = mode.map_or((0, 0), |mode| mode.dimensions);
+
+                    (width as f32 / scale, height as f32 / scale)
+                }
+            };
+
+            let name = match info.name {
+                Some(name) => name,
+                None => format!("{} {}", info.make, info.model),
+            };
+
+            let bounds = Rect::min_size(Point::new(x as f32, y as f32), Size::new(width, height));
+
+            monitors.push(Monitor {
+                id: MonitorId::from_name(&name),
+                name,
+                bounds,
+                work_area: bounds,
+                scale,
+                primary: false,
+            });
+        }
+
+        self.events.push(Event::Monitors { monitors });
+    }
+}
+
+enum PointerConstraint {
+    Confined(ZwpConfinedPointerV1),
+    Locked(ZwpLockedPointerV1),
+}
+
+impl PointerConstraint {
+    fn destroy(self) {
+        match self {
+            PointerConstraint::Confined(confined) => confined.destroy(),
+            PointerConstraint::Locked(locked) => locked.destroy(),
+        }
+    }
 }
 
 struct PointerState {
     seat: WlSeat,
     pointer: ThemedPointer,
+    relative_pointer: Option<ZwpRelativePointerV1>,
     last_button_serial: u32,
 }
 
@@ -632,12 +876,25 @@ struct KeyboardState {
     keyboard: WlKeyboard,
     xkb_keyboard: XkbKeyboard,
 
+    // keys that are held down, by keycode
+    pressed: Vec<(u32, Key, Option<Code>)>,
+
     // gap, delay
     repeat: Option<(Duration, Duration)>,
-    repeat_keysym: Option<Keysym>,
+    repeat_keycode: Option<u32>,
     repeat_token: Option<RegistrationToken>,
 }
 
+impl KeyboardState {
+    fn stop_repeat(&mut self, loop_handle: &LoopHandle<'static, State>) {
+        self.repeat_keycode = None;
+
+        if let Some(token) = self.repeat_token.take() {
+            loop_handle.remove(token);
+        }
+    }
+}
+
 enum Event {
     Resized {
         id: WindowId,
@@ -678,6 +935,12 @@ enum Event {
         delta: Vector,
     },
 
+    PointerRelativeMoved {
+        id: WindowId,
+        object_id: ObjectId,
+        delta: Vector,
+    },
+
     Keyboard {
         id: WindowId,
         key: Key,
@@ -689,6 +952,10 @@ enum Event {
     Modifiers {
         modifiers: ori_core::event::Modifiers,
     },
+
+    Monitors {
+        monitors: Vec<Monitor>,
+    },
 }
 
 #[allow(unused)]
@@ -696,12 +963,16 @@ struct WindowState {
     id: WindowId,
 
     needs_redraw: bool,
-    physical_width: u32,
-    physical_height: u32,
+    // whether a new frame has to be committed, e.g. after a resize or a configure
+    needs_present: bool,
+    width: u32,
+    height: u32,
     scale_factor: f32,
     cursor_icon: CursorIcon,
     frame_cursor_icon: Option<CursorIcon>,
     set_cursor_icon: bool,
+    cursor_visible: bool,
+    cursor_grab: CursorGrab,
     title: String,
     maximized: bool,
     resizable: bool,
@@ -715,6 +986,9 @@ struct WindowState {
     egl_surface: Option<EglSurface>,
     renderer: Option<GlowRenderer>,
 
+    fractional_scale: Option<WpFractionalScaleV1>,
+    viewport: Option<WpViewport>,
+    constraints: Vec<PointerConstraint>,
     frame: Option<AdwaitaFrame<State>>,
     xdg_window: XdgWindow,
 }
@@ -741,8 +1015,8 @@ impl WindowState {
 
                 let (current_width, current_height) = frame.add_borders(
                     //
-                    self.physical_width,
-                    self.physical_height,
+                    self.width,
+                    self.height,
                 );
 
                 let one = NonZero::new(1).unwrap();
@@ -755,30 +1029,23 @@ impl WindowState {
                 let mut height = height.unwrap_or(one);
 
                 if !self.resizable {
-                    width = NonZero::new(self.physical_width).unwrap();
-                    height = NonZero::new(self.physical_height).unwrap();
+                    width = NonZero::new(self.width).unwrap();
+                    height = NonZero::new(self.height).unwrap();
                 }
 
                 frame.resize(width, height);
 
                 let (x, y) = frame.location();
 
-                self.physical_width = width.get();
-                self.physical_height = height.get();
+                self.width = width.get();
+                self.height = height.get();
                 self.needs_redraw = true;
 
                 let (outer_width, outer_height) = frame.add_borders(width.get(), height.get());
 
                 set_resizable(self, self.resizable);
 
-                let egl_surface = self.egl_surface.as_ref().unwrap();
-                let wl_egl_surface = self.wl_egl_surface.as_ref().unwrap();
-
-                // i have no idea why this is necessary, but it is
-                //
-                // KEEP MAKE CURRENT HERE!
-                egl_surface.make_current().unwrap();
-                wl_egl_surface.resize(width.get() as i32, height.get() as i32, 0, 0);
+                self.resize_buffer();
                 self.xdg_window.xdg_surface().set_window_geometry(
                     x,
                     y,
@@ -797,28 +1064,21 @@ impl WindowState {
                     frame.set_hidden(true);
                 }
 
-                let mut width = width.map_or(self.physical_width, |w| w.get());
-                let mut height = height.map_or(self.physical_height, |h| h.get());
+                let mut width = width.map_or(self.width, |w| w.get());
+                let mut height = height.map_or(self.height, |h| h.get());
 
                 if !self.resizable {
-                    width = self.physical_width;
-                    height = self.physical_height;
+                    width = self.width;
+                    height = self.height;
                 }
 
-                self.physical_width = width;
-                self.physical_height = height;
+                self.width = width;
+                self.height = height;
                 self.needs_redraw = true;
 
                 set_resizable(self, self.resizable);
 
-                let egl_surface = self.egl_surface.as_ref().unwrap();
-                let wl_egl_surface = self.wl_egl_surface.as_ref().unwrap();
-
-                // i have no idea why this is necessary, but it is
-                //
-                // KEEP MAKE CURRENT HERE!
-                egl_surface.make_current().unwrap();
-                wl_egl_surface.resize(width as i32, height as i32, 0, 0);
+                self.resize_buffer();
                 self.xdg_window.set_window_geometry(0, 0, width, height);
 
                 Some(Event::Resized {
@@ -829,6 +1089,78 @@ impl WindowState {
             }
         }
     }
+
+    // the size of the buffer in physical pixels
+    fn buffer_size(&self) -> (u32, u32) {
+        let width = (self.width as f32 * self.scale_factor).round() as u32;
+        let height = (self.height as f32 * self.scale_factor).round() as u32;
+
+        (width.max(1), height.max(1))
+    }
+
+    fn resize_buffer(&mut self) {
+        let (width, height) = self.buffer_size();
+        self.needs_present = true;
+
+        // the viewport scales the buffer down to the logical size of the surface
+        if let Some(ref viewport) = self.viewport {
+            viewport.set_destination(self.width as i32, self.height as i32);
+        }
+
+        let (Some(egl_surface), Some(wl_egl_surface)) = (&self.egl_surface, &self.wl_egl_surface)
+        else {
+            return;
+        };
+
+        // i have no idea why this is necessary, but it is
+        //
+        // KEEP MAKE CURRENT HERE!
+        egl_surface.make_current().unwrap();
+        wl_egl_surface.resize(width as i32, height as i32, 0, 0);
+    }
+
+    fn rescale(&mut self, scale: f32) -> Option<Event> {
+        // without a viewport, buffers can only be scaled by integer factors
+        let scale = match self.viewport {
+            Some(_) => scale,
+            None => scale.round().max(1.0),
+        };
+
+        if scale == self.scale_factor {
+            return None;
+        }
+
+        if let Some(ref mut frame) = self.frame {
+            frame.set_scaling_factor(scale as f64);
+        }
+
+        if self.viewport.is_none() {
+            let surface = self.xdg_window.wl_surface();
+            surface.set_buffer_scale(scale as i32);
+        }
+
+        self.scale_factor = scale;
+        self.needs_redraw = true;
+        self.resize_buffer();
+
+        Some(Event::Scaled { id: self.id, scale })
+    }
+}
+
+impl Drop for WindowState {
+    fn drop(&mut self) {
+        if let Some(ref fractional_scale) = self.fractional_scale {
+            fractional_scale.destroy();
+        }
+
+        if let Some(ref viewport) = self.viewport {
+            viewport.destroy();
+        }
+
+        for constraint in self.constraints.drain(..) {
+            constraint.destroy();
+        }
+    }
 }
 
 fn window_index_by_id(windows: &[WindowState], id: WindowId) -> Option<usize> {
@@ -862,18 +1194,17 @@ impl CompositorHandler for State {
         surface: &WlSurface,
         new_factor: i32,
     ) {
-        if let Some(window) = window_by_surface(&mut self.windows, surface) {
-            if let Some(ref mut frame) = window.frame {
-                frame.set_scaling_factor(new_factor as f64);
-            }
+        let Some(window) = window_by_surface(&mut self.windows, surface) else {
+            return;
+        };
 
-            window.scale_factor = new_factor as f32;
-            window.needs_redraw = true;
+        // the fractional scale is more precise, prefer that when available
+        if window.fractional_scale.is_some() {
+            return;
+        }
 
-            self.events.push(Event::Scaled {
-                id: window.id,
-                scale: new_factor as f32,
-            });
+        if let Some(event) = window.rescale(new_factor as f32) {
+            self.events.push(event);
         }
     }
 
@@ -919,11 +1250,16 @@ impl OutputHandler for State {
         &mut self.output
     }
 
-    fn new_output(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _output: WlOutput) {}
+    fn new_output(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _output: WlOutput) {
+        self.update_monitors();
+    }
 
-    fn update_output(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _output: WlOutput) {}
+    fn update_output(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _output: WlOutput) {
+        self.update_monitors();
+    }
 
     fn output_destroyed(&mut self, _conn: &Connection, _qh: &QueueHandle<Self>, _output: WlOutput) {
+        self.update_monitors();
     }
 }
 
@@ -944,12 +1280,15 @@ impl WindowHandler for State {
     ) {
         if let Some(window) = window_by_surface(&mut self.windows, window.wl_surface()) {
             window.last_configure = Some(configure.clone());
+            window.needs_present = true;
 
             if window.renderer.is_none() {
+                let (width, height) = window.buffer_size();
+
                 let wl_egl_surface = WlEglSurface::new(
                     window.xdg_window.wl_surface().id(),
-                    window.physical_width as i32,
-                    window.physical_height as i32,
+                    width as i32,
+                    height as i32,
                 )
                 .unwrap();
 
@@ -994,6 +1333,7 @@ impl WindowHandler for State {
                 .unwrap();
 
                 frame.set_title(&window.title);
+                frame.set_scaling_factor(window.scale_factor as f64);
                 window.frame = Some(frame);
             }
 
@@ -1035,13 +1375,26 @@ impl SeatHandler for State {
             );
 
             if let Ok(pointer) = pointer {
+                // relative motion is optional, not all compositors support it
+                let relative_pointer = (self.relative_pointer)
+                    .get_relative_pointer(pointer.pointer(), qh)
+                    .ok();
+
                 let state = PointerState {
                     seat: seat.clone(),
                     pointer,
+                    relative_pointer,
                     last_button_serial: 0,
                 };
 
                 self.pointers.push(state);
+
+                // constrain the new pointer in windows that have grabbed the cursor
+                for window in &mut self.windows {
+                    if window.cursor_grab != CursorGrab::None {
+                        set_cursor_grab(window, &self.pointers, &self.pointer_constraints, qh);
+                    }
+                }
             }
         }
 
@@ -1054,8 +1407,10 @@ impl SeatHandler for State {
                 keyboard,
                 xkb_keyboard,
 
+                pressed: Vec::new(),
+
                 repeat: None,
-                repeat_keysym: None,
+                repeat_keycode: None,
                 repeat_token: None,
             };
 
@@ -1072,6 +1427,10 @@ impl SeatHandler for State {
     ) {
         if capability == Capability::Pointer {
             for pointer in self.pointers.drain(..) {
+                if let Some(relative_pointer) = pointer.relative_pointer {
+                    relative_pointer.destroy();
+                }
+
                 pointer.pointer.pointer().release();
             }
         }
@@ -1300,7 +1659,21 @@ impl Dispatch<WlKeyboard, ()> for State {
             KeyboardEvent::Leave { surface, .. } => {
                 if let Some(window) = window_by_surface(&mut state.windows, &surface) {
                     window.keyboards.retain(|id| *id != keyboard.keyboard.id());
+
+                    // we won't receive the release events for keys held when the window loses
+                    // focus, so release them here to avoid them getting stuck
+                    for (_, key, code) in keyboard.pressed.drain(..) {
+                        state.events.push(Event::Keyboard {
+                            id: window.id,
+                            key,
+                            code,
+                            text: None,
+                            pressed: false,
+                        });
+                    }
                 }
+
+                keyboard.stop_repeat(&state.loop_handle);
             }
             KeyboardEvent::Key {
                 key: scancode,
@@ -1318,14 +1691,20 @@ impl Dispatch<WlKeyboard, ()> for State {
                 let layout = xkb_state.layout();
 
                 let keycode = scancode + 8;
-                let code = Code::from_linux_scancode(scancode as u8);
+                let code = Code::from_linux_scancode(scancode);
                 let keysym_raw = keymap.first_keysym(layout, keycode).unwrap();
                 let keysym = xkb_state.get_one_sym(keycode);
                 let key = keyboard.xkb_keyboard.keysym_to_key(keysym_raw);
-                let text = keyboard.xkb_keyboard.keysym_to_utf8(keysym);
+                let repeats = keymap.key_repeats(keycode);
 
                 let pressed = matches!(key_state, WEnum::Value(KeyState::Pressed));
 
+                // only presses are fed through the compose state
+                let text = match pressed {
+                    true => keyboard.xkb_keyboard.key_text(keysym),
+                    false => keyboard.xkb_keyboard.keysym_to_utf8(keysym),
+                };
+
                 let mut window_ids = Vec::new();
 
                 for window in &mut state.windows {
@@ -1345,24 +1724,38 @@ impl Dispatch<WlKeyboard, ()> for State {
                 }
 
                 if !pressed {
-                    keyboard.repeat_keysym = None;
+                    keyboard
+                        .pressed
+                        .retain(|&(pressed, _, _)| pressed != keycode);
 
-                    if let Some(token) = keyboard.repeat_token.take() {
-                        state.loop_handle.remove(token);
+                    // releasing a key other than the repeating one shouldn't stop the repeat
+                    if keyboard.repeat_keycode == Some(keycode) {
+                        keyboard.stop_repeat(&state.loop_handle);
                     }
 
                     return;
                 }
 
+                if !keyboard
+                    .pressed
+                    .iter()
+                    .any(|&(pressed, _, _)| pressed == keycode)
+                {
+                    keyboard.pressed.push((keycode, key, code));
+                }
+
+                // only the most recently pressed key is repeated
+                keyboard.stop_repeat(&state.loop_handle);
+
                 let Some((_, delay)) = keyboard.repeat else {
                     return;
                 };
 
-                if !keymap.key_repeats(keycode) {
+                if !repeats {
                     return;
                 }
 
-                keyboard.repeat_keysym = Some(keysym);
+                keyboard.repeat_keycode = Some(keycode);
 
                 let timer = Timer::from_duration(delay);
                 let kb_id = keyboard.keyboard.id();
@@ -1371,7 +1764,7 @@ impl Dispatch<WlKeyboard, ()> for State {
                         return TimeoutAction::Drop;
                     };
 
-                    if keyboard.repeat_keysym != Some(keysym) {
+                    if keyboard.repeat_keycode != Some(keycode) {
                         return TimeoutAction::Drop;
                     }
 
@@ -1415,12 +1808,8 @@ impl Dispatch<WlKeyboard, ()> for State {
             }
             KeyboardEvent::RepeatInfo { rate, delay } => match rate {
                 0 => {
-                    keyboard.repeat_keysym = None;
                     keyboard.repeat = None;
-
-                    if let Some(token) = keyboard.repeat_token.take() {
-                        state.loop_handle.remove(token);
-                    }
+                    keyboard.stop_repeat(&state.loop_handle);
                 }
                 _ => {
                     let rate = Duration::from_micros(1_000_000 / rate as u64);
@@ -1434,6 +1823,137 @@ impl Dispatch<WlKeyboard, ()> for State {
     }
 }
 
+impl Dispatch<WpFractionalScaleV1, ()> for State {
+    fn event(
+        state: &mut Self,
+        proxy: &WpFractionalScaleV1,
+        event: <WpFractionalScaleV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+        let FractionalScaleEvent::PreferredScale { scale } = event else {
+            return;
+        };
+
+        let window = state.windows.iter_mut().find(|window| {
+            // find the window the fractional scale belongs to
+            window.fractional_scale.as_ref() == Some(proxy)
+        });
+
+        if let Some(window) = window {
+            // the scale is sent as a fraction with a denominator of 120
+            if let Some(event) = window.rescale(scale as f32 / 120.0) {
+                state.events.push(event);
+            }
+        }
+    }
+}
+
+// the remaining scaling objects have no events
+impl Dispatch<WpFractionalScaleManagerV1, ()> for State {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WpFractionalScaleManagerV1,
+        _event: <WpFractionalScaleManagerV1 as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<WpViewporter, ()> for State {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WpViewporter,
+        _event: <WpViewporter as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<WpViewport, ()> for State {
+    fn event(
+        _state: &mut Self,
+        _proxy: &WpViewport,
+        _event: <WpViewport as Proxy>::Event,
+        _data: &(),
+        _conn: &Connection,
+        _qhandle: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl RelativePointerHandler for State {
+    fn relative_pointer_motion(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _relative_pointer: &ZwpRelativePointerV1,
+        pointer: &WlPointer,
+        event: RelativeMotionEvent,
+    ) {
+        let (x, y) = event.delta;
+        let delta = Vector::new(x as f32, y as f32);
+
+        // the motion is sent to the windows the pointer is over
+        for window in &self.windows {
+            if window.pointers.contains(&pointer.id()) {
+                self.events.push(Event::PointerRelativeMoved {
+                    id: window.id,
+                    object_id: pointer.id(),
+                    delta,
+                });
+            }
+        }
+    }
+}
+
+impl PointerConstraintsHandler for State {
+    fn confined(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _confined_pointer: &ZwpConfinedPointerV1,
+        _surface: &WlSurface,
+        _pointer: &WlPointer,
+    ) {
+    }
+
+    fn unconfined(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _confined_pointer: &ZwpConfinedPointerV1,
+        _surface: &WlSurface,
+        _pointer: &WlPointer,
+    ) {
+    }
+
+    fn locked(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _locked_pointer: &ZwpLockedPointerV1,
+        _surface: &WlSurface,
+        _pointer: &WlPointer,
+    ) {
+    }
+
+    fn unlocked(
+        &mut self,
+        _conn: &Connection,
+        _qh: &QueueHandle<Self>,
+        _locked_pointer: &ZwpLockedPointerV1,
+        _surface: &WlSurface,
+        _pointer: &WlPointer,
+    ) {
+    }
+}
+
 impl ProvidesRegistryState for State {
     fn registry(&mut self) -> &mut RegistryState {
         &mut self.registry
@@ -1463,6 +1983,8 @@ delegate_shm!(State);
 
 delegate_seat!(State);
 delegate_pointer!(State);
+delegate_pointer_constraints!(State);
+delegate_relative_pointer!(State);
 
 delegate_xdg_shell!(State);
 delegate_xdg_window!(State);
@@ -1,11 +1,14 @@
 use std::{
     collections::{hash_map::Entry, HashMap},
+    ffi::c_void,
+    num::NonZeroU32,
+    ptr::NonNull,
     sync::{
         mpsc::{Receiver, RecvTimeoutError, Sender},
         Arc,
     },
     thread::{self, JoinHandle},
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 use as_raw_xcb_connection::AsRawXcbConnection;
@@ -15,18 +18,20 @@ use ori_core::{
     command::CommandWaker,
     event::{Code, Modifiers, PointerButton, PointerId},
     image::Image,
-    layout::{Point, Vector},
-    window::{Cursor, Window, WindowId, WindowUpdate},
+    layout::{Point, Rect, Size, Vector},
+    raw_window_handle::{XcbDisplayHandle, XcbWindowHandle},
+    window::{Cursor, CursorGrab, Monitor, MonitorId, Window, WindowId, WindowUpdate},
 };
 use ori_glow::GlowRenderer;
 
-use tracing::warn;
+use tracing::{trace_span, warn};
 use x11rb::{
     atom_manager,
     connection::{Connection, RequestConnection},
     cursor::Handle as CursorHandle,
     properties::WmSizeHints,
     protocol::{
+        randr::{ConnectionExt as _, NotifyMask as RandrNotifyMask},
         render::{ConnectionExt as _, PictType},
         sync::{ConnectionExt as _, Int64},
         xkb::{
@@ -35,9 +40,9 @@ use x11rb::{
         },
         xproto::{
             AtomEnum, ChangeWindowAttributesAux, ClientMessageData, ClientMessageEvent,
-            ColormapAlloc, ConfigureWindowAux, ConnectionExt as _, CreateWindowAux,
-            Cursor as XCursor, EventMask, ModMask, PropMode, VisualClass, Visualid, WindowClass,
-            CLIENT_MESSAGE_EVENT,
+            ColormapAlloc, ConfigureWindowAux, ConnectionExt as _, CreateGCAux, CreateWindowAux,
+            Cursor as XCursor, EventMask, GrabMode, GrabStatus, ModMask, PropMode, Rectangle,
+            VisualClass, Visualid, WindowClass, CLIENT_MESSAGE_EVENT,
         },
         Event as XEvent,
     },
@@ -49,7 +54,10 @@ use x11rb::{
 
 use crate::platform::{
     egl::{EglContext, EglNativeDisplay, EglSurface},
-    linux::xkb::{XkbContext, XkbKeyboard},
+    linux::{
+        open, settings,
+        xkb::{XkbContext, XkbKeyboard},
+    },
 };
 
 use super::{clipboard::X11ClipboardServer, X11Error};
@@ -77,6 +85,7 @@ atom_manager! {
         _NET_WM_WINDOW_TYPE_NORMAL,
         _NET_WM_WINDOW_TYPE_DIALOG,
         _NET_WM_WINDOW_TYPE_DOCK,
+        _NET_WORKAREA,
     }
 }
 
@@ -89,7 +98,14 @@ struct X11Window {
     egl_surface: EglSurface,
     renderer: GlowRenderer,
     needs_redraw: bool,
+    // whether the last frame is no longer on screen, e.g. after a resize or an expose
+    needs_present: bool,
     sync_counter: Option<u32>,
+    cursor: Cursor,
+    cursor_visible: bool,
+    cursor_grab: CursorGrab,
+    // the last root position of the pointer, used for relative motion
+    last_motion: Option<Point>,
 }
 
 impl X11Window {
@@ -420,6 +436,7 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), X11Error> {
         database,
         cursor_handle,
         cursors: HashMap::new(),
+        invisible_cursor: None,
 
         egl_context,
         xkb_context,
@@ -427,15 +444,34 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), X11Error> {
     };
 
     state.app.init(data);
+
+    // watch whether the user prefers reduced motion, and the system color scheme, the
+    // watcher is stopped when it's dropped at exit
+    let _settings = settings::watch(state.app.proxy());
+
+    // report the monitors, randr might not be available so this isn't fatal
+    if let Err(err) = state.init_randr().and_then(|_| state.update_monitors(data)) {
+        warn!("Failed to query the monitors: {:?}", err);
+    }
+
     state.handle_app_requests(data)?;
 
     while state.running {
         state.conn.flush()?;
 
+        // wake up in time for the next timer
+        let timeout = match state.app.next_timer() {
+            Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+            None => Duration::from_millis(2),
+        };
+
         let mut event_option = if state.needs_redraw() {
             state.event_rx.try_recv().ok()
         } else {
-            match state.event_rx.recv_timeout(Duration::from_millis(2)) {
+            match state
+                .event_rx
+                .recv_timeout(timeout.min(Duration::from_millis(2)))
+            {
                 Ok(event) => Some(event),
                 Err(err) => match err {
                     RecvTimeoutError::Timeout => None,
@@ -454,6 +490,9 @@ pub fn run<T>(app: AppBuilder<T>, data: &mut T) -> Result<(), X11Error> {
             event_option = state.event_rx.try_recv().ok();
         }
 
+        state.app.handle_timers(data);
+        state.handle_app_requests(data)?;
+
         state.render_windows(data)?;
         state.handle_app_requests(data)?;
 
@@ -478,6 +517,7 @@ struct X11App<T> {
     database: Database,
     cursor_handle: CursorHandle,
     cursors: HashMap<Cursor, XCursor>,
+    invisible_cursor: Option<XCursor>,
 
     egl_context: EglContext,
     xkb_context: XkbContext,
@@ -506,7 +546,7 @@ impl<T> X11App<T> {
     fn open_window(
         &mut self,
         data: &mut T,
-        window: Window,
+        mut window: Window,
         ui: UiBuilder<T>,
     ) -> Result<(), X11Error> {
         let win_id = self.conn.generate_id()?;
@@ -631,15 +671,33 @@ impl<T> X11App<T> {
             egl_surface,
             renderer,
             needs_redraw: true,
+            needs_present: true,
             sync_counter,
+            cursor: Cursor::Default,
+            cursor_visible: window.cursor_visible,
+            cursor_grab: window.cursor_grab,
+            last_motion: None,
         };
 
+        if !window.cursor_visible {
+            self.set_cursor_visible(win_id, Cursor::Default, false)?;
+        }
+
         if window.visible {
             self.conn.map_window(win_id)?;
         }
 
         self.conn.flush()?;
 
+        let window_handle = XcbWindowHandle::new(NonZeroU32::new(win_id).unwrap());
+        let display_handle = XcbDisplayHandle::new(
+            NonNull::new(self.conn.as_raw_xcb_connection() as *mut c_void),
+            self.screen as i32,
+        );
+
+        // SAFETY: the connection outlives the window, which is destroyed when it's closed
+        unsafe { window.set_raw_handles(window_handle.into(), display_handle.into()) };
+
         self.windows.push(x11_window);
         self.app.add_window(data, ui, window);
 
@@ -672,6 +730,17 @@ impl<T> X11App<T> {
             window.needs_redraw = false;
 
             if let Some(state) = self.app.draw_window(data, window.ori_id) {
+                // nothing changed, and the last frame is still on screen
+                if !state.changed && !window.needs_present {
+                    self.app.frame_rendered(window.ori_id, Duration::ZERO);
+                    continue;
+                }
+
+                window.needs_present = false;
+
+                let start = Instant::now();
+                let _span = trace_span!("present", window = ?window.ori_id).entered();
+
                 unsafe {
                     window.egl_surface.make_current()?;
 
@@ -688,6 +757,8 @@ impl<T> X11App<T> {
 
                     window.egl_surface.swap_buffers()?;
                 }
+
+                self.app.frame_rendered(window.ori_id, start.elapsed());
             }
         }
 
@@ -717,6 +788,91 @@ impl<T> X11App<T> {
         Ok(())
     }
 
+    // x11 has no way to hide the cursor, so we use a cursor with a blank image instead
+    fn invisible_cursor(&mut self, x_window: u32) -> Result<XCursor, X11Error> {
+        if let Some(cursor) = self.invisible_cursor {
+            return Ok(cursor);
+        }
+
+        let pixmap = self.conn.generate_id()?;
+        self.conn.create_pixmap(1, pixmap, x_window, 1, 1)?;
+
+        // the contents of a new pixmap are undefined, so clear it
+        let gc = self.conn.generate_id()?;
+        let aux = CreateGCAux::new().foreground(0);
+        self.conn.create_gc(gc, pixmap, &aux)?;
+        self.conn.poly_fill_rectangle(
+            pixmap,
+            gc,
+            &[Rectangle {
+                x: 0,
+                y: 0,
+                width: 1,
+                height: 1,
+            }],
+        )?;
+        self.conn.free_gc(gc)?;
+
+        let cursor = self.conn.generate_id()?;
+        (self.conn).create_cursor(cursor, pixmap, pixmap, 0, 0, 0, 0, 0, 0, 0, 0)?;
+        self.conn.free_pixmap(pixmap)?;
+
+        self.invisible_cursor = Some(cursor);
+        Ok(cursor)
+    }
+
+    fn set_cursor_visible(
+        &mut self,
+        x_window: u32,
+        cursor: Cursor,
+        visible: bool,
+    ) -> Result<(), X11Error> {
+        if visible {
+            return self.set_cursor(x_window, cursor);
+        }
+
+        let cursor = self.invisible_cursor(x_window)?;
+        let aux = ChangeWindowAttributesAux::new().cursor(cursor);
+        self.conn.change_window_attributes(x_window, &aux)?;
+
+        Ok(())
+    }
+
+    fn set_cursor_grab(&mut self, x_window: u32, grab: CursorGrab) -> Result<(), X11Error> {
+        if grab == CursorGrab::None {
+            self.conn.ungrab_pointer(x11rb::CURRENT_TIME)?;
+            return Ok(());
+        }
+
+        if grab == CursorGrab::Locked {
+            warn!("Locking the cursor is not supported on X11, confining it instead");
+        }
+
+        let event_mask = EventMask::POINTER_MOTION
+            | EventMask::LEAVE_WINDOW
+            | EventMask::BUTTON_PRESS
+            | EventMask::BUTTON_RELEASE;
+
+        let reply = (self.conn)
+            .grab_pointer(
+                true,
+                x_window,
+                event_mask,
+                GrabMode::ASYNC,
+                GrabMode::ASYNC,
+                x_window,
+                x11rb::NONE,
+                x11rb::CURRENT_TIME,
+            )?
+            .reply()?;
+
+        if reply.status != GrabStatus::SUCCESS {
+            warn!("Failed to grab the cursor: {:?}", reply.status);
+        }
+
+        Ok(())
+    }
+
     fn handle_app_request(&mut self, data: &mut T, request: AppRequest<T>) -> Result<(), X11Error> {
         match request {
             AppRequest::OpenWindow(window, ui) => self.open_window(data, window, ui)?,
@@ -762,6 +918,7 @@ impl<T> X11App<T> {
 
                         window.physical_width = physical_width;
                         window.physical_height = physical_height;
+                        window.needs_present = true;
 
                         self.conn.configure_window(window.x11_id, &aux)?;
                     }
@@ -809,12 +966,29 @@ impl<T> X11App<T> {
                         self.request_redraw(id);
                     }
                     WindowUpdate::Cursor(cursor) => {
+                        window.cursor = cursor;
+
+                        if window.cursor_visible {
+                            let x_window = window.x11_id;
+                            self.set_cursor(x_window, cursor)?;
+                        }
+                    }
+                    WindowUpdate::CursorVisible(visible) => {
+                        window.cursor_visible = visible;
+
+                        let (x_window, cursor) = (window.x11_id, window.cursor);
+                        self.set_cursor_visible(x_window, cursor, visible)?;
+                    }
+                    WindowUpdate::CursorGrab(grab) => {
+                        window.cursor_grab = grab;
+
                         let x_window = window.x11_id;
-                        self.set_cursor(x_window, cursor)?;
+                        self.set_cursor_grab(x_window, grab)?;
                     }
                     WindowUpdate::Ime(_) => {}
                 }
             }
+            AppRequest::OpenUrl(url) => open::open_url(&url),
             AppRequest::Quit => self.running = false,
         }
 
@@ -823,9 +997,24 @@ impl<T> X11App<T> {
 
     fn handle_event(&mut self, data: &mut T, event: XEvent) -> Result<(), X11Error> {
         match event {
+            XEvent::RandrScreenChangeNotify(_) | XEvent::RandrNotify(_) => {
+                self.update_monitors(data)?;
+            }
+            XEvent::MapNotify(event) => {
+                // the pointer can only be grabbed when the window is viewable
+                if let Some(index) = self.get_window_x11(event.window) {
+                    let window = &self.windows[index];
+
+                    if window.cursor_grab != CursorGrab::None {
+                        let x_window = window.x11_id;
+                        self.set_cursor_grab(x_window, window.cursor_grab)?;
+                    }
+                }
+            }
             XEvent::Expose(event) => {
                 if let Some(index) = self.get_window_x11(event.window) {
                     self.windows[index].needs_redraw = true;
+                    self.windows[index].needs_present = true;
                 }
             }
             XEvent::ConfigureNotify(event) => {
@@ -856,6 +1045,7 @@ impl<T> X11App<T> {
                         let id = window.ori_id;
                         (self.app).window_resized(data, id, logical_width, logical_height);
                         window.needs_redraw = true;
+                        window.needs_present = true;
                     }
                 }
             }
@@ -885,6 +1075,7 @@ impl<T> X11App<T> {
 
                     self.conn.sync_set_counter(counter, Int64 { hi, lo })?;
                     window.needs_redraw = true;
+                    window.needs_present = true;
                 }
             }
             XEvent::MotionNotify(event) => {
@@ -893,15 +1084,26 @@ impl<T> X11App<T> {
                 if let Some(index) = self.get_window_x11(event.event) {
                     let pointer_id = PointerId::from_hash(&event.child);
 
+                    // x11 has no relative motion without xinput, so derive it from the cursor
+                    let root = Point::new(event.root_x as f32, event.root_y as f32);
+                    let last_motion = self.windows[index].last_motion.replace(root);
+
                     let window = &self.windows[index];
                     let id = window.ori_id;
+                    let scale_factor = window.scale_factor;
                     self.app
-                        .pointer_moved(data, id, pointer_id, position / window.scale_factor);
+                        .pointer_moved(data, id, pointer_id, position / scale_factor);
+
+                    if let Some(last_motion) = last_motion {
+                        let delta = (root - last_motion) / scale_factor;
+                        (self.app).pointer_relative_moved(data, id, pointer_id, delta);
+                    }
                 }
             }
             XEvent::LeaveNotify(event) => {
                 if let Some(index) = self.get_window_x11(event.event) {
                     let pointer_id = PointerId::from_hash(&event.child);
+                    self.windows[index].last_motion = None;
 
                     let id = self.windows[index].ori_id;
                     self.app.pointer_left(data, id, pointer_id);
@@ -944,12 +1146,12 @@ impl<T> X11App<T> {
                     let state = self.core_keyboard.state().unwrap();
 
                     let layout = state.layout();
-                    let code = Code::from_linux_scancode(event.detail - 8);
+                    let code = Code::from_linux_scancode(event.detail as u32 - 8);
                     let keysym_raw = keymap.first_keysym(layout, event.detail as _).unwrap();
                     let keysym = state.get_one_sym(event.detail as _);
 
                     let key = self.core_keyboard.keysym_to_key(keysym_raw);
-                    let text = self.core_keyboard.keysym_to_utf8(keysym);
+                    let text = self.core_keyboard.key_text(keysym);
 
                     let id = self.windows[index].ori_id;
                     (self.app).keyboard_key(data, id, key, code, text, true);
@@ -961,7 +1163,7 @@ impl<T> X11App<T> {
                     let state = self.core_keyboard.state().unwrap();
 
                     let layout = state.layout();
-                    let code = Code::from_linux_scancode(event.detail - 8);
+                    let code = Code::from_linux_scancode(event.detail as u32 - 8);
                     let keysym_raw = keymap.first_keysym(layout, event.detail as _).unwrap();
                     let keysym = state.get_one_sym(event.detail as _);
 
@@ -1062,6 +1264,87 @@ impl<T> X11App<T> {
         Ok((screen.root_depth, screen.root_visual))
     }
 
+    fn init_randr(&self) -> Result<(), X11Error> {
+        let root = self.conn.setup().roots[self.screen].root;
+
+        // monitors were added in randr 1.5
+        self.conn.randr_query_version(1, 5)?.reply()?;
+
+        let mask = RandrNotifyMask::SCREEN_CHANGE
+            | RandrNotifyMask::CRTC_CHANGE
+            | RandrNotifyMask::OUTPUT_CHANGE;
+        self.conn.randr_select_input(root, mask)?;
+
+        Ok(())
+    }
+
+    // get the area of the desktop not covered by panels and docks
+    fn get_work_area(&self) -> Result<Option<Rect>, X11Error> {
+        let root = self.conn.setup().roots[self.screen].root;
+
+        let reply = self.conn.get_property(
+            false,
+            root,
+            self.atoms._NET_WORKAREA,
+            AtomEnum::CARDINAL,
+            0,
+            4,
+        )?;
+
+        // the work area is reported per desktop, the first one is used
+        let area = reply
+            .reply()?
+            .value32()
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        Ok(match area[..] {
+            [x, y, width, height, ..] => Some(Rect::min_size(
+                Point::new(x as f32, y as f32),
+                Size::new(width as f32, height as f32),
+            )),
+            _ => None,
+        })
+    }
+
+    fn update_monitors(&mut self, data: &mut T) -> Result<(), X11Error> {
+        let root = self.conn.setup().roots[self.screen].root;
+
+        let reply = self.conn.randr_get_monitors(root, true)?.reply()?;
+        let work_area = self.get_work_area()?;
+
+        let mut monitors = Vec::new();
+
+        for info in reply.monitors {
+            let name = self.conn.get_atom_name(info.name)?.reply()?;
+            let name = String::from_utf8_lossy(&name.name).into_owned();
+
+            let bounds = Rect::min_size(
+                Point::new(info.x as f32, info.y as f32),
+                Size::new(info.width as f32, info.height as f32),
+            );
+
+            let work_area = match work_area.and_then(|area| area.try_intersection(bounds)) {
+                Some(area) => area,
+                None => bounds,
+            };
+
+            monitors.push(Monitor {
+                id: MonitorId::from_name(&name),
+                name,
+                bounds,
+                work_area,
+                scale: 1.0,
+                primary: info.primary,
+            });
+        }
+
+        self.app.monitors_changed(data, monitors);
+
+        Ok(())
+    }
+
     fn init_xkb(conn: &XCBConnection) -> Result<(), X11Error> {
         conn.xkb_use_extension(1, 0)?;
 
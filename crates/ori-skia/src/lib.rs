@@ -49,7 +49,8 @@ impl SkiaRenderer {
         self.update_surface(width, height);
 
         let skia_canvas = self.surface.as_mut().unwrap().canvas();
-        skia_canvas.clear(Self::skia_color(color));
+        // skia premultiplies the clear color, like the content drawn over it
+        skia_canvas.clear(Self::skia_color_4f(color));
 
         for primitive in canvas.primitives() {
             let transform = Affine::scale(Vector::all(scale_factor));
@@ -101,6 +102,23 @@ impl SkiaRenderer {
 
                 canvas.restore();
             }
+            Primitive::BackdropBlur { curve, radius } => {
+                let Some(blur) = skia_safe::image_filters::blur(
+                    (*radius, *radius),
+                    skia_safe::TileMode::Clamp,
+                    None,
+                    None,
+                ) else {
+                    return;
+                };
+
+                // a layer with a backdrop starts out as the blurred content behind it
+                canvas.save();
+                canvas.clip_path(&Self::skia_path(curve), None, true);
+                canvas.save_layer(&skia_safe::canvas::SaveLayerRec::default().backdrop(&blur));
+                canvas.restore();
+                canvas.restore();
+            }
         }
     }
 
@@ -218,12 +236,8 @@ impl SkiaRenderer {
     }
 
     fn skia_color(color: Color) -> skia_safe::Color {
-        skia_safe::Color::from_argb(
-            (color.a * 255.0) as u8,
-            (color.r * 255.0) as u8,
-            (color.g * 255.0) as u8,
-            (color.b * 255.0) as u8,
-        )
+        let [r, g, b, a] = color.to_rgba8();
+        skia_safe::Color::from_argb(a, r, g, b)
     }
 
     fn update_surface(&mut self, width: u32, height: u32) {
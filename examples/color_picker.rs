@@ -16,13 +16,18 @@ fn ui(data: &mut Data) -> impl View<Data> {
     let picker = color_picker()
         .color(data.color)
         .on_input(|cx, data: &mut Data, color| {
-            data.color = color;
-            cx.rebuild();
+            cx.set_if_changed(&mut data.color, color);
+        });
+
+    let hsv = hsv_picker()
+        .color(data.color)
+        .on_input(|cx, data: &mut Data, color| {
+            cx.set_if_changed(&mut data.color, color);
         });
 
     let color = background(data.color, height(30.0, ())).border_radius(8.0);
 
-    center(vstack![picker, color].align(Align::Stretch).gap(10.0))
+    center(vstack![picker, hsv, color].align(Align::Stretch).gap(10.0))
 }
 
 fn main() {
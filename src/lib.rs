@@ -37,20 +37,21 @@ pub mod log {
 pub mod prelude {
     //! Convenient imports for Ori.
 
-    pub use ori_app::{App, AppBuilder, AppCommand, AppDelegate, DelegateCx};
+    pub use ori_app::{App, AppBuilder, AppCommand, AppDelegate, DelegateCx, FramePacing};
 
     pub use ori_core::{
         canvas::{
             hex, hsl, hsla, hsv, hsva, okhsl, okhsla, okhsv, okhsva, oklab, oklaba, oklch, oklcha,
             rgb, rgba, BlendMode, BorderRadius, BorderWidth, Canvas, Color, Curve, FillRule, Paint,
-            Pattern, Shader, Stroke, StrokeCap, StrokeJoin,
+            Pattern, Plot, Shader, Shadow, Stroke, StrokeCap, StrokeJoin,
         },
         clipboard::Clipboard,
-        command::CommandProxy,
+        command::{CommandProxy, EventProxy},
         context::{BaseCx, BuildCx, DrawCx, EventCx, LayoutCx, RebuildCx},
         event::{
             Code, Event, Key, KeyPressed, Modifiers, PointerButton, PointerId, PointerMoved,
-            PointerPressed, PointerReleased, PointerScrolled, WindowCloseRequested,
+            PointerPressed, PointerRelativeMoved, PointerReleased, PointerScrolled, Shortcut,
+            TimerId, WindowCloseRequested,
         },
         image::{Image, ImageData, ImageId},
         layout::{
@@ -60,23 +61,31 @@ pub mod prelude {
         log::{debug, error, info, trace, warn},
         rebuild::Rebuild,
         style,
-        style::{comp, key, val, Style, Styled, Styles, Theme},
+        style::{
+            comp, em, key, rem, val, vh, vw, ColorScheme, Style, Styled, Styles, Theme, Viewport,
+        },
         text::{
             include_font, FontFamily, FontSource, FontStretch, FontStyle, FontWeight, Fonts,
-            TextAlign, TextBuffer, TextWrap,
+            TextAlign, TextBuffer, TextOverflow, TextWrap,
+        },
+        transition::{
+            ease, linear, spring, Easing, Interpolate, Keyframe, Keyframes, Playback, Spring,
+            Transition,
         },
-        transition::{ease, linear, Easing, Transition},
         view::{
             any, pod, AnyView, BoxedView, Pod, PodSeq, SeqState, State, View, ViewSeq, ViewState,
         },
         views::*,
-        window::{Cursor, Pointer, Window, WindowId, WindowSizing},
+        window::{Cursor, CursorGrab, Pointer, Window, WindowId, WindowSizing},
     };
 
     pub use ori_macro::{desktop, is_desktop, is_mobile, is_web, mobile, web, Build, Styled};
 
     #[cfg(feature = "image")]
     pub use ori_core::include_image;
+
+    #[cfg(feature = "debug")]
+    pub use ori_core::debug::{Inspector, RedrawLog, ViewStats};
 }
 
 #[cfg(feature = "dylib")]